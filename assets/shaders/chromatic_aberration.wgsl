@@ -0,0 +1,54 @@
+// Shader: chromatic_aberration
+// Purpose: Full-screen radial RGB channel separation, fringing the edges more than the center
+// Bindings: screen_texture (group 0, binding 0), screen_sampler (group 0, binding 1), aberration (group 0, binding 2)
+// Compatible with: Bevy 0.15 render graph (runs after bloom, before vignette)
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+// ============================================================================
+// UNIFORM STRUCTURES
+// ============================================================================
+
+struct ChromaticAberrationUniform {
+    // Red/blue offset at the screen corners in physical pixels (peaks in Act III)
+    strength: f32,
+    // Padding for 16-byte alignment
+    _padding_a: f32,
+    _padding_b: f32,
+    _padding_c: f32,
+}
+
+// ============================================================================
+// BINDINGS
+// ============================================================================
+
+@group(0) @binding(0)
+var screen_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var screen_sampler: sampler;
+
+@group(0) @binding(2)
+var<uniform> aberration: ChromaticAberrationUniform;
+
+// ============================================================================
+// CHROMATIC ABERRATION
+// ============================================================================
+
+// Distance from the center to a corner in UV space
+const CORNER_DISTANCE: f32 = 0.70710678;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Offset grows linearly from zero at the center to `strength` pixels at the corners
+    let texel = 1.0 / vec2<f32>(textureDimensions(screen_texture));
+    let direction = in.uv - vec2<f32>(0.5, 0.5);
+    let offset = direction / CORNER_DISTANCE * aberration.strength * texel;
+
+    // Red pushed outward, blue pulled inward, green untouched
+    let r = textureSample(screen_texture, screen_sampler, in.uv + offset).r;
+    let center = textureSample(screen_texture, screen_sampler, in.uv);
+    let b = textureSample(screen_texture, screen_sampler, in.uv - offset).b;
+
+    return vec4<f32>(r, center.g, b, center.a);
+}
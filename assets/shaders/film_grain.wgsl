@@ -0,0 +1,67 @@
+// Shader: film_grain
+// Purpose: Full-screen luminance-only film grain, re-seeded every frame from globals.time
+// Bindings: screen_texture (group 0, binding 0), screen_sampler (group 0, binding 1), grain (group 0, binding 2), globals (group 0, binding 3)
+// Compatible with: Bevy 0.15 render graph (runs after vignette, before tonemapping)
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+
+// ============================================================================
+// UNIFORM STRUCTURES
+// ============================================================================
+
+struct FilmGrainUniform {
+    // Peak per-pixel noise in linear space (default: 0.02)
+    amount: f32,
+    // Padding for 16-byte alignment
+    _padding_a: f32,
+    _padding_b: f32,
+    _padding_c: f32,
+}
+
+// ============================================================================
+// BINDINGS
+// ============================================================================
+
+@group(0) @binding(0)
+var screen_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var screen_sampler: sampler;
+
+@group(0) @binding(2)
+var<uniform> grain: FilmGrainUniform;
+
+@group(0) @binding(3)
+var<uniform> globals: Globals;
+
+// ============================================================================
+// UTILITY FUNCTIONS
+// ============================================================================
+
+// Fast pseudo-random hash function
+fn hash(p: vec2<f32>) -> f32 {
+    var p3 = fract(vec3<f32>(p.xyx) * 0.1031);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.x + p3.y) * p3.z);
+}
+
+// ============================================================================
+// FILM GRAIN
+// ============================================================================
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let scene = textureSample(screen_texture, screen_sampler, in.uv);
+
+    // Offset the pixel grid by a per-frame seed; fract keeps the hash input
+    // small so precision holds however long the show runs
+    let seed = vec2<f32>(fract(globals.time * 13.0), fract(globals.time * 17.0)) * 1024.0;
+    let noise = hash(in.position.xy + seed) * 2.0 - 1.0;
+
+    // Add the same linear offset to every channel, so the grain carries no
+    // color cast and its magnitude is exactly `amount`
+    let grained = max(scene.rgb + vec3<f32>(noise * grain.amount), vec3<f32>(0.0));
+
+    return vec4<f32>(grained, scene.a);
+}
@@ -0,0 +1,86 @@
+// Shader: focus_blur
+// Purpose: Depth-of-field style disc blur that grows with distance from the interaction center
+// Bindings: screen_texture (group 0, binding 0), screen_sampler (group 0, binding 1), blur (group 0, binding 2), globals (group 0, binding 3)
+// Compatible with: Bevy 0.15 render graph (runs after the main pass, before bloom)
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+
+// ============================================================================
+// UNIFORM STRUCTURES
+// ============================================================================
+
+struct FocusBlurUniform {
+    // Interaction center in screen UV
+    focus: vec2<f32>,
+    // Blur radius at full defocus in UV units
+    strength: f32,
+    // Distance from the focus that stays fully sharp (screen-height units)
+    sharp_radius: f32,
+    // Distance over which the blur ramps up to full strength
+    falloff: f32,
+    // Viewport width over height
+    aspect: f32,
+    // Padding for 16-byte alignment
+    _padding_a: f32,
+    _padding_b: f32,
+}
+
+// ============================================================================
+// BINDINGS
+// ============================================================================
+
+@group(0) @binding(0)
+var screen_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var screen_sampler: sampler;
+
+@group(0) @binding(2)
+var<uniform> blur: FocusBlurUniform;
+
+@group(0) @binding(3)
+var<uniform> globals: Globals;
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+// Taps per pixel, spread over a disc on a golden-angle spiral
+const SAMPLE_COUNT: u32 = 16u;
+
+const GOLDEN_ANGLE: f32 = 2.39996323;
+
+// Radius (UV) below which a pixel is treated as in focus
+const MIN_RADIUS: f32 = 0.0002;
+
+// ============================================================================
+// FOCUS BLUR
+// ============================================================================
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Measure in screen-height units so the sharp region stays round
+    let offset = (in.uv - blur.focus) * vec2<f32>(blur.aspect, 1.0);
+    let defocus = smoothstep(blur.sharp_radius, blur.sharp_radius + blur.falloff, length(offset));
+    let radius = blur.strength * defocus;
+
+    // In-focus pixels pass through untouched. Explicit-level sampling keeps
+    // this early return legal in non-uniform control flow.
+    let scene = textureSampleLevel(screen_texture, screen_sampler, in.uv, 0.0);
+    if radius < MIN_RADIUS {
+        return scene;
+    }
+
+    // Uniform disc: sqrt spacing keeps the taps evenly dense out to the rim
+    var sum = vec3<f32>(0.0);
+    for (var i = 0u; i < SAMPLE_COUNT; i += 1u) {
+        let t = (f32(i) + 0.5) / f32(SAMPLE_COUNT);
+        let angle = f32(i) * GOLDEN_ANGLE;
+        let disc = vec2<f32>(cos(angle), sin(angle)) * sqrt(t) * radius;
+        let uv = in.uv + vec2<f32>(disc.x / blur.aspect, disc.y);
+        sum += textureSampleLevel(screen_texture, screen_sampler, uv, 0.0).rgb;
+    }
+
+    return vec4<f32>(sum / f32(SAMPLE_COUNT), scene.a);
+}
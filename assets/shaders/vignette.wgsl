@@ -0,0 +1,52 @@
+// Shader: vignette
+// Purpose: Full-screen vignette pass, darkening toward the edges with a smooth radial falloff
+// Bindings: screen_texture (group 0, binding 0), screen_sampler (group 0, binding 1), vignette (group 0, binding 2)
+// Compatible with: Bevy 0.15 render graph (runs after bloom, before tonemapping)
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+// ============================================================================
+// UNIFORM STRUCTURES
+// ============================================================================
+
+struct VignetteUniform {
+    // Linear RGB the edges fade toward (default: black)
+    color: vec3<f32>,
+    // Darkness at the edges, driven down across Acts I-V
+    intensity: f32,
+    // Edge falloff (higher = softer)
+    smoothness: f32,
+}
+
+// ============================================================================
+// BINDINGS
+// ============================================================================
+
+@group(0) @binding(0)
+var screen_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var screen_sampler: sampler;
+
+@group(0) @binding(2)
+var<uniform> vignette: VignetteUniform;
+
+// ============================================================================
+// VIGNETTE
+// ============================================================================
+
+// Distance from center where darkening begins (corners sit at ~0.707)
+const VIGNETTE_START: f32 = 0.4;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let scene = textureSample(screen_texture, screen_sampler, in.uv);
+
+    // Softer vignettes stretch the ramp past the corners
+    let dist = distance(in.uv, vec2<f32>(0.5, 0.5));
+    let vignette_end = 0.6 + vignette.smoothness * 0.3;
+    let falloff = smoothstep(VIGNETTE_START, vignette_end, dist) * vignette.intensity;
+
+    // Fade toward the vignette color (black reproduces a plain darkening)
+    return vec4<f32>(mix(scene.rgb, vignette.color, falloff), scene.a);
+}
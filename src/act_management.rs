@@ -2,15 +2,25 @@
 //! Purpose: Manages the five-act narrative structure and state transitions for Chromatic Elegy
 //! Dependencies: types, resources, bevy::prelude
 
+use std::fmt;
+use std::path::PathBuf;
+
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::intro::AppState;
+use crate::components::{Particle, ParticleBehavior, ParticleMotion};
+use crate::interaction::{HyperspaceJumpEvent, ThreeFingerSwipe};
 use crate::resources::{
-    ActState, ActTimings, BackgroundGradients, CurrentBackground,
-    CurrentInteractionMode, InterpolatedActValues, PostProcessSettings,
+    ActState, ActTimings, BackgroundGradients, BaseInteractionMode, ColorInterpolation,
+    CurrentBackground, ExperiencePaused, InterpolatedActValues, PhysicsProfile,
+    PostProcessSettings, MAX_RESUME_DELTA_SECS,
+};
+use crate::types::{
+    in_fidget_state, Act, BloomComposite, GradientStyle, InteractionMode, ParticleBehaviorType,
 };
-use crate::types::{Act, TOTAL_DURATION_SECONDS};
-use crate::interaction::HyperspaceJumpEvent;
+use crate::visual::{color_lerp_in, color_to_hex, hex_to_color};
+
+pub use crate::types::ease_in_out_cubic;
 
 // =============================================================================
 // EVENTS
@@ -38,6 +48,42 @@ pub struct ActTransitionCompleted {
     pub act: Act,
 }
 
+/// Request to start a timed transition to a specific act.
+///
+/// Unlike a seek, the change animates over `ActTimings.transition_duration_ms`
+/// with the usual `ActTransitionStarted` / `ActTransitionCompleted` events.
+/// Elapsed time jumps to the target act's start, so automatic progression
+/// carries on from there.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoToAct(pub Act);
+
+/// Request to jump the timeline straight to `seconds` (clamped to one pass).
+///
+/// Unlike `GoToAct` there is no transition: the act, its progress, and every
+/// act-driven visual snap to the target on the same frame. Number keys 1-5
+/// seek to the start of each act.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct SeekTo {
+    /// Target position in the pass, in seconds
+    pub seconds: f32,
+}
+
+/// Request to start a timed transition to the next act (a performer's
+/// "advance" button). Ignored during the final act.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AdvanceAct;
+
+/// Event sent once per pass when the experience reaches its full duration.
+///
+/// Fires as `total_elapsed_seconds` crosses `ActTimings::total_seconds`, before
+/// the experience cycles back to Act I. Hosts can use it to advance a
+/// playlist or record analytics.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExperienceCompleted {
+    /// Number of full passes completed, starting at 1
+    pub pass: u32,
+}
+
 // =============================================================================
 // ACT-SPECIFIC CONSTANTS
 // =============================================================================
@@ -50,6 +96,10 @@ const ACT_SATURATION: [f32; 5] = [0.4, 0.6, 1.0, 0.7, 0.3];
 /// Index corresponds to Act enum: Emergence=0, Accumulation=1, etc.
 const ACT_DENSITY: [f32; 5] = [200.0, 1000.0, 5000.0, 2000.0, 100.0];
 
+/// Particle lifetime multiplier for each act.
+/// Energetic acts keep peas brief; calm late acts let them linger and accumulate.
+const ACT_LIFETIME_MULTIPLIER: [f32; 5] = [1.0, 1.0, 0.7, 1.2, 1.6];
+
 /// Chromatic aberration strength for each act.
 /// Increases dramatically in Act III (Crescendo).
 const ACT_CHROMATIC_ABERRATION: [f32; 5] = [0.0, 0.002, 0.008, 0.004, 0.001];
@@ -58,35 +108,371 @@ const ACT_CHROMATIC_ABERRATION: [f32; 5] = [0.0, 0.002, 0.008, 0.004, 0.001];
 /// Strong early (intimate), dissolves by Act V (open, transcendent).
 const ACT_VIGNETTE: [f32; 5] = [0.5, 0.4, 0.35, 0.2, 0.05];
 
+/// Vignette edge color for each act.
+/// Black through the dark acts, warming to deep amber as the light returns.
+const ACT_VIGNETTE_COLORS: [&str; 5] = ["#000000", "#000000", "#0a0602", "#1f1206", "#3a2410"];
+
+/// Background gradient direction for each act.
+/// Every act keeps the original vertical wash unless a scene picks another.
+const ACT_GRADIENT_STYLES: [GradientStyle; 5] = [GradientStyle::Vertical; 5];
+
 /// Bloom intensity for each act following the emotional arc.
 /// Peaks at Crescendo, gentle at Emergence, luminous at Transcendence.
 const ACT_BLOOM: [f32; 5] = [0.2, 0.35, 0.6, 0.45, 0.5];
 
+/// Bloom compositing for each act.
+/// Crescendo adds bloom on top for punch; every other act conserves energy.
+const ACT_BLOOM_COMPOSITE: [BloomComposite; 5] = [
+    BloomComposite::EnergyConserving,
+    BloomComposite::EnergyConserving,
+    BloomComposite::Additive,
+    BloomComposite::EnergyConserving,
+    BloomComposite::EnergyConserving,
+];
+
+/// Bloom low-frequency boost for each act.
+/// Tight in Crescendo, widest and most diffuse in Transcendence.
+const ACT_BLOOM_LF_BOOST: [f32; 5] = [0.7, 0.7, 0.4, 0.7, 0.95];
+
+/// Bloom prefilter threshold for each act.
+/// Only Crescendo restricts bloom to its brightest peas.
+const ACT_BLOOM_PREFILTER_THRESHOLD: [f32; 5] = [0.0, 0.0, 0.6, 0.0, 0.0];
+
+/// Bloom prefilter threshold softness for each act.
+const ACT_BLOOM_PREFILTER_SOFTNESS: [f32; 5] = [0.0, 0.0, 0.3, 0.0, 0.0];
+
+/// Focus blur strength for each act.
+/// Only Crescendo narrows the focus to the interaction center.
+const ACT_FOCUS_BLUR: [f32; 5] = [0.0, 0.0, 0.6, 0.0, 0.0];
+
+/// Speed cap, drag, and acceleration scaling for each act.
+/// Crescendo runs fast and punchy; Transcendence is capped low and coasts
+/// on light drag so the Float peas feel weightless.
+const ACT_PHYSICS: [PhysicsProfile; 5] = [
+    PhysicsProfile {
+        max_speed: 400.0,
+        drag_scale: 1.0,
+        acceleration_scale: 1.0,
+    },
+    PhysicsProfile {
+        max_speed: 500.0,
+        drag_scale: 1.0,
+        acceleration_scale: 1.0,
+    },
+    PhysicsProfile {
+        max_speed: 750.0,
+        drag_scale: 0.85,
+        acceleration_scale: 1.3,
+    },
+    PhysicsProfile {
+        max_speed: 550.0,
+        drag_scale: 1.0,
+        acceleration_scale: 1.0,
+    },
+    PhysicsProfile {
+        max_speed: 180.0,
+        drag_scale: 0.6,
+        acceleration_scale: 0.7,
+    },
+];
+
+/// Number of acts every scene table must describe.
+const ACT_COUNT: usize = 5;
+
 // =============================================================================
-// HELPER FUNCTIONS
+// ACT SCENE
 // =============================================================================
 
-/// Performs smooth ease-in-out-cubic interpolation.
-///
-/// This easing function provides a natural acceleration and deceleration
-/// curve for smooth visual transitions between acts.
-///
-/// # Arguments
-/// * `t` - Input value in range [0.0, 1.0]
+/// Error produced when loading or validating an [`ActScene`].
+#[derive(Debug)]
+pub enum ActSceneError {
+    /// The scene file could not be read
+    Io(std::io::Error),
+    /// The scene file is not valid RON for an `ActScene`
+    Parse(String),
+    /// The scene parsed but a table has the wrong length or an out-of-range value
+    Invalid(String),
+}
+
+impl fmt::Display for ActSceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActSceneError::Io(err) => write!(f, "failed to read act scene: {err}"),
+            ActSceneError::Parse(msg) => write!(f, "failed to parse act scene: {msg}"),
+            ActSceneError::Invalid(msg) => write!(f, "invalid act scene: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ActSceneError {}
+
+/// Declarative description of the whole five-act arc.
 ///
-/// # Returns
-/// Eased value in range [0.0, 1.0]
-#[inline]
-#[must_use]
-pub fn ease_in_out_cubic(t: f32) -> f32 {
-    let t = t.clamp(0.0, 1.0);
-    if t < 0.5 {
-        4.0 * t * t * t
-    } else {
-        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+/// Each table holds one entry per act (index corresponds to `Act::index()`).
+/// `interpolate_act_values` and `update_post_process_for_act` read from this
+/// resource, so composers can reshape the experience from a RON file without
+/// touching code. The default scene reproduces the built-in values.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActScene {
+    /// Background gradient per act as `[start, end]` hex colors
+    pub gradients: Vec<[String; 2]>,
+    /// Background gradient direction per act; scenes without it stay vertical
+    #[serde(default = "default_gradient_styles")]
+    pub gradient_styles: Vec<GradientStyle>,
+    /// Saturation multiplier per act (0.0 - 2.0)
+    pub saturation: Vec<f32>,
+    /// Target particle density per act
+    pub density: Vec<f32>,
+    /// Particle lifetime multiplier per act
+    pub lifetime_multiplier: Vec<f32>,
+    /// Particle behavior per act
+    pub behavior: Vec<ParticleBehaviorType>,
+    /// Interaction mode per act
+    pub interaction_mode: Vec<InteractionMode>,
+    /// Speed cap and force scaling per act; scenes without it use the built-in profiles
+    #[serde(default = "default_physics")]
+    pub physics: Vec<PhysicsProfile>,
+    /// Chromatic aberration strength per act (0.0 - 0.1)
+    pub chromatic_aberration: Vec<f32>,
+    /// Vignette intensity per act (0.0 - 1.0)
+    pub vignette: Vec<f32>,
+    /// Vignette edge color per act as hex; scenes without it use the built-in tints
+    #[serde(default = "default_vignette_colors")]
+    pub vignette_colors: Vec<String>,
+    /// Bloom intensity per act (0.0 - 2.0)
+    pub bloom: Vec<f32>,
+    /// Bloom compositing per act; scenes without it use the built-in modes
+    #[serde(default = "default_bloom_composite")]
+    pub bloom_composite: Vec<BloomComposite>,
+    /// Bloom low-frequency boost per act (0.0 - 1.0)
+    #[serde(default = "default_bloom_low_frequency_boost")]
+    pub bloom_low_frequency_boost: Vec<f32>,
+    /// Bloom prefilter threshold per act (0.0 - 10.0)
+    #[serde(default = "default_bloom_prefilter_threshold")]
+    pub bloom_prefilter_threshold: Vec<f32>,
+    /// Bloom prefilter threshold softness per act (0.0 - 1.0)
+    #[serde(default = "default_bloom_prefilter_softness")]
+    pub bloom_prefilter_softness: Vec<f32>,
+    /// Focus blur strength per act (0.0 - 1.0)
+    #[serde(default = "default_focus_blur")]
+    pub focus_blur: Vec<f32>,
+}
+
+impl Default for ActScene {
+    fn default() -> Self {
+        let gradients = BackgroundGradients::default()
+            .act_gradients
+            .iter()
+            .map(|pair| [color_to_hex(pair[0]), color_to_hex(pair[1])])
+            .collect();
+
+        Self {
+            gradients,
+            gradient_styles: default_gradient_styles(),
+            saturation: ACT_SATURATION.to_vec(),
+            density: ACT_DENSITY.to_vec(),
+            lifetime_multiplier: ACT_LIFETIME_MULTIPLIER.to_vec(),
+            behavior: Act::all().iter().map(Act::default_behavior).collect(),
+            interaction_mode: Act::all()
+                .iter()
+                .map(Act::default_interaction_mode)
+                .collect(),
+            physics: default_physics(),
+            chromatic_aberration: ACT_CHROMATIC_ABERRATION.to_vec(),
+            vignette: ACT_VIGNETTE.to_vec(),
+            vignette_colors: default_vignette_colors(),
+            bloom: ACT_BLOOM.to_vec(),
+            bloom_composite: default_bloom_composite(),
+            bloom_low_frequency_boost: default_bloom_low_frequency_boost(),
+            bloom_prefilter_threshold: default_bloom_prefilter_threshold(),
+            bloom_prefilter_softness: default_bloom_prefilter_softness(),
+            focus_blur: default_focus_blur(),
+        }
     }
 }
 
+/// Built-in per-act physics profiles, used when a scene file omits them.
+fn default_physics() -> Vec<PhysicsProfile> {
+    ACT_PHYSICS.to_vec()
+}
+
+/// Built-in per-act gradient directions, used when a scene file omits them.
+fn default_gradient_styles() -> Vec<GradientStyle> {
+    ACT_GRADIENT_STYLES.to_vec()
+}
+
+/// Built-in per-act vignette colors, used when a scene file omits them.
+fn default_vignette_colors() -> Vec<String> {
+    ACT_VIGNETTE_COLORS
+        .iter()
+        .map(|hex| hex.to_string())
+        .collect()
+}
+
+/// Built-in per-act bloom compositing, used when a scene file omits it.
+fn default_bloom_composite() -> Vec<BloomComposite> {
+    ACT_BLOOM_COMPOSITE.to_vec()
+}
+
+/// Built-in per-act bloom low-frequency boost, used when a scene file omits it.
+fn default_bloom_low_frequency_boost() -> Vec<f32> {
+    ACT_BLOOM_LF_BOOST.to_vec()
+}
+
+/// Built-in per-act bloom prefilter threshold, used when a scene file omits it.
+fn default_bloom_prefilter_threshold() -> Vec<f32> {
+    ACT_BLOOM_PREFILTER_THRESHOLD.to_vec()
+}
+
+/// Built-in per-act bloom prefilter softness, used when a scene file omits it.
+fn default_bloom_prefilter_softness() -> Vec<f32> {
+    ACT_BLOOM_PREFILTER_SOFTNESS.to_vec()
+}
+
+/// Built-in per-act focus blur, used when a scene file omits it.
+fn default_focus_blur() -> Vec<f32> {
+    ACT_FOCUS_BLUR.to_vec()
+}
+
+impl ActScene {
+    /// Parses and validates a scene from RON text.
+    pub fn from_ron_str(source: &str) -> Result<Self, ActSceneError> {
+        let scene: ActScene =
+            ron::from_str(source).map_err(|err| ActSceneError::Parse(err.to_string()))?;
+        scene.validate()?;
+        Ok(scene)
+    }
+
+    /// Reads, parses, and validates a scene from a RON file.
+    pub fn load(path: &std::path::Path) -> Result<Self, ActSceneError> {
+        let source = std::fs::read_to_string(path).map_err(ActSceneError::Io)?;
+        Self::from_ron_str(&source)
+    }
+
+    /// Serializes the scene as pretty-printed RON.
+    pub fn to_ron_string(&self) -> Result<String, ActSceneError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| ActSceneError::Parse(err.to_string()))
+    }
+
+    /// Checks that every table has one entry per act and values are in range.
+    pub fn validate(&self) -> Result<(), ActSceneError> {
+        let lengths = [
+            ("gradients", self.gradients.len()),
+            ("gradient_styles", self.gradient_styles.len()),
+            ("saturation", self.saturation.len()),
+            ("density", self.density.len()),
+            ("lifetime_multiplier", self.lifetime_multiplier.len()),
+            ("behavior", self.behavior.len()),
+            ("interaction_mode", self.interaction_mode.len()),
+            ("physics", self.physics.len()),
+            ("chromatic_aberration", self.chromatic_aberration.len()),
+            ("vignette", self.vignette.len()),
+            ("vignette_colors", self.vignette_colors.len()),
+            ("bloom", self.bloom.len()),
+            ("bloom_composite", self.bloom_composite.len()),
+            (
+                "bloom_low_frequency_boost",
+                self.bloom_low_frequency_boost.len(),
+            ),
+            (
+                "bloom_prefilter_threshold",
+                self.bloom_prefilter_threshold.len(),
+            ),
+            (
+                "bloom_prefilter_softness",
+                self.bloom_prefilter_softness.len(),
+            ),
+            ("focus_blur", self.focus_blur.len()),
+        ];
+        for (name, len) in lengths {
+            if len != ACT_COUNT {
+                return Err(ActSceneError::Invalid(format!(
+                    "`{name}` has {len} entries, expected {ACT_COUNT}"
+                )));
+            }
+        }
+
+        let physics_field = |field: fn(&PhysicsProfile) -> f32| -> Vec<f32> {
+            self.physics.iter().map(field).collect()
+        };
+        let max_speed = physics_field(|p| p.max_speed);
+        let drag_scale = physics_field(|p| p.drag_scale);
+        let acceleration_scale = physics_field(|p| p.acceleration_scale);
+
+        let ranges = [
+            ("saturation", &self.saturation, 0.0, 2.0),
+            ("density", &self.density, 0.0, 15_000.0),
+            ("lifetime_multiplier", &self.lifetime_multiplier, 0.05, 10.0),
+            ("chromatic_aberration", &self.chromatic_aberration, 0.0, 0.1),
+            ("vignette", &self.vignette, 0.0, 1.0),
+            ("bloom", &self.bloom, 0.0, 2.0),
+            (
+                "bloom_low_frequency_boost",
+                &self.bloom_low_frequency_boost,
+                0.0,
+                1.0,
+            ),
+            (
+                "bloom_prefilter_threshold",
+                &self.bloom_prefilter_threshold,
+                0.0,
+                10.0,
+            ),
+            (
+                "bloom_prefilter_softness",
+                &self.bloom_prefilter_softness,
+                0.0,
+                1.0,
+            ),
+            ("focus_blur", &self.focus_blur, 0.0, 1.0),
+            ("physics.max_speed", &max_speed, 10.0, 5_000.0),
+            ("physics.drag_scale", &drag_scale, 0.0, 5.0),
+            ("physics.acceleration_scale", &acceleration_scale, 0.0, 5.0),
+        ];
+        for (name, values, min, max) in ranges {
+            if let Some(value) = values.iter().find(|v| !(min..=max).contains(*v)) {
+                return Err(ActSceneError::Invalid(format!(
+                    "`{name}` value {value} outside {min}..={max}"
+                )));
+            }
+        }
+
+        let colors = self.gradients.iter().flatten().chain(&self.vignette_colors);
+        for hex in colors {
+            let digits = hex.trim_start_matches('#');
+            let valid_length = digits.len() == 6 || digits.len() == 8;
+            if !valid_length || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ActSceneError::Invalid(format!(
+                    "color `{hex}` is not #rrggbb or #rrggbbaa"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the parsed background gradients for all acts.
+    #[must_use]
+    pub fn background_gradients(&self) -> BackgroundGradients {
+        let mut gradients = BackgroundGradients::default();
+        for (target, pair) in gradients.act_gradients.iter_mut().zip(&self.gradients) {
+            *target = [hex_to_color(&pair[0]), hex_to_color(&pair[1])];
+        }
+        gradients
+    }
+}
+
+/// Optional path to a RON act scene loaded at startup.
+///
+/// When `None` (the default) or when loading fails, the built-in scene is used.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ActScenePath(pub Option<PathBuf>);
+
+// =============================================================================
+// HELPER FUNCTIONS
+// =============================================================================
+
 /// Linearly interpolates between two f32 values.
 ///
 /// # Arguments
@@ -99,30 +485,171 @@ fn lerp_f32(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t
 }
 
-/// Linearly interpolates between two colors.
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Applies `GoToAct` and `AdvanceAct` requests ahead of `update_act_progression`.
 ///
-/// Interpolation is performed in sRGB space for each component.
+/// Moves `total_elapsed_seconds` to the target act's start boundary and
+/// clears any in-flight transition; `update_act_progression` then sees the
+/// act change and runs a normal timed transition. The last request wins.
 ///
-/// # Arguments
-/// * `a` - Start color
-/// * `b` - End color
-/// * `t` - Interpolation factor (0.0 = a, 1.0 = b)
-#[inline]
+/// Requesting the act a transition is already entering finishes that
+/// transition at once and sends its `ActTransitionCompleted`.
+///
+/// # Ordering
+/// Runs before `update_act_progression`, in `ActManagementSet::UpdateProgression`.
+pub fn apply_act_navigation(
+    mut go_to_events: EventReader<GoToAct>,
+    mut advance_events: EventReader<AdvanceAct>,
+    mut act_state: ResMut<ActState>,
+    act_timings: Res<ActTimings>,
+    mut transition_completed_events: EventWriter<ActTransitionCompleted>,
+) {
+    let mut target = go_to_events.read().last().map(|event| event.0);
+    if advance_events.read().count() > 0 {
+        target = target.or(act_state.current_act.next());
+    }
+
+    let Some(target) = target else {
+        return;
+    };
+    if target == act_state.current_act && !act_state.is_transitioning {
+        return;
+    }
+
+    act_state.total_elapsed_seconds = act_timings.act_boundaries_seconds[target.index()];
+    if target == act_state.current_act {
+        act_state.is_transitioning = false;
+        act_state.transition_progress = 1.0;
+        transition_completed_events.send(ActTransitionCompleted { act: target });
+        info!("Finishing the transition into {:?} on request", target);
+        return;
+    }
+
+    act_state.is_transitioning = false;
+    info!("Advancing to {:?} on request", target);
+}
+
+/// Sends `SeekTo` for the start of Acts I-V when 1-5 is pressed.
+///
+/// Runs without a keyboard resource (headless apps) by doing nothing.
+///
+/// # Stage
+/// PreUpdate
+pub fn handle_act_seek_keys(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    act_timings: Res<ActTimings>,
+    mut seek_events: EventWriter<SeekTo>,
+) {
+    let Some(keyboard) = keyboard else {
+        return;
+    };
+
+    let act_keys = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+    ];
+    for (key, act) in act_keys.into_iter().zip(Act::all()) {
+        if keyboard.just_pressed(key) {
+            seek_events.send(SeekTo {
+                seconds: act_timings.act_boundaries_seconds[act.index()],
+            });
+        }
+    }
+}
+
+/// Returns the act a three-finger swipe seeks to from `current`: the next act
+/// for a forward swipe, the previous one otherwise, or `None` past either end.
 #[must_use]
-fn lerp_color(a: Color, b: Color, t: f32) -> Color {
-    let a_srgb = a.to_srgba();
-    let b_srgb = b.to_srgba();
-    Color::srgba(
-        lerp_f32(a_srgb.red, b_srgb.red, t),
-        lerp_f32(a_srgb.green, b_srgb.green, t),
-        lerp_f32(a_srgb.blue, b_srgb.blue, t),
-        lerp_f32(a_srgb.alpha, b_srgb.alpha, t),
-    )
+pub fn swipe_target_act(current: Act, forward: bool) -> Option<Act> {
+    if forward {
+        current.next()
+    } else {
+        current.previous()
+    }
 }
 
-// =============================================================================
-// SYSTEMS
-// =============================================================================
+/// Sends `SeekTo` for the start of the next or previous act on a
+/// `ThreeFingerSwipe`, so installations can skip ahead without a keyboard.
+///
+/// Swipes past the first or last act are ignored.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// Runs after `handle_touch_gestures`.
+pub fn handle_act_swipes(
+    mut swipes: EventReader<ThreeFingerSwipe>,
+    act_state: Res<ActState>,
+    act_timings: Res<ActTimings>,
+    mut seek_events: EventWriter<SeekTo>,
+) {
+    for swipe in swipes.read() {
+        if let Some(act) = swipe_target_act(act_state.current_act, swipe.forward) {
+            seek_events.send(SeekTo {
+                seconds: act_timings.act_boundaries_seconds[act.index()],
+            });
+        }
+    }
+}
+
+/// Applies `SeekTo` requests, snapping the timeline to the target time.
+///
+/// Clamps the target into the pass, sets the act and its progress from
+/// `ActTimings`, and clears any in-flight transition so seeking backward
+/// leaves nothing stale. `interpolate_act_values` and
+/// `update_post_process_for_act` run later in the same frame, so visuals
+/// land on the new act without blending. The last request wins.
+///
+/// A seek that lands at or past the end of the pass sends
+/// `ExperienceCompleted` itself, since `update_act_progression` only signals
+/// completion when elapsed time crosses the end.
+///
+/// # Ordering
+/// Runs after `apply_act_navigation` and before `update_act_progression`,
+/// in `ActManagementSet::UpdateProgression`.
+pub fn apply_seek(
+    mut seek_events: EventReader<SeekTo>,
+    mut act_state: ResMut<ActState>,
+    act_timings: Res<ActTimings>,
+    mut completed_events: EventWriter<ExperienceCompleted>,
+) {
+    let Some(seek) = seek_events.read().last() else {
+        return;
+    };
+
+    let total_seconds = act_timings.total_seconds();
+    let elapsed = seek.seconds.clamp(0.0, total_seconds);
+    if elapsed >= total_seconds && act_state.total_elapsed_seconds < total_seconds {
+        act_state.completed_passes += 1;
+        completed_events.send(ExperienceCompleted {
+            pass: act_state.completed_passes,
+        });
+        info!("Experience pass {} complete", act_state.completed_passes);
+    }
+
+    let act = act_timings.act_at(elapsed);
+    let act_start = act_timings.act_boundaries_seconds[act.index()];
+    let act_end = act_timings.act_boundaries_seconds[act.index() + 1];
+
+    act_state.total_elapsed_seconds = elapsed;
+    act_state.current_act = act;
+    act_state.act_progress = if act_end > act_start {
+        ((elapsed - act_start) / (act_end - act_start)).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    act_state.is_transitioning = false;
+    act_state.transition_progress = 0.0;
+    act_state.transition_from = None;
+    info!("Seeking to {:.1}s ({:?})", elapsed, act);
+}
 
 /// Updates the act progression based on elapsed time.
 ///
@@ -131,22 +658,52 @@ fn lerp_color(a: Color, b: Color, t: f32) -> Color {
 /// - Determines the current act from ActTimings boundaries
 /// - Sets `is_transitioning` and `transition_progress` during act changes
 /// - Sends `ActTransitionStarted` and `ActTransitionCompleted` events
+/// - Sends `ExperienceCompleted` once per pass as the full duration elapses
+///
+/// While `ExperiencePaused` is set the timeline holds still. The first frame
+/// after a resume advances by at most `MAX_RESUME_DELTA_SECS`, so a long
+/// stall while paused cannot jump the experience forward.
 ///
 /// # Priority
 /// HIGH - Must run before other act-dependent systems.
+#[allow(clippy::too_many_arguments)]
 pub fn update_act_progression(
     time: Res<Time>,
+    paused: Res<ExperiencePaused>,
+    mut was_paused: Local<bool>,
     mut act_state: ResMut<ActState>,
     act_timings: Res<ActTimings>,
     mut transition_started_events: EventWriter<ActTransitionStarted>,
     mut transition_completed_events: EventWriter<ActTransitionCompleted>,
     mut hyperspace_events: EventWriter<HyperspaceJumpEvent>,
+    mut completed_events: EventWriter<ExperienceCompleted>,
 ) {
-    // Advance elapsed time
-    act_state.total_elapsed_seconds += time.delta_secs();
+    if paused.0 {
+        *was_paused = true;
+        return;
+    }
+
+    // Advance elapsed time, clamping the first step after a resume
+    let delta = if std::mem::take(&mut *was_paused) {
+        time.delta_secs().min(MAX_RESUME_DELTA_SECS)
+    } else {
+        time.delta_secs()
+    };
+    let previous_elapsed = act_state.total_elapsed_seconds;
+    act_state.total_elapsed_seconds += delta;
+    let total_seconds = act_timings.total_seconds();
+
+    // Signal a completed pass exactly once, before any cycle logic runs
+    if previous_elapsed < total_seconds && act_state.total_elapsed_seconds >= total_seconds {
+        act_state.completed_passes += 1;
+        completed_events.send(ExperienceCompleted {
+            pass: act_state.completed_passes,
+        });
+        info!("Experience pass {} complete", act_state.completed_passes);
+    }
 
     // Cycle back to beginning when reaching the end (fidget app loop)
-    if act_state.total_elapsed_seconds >= TOTAL_DURATION_SECONDS + 2.0 {
+    if act_state.total_elapsed_seconds >= total_seconds + 2.0 {
         // Trigger hyperspace effect at screen center before cycling
         hyperspace_events.send(HyperspaceJumpEvent {
             vanishing_point: Vec2::ZERO,
@@ -158,6 +715,7 @@ pub fn update_act_progression(
         act_state.act_progress = 0.0;
         act_state.is_transitioning = false;
         act_state.transition_progress = 0.0;
+        act_state.transition_from = None;
 
         info!("Experience cycling back to Act I: Emergence");
     }
@@ -167,7 +725,7 @@ pub fn update_act_progression(
     let boundaries = &act_timings.act_boundaries_seconds;
     let transition_duration_secs = act_timings.transition_duration_ms / 1000.0;
 
-    let new_act = Act::from_elapsed_seconds(elapsed);
+    let new_act = act_timings.act_at(elapsed);
     let act_index = new_act.index();
 
     // Calculate progress within current act
@@ -190,6 +748,7 @@ pub fn update_act_progression(
             // Begin transition
             act_state.is_transitioning = true;
             act_state.transition_progress = 0.0;
+            act_state.transition_from = Some(from_act);
 
             transition_started_events.send(ActTransitionStarted {
                 from: from_act,
@@ -232,9 +791,12 @@ pub fn update_act_progression(
 ///
 /// This system:
 /// - Reads ActState, ActTimings, ColorPalette, BackgroundGradients
-/// - Writes to InterpolatedActValues, CurrentInteractionMode, CurrentBackground
+/// - Writes to InterpolatedActValues, BaseInteractionMode, CurrentBackground
 /// - Uses smooth ease-in-out-cubic interpolation during transitions
-/// - Sets particle_behavior, interaction_mode, saturation_multiplier, density_target per act
+/// - Sets particle_behavior, behavior_coefficients, interaction_mode,
+///   saturation_multiplier, density_target, and lifetime_multiplier per act
+/// - Crossfades behavior and interaction mode through a transition: the
+///   nearest act leads and the other act's stays mixed in by weight
 ///
 /// # Ordering
 /// Runs after `update_act_progression`.
@@ -242,66 +804,93 @@ pub fn interpolate_act_values(
     act_state: Res<ActState>,
     background_gradients: Res<BackgroundGradients>,
     mut interpolated_values: ResMut<InterpolatedActValues>,
-    mut current_interaction_mode: ResMut<CurrentInteractionMode>,
+    mut base_interaction_mode: ResMut<BaseInteractionMode>,
     mut current_background: ResMut<CurrentBackground>,
+    color_interpolation: Res<ColorInterpolation>,
+    scene: Res<ActScene>,
 ) {
     let current_act = act_state.current_act;
     let act_index = current_act.index();
 
     if act_state.is_transitioning {
-        // Get previous act for interpolation
-        let prev_act = current_act.previous().unwrap_or(current_act);
+        // Get the act this transition started from for interpolation
+        let prev_act = act_state.transition_source();
         let prev_index = prev_act.index();
 
         // Apply easing to transition progress
         let t = ease_in_out_cubic(act_state.transition_progress);
 
         // Interpolate saturation and density
-        interpolated_values.saturation_multiplier = lerp_f32(
-            ACT_SATURATION[prev_index],
-            ACT_SATURATION[act_index],
-            t,
-        );
-        interpolated_values.density_target = lerp_f32(
-            ACT_DENSITY[prev_index],
-            ACT_DENSITY[act_index],
+        interpolated_values.saturation_multiplier =
+            lerp_f32(scene.saturation[prev_index], scene.saturation[act_index], t);
+        interpolated_values.density_target =
+            lerp_f32(scene.density[prev_index], scene.density[act_index], t);
+        interpolated_values.lifetime_multiplier = lerp_f32(
+            scene.lifetime_multiplier[prev_index],
+            scene.lifetime_multiplier[act_index],
             t,
         );
+        interpolated_values.physics = scene.physics[prev_index].lerp(&scene.physics[act_index], t);
 
         // Interpolate background colors
         let prev_gradient = &background_gradients.act_gradients[prev_index];
         let curr_gradient = &background_gradients.act_gradients[act_index];
 
-        interpolated_values.background_color_start = lerp_color(
+        interpolated_values.background_color_start = color_lerp_in(
             prev_gradient[0],
             curr_gradient[0],
             t,
+            color_interpolation.space,
         );
-        interpolated_values.background_color_end = lerp_color(
+        interpolated_values.background_color_end = color_lerp_in(
             prev_gradient[1],
             curr_gradient[1],
             t,
+            color_interpolation.space,
         );
 
         current_background.gradient_start = interpolated_values.background_color_start;
         current_background.gradient_end = interpolated_values.background_color_end;
 
-        // For behavior and interaction mode, switch at halfway point
-        if t < 0.5 {
-            interpolated_values.particle_behavior = prev_act.default_behavior();
-            interpolated_values.interaction_mode = prev_act.default_interaction_mode();
-            current_interaction_mode.mode = prev_act.default_interaction_mode();
+        // Gradient direction can't blend, so switch at the midpoint
+        let style_index = if t < 0.5 { prev_index } else { act_index };
+        current_background.style = scene.gradient_styles[style_index];
+
+        // Behavior forces morph continuously between the two acts
+        interpolated_values.behavior_coefficients = scene.behavior[prev_index]
+            .coefficients()
+            .lerp(&scene.behavior[act_index].coefficients(), t);
+
+        // The nearest act leads; the other act's behavior and mode stay mixed
+        // in by weight so forces crossfade instead of snapping at halfway
+        let (near_index, far_index, far_weight) = if t < 0.5 {
+            (prev_index, act_index, t)
         } else {
-            interpolated_values.particle_behavior = current_act.default_behavior();
-            interpolated_values.interaction_mode = current_act.default_interaction_mode();
-            current_interaction_mode.mode = current_act.default_interaction_mode();
-        }
+            (act_index, prev_index, 1.0 - t)
+        };
+        let (near_behavior, far_behavior) = (scene.behavior[near_index], scene.behavior[far_index]);
+        interpolated_values.particle_behavior = near_behavior;
+        interpolated_values.behavior_blend_target =
+            (far_behavior != near_behavior).then_some((far_behavior, far_weight));
+
+        let (near_mode, far_mode) = (
+            scene.interaction_mode[near_index],
+            scene.interaction_mode[far_index],
+        );
+        interpolated_values.interaction_mode = near_mode;
+        base_interaction_mode.0.mode = near_mode;
+        base_interaction_mode.0.blend_target =
+            (far_mode != near_mode).then_some((far_mode, far_weight));
     } else {
         // Not transitioning - use current act values directly
-        interpolated_values.saturation_multiplier = ACT_SATURATION[act_index];
-        interpolated_values.density_target = ACT_DENSITY[act_index];
-        interpolated_values.particle_behavior = current_act.default_behavior();
-        interpolated_values.interaction_mode = current_act.default_interaction_mode();
+        interpolated_values.saturation_multiplier = scene.saturation[act_index];
+        interpolated_values.density_target = scene.density[act_index];
+        interpolated_values.lifetime_multiplier = scene.lifetime_multiplier[act_index];
+        interpolated_values.physics = scene.physics[act_index];
+        interpolated_values.particle_behavior = scene.behavior[act_index];
+        interpolated_values.behavior_blend_target = None;
+        interpolated_values.behavior_coefficients = scene.behavior[act_index].coefficients();
+        interpolated_values.interaction_mode = scene.interaction_mode[act_index];
 
         // Set background from current act gradient
         let gradient = &background_gradients.act_gradients[act_index];
@@ -310,8 +899,40 @@ pub fn interpolate_act_values(
 
         current_background.gradient_start = gradient[0];
         current_background.gradient_end = gradient[1];
+        current_background.style = scene.gradient_styles[act_index];
+
+        base_interaction_mode
+            .0
+            .set(scene.interaction_mode[act_index]);
+    }
+}
 
-        current_interaction_mode.mode = current_act.default_interaction_mode();
+/// Pushes the interpolated behavior coefficients, blend target, and drag onto
+/// every particle.
+///
+/// Particles spawned in an earlier act follow the current blend too, so the
+/// whole field morphs together through a transition. Drag is synced the same
+/// way rather than only at spawn, so live particles slow down or loosen up
+/// with the act instead of keeping the drag they were born with.
+///
+/// # Ordering
+/// Runs after `interpolate_act_values`, in `ActManagementSet::InterpolateValues`.
+/// `apply_hyperspace` runs after this set so its low drag wins while a jump
+/// is active.
+pub fn sync_particle_behavior_coefficients(
+    interpolated_values: Res<InterpolatedActValues>,
+    mut query: Query<(&mut ParticleBehavior, &mut ParticleMotion), With<Particle>>,
+) {
+    let (coefficients, blend_target) = interpolated_values.particle_blend();
+    let drag = interpolated_values.behavior_coefficients.drag;
+    for (mut behavior, mut motion) in query.iter_mut() {
+        if behavior.coefficients != coefficients || behavior.blend_target != blend_target {
+            behavior.coefficients = coefficients;
+            behavior.blend_target = blend_target;
+        }
+        if motion.drag != drag {
+            motion.drag = drag;
+        }
     }
 }
 
@@ -319,21 +940,27 @@ pub fn interpolate_act_values(
 ///
 /// This system adjusts:
 /// - Chromatic aberration: increases in Act III (Crescendo)
-/// - Vignette: strong early, dissolves by Act V (Transcendence)
+/// - Vignette: strong early, dissolves by Act V (Transcendence), and its
+///   edge color warms from black toward amber
 /// - Bloom intensity: follows the emotional arc
+/// - Bloom character: low-frequency boost and prefilter interpolate; the
+///   composite mode switches halfway through a transition
+/// - Focus blur: narrows the focus around the cursor in Act III (Crescendo)
 ///
 /// # Ordering
 /// Runs after `interpolate_act_values`.
 pub fn update_post_process_for_act(
     act_state: Res<ActState>,
     mut post_process: ResMut<PostProcessSettings>,
+    scene: Res<ActScene>,
+    color_interpolation: Res<ColorInterpolation>,
 ) {
     let current_act = act_state.current_act;
     let act_index = current_act.index();
 
     if act_state.is_transitioning {
-        // Get previous act for interpolation
-        let prev_act = current_act.previous().unwrap_or(current_act);
+        // Get the act this transition started from for interpolation
+        let prev_act = act_state.transition_source();
         let prev_index = prev_act.index();
 
         // Apply easing to transition progress
@@ -341,30 +968,92 @@ pub fn update_post_process_for_act(
 
         // Interpolate post-processing values
         post_process.chromatic_aberration_strength = lerp_f32(
-            ACT_CHROMATIC_ABERRATION[prev_index],
-            ACT_CHROMATIC_ABERRATION[act_index],
+            scene.chromatic_aberration[prev_index],
+            scene.chromatic_aberration[act_index],
             t,
         );
 
-        post_process.vignette_intensity = lerp_f32(
-            ACT_VIGNETTE[prev_index],
-            ACT_VIGNETTE[act_index],
+        post_process.vignette_intensity =
+            lerp_f32(scene.vignette[prev_index], scene.vignette[act_index], t);
+
+        post_process.vignette_color = color_lerp_in(
+            hex_to_color(&scene.vignette_colors[prev_index]),
+            hex_to_color(&scene.vignette_colors[act_index]),
             t,
+            color_interpolation.space,
         );
 
-        post_process.bloom_intensity = lerp_f32(
-            ACT_BLOOM[prev_index],
-            ACT_BLOOM[act_index],
+        post_process.bloom_intensity = lerp_f32(scene.bloom[prev_index], scene.bloom[act_index], t);
+
+        post_process.bloom_low_frequency_boost = lerp_f32(
+            scene.bloom_low_frequency_boost[prev_index],
+            scene.bloom_low_frequency_boost[act_index],
             t,
         );
+
+        post_process.bloom_prefilter_threshold = lerp_f32(
+            scene.bloom_prefilter_threshold[prev_index],
+            scene.bloom_prefilter_threshold[act_index],
+            t,
+        );
+
+        post_process.bloom_prefilter_softness = lerp_f32(
+            scene.bloom_prefilter_softness[prev_index],
+            scene.bloom_prefilter_softness[act_index],
+            t,
+        );
+
+        post_process.focus_blur_strength =
+            lerp_f32(scene.focus_blur[prev_index], scene.focus_blur[act_index], t);
+
+        // Compositing can't blend, so switch at the midpoint
+        let composite_index = if t < 0.5 { prev_index } else { act_index };
+        post_process.bloom_composite = scene.bloom_composite[composite_index];
     } else {
         // Not transitioning - use current act values directly
-        post_process.chromatic_aberration_strength = ACT_CHROMATIC_ABERRATION[act_index];
-        post_process.vignette_intensity = ACT_VIGNETTE[act_index];
-        post_process.bloom_intensity = ACT_BLOOM[act_index];
+        post_process.chromatic_aberration_strength = scene.chromatic_aberration[act_index];
+        post_process.vignette_intensity = scene.vignette[act_index];
+        post_process.vignette_color = hex_to_color(&scene.vignette_colors[act_index]);
+        post_process.bloom_intensity = scene.bloom[act_index];
+        post_process.bloom_low_frequency_boost = scene.bloom_low_frequency_boost[act_index];
+        post_process.bloom_prefilter_threshold = scene.bloom_prefilter_threshold[act_index];
+        post_process.bloom_prefilter_softness = scene.bloom_prefilter_softness[act_index];
+        post_process.focus_blur_strength = scene.focus_blur[act_index];
+        post_process.bloom_composite = scene.bloom_composite[act_index];
     }
 }
 
+/// Loads the act scene from `ActScenePath` at startup, if one is configured.
+///
+/// Invalid or unreadable files are logged and the built-in scene is kept.
+///
+/// # Stage
+/// Startup
+pub fn load_act_scene(scene_path: Res<ActScenePath>, mut scene: ResMut<ActScene>) {
+    let Some(path) = scene_path.0.as_deref() else {
+        return;
+    };
+
+    match ActScene::load(path) {
+        Ok(loaded) => {
+            info!("Loaded act scene from {}", path.display());
+            *scene = loaded;
+        }
+        Err(err) => warn!("{err}; using built-in act scene"),
+    }
+}
+
+/// Pushes the scene's gradients into `BackgroundGradients` when the scene changes.
+///
+/// # Ordering
+/// Runs before `interpolate_act_values`.
+pub fn apply_act_scene_gradients(
+    scene: Res<ActScene>,
+    mut background_gradients: ResMut<BackgroundGradients>,
+) {
+    *background_gradients = scene.background_gradients();
+}
+
 // =============================================================================
 // SYSTEM SETS
 // =============================================================================
@@ -392,16 +1081,50 @@ pub enum ActManagementSet {
 /// - Proper system ordering to ensure consistent state
 ///
 /// # Systems
+/// - `handle_act_seek_keys` (PreUpdate) - Seeks to the start of Acts I-V on 1-5
+/// - `handle_act_swipes` (PreUpdate) - Seeks to the next/previous act on a three-finger swipe
+/// - `apply_act_navigation` - Turns `GoToAct` / `AdvanceAct` into timed transitions
 /// - `update_act_progression` - Advances time and determines current act
 /// - `interpolate_act_values` - Smoothly transitions act-dependent values
+/// - `sync_particle_behavior_coefficients` - Applies blended behavior weights to particles
 /// - `update_post_process_for_act` - Adjusts visual effects per act
+/// - `load_act_scene` / `apply_act_scene_gradients` - Drive the arc from `ActScene`
 pub struct ActManagementPlugin;
 
 impl Plugin for ActManagementPlugin {
     fn build(&self, app: &mut App) {
         // Register events
         app.add_event::<ActTransitionStarted>()
-            .add_event::<ActTransitionCompleted>();
+            .add_event::<ActTransitionCompleted>()
+            .add_event::<ExperienceCompleted>()
+            .add_event::<GoToAct>()
+            .add_event::<AdvanceAct>()
+            .add_event::<SeekTo>()
+            // Sent on hyperspace jumps; also registered by InteractionPlugin
+            .add_event::<HyperspaceJumpEvent>()
+            // Sent by handle_touch_gestures; also registered by InteractionPlugin
+            .add_event::<ThreeFingerSwipe>()
+            .init_resource::<ActScene>()
+            .init_resource::<ActScenePath>()
+            // Loaded before a generated palette is mirrored into the scene
+            .add_systems(
+                Startup,
+                load_act_scene.before(crate::visual::apply_palette_config),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    handle_act_seek_keys,
+                    handle_act_swipes.after(crate::interaction::handle_touch_gestures),
+                )
+                    .run_if(in_fidget_state),
+            )
+            .add_systems(
+                Update,
+                apply_act_scene_gradients
+                    .run_if(resource_changed::<ActScene>)
+                    .before(ActManagementSet::InterpolateValues),
+            );
 
         // Configure system sets (only run in Fidget state)
         app.configure_sets(
@@ -412,15 +1135,20 @@ impl Plugin for ActManagementPlugin {
                 ActManagementSet::UpdatePostProcess,
             )
                 .chain()
-                .run_if(in_state(AppState::Fidget)),
+                .run_if(in_fidget_state),
         );
 
         // Add systems with ordering
         app.add_systems(
             Update,
             (
-                update_act_progression.in_set(ActManagementSet::UpdateProgression),
+                (apply_act_navigation, apply_seek, update_act_progression)
+                    .chain()
+                    .in_set(ActManagementSet::UpdateProgression),
                 interpolate_act_values.in_set(ActManagementSet::InterpolateValues),
+                sync_particle_behavior_coefficients
+                    .after(interpolate_act_values)
+                    .in_set(ActManagementSet::InterpolateValues),
                 update_post_process_for_act.in_set(ActManagementSet::UpdatePostProcess),
             ),
         );
@@ -434,6 +1162,7 @@ impl Plugin for ActManagementPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{BehaviorCoefficients, ColorLerpSpace, TOTAL_DURATION_SECONDS};
 
     #[test]
     fn test_ease_in_out_cubic() {
@@ -467,7 +1196,7 @@ mod tests {
         let black = Color::srgb(0.0, 0.0, 0.0);
         let white = Color::srgb(1.0, 1.0, 1.0);
 
-        let mid = lerp_color(black, white, 0.5);
+        let mid = color_lerp_in(black, white, 0.5, ColorLerpSpace::Srgb);
         let mid_srgb = mid.to_srgba();
 
         assert!((mid_srgb.red - 0.5).abs() < 0.001);
@@ -480,9 +1209,11 @@ mod tests {
         // Ensure all act constant arrays have correct length
         assert_eq!(ACT_SATURATION.len(), 5);
         assert_eq!(ACT_DENSITY.len(), 5);
+        assert_eq!(ACT_LIFETIME_MULTIPLIER.len(), 5);
         assert_eq!(ACT_CHROMATIC_ABERRATION.len(), 5);
         assert_eq!(ACT_VIGNETTE.len(), 5);
         assert_eq!(ACT_BLOOM.len(), 5);
+        assert_eq!(ACT_FOCUS_BLUR.len(), 5);
     }
 
     #[test]
@@ -505,6 +1236,346 @@ mod tests {
         assert!((ACT_DENSITY[4] - 100.0).abs() < f32::EPSILON);  // Act V
     }
 
+    #[test]
+    fn test_transcendence_spawns_outlive_emergence_spawns() {
+        use crate::components::{ParticleBundle, ParticleState};
+        use crate::resources::{
+            FadeProfiles, ParticlePool, ParticleRng, ParticleSpawnQueue, ParticleSpawnRequest,
+            SpawnBudgetConfig, TrailProfiles,
+        };
+
+        fn spawned_lifetime_ms(act: Act) -> f32 {
+            let mut app = App::new();
+            app.insert_resource(ActState {
+                current_act: act,
+                ..Default::default()
+            })
+            .init_resource::<BackgroundGradients>()
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<BaseInteractionMode>()
+            .init_resource::<CurrentBackground>()
+            .init_resource::<ColorInterpolation>()
+            .init_resource::<ActScene>()
+            .init_resource::<ParticlePool>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<SpawnBudgetConfig>()
+            .init_resource::<FadeProfiles>()
+            .init_resource::<TrailProfiles>()
+            .add_event::<crate::particle::PoolExhausted>()
+            .add_systems(
+                Update,
+                (
+                    interpolate_act_values,
+                    crate::particle::spawn_particles_from_queue,
+                )
+                    .chain(),
+            );
+
+            let entity = app.world_mut().spawn(ParticleBundle::new(0)).id();
+            app.world_mut()
+                .resource_mut::<ParticlePool>()
+                .available_entities
+                .push(entity);
+            app.world_mut()
+                .resource_mut::<ParticleSpawnQueue>()
+                .pending_spawns
+                .push(ParticleSpawnRequest::default());
+
+            app.update();
+
+            let state = app.world().get::<ParticleState>(entity).unwrap();
+            assert!(state.active);
+            state.lifetime_total_ms
+        }
+
+        let emergence = spawned_lifetime_ms(Act::Emergence);
+        let transcendence = spawned_lifetime_ms(Act::Transcendence);
+        assert!(transcendence > emergence);
+    }
+
+    #[test]
+    fn test_act_scene_ron_round_trip() {
+        let scene = ActScene::default();
+        assert!(scene.validate().is_ok());
+
+        let ron = scene.to_ron_string().unwrap();
+        let parsed = ActScene::from_ron_str(&ron).unwrap();
+        assert_eq!(parsed, scene);
+    }
+
+    #[test]
+    fn test_act_scene_validation() {
+        let mut short = ActScene::default();
+        short.bloom.pop();
+        assert!(matches!(short.validate(), Err(ActSceneError::Invalid(_))));
+
+        let mut out_of_range = ActScene::default();
+        out_of_range.vignette[2] = 3.0;
+        assert!(matches!(
+            out_of_range.validate(),
+            Err(ActSceneError::Invalid(_))
+        ));
+
+        let mut bad_color = ActScene::default();
+        bad_color.gradients[0][1] = "#12345".to_string();
+        assert!(matches!(
+            bad_color.validate(),
+            Err(ActSceneError::Invalid(_))
+        ));
+
+        assert!(matches!(
+            ActScene::from_ron_str("not a scene"),
+            Err(ActSceneError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_crescendo_speed_cap_exceeds_transcendence() {
+        use crate::components::{ParticleMotion, ParticleState};
+        use std::time::Duration;
+
+        let capped_speed = |act: Act| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .insert_resource(ActState {
+                    current_act: act,
+                    ..Default::default()
+                })
+                .init_resource::<ActScene>()
+                .init_resource::<BackgroundGradients>()
+                .init_resource::<InterpolatedActValues>()
+                .init_resource::<BaseInteractionMode>()
+                .init_resource::<CurrentBackground>()
+                .init_resource::<ColorInterpolation>()
+                .add_systems(
+                    Update,
+                    (
+                        interpolate_act_values,
+                        crate::particle::apply_velocity_changes,
+                    )
+                        .chain(),
+                );
+            let particle = app
+                .world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active: true,
+                        lifetime_remaining_ms: 1000.0,
+                        lifetime_total_ms: 1000.0,
+                    },
+                    ParticleMotion {
+                        velocity: Vec2::new(5000.0, 0.0),
+                        drag: 1.0,
+                        ..Default::default()
+                    },
+                ))
+                .id();
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(16));
+            app.update();
+            app.world()
+                .get::<ParticleMotion>(particle)
+                .unwrap()
+                .velocity
+                .length()
+        };
+
+        let scene = ActScene::default();
+        let crescendo = capped_speed(Act::Crescendo);
+        let transcendence = capped_speed(Act::Transcendence);
+        assert!((crescendo - scene.physics[Act::Crescendo.index()].max_speed).abs() < 1e-2);
+        assert!((transcendence - scene.physics[Act::Transcendence.index()].max_speed).abs() < 1e-2);
+        assert!(
+            crescendo > transcendence * 2.0,
+            "{crescendo} vs {transcendence}"
+        );
+    }
+
+    #[test]
+    fn test_custom_scene_saturation_drives_interpolated_values() {
+        let scene = ActScene {
+            saturation: vec![0.9, 0.8, 0.7, 0.6, 0.5],
+            ..Default::default()
+        };
+
+        let mut app = App::new();
+        app.insert_resource(ActState {
+            current_act: Act::Crescendo,
+            ..Default::default()
+        })
+        .insert_resource(scene)
+        .init_resource::<BackgroundGradients>()
+        .init_resource::<InterpolatedActValues>()
+        .init_resource::<BaseInteractionMode>()
+        .init_resource::<CurrentBackground>()
+        .init_resource::<ColorInterpolation>()
+        .add_systems(Update, interpolate_act_values);
+
+        app.update();
+
+        let values = app.world().resource::<InterpolatedActValues>();
+        assert!((values.saturation_multiplier - 0.7).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_mid_transition_behavior_coefficients_blend() {
+        use crate::components::ParticleBundle;
+
+        let mut app = App::new();
+        app.insert_resource(ActState {
+            current_act: Act::Crescendo,
+            is_transitioning: true,
+            transition_progress: 0.5,
+            ..Default::default()
+        })
+        .init_resource::<ActScene>()
+        .init_resource::<BackgroundGradients>()
+        .init_resource::<InterpolatedActValues>()
+        .init_resource::<BaseInteractionMode>()
+        .init_resource::<CurrentBackground>()
+        .init_resource::<ColorInterpolation>()
+        .add_systems(
+            Update,
+            (interpolate_act_values, sync_particle_behavior_coefficients).chain(),
+        );
+        let particle = app.world_mut().spawn(ParticleBundle::new(0)).id();
+
+        app.update();
+
+        let scene = ActScene::default();
+        let from = scene.behavior[Act::Accumulation.index()].coefficients();
+        let to = scene.behavior[Act::Crescendo.index()].coefficients();
+        let blended = app
+            .world()
+            .resource::<InterpolatedActValues>()
+            .behavior_coefficients;
+
+        let fields = |c: &BehaviorCoefficients| {
+            [
+                c.wander,
+                c.cohesion,
+                c.centripetal,
+                c.tangential,
+                c.lift,
+                c.outward,
+                c.drag,
+            ]
+        };
+        for ((a, b), mid) in fields(&from)
+            .into_iter()
+            .zip(fields(&to))
+            .zip(fields(&blended))
+        {
+            assert!(
+                mid >= a.min(b) && mid <= a.max(b),
+                "{mid} not between {a} and {b}"
+            );
+            if a != b {
+                assert!(
+                    mid != a && mid != b,
+                    "{mid} should be strictly between {a} and {b}"
+                );
+            }
+        }
+
+        // Existing particles follow the blend
+        let behavior = app.world().get::<ParticleBehavior>(particle).unwrap();
+        let followed = behavior.blended_coefficients();
+        for (actual, expected) in fields(&followed).into_iter().zip(fields(&blended)) {
+            assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+        }
+
+        // ...and so does their drag, not just the drag they spawned with
+        let motion = app.world().get::<ParticleMotion>(particle).unwrap();
+        assert!(
+            (motion.drag - blended.drag).abs() < 1e-6,
+            "{} != {}",
+            motion.drag,
+            blended.drag
+        );
+    }
+
+    #[test]
+    fn test_halfway_transition_weights_both_acts_equally() {
+        let mut app = App::new();
+        app.insert_resource(ActState {
+            current_act: Act::Crescendo,
+            is_transitioning: true,
+            transition_progress: 0.5,
+            transition_from: Some(Act::Accumulation),
+            ..Default::default()
+        })
+        .init_resource::<ActScene>()
+        .init_resource::<BackgroundGradients>()
+        .init_resource::<InterpolatedActValues>()
+        .init_resource::<BaseInteractionMode>()
+        .init_resource::<CurrentBackground>()
+        .init_resource::<ColorInterpolation>()
+        .add_systems(Update, interpolate_act_values);
+
+        app.update();
+
+        let scene = ActScene::default();
+        let (from, to) = (Act::Accumulation.index(), Act::Crescendo.index());
+        let mut modes: Vec<_> = app
+            .world()
+            .resource::<BaseInteractionMode>()
+            .0
+            .weighted_modes()
+            .collect();
+        modes.sort_by_key(|(mode, _)| *mode != scene.interaction_mode[from]);
+        assert_eq!(
+            modes,
+            [
+                (scene.interaction_mode[from], 0.5),
+                (scene.interaction_mode[to], 0.5)
+            ]
+        );
+
+        let values = app.world().resource::<InterpolatedActValues>();
+        let behaviors = [scene.behavior[from], scene.behavior[to]];
+        assert!(behaviors.contains(&values.particle_behavior));
+        let (other, weight) = values.behavior_blend_target.unwrap();
+        assert!(behaviors.contains(&other) && other != values.particle_behavior);
+        assert_eq!(weight, 0.5);
+    }
+
+    #[test]
+    fn test_gradient_style_switches_at_transition_midpoint() {
+        let mut scene = ActScene::default();
+        scene.gradient_styles[Act::Accumulation.index()] = GradientStyle::Horizontal;
+        scene.gradient_styles[Act::Crescendo.index()] = GradientStyle::Radial;
+
+        let mut app = App::new();
+        app.insert_resource(ActState {
+            current_act: Act::Crescendo,
+            is_transitioning: true,
+            transition_from: Some(Act::Accumulation),
+            ..Default::default()
+        })
+        .insert_resource(scene)
+        .init_resource::<BackgroundGradients>()
+        .init_resource::<InterpolatedActValues>()
+        .init_resource::<BaseInteractionMode>()
+        .init_resource::<CurrentBackground>()
+        .init_resource::<ColorInterpolation>()
+        .add_systems(Update, interpolate_act_values);
+
+        let mut style_at = |progress: f32| {
+            app.world_mut()
+                .resource_mut::<ActState>()
+                .transition_progress = progress;
+            app.update();
+            app.world().resource::<CurrentBackground>().style
+        };
+        assert_eq!(style_at(0.25), GradientStyle::Horizontal);
+        assert_eq!(style_at(0.75), GradientStyle::Radial);
+        assert_eq!(CurrentBackground::default().style, GradientStyle::Vertical);
+    }
+
     #[test]
     fn test_chromatic_aberration_peaks_at_crescendo() {
         // Act III (Crescendo) should have highest chromatic aberration
@@ -528,6 +1599,38 @@ mod tests {
         assert!(ACT_VIGNETTE[3] >= ACT_VIGNETTE[4]);
     }
 
+    #[test]
+    fn test_vignette_color_warms_through_transition() {
+        let mut app = App::new();
+        app.insert_resource(ActState {
+            current_act: Act::Transcendence,
+            is_transitioning: true,
+            transition_progress: 0.5,
+            ..Default::default()
+        })
+        .init_resource::<ActScene>()
+        .init_resource::<PostProcessSettings>()
+        .init_resource::<ColorInterpolation>()
+        .add_systems(Update, update_post_process_for_act);
+        app.update();
+
+        let from = hex_to_color(ACT_VIGNETTE_COLORS[Act::Release.index()]).to_srgba();
+        let to = hex_to_color(ACT_VIGNETTE_COLORS[Act::Transcendence.index()]).to_srgba();
+        let mid = app
+            .world()
+            .resource::<PostProcessSettings>()
+            .vignette_color
+            .to_srgba();
+        assert!(mid.red > from.red && mid.red < to.red);
+
+        // Transcendence tints warm rather than black; Emergence stays black
+        assert!(to.red > to.blue);
+        assert_eq!(
+            hex_to_color(ACT_VIGNETTE_COLORS[0]),
+            Color::srgb(0.0, 0.0, 0.0)
+        );
+    }
+
     #[test]
     fn test_act_transition_started_event() {
         let event = ActTransitionStarted {
@@ -545,4 +1648,327 @@ mod tests {
         };
         assert_eq!(event.act, Act::Crescendo);
     }
+
+    #[test]
+    fn test_go_to_act_drives_timed_transition() {
+        use bevy::ecs::event::Events;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ActState>()
+            .init_resource::<ActTimings>()
+            .init_resource::<ExperiencePaused>()
+            .add_event::<ActTransitionStarted>()
+            .add_event::<ActTransitionCompleted>()
+            .add_event::<HyperspaceJumpEvent>()
+            .add_event::<ExperienceCompleted>()
+            .add_event::<GoToAct>()
+            .add_event::<AdvanceAct>()
+            .add_systems(
+                Update,
+                (apply_act_navigation, update_act_progression).chain(),
+            );
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+        };
+
+        step(&mut app);
+        app.world_mut().send_event(GoToAct(Act::Crescendo));
+        step(&mut app);
+
+        let state = app.world().resource::<ActState>();
+        assert!(state.is_transitioning);
+        assert!(state.transition_progress < 0.5);
+        assert_eq!(state.transition_source(), Act::Emergence);
+        let started = app.world().resource::<Events<ActTransitionStarted>>();
+        assert!(started
+            .iter_current_update_events()
+            .any(|event| event.from == Act::Emergence && event.to == Act::Crescendo));
+
+        // Let the two-second transition play out
+        for _ in 0..25 {
+            step(&mut app);
+        }
+
+        let state = app.world().resource::<ActState>();
+        assert_eq!(state.current_act, Act::Crescendo);
+        assert!(!state.is_transitioning);
+        let timings = app.world().resource::<ActTimings>();
+        assert!(state.total_elapsed_seconds > timings.act_boundaries_seconds[2]);
+        assert!(state.total_elapsed_seconds < timings.act_boundaries_seconds[3]);
+
+        // Advancing moves on to the next act
+        app.world_mut().send_event(AdvanceAct);
+        for _ in 0..25 {
+            step(&mut app);
+        }
+        assert_eq!(app.world().resource::<ActState>().current_act, Act::Release);
+    }
+
+    #[test]
+    fn test_go_to_current_act_finishes_transition() {
+        use bevy::ecs::event::Events;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ActState>()
+            .init_resource::<ActTimings>()
+            .init_resource::<ExperiencePaused>()
+            .add_event::<ActTransitionStarted>()
+            .add_event::<ActTransitionCompleted>()
+            .add_event::<HyperspaceJumpEvent>()
+            .add_event::<ExperienceCompleted>()
+            .add_event::<GoToAct>()
+            .add_event::<AdvanceAct>()
+            .add_systems(
+                Update,
+                (apply_act_navigation, update_act_progression).chain(),
+            );
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+        };
+
+        app.world_mut().send_event(GoToAct(Act::Crescendo));
+        step(&mut app);
+        step(&mut app);
+        assert!(app.world().resource::<ActState>().is_transitioning);
+
+        // Asking again for the act being entered completes the transition
+        app.world_mut().send_event(GoToAct(Act::Crescendo));
+        step(&mut app);
+
+        let state = app.world().resource::<ActState>();
+        assert_eq!(state.current_act, Act::Crescendo);
+        assert!(!state.is_transitioning);
+        assert_eq!(state.transition_progress, 1.0);
+        let completed = app.world().resource::<Events<ActTransitionCompleted>>();
+        assert_eq!(
+            completed
+                .iter_current_update_events()
+                .map(|event| event.act)
+                .collect::<Vec<_>>(),
+            vec![Act::Crescendo]
+        );
+    }
+
+    #[test]
+    fn test_experience_completed_fires_once_per_pass() {
+        use bevy::ecs::event::Events;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ActState>()
+            .init_resource::<ActTimings>()
+            .init_resource::<ExperiencePaused>()
+            .add_event::<ActTransitionStarted>()
+            .add_event::<ActTransitionCompleted>()
+            .add_event::<HyperspaceJumpEvent>()
+            .add_event::<ExperienceCompleted>()
+            .add_systems(Update, update_act_progression);
+
+        let mut reader = app
+            .world()
+            .resource::<Events<ExperienceCompleted>>()
+            .get_cursor();
+        let mut passes = Vec::new();
+
+        // Two and a half passes in one-second frames
+        for _ in 0..2300 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+
+            let events = app.world().resource::<Events<ExperienceCompleted>>();
+            passes.extend(reader.read(events).map(|event| event.pass));
+        }
+
+        assert_eq!(passes, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_scaled_timings_drive_progression_and_cycle() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ActState>()
+            .insert_resource(ActTimings::scaled(120.0))
+            .init_resource::<ExperiencePaused>()
+            .add_event::<ActTransitionStarted>()
+            .add_event::<ActTransitionCompleted>()
+            .add_event::<HyperspaceJumpEvent>()
+            .add_event::<ExperienceCompleted>()
+            .add_systems(Update, update_act_progression);
+
+        let mut run_seconds = |seconds: u32| {
+            for _ in 0..seconds {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_secs(1));
+                app.update();
+            }
+            app.world().resource::<ActState>().clone()
+        };
+
+        // Half of a 2-minute run lands in Crescendo, as it does at 450s of 900s
+        let state = run_seconds(60);
+        assert_eq!(state.current_act, Act::Crescendo);
+        assert!((state.act_progress - 1.0 / 6.0).abs() < 1e-3);
+
+        // The pass cycles shortly after the configured total, not after 900s
+        let state = run_seconds(63);
+        assert_eq!(state.current_act, Act::Emergence);
+        assert!(state.total_elapsed_seconds < 5.0);
+    }
+
+    #[test]
+    fn test_elapsed_time_holds_while_paused() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ActState>()
+            .init_resource::<ActTimings>()
+            .init_resource::<ExperiencePaused>()
+            .add_event::<ActTransitionStarted>()
+            .add_event::<ActTransitionCompleted>()
+            .add_event::<HyperspaceJumpEvent>()
+            .add_event::<ExperienceCompleted>()
+            .add_systems(Update, update_act_progression);
+
+        let step = |app: &mut App, millis: u64| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            app.update();
+        };
+
+        step(&mut app, 100);
+        let before = app.world().resource::<ActState>().total_elapsed_seconds;
+
+        app.world_mut().resource_mut::<ExperiencePaused>().0 = true;
+        for _ in 0..10 {
+            step(&mut app, 100);
+        }
+        let held = app.world().resource::<ActState>().total_elapsed_seconds;
+        assert_eq!(held, before);
+
+        // A long stall on the resume frame only advances by the clamp
+        app.world_mut().resource_mut::<ExperiencePaused>().0 = false;
+        step(&mut app, 5000);
+        let resumed = app.world().resource::<ActState>().total_elapsed_seconds;
+        assert!((resumed - held - MAX_RESUME_DELTA_SECS).abs() < 1e-4);
+
+        // Later frames advance normally
+        step(&mut app, 100);
+        let next = app.world().resource::<ActState>().total_elapsed_seconds;
+        assert!((next - resumed - 0.1).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_seek_snaps_act_and_clears_transition() {
+        let mut app = App::new();
+        app.init_resource::<ActState>()
+            .init_resource::<ActTimings>()
+            .add_event::<SeekTo>()
+            .add_event::<ExperienceCompleted>()
+            .add_systems(Update, apply_seek);
+
+        // Seek forward into the middle of the Crescendo
+        app.world_mut().send_event(SeekTo { seconds: 510.0 });
+        app.update();
+        let state = app.world().resource::<ActState>();
+        assert_eq!(state.current_act, Act::Crescendo);
+        assert!((state.act_progress - 0.5).abs() < 1e-4);
+        assert!(!state.is_transitioning);
+
+        // Seeking backward mid-transition leaves no stale transition state
+        {
+            let mut state = app.world_mut().resource_mut::<ActState>();
+            state.is_transitioning = true;
+            state.transition_progress = 0.4;
+            state.transition_from = Some(Act::Accumulation);
+        }
+        app.world_mut().send_event(SeekTo { seconds: 10.0 });
+        app.update();
+        let state = app.world().resource::<ActState>();
+        assert_eq!(state.current_act, Act::Emergence);
+        assert!(!state.is_transitioning);
+        assert_eq!(state.transition_progress, 0.0);
+        assert_eq!(state.transition_from, None);
+
+        // Targets outside the pass are clamped
+        app.world_mut().send_event(SeekTo { seconds: -30.0 });
+        app.update();
+        assert_eq!(
+            app.world().resource::<ActState>().total_elapsed_seconds,
+            0.0
+        );
+
+        app.world_mut().send_event(SeekTo { seconds: 5000.0 });
+        app.update();
+        let state = app.world().resource::<ActState>();
+        assert_eq!(state.total_elapsed_seconds, TOTAL_DURATION_SECONDS);
+        assert_eq!(state.current_act, Act::Transcendence);
+        assert_eq!(state.act_progress, 1.0);
+
+        // Landing on the end completes the pass exactly once
+        let completed = app.world().resource::<Events<ExperienceCompleted>>();
+        let passes: Vec<u32> = completed
+            .get_cursor()
+            .read(completed)
+            .map(|e| e.pass)
+            .collect();
+        assert_eq!(passes, vec![1]);
+
+        app.world_mut().send_event(SeekTo {
+            seconds: TOTAL_DURATION_SECONDS,
+        });
+        app.update();
+        assert_eq!(app.world().resource::<ActState>().completed_passes, 1);
+    }
+
+    #[test]
+    fn test_three_finger_swipes_step_between_act_starts() {
+        let mut app = App::new();
+        app.init_resource::<ActState>()
+            .init_resource::<ActTimings>()
+            .add_event::<ThreeFingerSwipe>()
+            .add_event::<SeekTo>()
+            .add_event::<ExperienceCompleted>()
+            .add_systems(Update, (handle_act_swipes, apply_seek).chain());
+
+        let mut swipe = |forward: bool| {
+            app.world_mut().send_event(ThreeFingerSwipe { forward });
+            app.update();
+            let state = app.world().resource::<ActState>();
+            (state.current_act, state.total_elapsed_seconds)
+        };
+
+        // Nothing before the first act
+        assert_eq!(swipe(false), (Act::Emergence, 0.0));
+        assert_eq!(
+            swipe(true),
+            (Act::Accumulation, Act::Accumulation.start_seconds())
+        );
+        assert_eq!(swipe(false), (Act::Emergence, 0.0));
+
+        for _ in 0..4 {
+            swipe(true);
+        }
+        let last = (Act::Transcendence, Act::Transcendence.start_seconds());
+        assert_eq!(swipe(true), last, "swiping past the last act is ignored");
+    }
 }
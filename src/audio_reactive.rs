@@ -1,41 +1,266 @@
 //! Module: audio_reactive
 //! Purpose: Audio analysis and visual synchronization systems for Chromatic Elegy
-//! Dependencies: types, components, resources, bevy::prelude
+//! Dependencies: types, components, resources, microphone, bevy::prelude
 
-use bevy::prelude::*;
 use bevy::color::Hsla;
+use bevy::prelude::*;
 
-use crate::components::{
-    AudioReactive, Particle, ParticleState, ParticleVisual, PulseResponder,
-};
-use crate::intro::AppState;
+use crate::components::{AudioReactive, Particle, ParticleState, ParticleVisual, PulseResponder};
+use crate::interaction::{ExplosionEvent, HyperspaceJumpEvent};
+use crate::microphone::{sync_audio_capture, AudioInputConfig, MicrophoneInput};
+use crate::osc::osc_input_live;
+use crate::particle::update_intensity;
 use crate::resources::{
-    ActState, AmbientAudioState, AudioAnalysis, AudioVisualMapping, CurrentBackground,
-    ParticlePool, ParticleSpawnQueue,
+    ActState, AmbientAudioState, AudioAnalysis, AudioVisualMapping, CurrentBackground, Intensity,
+    MotionTiming, ParticlePool, ParticleSpawnQueue, Quietude,
 };
-use crate::types::{Act, BeatStrength, FrequencyBand};
+use crate::types::{ease_in_out_cubic, in_fidget_state, Act, BeatStrength, FrequencyBand};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Spawn rate gain at zero and full `Intensity`.
+const SPAWN_RATE_INTENSITY_GAIN: (f32, f32) = (0.8, 1.2);
+
+/// Pulse bloom boost gain at zero and full `Intensity`.
+const BLOOM_PUMP_INTENSITY_GAIN: (f32, f32) = (0.5, 1.5);
 
 // =============================================================================
 // EVENTS
 // =============================================================================
 
-/// Event fired when a beat is detected in the audio stream.
+/// Defined next to the particle systems that consume it, so `ParticlePlugin`
+/// compiles without the `audio` feature.
+pub use crate::particle::BeatDetected;
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Deterministic beat clock locked to `MotionTiming.current_rhythm_bpm`.
+///
+/// When enabled, `metronome_beats` replaces `detect_beats` as the beat source,
+/// emitting `BeatDetected` precisely on the BPM grid. Useful for music-less
+/// installations that still want predictable rhythmic spawns. Modulating
+/// `current_rhythm_bpm` changes the tempo smoothly without phase jumps.
+#[derive(Resource, Debug, Clone)]
+pub struct Metronome {
+    /// Whether the metronome drives beats instead of audio analysis
+    pub enabled: bool,
+    /// Per-beat strength pattern, cycled in order (index 0 is the downbeat)
+    pub pattern: Vec<BeatStrength>,
+    /// Progress through the current beat (0.0 - 1.0)
+    pub phase: f32,
+    /// Number of beats emitted so far
+    pub beat_count: u64,
+}
+
+impl Default for Metronome {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            // Four-beat bar: strong downbeat, soft offbeats, medium backbeat
+            pattern: vec![
+                BeatStrength::Strong,
+                BeatStrength::Soft,
+                BeatStrength::Medium,
+                BeatStrength::Soft,
+            ],
+            phase: 0.0,
+            beat_count: 0,
+        }
+    }
+}
+
+impl Metronome {
+    /// Advances the beat clock and writes the strengths of any beats crossed.
+    ///
+    /// `beats` is cleared first, so a caller can reuse one buffer every frame.
+    ///
+    /// # Arguments
+    /// * `bpm` - Tempo in beats per minute
+    /// * `delta_seconds` - Time elapsed since the last tick
+    /// * `beats` - Receives the crossed beats, oldest first
+    pub fn tick(&mut self, bpm: f32, delta_seconds: f32, beats: &mut Vec<BeatStrength>) {
+        beats.clear();
+        if bpm <= 0.0 || delta_seconds <= 0.0 {
+            return;
+        }
+
+        self.phase += delta_seconds * bpm / 60.0;
+        while self.phase >= 1.0 {
+            self.phase -= 1.0;
+            beats.push(self.strength_for_beat(self.beat_count));
+            self.beat_count += 1;
+        }
+    }
+
+    /// Returns the configured strength for the given beat number.
+    #[must_use]
+    pub fn strength_for_beat(&self, beat: u64) -> BeatStrength {
+        if self.pattern.is_empty() {
+            return BeatStrength::Medium;
+        }
+        self.pattern[(beat % self.pattern.len() as u64) as usize]
+    }
+}
+
+/// Optional one-shot sound effects for interactions and strong beats.
+///
+/// Each sound plays as a transient `AudioPlayer` that despawns when finished,
+/// mixed under the ambient loop by `master_volume`. All handles default to
+/// `None`, so the experience stays silent apart from the ambient loop until
+/// a host assigns sounds.
+#[derive(Resource, Debug, Clone)]
+pub struct Sfx {
+    /// Played on `ExplosionEvent`
+    pub explosion: Option<Handle<AudioSource>>,
+    /// Played on `HyperspaceJumpEvent`
+    pub hyperspace: Option<Handle<AudioSource>>,
+    /// Played on `BeatDetected` with `BeatStrength::Strong`
+    pub strong_beat: Option<Handle<AudioSource>>,
+    /// Volume applied to every effect (0.0 to 1.0)
+    pub master_volume: f32,
+    /// Only play while the ambient loop holds audio focus, so effects never
+    /// grab focus on their own
+    pub follow_ambient_focus: bool,
+}
+
+impl Default for Sfx {
+    fn default() -> Self {
+        Self {
+            explosion: None,
+            hyperspace: None,
+            strong_beat: None,
+            master_volume: 0.4,
+            follow_ambient_focus: true,
+        }
+    }
+}
+
+/// Parameters for the per-particle breathing pulse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PulseParams {
+    /// Pulse cycles per second
+    pub frequency_hz: f32,
+    /// Opacity variation range (0.25 = oscillates between 0.75 and 1.0)
+    pub opacity_range: f32,
+    /// Scale variation added at the pulse peak (0.0 = constant scale)
+    pub scale_amplitude: f32,
+    /// Bloom contribution added when the pulse is bright
+    pub bloom_boost: f32,
+}
+
+impl Default for PulseParams {
+    fn default() -> Self {
+        Self {
+            frequency_hz: 1.5,
+            opacity_range: 0.25,
+            scale_amplitude: 0.0,
+            bloom_boost: 0.15,
+        }
+    }
+}
+
+impl PulseParams {
+    /// Blends two parameter sets (`t` = 0.0 is `self`).
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            frequency_hz: self.frequency_hz.lerp(other.frequency_hz, t),
+            opacity_range: self.opacity_range.lerp(other.opacity_range, t),
+            scale_amplitude: self.scale_amplitude.lerp(other.scale_amplitude, t),
+            bloom_boost: self.bloom_boost.lerp(other.bloom_boost, t),
+        }
+    }
+}
+
+/// Configuration for `apply_pulse_effect`, with optional per-act overrides.
 ///
-/// The strength classification determines the visual response magnitude,
-/// from subtle pulses to dramatic burst emissions.
-#[derive(Event, Debug, Clone, Copy)]
-pub struct BeatDetected {
-    /// Classification of the detected beat's intensity
-    pub strength: BeatStrength,
+/// Emergence and Transcendence breathe slower and deeper, Crescendo stays
+/// subtle under its own chaos, and Accumulation keeps the base pulse. The
+/// parameters blend through act transitions like other act values.
+#[derive(Resource, Debug, Clone)]
+pub struct PulseConfig {
+    /// Whether the breathing pulse runs at all
+    pub enabled: bool,
+    /// Parameters used when an act has no override
+    pub base: PulseParams,
+    /// Per-act overrides indexed by `Act::index()`
+    pub act_overrides: [Option<PulseParams>; 5],
+}
+
+impl Default for PulseConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            base: PulseParams::default(),
+            act_overrides: [
+                Some(PulseParams {
+                    frequency_hz: 1.0,
+                    opacity_range: 0.35,
+                    scale_amplitude: 0.05,
+                    bloom_boost: 0.12,
+                }),
+                None,
+                Some(PulseParams {
+                    frequency_hz: 1.8,
+                    opacity_range: 0.1,
+                    scale_amplitude: 0.0,
+                    bloom_boost: 0.08,
+                }),
+                Some(PulseParams {
+                    frequency_hz: 1.2,
+                    opacity_range: 0.3,
+                    scale_amplitude: 0.03,
+                    bloom_boost: 0.15,
+                }),
+                Some(PulseParams {
+                    frequency_hz: 0.75,
+                    opacity_range: 0.4,
+                    scale_amplitude: 0.08,
+                    bloom_boost: 0.2,
+                }),
+            ],
+        }
+    }
+}
+
+impl PulseConfig {
+    /// Returns the effective pulse parameters for an act.
+    #[must_use]
+    pub fn params_for(&self, act: Act) -> PulseParams {
+        self.act_overrides[act.index()].unwrap_or(self.base)
+    }
+
+    /// Pulse parameters for the current act, eased across a transition.
+    #[must_use]
+    pub fn current(&self, act_state: &ActState) -> PulseParams {
+        let current = self.params_for(act_state.current_act);
+        if !act_state.is_transitioning {
+            return current;
+        }
+        let t = ease_in_out_cubic(act_state.transition_progress);
+        self.params_for(act_state.transition_source())
+            .lerp(&current, t)
+    }
 }
 
 // =============================================================================
 // HELPER FUNCTIONS
 // =============================================================================
 
+/// Frame rate at which a `lerp_smooth` smoothing factor applies exactly once
+/// per frame. Other frame rates scale the exponent by real delta time, so the
+/// approach rate is the same in wall-clock time at any refresh rate.
+const SMOOTHING_REFERENCE_FPS: f32 = 60.0;
+
 /// Smoothly interpolates between current and target values with configurable smoothing.
 ///
-/// Uses exponential smoothing for natural, organic transitions.
+/// Uses exponential smoothing for natural, organic transitions. The smoothing
+/// factor is defined per frame at `SMOOTHING_REFERENCE_FPS` and scaled by
+/// `dt`, so results are frame-rate independent.
 ///
 /// # Arguments
 /// * `current` - The current value to interpolate from
@@ -49,7 +274,7 @@ pub struct BeatDetected {
 pub fn lerp_smooth(current: f32, target: f32, smoothing: f32, dt: f32) -> f32 {
     // Calculate the interpolation factor based on smoothing and delta time
     // Higher smoothing means slower approach to target
-    let factor = 1.0 - (smoothing.clamp(0.0, 0.99)).powf(dt * 60.0);
+    let factor = 1.0 - (smoothing.clamp(0.0, 0.99)).powf(dt * SMOOTHING_REFERENCE_FPS);
     current + (target - current) * factor
 }
 
@@ -79,20 +304,31 @@ pub fn map_range(value: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f3
 
 /// Processes audio input and updates frequency band analysis.
 ///
-/// This is a placeholder implementation that generates procedural audio-like data
-/// based on elapsed time and current act. Actual FFT audio integration can be added
-/// later by replacing the procedural generation with real audio analysis.
+/// While `MicrophoneInput` is live (see `AudioInputConfig.source`), drains the
+/// capture thread's channel and writes the newest FFT band levels. Otherwise
+/// generates procedural audio-like data based on elapsed time and the global
+/// `Intensity` (last frame's value, since intensity itself depends on the
+/// audio produced here), so the experience runs without any audio device.
+/// Skipped while `OscInput` is live, as OSC then writes the levels.
 ///
 /// # System Ordering
 /// - Priority: HIGH
-/// - Runs before: `detect_beats`
+/// - Runs after: `sync_audio_capture`
+/// - Runs before: `detect_beats`, `update_intensity`
 pub fn process_audio_input(
     time: Res<Time>,
-    act_state: Res<ActState>,
+    intensity: Res<Intensity>,
     mut audio_analysis: ResMut<AudioAnalysis>,
+    mut microphone: Option<ResMut<MicrophoneInput>>,
 ) {
+    // Real capture wins; a failed or idle device falls through to procedural data
+    if let Some(levels) = microphone.as_deref_mut().and_then(MicrophoneInput::drain) {
+        levels.apply_to(&mut audio_analysis);
+        return;
+    }
+
     let elapsed = time.elapsed_secs();
-    let act_factor = get_act_intensity_factor(&act_state.current_act);
+    let act_factor = intensity.value;
 
     // Generate procedural audio-like data based on time and act
     // These simulate realistic amplitude variations for testing without real audio
@@ -129,56 +365,48 @@ pub fn process_audio_input(
     audio_analysis.frequency_shimmer = (shimmer_base * act_factor * 0.5).clamp(0.0, 1.0);
 }
 
-/// Returns an intensity factor based on the current act.
+/// Advances `Quietude` from the current peak amplitude.
 ///
-/// Acts have different baseline intensities that affect audio-visual mappings:
-/// - Emergence: Very low (0.3) - sparse, quiet
-/// - Accumulation: Medium (0.6) - building
-/// - Crescendo: Maximum (1.0) - peak intensity
-/// - Release: Medium-high (0.7) - diminishing
-/// - Transcendence: Low (0.4) - peaceful dissolution
-fn get_act_intensity_factor(act: &Act) -> f32 {
-    match act {
-        Act::Emergence => 0.3,
-        Act::Accumulation => 0.6,
-        Act::Crescendo => 1.0,
-        Act::Release => 0.7,
-        Act::Transcendence => 0.4,
+/// Only writes when the value changes, so `update_bloom` can rely on change
+/// detection while the field rests fully calm or fully awake.
+///
+/// # System Ordering
+/// - Runs after: `process_audio_input`
+/// - Runs before: `apply_audio_to_spawn_rate`, `apply_pulse_effect`
+pub fn update_quietude(
+    time: Res<Time>,
+    audio_analysis: Res<AudioAnalysis>,
+    mut quietude: ResMut<Quietude>,
+) {
+    let value = quietude.advance(audio_analysis.amplitude_peak, time.delta_secs());
+    if quietude.value != value {
+        quietude.value = value;
     }
 }
 
-/// Analyzes amplitude history to detect beat events and classify their strength.
+/// Detects beats as spectral-flux onsets and classifies their strength.
 ///
-/// Uses energy flux analysis to detect sudden amplitude increases that
-/// correspond to rhythmic beats. Beats are classified by their intensity.
+/// Runs `AudioAnalysis::detect_onset` on this frame's band energies, with the
+/// sensitivity knobs from `AudioVisualMapping`. A detected onset is
+/// classified from its flux (scaled by `onset_strength_gain`), so sudden
+/// energy jumps read as strong beats whatever the overall loudness.
 ///
 /// # System Ordering
 /// - Runs after: `process_audio_input`
 /// - Runs before: `apply_audio_to_spawn_rate`
 pub fn detect_beats(
     time: Res<Time>,
+    mapping: Res<AudioVisualMapping>,
     mut audio_analysis: ResMut<AudioAnalysis>,
     mut beat_events: EventWriter<BeatDetected>,
 ) {
-    let elapsed = time.elapsed_secs();
-
-    // Simulate beat detection using a rhythmic pattern
-    // In a real implementation, this would analyze amplitude history
-    // and detect sudden energy increases
-
-    // Create beats at approximately 60-72 BPM (every 0.83-1.0 seconds)
-    // with some variation
-    let beat_period = 0.9 + (elapsed * 0.1).sin() * 0.15;
-    let beat_phase = (elapsed % beat_period) / beat_period;
+    let strength = audio_analysis
+        .detect_onset(&mapping, time.delta_secs())
+        .map_or(BeatStrength::Silence, |flux| {
+            classify_beat_strength(flux * mapping.onset_strength_gain)
+        });
 
-    // Detect beat on the "attack" phase (first 10% of the period)
-    let is_beat = beat_phase < 0.1;
-
-    // Classify beat strength based on amplitude
-    let amplitude = audio_analysis.amplitude_peak;
-    let strength = classify_beat_strength(amplitude);
-
-    audio_analysis.beat_detected = is_beat && strength != BeatStrength::Silence;
+    audio_analysis.beat_detected = strength != BeatStrength::Silence;
     audio_analysis.beat_strength = strength;
 
     // Send beat event if detected
@@ -187,7 +415,44 @@ pub fn detect_beats(
     }
 }
 
-/// Classifies beat strength based on amplitude threshold.
+/// Emits beats on a fixed grid from `MotionTiming.current_rhythm_bpm`.
+///
+/// An alternative to `detect_beats` for installations without music. Beat
+/// strengths follow `Metronome.pattern`, so e.g. the downbeat can be strong.
+///
+/// # System Ordering
+/// - Runs after: `process_audio_input`
+/// - Runs before: `apply_audio_to_spawn_rate`
+pub fn metronome_beats(
+    time: Res<Time>,
+    motion_timing: Res<MotionTiming>,
+    mut metronome: ResMut<Metronome>,
+    mut audio_analysis: ResMut<AudioAnalysis>,
+    mut beat_events: EventWriter<BeatDetected>,
+    mut beats: Local<Vec<BeatStrength>>,
+) {
+    metronome.tick(
+        motion_timing.current_rhythm_bpm,
+        time.delta_secs(),
+        &mut beats,
+    );
+
+    audio_analysis.beat_detected = !beats.is_empty();
+    if let Some(&strength) = beats.last() {
+        audio_analysis.beat_strength = strength;
+    }
+
+    for &strength in beats.iter() {
+        beat_events.send(BeatDetected { strength });
+    }
+}
+
+/// Run condition: true when the metronome drives beats.
+pub fn metronome_enabled(metronome: Res<Metronome>) -> bool {
+    metronome.enabled
+}
+
+/// Classifies beat strength from a level (scaled onset flux in `detect_beats`).
 ///
 /// # Thresholds
 /// - Silence: amplitude < 0.1
@@ -217,27 +482,36 @@ fn classify_beat_strength(amplitude: f32) -> BeatStrength {
 ///
 /// # Mapping Range
 /// - Input: frequency_mid (0.0 - 1.0)
-/// - Output: spawn_rate_per_second (4.0 - 40.0)
+/// - Output: spawn_rate_per_second (4.0 - 40.0), lowered further by `Quietude`
 ///
 /// # System Ordering
 /// - Runs after: `detect_beats`
 /// - Runs before: `spawn_particles_from_queue`
 pub fn apply_audio_to_spawn_rate(
+    time: Res<Time>,
     audio_analysis: Res<AudioAnalysis>,
     mapping: Res<AudioVisualMapping>,
+    intensity: Res<Intensity>,
+    quietude: Res<Quietude>,
     mut spawn_queue: ResMut<ParticleSpawnQueue>,
 ) {
     let (min_rate, max_rate) = mapping.frequency_to_spawn_rate_range;
 
-    // Map mid-frequency energy to spawn rate
-    let target_rate = map_range(audio_analysis.frequency_mid, 0.0, 1.0, min_rate, max_rate);
+    // Map mid-frequency energy to spawn rate, scaled by the global intensity
+    let (calm_gain, peak_gain) = SPAWN_RATE_INTENSITY_GAIN;
+    let target_rate = (map_range(audio_analysis.frequency_mid, 0.0, 1.0, min_rate, max_rate)
+        * intensity.gain(calm_gain, peak_gain))
+    .clamp(min_rate, max_rate);
+
+    // Quiet passages thin spawning below the mapped minimum
+    let target_rate = target_rate * quietude.scale(quietude.spawn_floor);
 
     // Apply smoothing to prevent jarring rate changes
     spawn_queue.spawn_rate_per_second = lerp_smooth(
         spawn_queue.spawn_rate_per_second,
         target_rate,
         0.2,
-        1.0 / 60.0, // Assume 60 FPS for smoothing
+        time.delta_secs(),
     );
 }
 
@@ -245,12 +519,15 @@ pub fn apply_audio_to_spawn_rate(
 ///
 /// Particles with the `AudioReactive` component have their opacity, saturation,
 /// scale, and bloom contribution modulated based on their assigned frequency band.
+/// Their hue is shifted from the base color by the shimmer band, the band
+/// whose `FrequencyBand::visual_target` is the hue shift.
 ///
 /// # Visual Mappings
 /// - Amplitude -> Opacity: 0.3 - 0.6
 /// - Amplitude -> Saturation: 0.4 - 1.0
 /// - Amplitude -> Scale: 1.0 - 2.5
 /// - Amplitude -> Bloom: 0.0 - 0.8
+/// - Shimmer energy -> Hue shift: -5 to +5 degrees
 pub fn apply_audio_to_visuals(
     time: Res<Time>,
     audio_analysis: Res<AudioAnalysis>,
@@ -258,6 +535,7 @@ pub fn apply_audio_to_visuals(
     mut query: Query<(&mut ParticleVisual, &AudioReactive), With<Particle>>,
 ) {
     let dt = time.delta_secs();
+    let shimmer = audio_analysis.frequency_shimmer;
 
     for (mut visual, audio_reactive) in query.iter_mut() {
         // Get amplitude for this particle's frequency band
@@ -317,8 +595,20 @@ pub fn apply_audio_to_visuals(
             dt,
         );
 
+        // Shift hue by shimmer energy, smoothed as an offset so it wraps cleanly
+        let target_hue_shift = map_range(
+            shimmer * audio_reactive.amplitude_sensitivity,
+            0.0,
+            1.0,
+            mapping.frequency_to_hue_shift_range.0,
+            mapping.frequency_to_hue_shift_range.1,
+        );
+        visual.hue_shift_degrees =
+            lerp_smooth(visual.hue_shift_degrees, target_hue_shift, smoothing, dt);
+        let new_hue = (base_hsla.hue + visual.hue_shift_degrees).rem_euclid(360.0);
+
         visual.current_color = Color::from(Hsla::new(
-            current_hsla.hue,
+            new_hue,
             new_saturation,
             current_hsla.lightness,
             visual.opacity,
@@ -345,16 +635,25 @@ fn get_amplitude_for_band(analysis: &AudioAnalysis, band: FrequencyBand) -> f32
 /// - Each particle pulses independently based on its lifetime
 /// - Younger particles pulse more intensely, fading as they age
 /// - Creates organic, varied visual where particles are at different phases
+/// - Frequency, depth, and bloom come from `PulseConfig` for the current act,
+///   blended through act transitions
+/// - The bloom boost pumps harder as the global `Intensity` rises and dims
+///   with `Quietude`
 pub fn apply_pulse_effect(
     mut query: Query<(&ParticleState, &mut ParticleVisual, &mut PulseResponder), With<Particle>>,
+    pulse_config: Res<PulseConfig>,
+    act_state: Res<ActState>,
+    intensity: Res<Intensity>,
+    quietude: Res<Quietude>,
 ) {
-    // Pulse frequency - how many cycles per second
-    const PULSE_FREQUENCY: f32 = 1.5;
-    // Opacity variation range (0.25 = oscillates between 0.75 and 1.0)
-    const PULSE_OPACITY_RANGE: f32 = 0.25;
+    let params = pulse_config.current(&act_state);
+    let (calm_gain, peak_gain) = BLOOM_PUMP_INTENSITY_GAIN;
+    let bloom_boost = params.bloom_boost
+        * intensity.gain(calm_gain, peak_gain)
+        * quietude.scale(quietude.bloom_floor);
 
     for (state, mut visual, mut pulse_responder) in query.iter_mut() {
-        if !state.active || state.lifetime_total_ms <= 0.0 {
+        if !pulse_config.enabled || !state.active || state.lifetime_total_ms <= 0.0 {
             pulse_responder.current_scale_modifier = 1.0;
             pulse_responder.current_opacity_modifier = 1.0;
             continue;
@@ -366,26 +665,50 @@ pub fn apply_pulse_effect(
         // Lifetime progress (0.0 = just spawned, 1.0 = about to expire)
         let lifetime_progress = 1.0 - (state.lifetime_remaining_ms / state.lifetime_total_ms);
 
-        // Pulse intensity fades as particle ages (stronger when young)
-        let intensity = (1.0 - lifetime_progress * 0.7).max(0.0);
+        let (scale_modifier, opacity_modifier, pulse_value) =
+            calculate_pulse_modifiers(&params, age_seconds, lifetime_progress);
 
-        // Continuous sine wave based on particle's age
-        // Each particle is at a different phase based on when it was spawned
-        let phase = age_seconds * PULSE_FREQUENCY * std::f32::consts::TAU;
-        let pulse_value = (phase.sin() * 0.5 + 0.5) * intensity; // 0.0 to intensity
-
-        // Apply pulse as opacity modifier (no scale to avoid blurriness)
-        // Opacity oscillates from (1.0 - range) to 1.0 based on pulse
-        pulse_responder.current_scale_modifier = 1.0; // Keep scale constant
-        pulse_responder.current_opacity_modifier = 1.0 - (1.0 - pulse_value) * PULSE_OPACITY_RANGE * intensity;
+        pulse_responder.current_scale_modifier = scale_modifier;
+        pulse_responder.current_opacity_modifier = opacity_modifier;
 
         // Subtle bloom contribution when pulsing bright
         if pulse_value > 0.5 {
-            visual.bloom_contribution = (visual.bloom_contribution + pulse_value * 0.15).min(1.0);
+            visual.bloom_contribution =
+                (visual.bloom_contribution + pulse_value * bloom_boost).min(1.0);
         }
     }
 }
 
+/// Calculates the pulse scale and opacity modifiers for a particle.
+///
+/// # Arguments
+/// * `params` - Pulse parameters for the current act
+/// * `age_seconds` - Time since the particle spawned
+/// * `lifetime_progress` - Fraction of lifetime elapsed (0.0 - 1.0)
+///
+/// # Returns
+/// `(scale_modifier, opacity_modifier, pulse_value)`
+#[must_use]
+pub fn calculate_pulse_modifiers(
+    params: &PulseParams,
+    age_seconds: f32,
+    lifetime_progress: f32,
+) -> (f32, f32, f32) {
+    // Pulse intensity fades as particle ages (stronger when young)
+    let intensity = (1.0 - lifetime_progress * 0.7).max(0.0);
+
+    // Continuous sine wave based on particle's age
+    // Each particle is at a different phase based on when it was spawned
+    let phase = age_seconds * params.frequency_hz * std::f32::consts::TAU;
+    let pulse_value = (phase.sin() * 0.5 + 0.5) * intensity; // 0.0 to intensity
+
+    // Opacity oscillates from (1.0 - range) to 1.0 based on pulse
+    let opacity_modifier = 1.0 - (1.0 - pulse_value) * params.opacity_range * intensity;
+    let scale_modifier = 1.0 + pulse_value * params.scale_amplitude;
+
+    (scale_modifier, opacity_modifier, pulse_value)
+}
+
 /// Applies subtle breathing effect to the background based on bass frequencies.
 ///
 /// Creates a gentle pulse in the background that responds to low-frequency
@@ -414,33 +737,145 @@ pub fn apply_background_pulse(
 // AMBIENT AUDIO SYSTEMS
 // =============================================================================
 
-/// Resource to hold the pre-loaded audio handle (doesn't start playback).
-#[derive(Resource)]
-pub struct AmbientAudioHandle(pub Handle<AudioSource>);
+/// Where the ambient loop's audio comes from.
+///
+/// Paths are asset paths relative to `assets/`. A non-empty `playlist`
+/// replaces `path`; its tracks play in order, each cross-fading into the next
+/// as it nears its end, and the list wraps around. Read once at startup.
+#[derive(Resource, Debug, Clone)]
+pub struct AmbientAudioConfig {
+    /// Single looping track, used when `playlist` is empty
+    pub path: String,
+    /// Tracks played in order with cross-fades between them
+    pub playlist: Vec<String>,
+    /// Length of the cross-fade between playlist tracks in seconds (at most
+    /// half of the outgoing track)
+    pub crossfade_seconds: f32,
+}
+
+impl Default for AmbientAudioConfig {
+    fn default() -> Self {
+        Self {
+            path: "audio/loop.wav".to_string(),
+            playlist: Vec::new(),
+            crossfade_seconds: 4.0,
+        }
+    }
+}
+
+impl AmbientAudioConfig {
+    /// Returns the asset paths to play, in order.
+    #[must_use]
+    pub fn tracks(&self) -> Vec<&str> {
+        if self.playlist.is_empty() {
+            vec![self.path.as_str()]
+        } else {
+            self.playlist.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// Returns the cross-fade length for a track lasting `track_seconds`.
+    ///
+    /// Clamped to half the track so the switch point never reaches the
+    /// track's start, which would spawn a new player every frame.
+    #[must_use]
+    pub fn crossfade_seconds_for(&self, track_seconds: f32) -> f32 {
+        self.crossfade_seconds
+            .clamp(0.0, track_seconds.max(0.0) * 0.5)
+    }
+}
+
+/// Pre-loaded ambient track handles, in playlist order (doesn't start playback).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AmbientAudioHandles(pub Vec<Handle<AudioSource>>);
 
 /// Marker resource indicating audio should be disabled (file not found).
 #[derive(Resource, Default)]
 pub struct AudioDisabled;
 
-/// Pre-loads the ambient audio asset without starting playback.
+/// Pre-loads the configured ambient tracks without starting playback.
 ///
 /// Audio playback is deferred until particles exceed the threshold,
 /// preventing the app from stealing audio focus on launch.
 ///
-/// If the audio file (assets/audio/loop.wav) doesn't exist, audio is
-/// silently disabled - this allows disabling audio by simply removing the file.
-pub fn preload_ambient_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
-    // Check if audio file exists in assets directory
+/// Tracks missing from the assets directory are skipped with a log message;
+/// if none remain, ambient audio is disabled. This still allows disabling
+/// audio by simply removing the default `assets/audio/loop.wav`.
+pub fn preload_ambient_audio(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    config: Res<AmbientAudioConfig>,
+) {
     // On Android, bundled assets aren't accessible via filesystem - this check will
     // return false, effectively disabling audio until a proper audio file is bundled
-    let audio_path = std::path::Path::new("assets/audio/loop.wav");
-    if audio_path.exists() {
-        info!("Loading ambient audio loop");
-        let audio_handle: Handle<AudioSource> = asset_server.load("audio/loop.wav");
-        commands.insert_resource(AmbientAudioHandle(audio_handle));
+    let handles: Vec<Handle<AudioSource>> = config
+        .tracks()
+        .into_iter()
+        .filter(|path| {
+            let exists = std::path::Path::new("assets").join(path).exists();
+            if !exists {
+                info!("Ambient audio track '{}' not found - skipping", path);
+            }
+            exists
+        })
+        .map(|path| asset_server.load(path.to_string()))
+        .collect();
+
+    if handles.is_empty() {
+        info!("No ambient audio tracks available - disabling ambient audio");
+        commands.insert_resource(AudioDisabled);
     } else {
-        // Audio file not present - disable audio feature silently
-        let _ = &asset_server; // Suppress unused warning
+        info!("Loading {} ambient audio track(s)", handles.len());
+        commands.insert_resource(AmbientAudioHandles(handles));
+    }
+}
+
+/// Drops ambient tracks whose asset failed to load.
+///
+/// Each failure is logged and `current_track` shifts down past the removed
+/// tracks before it, so it keeps naming the same track (or, if the current
+/// track failed, the one after it). Once no track is left, any playing
+/// players are released and ambient audio is disabled.
+pub fn drop_failed_ambient_tracks(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut handles: ResMut<AmbientAudioHandles>,
+    mut ambient_state: ResMut<AmbientAudioState>,
+) {
+    use bevy::asset::LoadState;
+
+    let before = handles.0.len();
+    let current = ambient_state.current_track;
+    let mut index = 0;
+    let mut removed_before_current = 0;
+    handles.0.retain(|handle| {
+        let failed = matches!(
+            asset_server.get_load_state(handle),
+            Some(LoadState::Failed(_))
+        );
+        if failed {
+            // On Android the file may be missing from the bundle
+            warn!(
+                "Ambient audio track {:?} failed to load - skipping",
+                handle.path()
+            );
+            if index < current {
+                removed_before_current += 1;
+            }
+        }
+        index += 1;
+        !failed
+    });
+    if handles.0.len() == before {
+        return;
+    }
+
+    ambient_state.current_track = (current - removed_before_current) % handles.0.len().max(1);
+    if handles.0.is_empty() {
+        warn!("All ambient audio tracks failed to load - disabling ambient audio");
+        for entity in ambient_state.release_players() {
+            commands.entity(entity).despawn();
+        }
         commands.insert_resource(AudioDisabled);
     }
 }
@@ -450,12 +885,14 @@ pub fn preload_ambient_audio(mut commands: Commands, asset_server: Res<AssetServ
 /// - Starts playback only when particles exceed threshold (avoids stealing audio focus)
 /// - Stops playback when particles drop to zero (releases audio focus)
 /// - Volume scales with particle count for smooth blending with device audio
+/// - During a playlist cross-fade the volume is split between the two tracks
+#[allow(clippy::too_many_arguments)]
 pub fn update_ambient_audio(
     mut commands: Commands,
     time: Res<Time>,
     particle_pool: Res<ParticlePool>,
     mut ambient_state: ResMut<AmbientAudioState>,
-    audio_handle: Option<Res<AmbientAudioHandle>>,
+    audio_handles: Res<AmbientAudioHandles>,
     audio_sinks: Query<&AudioSink>,
     audio_disabled: Option<Res<AudioDisabled>>,
     asset_server: Res<AssetServer>,
@@ -465,22 +902,15 @@ pub fn update_ambient_audio(
         return;
     }
 
-    // On Android, check if the asset failed to load
-    if let Some(ref handle) = audio_handle {
-        use bevy::asset::LoadState;
-        match asset_server.get_load_state(&handle.0) {
-            Some(LoadState::Failed(_)) => {
-                // Asset failed to load - audio file missing from bundle
-                info!("Audio asset failed to load - disabling audio");
-                commands.insert_resource(AudioDisabled);
-                return;
-            }
-            Some(LoadState::Loading) => {
-                // Still loading, wait
-                return;
-            }
-            _ => {}
-        }
+    // Wait until the current track is ready before starting playback
+    let Some(handle) = audio_handles.0.get(ambient_state.current_track) else {
+        return;
+    };
+    if matches!(
+        asset_server.get_load_state(handle),
+        Some(bevy::asset::LoadState::Loading)
+    ) {
+        return;
     }
     let dt = time.delta_secs();
     let active = particle_pool.active_count;
@@ -503,25 +933,16 @@ pub fn update_ambient_audio(
 
     if needs_audio && !has_audio {
         // Start audio playback - particles have crossed threshold
-        if let Some(handle) = &audio_handle {
-            info!("Starting ambient audio (particles: {})", active);
-            let entity = commands
-                .spawn((
-                    AudioPlayer::<AudioSource>(handle.0.clone()),
-                    PlaybackSettings {
-                        mode: bevy::audio::PlaybackMode::Loop,
-                        volume: bevy::audio::Volume::new(0.0), // Start silent, will fade in
-                        ..default()
-                    },
-                    Name::new("AmbientAudioLoop"),
-                ))
-                .id();
-            ambient_state.audio_entity = Some(entity);
+        info!("Starting ambient audio (particles: {})", active);
+        let entity = spawn_ambient_player(&mut commands, handle);
+        let track = ambient_state.current_track;
+        if let Some(stale) = ambient_state.begin_crossfade(entity, track) {
+            commands.entity(stale).despawn();
         }
     } else if !needs_audio && has_audio && ambient_state.current_volume < 0.001 {
         // Stop audio playback - volume has faded to zero, release audio focus
-        if let Some(entity) = ambient_state.audio_entity.take() {
-            info!("Stopping ambient audio (releasing audio focus)");
+        info!("Stopping ambient audio (releasing audio focus)");
+        for entity in ambient_state.release_players() {
             commands.entity(entity).despawn();
         }
     }
@@ -540,11 +961,145 @@ pub fn update_ambient_audio(
         dt,
     );
 
-    // Apply volume to audio sink if it exists
-    if let Some(entity) = ambient_state.audio_entity {
-        if let Ok(sink) = audio_sinks.get(entity) {
-            sink.set_volume(ambient_state.current_volume);
+    // Apply volume to the audio sinks that exist
+    let (incoming_volume, outgoing_volume) = ambient_state.crossfade_volumes();
+    let players = [
+        (ambient_state.audio_entity, incoming_volume),
+        (ambient_state.fading_entity, outgoing_volume),
+    ];
+    for (entity, volume) in players {
+        if let Some(sink) = entity.and_then(|entity| audio_sinks.get(entity).ok()) {
+            sink.set_volume(volume);
+        }
+    }
+}
+
+/// Cross-fades to the next playlist track as the current one nears its end.
+///
+/// Track lengths come from the decoded audio header and are cached per
+/// asset; a track whose length cannot be determined keeps looping.
+/// Single-track configurations simply loop.
+///
+/// # Ordering
+/// Runs after `update_ambient_audio`.
+pub fn advance_ambient_playlist(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<AmbientAudioConfig>,
+    handles: Res<AmbientAudioHandles>,
+    audio_sources: Res<Assets<AudioSource>>,
+    mut ambient_state: ResMut<AmbientAudioState>,
+    mut track_lengths: Local<bevy::utils::HashMap<AssetId<AudioSource>, Option<f32>>>,
+) {
+    use bevy::audio::{Decodable, Source};
+
+    let dt = time.delta_secs();
+    if let Some(finished) = ambient_state.advance_crossfade(dt, config.crossfade_seconds) {
+        commands.entity(finished).despawn();
+    }
+    if ambient_state.audio_entity.is_none() || handles.0.len() < 2 {
+        return;
+    }
+
+    ambient_state.track_elapsed_seconds += dt;
+    let Some(handle) = handles.0.get(ambient_state.current_track) else {
+        return;
+    };
+    let Some(source) = audio_sources.get(handle) else {
+        return;
+    };
+    let track_seconds = *track_lengths.entry(handle.id()).or_insert_with(|| {
+        let length = source.decoder().total_duration().map(|d| d.as_secs_f32());
+        if length.is_none() {
+            info!(
+                "Ambient track {:?} has no known length - it will loop",
+                handle.path()
+            );
         }
+        length
+    });
+    let Some(track_seconds) = track_seconds else {
+        return;
+    };
+
+    let switch_at = track_seconds - config.crossfade_seconds_for(track_seconds);
+    if ambient_state.track_elapsed_seconds < switch_at {
+        return;
+    }
+
+    let next = (ambient_state.current_track + 1) % handles.0.len();
+    info!("Cross-fading ambient audio to track {}", next);
+    let entity = spawn_ambient_player(&mut commands, &handles.0[next]);
+    if let Some(stale) = ambient_state.begin_crossfade(entity, next) {
+        commands.entity(stale).despawn();
+    }
+}
+
+/// Spawns a silent looping ambient player; `update_ambient_audio` fades it in.
+fn spawn_ambient_player(commands: &mut Commands, handle: &Handle<AudioSource>) -> Entity {
+    commands
+        .spawn((
+            AudioPlayer::<AudioSource>(handle.clone()),
+            PlaybackSettings {
+                mode: bevy::audio::PlaybackMode::Loop,
+                volume: bevy::audio::Volume::new(0.0), // Start silent, will fade in
+                ..default()
+            },
+            Name::new("AmbientAudioLoop"),
+        ))
+        .id()
+}
+
+// =============================================================================
+// SFX SYSTEMS
+// =============================================================================
+
+/// Plays the configured one-shot effects for this frame's events.
+///
+/// Each effect plays at most once per frame however many matching events
+/// arrived. Nothing plays when audio is disabled, or (with
+/// `Sfx.follow_ambient_focus`) while the ambient loop is stopped.
+///
+/// # System Ordering
+/// - Runs after: `detect_beats`
+pub fn play_interaction_sfx(
+    mut commands: Commands,
+    sfx: Res<Sfx>,
+    ambient_state: Res<AmbientAudioState>,
+    audio_disabled: Option<Res<AudioDisabled>>,
+    mut explosion_events: EventReader<ExplosionEvent>,
+    mut hyperspace_events: EventReader<HyperspaceJumpEvent>,
+    mut beat_events: EventReader<BeatDetected>,
+) {
+    let exploded = explosion_events.read().count() > 0;
+    let jumped = hyperspace_events.read().count() > 0;
+    let strong_beat = beat_events
+        .read()
+        .any(|beat| beat.strength == BeatStrength::Strong);
+
+    let focus_lost = sfx.follow_ambient_focus && ambient_state.audio_entity.is_none();
+    if audio_disabled.is_some() || focus_lost {
+        return;
+    }
+
+    let triggered = [
+        (exploded, &sfx.explosion, "ExplosionSfx"),
+        (jumped, &sfx.hyperspace, "HyperspaceSfx"),
+        (strong_beat, &sfx.strong_beat, "StrongBeatSfx"),
+    ];
+    for (fired, handle, name) in triggered {
+        let (true, Some(handle)) = (fired, handle) else {
+            continue;
+        };
+        commands.spawn((
+            AudioPlayer::<AudioSource>(handle.clone()),
+            PlaybackSettings {
+                mode: bevy::audio::PlaybackMode::Despawn,
+                volume: bevy::audio::Volume::new(sfx.master_volume.clamp(0.0, 1.0)),
+                ..default()
+            },
+            Name::new(name),
+        ));
     }
 }
 
@@ -555,13 +1110,16 @@ pub fn update_ambient_audio(
 /// Plugin that registers all audio-reactive systems and events.
 ///
 /// This plugin handles:
-/// - Audio input processing (placeholder for FFT integration)
-/// - Beat detection and event emission
+/// - Audio input processing (microphone/file FFT, or procedural fallback)
+/// - Beat detection and event emission (or a BPM-locked metronome); both are
+///   skipped while `OscInputPlugin` receives live OSC audio
 /// - Audio-to-spawn-rate mapping
 /// - Particle visual modulation based on audio
 /// - Pulse effects synchronized with beats
 /// - Background breathing effects
-/// - Ambient audio loop with volume based on particle count
+/// - Ambient audio loop (or cross-fading playlist, see `AmbientAudioConfig`)
+///   with volume based on particle count
+/// - Optional one-shot interaction sound effects (`Sfx`)
 pub struct AudioReactivePlugin;
 
 impl Plugin for AudioReactivePlugin {
@@ -569,25 +1127,66 @@ impl Plugin for AudioReactivePlugin {
         app
             // Register events
             .add_event::<BeatDetected>()
+            .init_resource::<Metronome>()
+            .init_resource::<PulseConfig>()
+            .init_resource::<Sfx>()
+            .init_resource::<AudioInputConfig>()
+            .init_resource::<MicrophoneInput>()
+            .init_resource::<AmbientAudioConfig>()
             // Startup: pre-load ambient audio (doesn't start playback)
             .add_systems(Startup, preload_ambient_audio)
+            // (Re)start capture whenever the audio source changes
+            .add_systems(
+                Update,
+                sync_audio_capture
+                    .run_if(resource_changed::<AudioInputConfig>)
+                    .before(process_audio_input),
+            )
             // Add systems with proper ordering (only in Fidget state)
             .add_systems(
                 Update,
                 (
-                    // Audio processing chain (high priority, runs first)
-                    process_audio_input,
-                    detect_beats.after(process_audio_input),
+                    // Audio processing chain (high priority, runs first); OSC input
+                    // replaces it while live. `ParticlePlugin` then derives `Intensity`
+                    process_audio_input
+                        .run_if(not(osc_input_live))
+                        .before(update_intensity),
+                    update_quietude
+                        .after(process_audio_input)
+                        .before(apply_audio_to_spawn_rate)
+                        .before(apply_pulse_effect),
+                    detect_beats
+                        .after(process_audio_input)
+                        .run_if(not(metronome_enabled))
+                        .run_if(not(osc_input_live)),
+                    metronome_beats
+                        .after(process_audio_input)
+                        .before(apply_audio_to_spawn_rate)
+                        .run_if(metronome_enabled),
                     // Spawn rate mapping (after beat detection)
-                    apply_audio_to_spawn_rate.after(detect_beats),
+                    apply_audio_to_spawn_rate
+                        .after(detect_beats)
+                        .after(update_intensity),
                     // Visual systems (can run in parallel after audio processing)
                     apply_audio_to_visuals.after(detect_beats),
-                    apply_pulse_effect.after(apply_audio_to_visuals),
+                    apply_pulse_effect
+                        .after(apply_audio_to_visuals)
+                        .after(update_intensity),
                     apply_background_pulse.after(detect_beats),
                     // Ambient audio management (starts/stops based on particles)
-                    update_ambient_audio,
+                    (
+                        drop_failed_ambient_tracks,
+                        update_ambient_audio,
+                        advance_ambient_playlist,
+                    )
+                        .chain()
+                        .run_if(resource_exists::<AmbientAudioHandles>)
+                        .run_if(not(resource_exists::<AudioDisabled>)),
+                    play_interaction_sfx
+                        .after(detect_beats)
+                        .after(metronome_beats),
                 )
-                    .run_if(in_state(AppState::Fidget)),
+                    .run_if(in_fidget_state),
             );
     }
 }
@@ -599,6 +1198,7 @@ impl Plugin for AudioReactivePlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::resources::MouseState;
 
     #[test]
     fn test_lerp_smooth_instant() {
@@ -622,6 +1222,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_spawn_rate_smoothing_is_frame_rate_independent() {
+        use std::time::Duration;
+
+        // Runs the spawn-rate system for 480ms of wall-clock time
+        let run = |step_ms: u64| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .insert_resource(AudioAnalysis {
+                    frequency_mid: 1.0,
+                    ..Default::default()
+                })
+                .init_resource::<AudioVisualMapping>()
+                .init_resource::<Intensity>()
+                .init_resource::<Quietude>()
+                .init_resource::<ParticleSpawnQueue>()
+                .add_systems(Update, apply_audio_to_spawn_rate);
+
+            for _ in 0..(480 / step_ms) {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(step_ms));
+                app.update();
+            }
+            app.world()
+                .resource::<ParticleSpawnQueue>()
+                .spawn_rate_per_second
+        };
+
+        let at_60hz = run(16);
+        let at_120hz = run(8);
+        let start = ParticleSpawnQueue::default().spawn_rate_per_second;
+        assert!(at_60hz > start, "rate should approach the target");
+        assert!(
+            (at_60hz - at_120hz).abs() < 0.01,
+            "16ms: {at_60hz}, 8ms: {at_120hz}"
+        );
+    }
+
     #[test]
     fn test_lerp_smooth_no_change_when_equal() {
         let result = lerp_smooth(0.5, 0.5, 0.5, 0.016);
@@ -711,13 +1350,509 @@ mod tests {
         assert_eq!(classify_beat_strength(1.0), BeatStrength::Strong);
     }
 
+    /// Feeds `frames` of bass energy produced by `bass` and returns the onset frames.
+    fn onset_frames(
+        analysis: &mut AudioAnalysis,
+        mapping: &AudioVisualMapping,
+        frames: usize,
+        bass: impl Fn(usize) -> f32,
+    ) -> Vec<usize> {
+        (0..frames)
+            .filter(|&frame| {
+                analysis.frequency_bass = bass(frame);
+                analysis.detect_onset(mapping, 1.0 / 60.0).is_some()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_onset_fires_on_energy_jumps_not_steady_ramps() {
+        let mapping = AudioVisualMapping::default();
+        let mut analysis = AudioAnalysis::default();
+
+        // A slow steady ramp has constant flux, which never clears the threshold
+        let ramp = onset_frames(&mut analysis, &mapping, 120, |frame| frame as f32 / 240.0);
+        assert!(ramp.is_empty(), "ramp fired at {ramp:?}");
+
+        // Kicks every half second: energy jumps to 0.9 and decays back down
+        let mut analysis = AudioAnalysis::default();
+        let kicks = onset_frames(&mut analysis, &mapping, 180, |frame| {
+            let since_kick = frame % 30;
+            if frame < 30 {
+                0.1
+            } else {
+                0.1 + 0.8 * (-(since_kick as f32) / 4.0).exp()
+            }
+        });
+        assert_eq!(kicks, vec![30, 60, 90, 120, 150]);
+        assert!(analysis.onset_flux < mapping.onset_min_flux);
+    }
+
+    #[test]
+    fn test_onset_min_interval_suppresses_double_triggers() {
+        let mapping = AudioVisualMapping::default();
+        let mut analysis = AudioAnalysis::default();
+
+        // Two jumps 3 frames (50 ms) apart count as one onset
+        let onsets = onset_frames(&mut analysis, &mapping, 40, |frame| match frame {
+            0..=19 => 0.0,
+            20..=22 => 0.5,
+            _ => 1.0,
+        });
+        assert_eq!(onsets, vec![20]);
+
+        // Raising the sensitivity floor ignores small jumps entirely
+        let strict = AudioVisualMapping {
+            onset_min_flux: 0.6,
+            ..Default::default()
+        };
+        let mut analysis = AudioAnalysis::default();
+        let onsets = onset_frames(&mut analysis, &strict, 40, |frame| match frame {
+            0..=19 => 0.0,
+            _ => 0.5,
+        });
+        assert!(onsets.is_empty());
+    }
+
+    #[test]
+    fn test_beat_strength_follows_flux() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<AudioAnalysis>()
+            .init_resource::<AudioVisualMapping>()
+            .add_event::<BeatDetected>()
+            .add_systems(Update, detect_beats);
+
+        let step = |app: &mut App, bass: f32| {
+            app.world_mut()
+                .resource_mut::<AudioAnalysis>()
+                .frequency_bass = bass;
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(16));
+            app.update();
+            let analysis = app.world().resource::<AudioAnalysis>();
+            (analysis.beat_detected, analysis.beat_strength)
+        };
+
+        for _ in 0..10 {
+            assert_eq!(step(&mut app, 0.2), (false, BeatStrength::Silence));
+        }
+        // A small jump is soft; loud but steady audio is not a beat
+        assert_eq!(step(&mut app, 0.25), (true, BeatStrength::Soft));
+        for _ in 0..20 {
+            step(&mut app, 0.25);
+        }
+        assert_eq!(step(&mut app, 0.25), (false, BeatStrength::Silence));
+        // A large jump is strong
+        assert_eq!(step(&mut app, 0.45), (true, BeatStrength::Strong));
+    }
+
+    #[test]
+    fn test_metronome_120_bpm_fires_two_beats_per_second() {
+        let mut metronome = Metronome::default();
+
+        // 64 frames per second keeps the frame delta exact in binary
+        let dt = 1.0 / 64.0;
+        let mut crossed = Vec::new();
+        for second in 0..10 {
+            let mut beats = 0;
+            for _ in 0..64 {
+                metronome.tick(120.0, dt, &mut crossed);
+                beats += crossed.len();
+            }
+            assert_eq!(beats, 2, "second {} fired {} beats", second, beats);
+        }
+        assert_eq!(metronome.beat_count, 20);
+    }
+
     #[test]
-    fn test_get_act_intensity_factor() {
-        assert_eq!(get_act_intensity_factor(&Act::Emergence), 0.3);
-        assert_eq!(get_act_intensity_factor(&Act::Accumulation), 0.6);
-        assert_eq!(get_act_intensity_factor(&Act::Crescendo), 1.0);
-        assert_eq!(get_act_intensity_factor(&Act::Release), 0.7);
-        assert_eq!(get_act_intensity_factor(&Act::Transcendence), 0.4);
+    fn test_metronome_strength_pattern() {
+        let mut metronome = Metronome {
+            pattern: vec![BeatStrength::Strong, BeatStrength::Soft],
+            ..Default::default()
+        };
+
+        let mut beats = Vec::new();
+        metronome.tick(60.0, 4.0, &mut beats);
+        assert_eq!(
+            beats,
+            vec![
+                BeatStrength::Strong,
+                BeatStrength::Soft,
+                BeatStrength::Strong,
+                BeatStrength::Soft,
+            ]
+        );
+
+        // A tick that crosses no beat empties the reused buffer in place
+        let buffer = beats.as_ptr();
+        metronome.tick(60.0, 0.5, &mut beats);
+        assert!(beats.is_empty());
+        assert_eq!(beats.as_ptr(), buffer);
+
+        // Empty pattern falls back to medium beats
+        metronome.pattern.clear();
+        assert_eq!(metronome.strength_for_beat(0), BeatStrength::Medium);
+    }
+
+    #[test]
+    fn test_pulse_amplitude_changes_scale_range() {
+        let scale_range = |params: &PulseParams| {
+            let (mut min, mut max) = (f32::MAX, f32::MIN);
+            for i in 0..100 {
+                let (scale, _, _) = calculate_pulse_modifiers(params, i as f32 * 0.01, 0.0);
+                min = min.min(scale);
+                max = max.max(scale);
+            }
+            max - min
+        };
+
+        // Default keeps scale constant, matching the original behaviour
+        let default_params = PulseParams::default();
+        assert!(scale_range(&default_params) < 0.001);
+
+        let deep = PulseParams {
+            scale_amplitude: 0.2,
+            ..default_params
+        };
+        let deeper = PulseParams {
+            scale_amplitude: 0.4,
+            ..default_params
+        };
+        assert!(scale_range(&deep) > 0.15);
+        assert!(scale_range(&deeper) > scale_range(&deep));
+    }
+
+    #[test]
+    fn test_pulse_config_act_override() {
+        let mut config = PulseConfig::default();
+
+        // Calm acts breathe slower and deeper than the Crescendo
+        let calm = config.params_for(Act::Transcendence);
+        let chaos = config.params_for(Act::Crescendo);
+        assert!(calm.frequency_hz < chaos.frequency_hz);
+        assert!(calm.opacity_range > chaos.opacity_range);
+        assert_eq!(config.params_for(Act::Accumulation), PulseParams::default());
+
+        let slow = PulseParams {
+            frequency_hz: 0.5,
+            ..PulseParams::default()
+        };
+        config.act_overrides[Act::Transcendence.index()] = Some(slow);
+        assert_eq!(config.params_for(Act::Transcendence), slow);
+    }
+
+    #[test]
+    fn test_pulse_params_blend_through_transition() {
+        let config = PulseConfig::default();
+        let mut act_state = ActState {
+            current_act: Act::Crescendo,
+            is_transitioning: true,
+            transition_progress: 0.0,
+            transition_from: Some(Act::Accumulation),
+            ..Default::default()
+        };
+
+        // The transition starts on the outgoing act and eases into the new one
+        assert_eq!(
+            config.current(&act_state),
+            config.params_for(Act::Accumulation)
+        );
+
+        act_state.transition_progress = 0.5;
+        let midway = config.current(&act_state);
+        let from = config.params_for(Act::Accumulation).opacity_range;
+        let to = config.params_for(Act::Crescendo).opacity_range;
+        assert!(midway.opacity_range < from && midway.opacity_range > to);
+
+        act_state.is_transitioning = false;
+        assert_eq!(
+            config.current(&act_state),
+            config.params_for(Act::Crescendo)
+        );
+    }
+
+    #[test]
+    fn test_explosion_with_sfx_spawns_one_shot_audio() {
+        let mut app = App::new();
+        app.insert_resource(Sfx {
+            explosion: Some(Handle::default()),
+            ..Default::default()
+        })
+        .insert_resource(AmbientAudioState {
+            audio_entity: Some(Entity::PLACEHOLDER),
+            ..Default::default()
+        })
+        .add_event::<ExplosionEvent>()
+        .add_event::<HyperspaceJumpEvent>()
+        .add_event::<BeatDetected>()
+        .add_systems(Update, play_interaction_sfx);
+
+        let count_players = |app: &mut App| {
+            app.world_mut()
+                .query::<(&AudioPlayer<AudioSource>, &PlaybackSettings)>()
+                .iter(app.world())
+                .filter(|(_, settings)| matches!(settings.mode, bevy::audio::PlaybackMode::Despawn))
+                .count()
+        };
+
+        // Without an event nothing plays
+        app.update();
+        assert_eq!(count_players(&mut app), 0);
+
+        app.world_mut().send_event(ExplosionEvent {
+            origin: Vec2::ZERO,
+            strength: 1.0,
+        });
+        app.world_mut().send_event(ExplosionEvent {
+            origin: Vec2::ONE,
+            strength: 1.0,
+        });
+        app.update();
+        assert_eq!(count_players(&mut app), 1);
+
+        // Unconfigured sounds stay silent
+        app.world_mut().send_event(HyperspaceJumpEvent {
+            vanishing_point: Vec2::ZERO,
+        });
+        app.update();
+        assert_eq!(count_players(&mut app), 1);
+    }
+
+    #[test]
+    fn test_crossfade_stays_below_track_length() {
+        let config = AmbientAudioConfig {
+            crossfade_seconds: 4.0,
+            ..Default::default()
+        };
+        assert_eq!(config.crossfade_seconds_for(60.0), 4.0);
+
+        // A track shorter than the cross-fade still plays half of itself first
+        assert_eq!(config.crossfade_seconds_for(3.0), 1.5);
+        assert_eq!(config.crossfade_seconds_for(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_ambient_playlist_switching_leaves_no_dangling_players() {
+        let mut world = World::new();
+        let mut state = AmbientAudioState::default();
+
+        // Every spawned player is either tracked by the state or despawned
+        let assert_tracked = |world: &mut World, state: &AmbientAudioState| {
+            let live: Vec<Entity> = world.query::<Entity>().iter(world).collect();
+            for entity in &live {
+                assert!(
+                    state.audio_entity == Some(*entity) || state.fading_entity == Some(*entity),
+                    "{entity} is playing untracked"
+                );
+            }
+            assert_eq!(
+                live.len(),
+                state
+                    .audio_entity
+                    .iter()
+                    .chain(state.fading_entity.iter())
+                    .count()
+            );
+        };
+        let switch = |world: &mut World, state: &mut AmbientAudioState, track| {
+            let incoming = world.spawn_empty().id();
+            if let Some(stale) = state.begin_crossfade(incoming, track) {
+                world.despawn(stale);
+            }
+        };
+
+        // Start the first track: nothing to fade out
+        switch(&mut world, &mut state, 0);
+        assert_eq!(state.fading_entity, None);
+        assert_eq!(state.crossfade_progress, 1.0);
+        assert_tracked(&mut world, &state);
+
+        // Switch mid-track and again before the first fade finishes
+        switch(&mut world, &mut state, 1);
+        assert!(state.fading_entity.is_some());
+        assert_tracked(&mut world, &state);
+        assert_eq!(state.advance_crossfade(1.0, 4.0), None);
+        switch(&mut world, &mut state, 2);
+        assert_eq!(state.current_track, 2);
+        assert_tracked(&mut world, &state);
+
+        // Finishing the fade hands back the outgoing player
+        state.current_volume = 0.6;
+        assert_eq!(state.advance_crossfade(2.0, 4.0), None);
+        let (incoming, outgoing) = state.crossfade_volumes();
+        assert!((incoming - 0.3).abs() < 1e-5 && (outgoing - 0.3).abs() < 1e-5);
+        let finished = state.advance_crossfade(2.0, 4.0).unwrap();
+        world.despawn(finished);
+        assert_eq!(state.crossfade_volumes(), (0.6, 0.0));
+        assert_tracked(&mut world, &state);
+
+        // Stopping mid-fade releases both players
+        switch(&mut world, &mut state, 0);
+        for entity in state.release_players() {
+            world.despawn(entity);
+        }
+        assert_eq!(state.audio_entity, None);
+        assert_eq!(state.fading_entity, None);
+        assert_tracked(&mut world, &state);
+        assert_eq!(world.entities().len(), 0);
+    }
+
+    #[test]
+    fn test_ambient_config_playlist_overrides_path() {
+        let mut config = AmbientAudioConfig::default();
+        assert_eq!(config.tracks(), vec!["audio/loop.wav"]);
+
+        config.playlist = vec!["audio/a.ogg".to_string(), "audio/b.ogg".to_string()];
+        assert_eq!(config.tracks(), vec!["audio/a.ogg", "audio/b.ogg"]);
+    }
+
+    #[test]
+    fn test_act_intensity_baseline() {
+        assert_eq!(Intensity::act_baseline(Act::Emergence), 0.3);
+        assert_eq!(Intensity::act_baseline(Act::Accumulation), 0.6);
+        assert_eq!(Intensity::act_baseline(Act::Crescendo), 1.0);
+        assert_eq!(Intensity::act_baseline(Act::Release), 0.7);
+        assert_eq!(Intensity::act_baseline(Act::Transcendence), 0.4);
+    }
+
+    #[test]
+    fn test_higher_act_and_audio_energy_raise_intensity() {
+        let compute = |act: Act, amplitude_peak: f32| {
+            let mut app = App::new();
+            app.insert_resource(ActState {
+                current_act: act,
+                ..Default::default()
+            })
+            .insert_resource(AudioAnalysis {
+                amplitude_peak,
+                ..Default::default()
+            })
+            .init_resource::<MouseState>()
+            .init_resource::<Intensity>()
+            .add_systems(Update, update_intensity);
+            app.update();
+            app.world().resource::<Intensity>().value
+        };
+
+        // A silent scene rests on the act baseline
+        assert_eq!(
+            compute(Act::Emergence, 0.0),
+            Intensity::act_baseline(Act::Emergence)
+        );
+
+        let calm = compute(Act::Emergence, 0.1);
+        let louder = compute(Act::Emergence, 0.9);
+        let higher_act = compute(Act::Accumulation, 0.1);
+        let both = compute(Act::Accumulation, 0.9);
+        assert!(louder > calm);
+        assert!(higher_act > calm);
+        assert!(both > louder && both > higher_act);
+        assert!(both <= 1.0);
+
+        // An external controller overrides the blend
+        let mut app = App::new();
+        app.init_resource::<ActState>()
+            .init_resource::<AudioAnalysis>()
+            .init_resource::<MouseState>()
+            .insert_resource(Intensity {
+                external_override: Some(0.85),
+                ..Default::default()
+            })
+            .add_systems(Update, update_intensity);
+        app.update();
+        assert_eq!(app.world().resource::<Intensity>().value, 0.85);
+    }
+
+    #[test]
+    fn test_sustained_silence_calms_turbulence() {
+        use crate::particle::turbulence_strength;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<AudioAnalysis>()
+            .init_resource::<Quietude>()
+            .add_systems(Update, update_quietude);
+        let run = |app: &mut App, amplitude_peak: f32, seconds: u64| {
+            app.world_mut()
+                .resource_mut::<AudioAnalysis>()
+                .amplitude_peak = amplitude_peak;
+            for _ in 0..seconds * 10 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(100));
+                app.update();
+            }
+        };
+        let intensity = Intensity::for_act(Act::Accumulation);
+        let strength =
+            |app: &App| turbulence_strength(Act::Accumulation, &intensity, app.world().resource());
+        let awake = strength(&app);
+
+        // A short gap between notes barely registers
+        run(&mut app, 0.0, 1);
+        assert!(strength(&app) > awake * 0.8);
+
+        // Sustained silence settles the field to the floor
+        run(&mut app, 0.0, 5);
+        let quiet = app.world().resource::<Quietude>().clone();
+        assert_eq!(quiet.value, 1.0);
+        assert!((strength(&app) - awake * quiet.turbulence_floor).abs() < 1e-4);
+
+        // Sound returning wakes it back up
+        run(&mut app, 0.5, 1);
+        assert_eq!(strength(&app), awake);
+    }
+
+    #[test]
+    fn test_shimmer_shifts_hue_and_wraps() {
+        let base = Color::from(Hsla::new(358.0, 0.8, 0.5, 1.0));
+        let run = |shimmer: f32| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .insert_resource(AudioAnalysis {
+                    frequency_shimmer: shimmer,
+                    ..Default::default()
+                })
+                .init_resource::<AudioVisualMapping>()
+                .add_systems(Update, apply_audio_to_visuals);
+            let entity = app
+                .world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleVisual {
+                        base_color: base,
+                        current_color: base,
+                        ..Default::default()
+                    },
+                    AudioReactive {
+                        amplitude_sensitivity: 1.0,
+                        frequency_band: FrequencyBand::Shimmer,
+                        response_smoothing: 0.0,
+                    },
+                ))
+                .id();
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(16));
+            app.update();
+            let visual = *app.world().get::<ParticleVisual>(entity).unwrap();
+            (visual.hue_shift_degrees, Hsla::from(visual.current_color))
+        };
+
+        // Full shimmer pushes +5 degrees, wrapping past 360
+        let (shift, color) = run(1.0);
+        assert!((shift - 5.0).abs() < 1e-3);
+        assert!((color.hue - 3.0).abs() < 0.1, "hue {}", color.hue);
+
+        // Silence pulls -5 degrees; saturation modulation is unaffected by the shift
+        let (shift, color) = run(0.0);
+        assert!((shift + 5.0).abs() < 1e-3);
+        assert!((color.hue - 353.0).abs() < 0.1, "hue {}", color.hue);
+        let mapping = AudioVisualMapping::default();
+        assert!((color.saturation - 0.8 * mapping.amplitude_to_saturation_range.0).abs() < 0.01);
     }
 
     #[test]
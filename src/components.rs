@@ -1,10 +1,13 @@
 //! Module: components
 //! Purpose: ECS components for the Chromatic Elegy particle visualization system
-//! Dependencies: bevy, crate::types
+//! Dependencies: bevy, crate::types, crate::trail
 
 use bevy::prelude::*;
 
-use crate::types::{FrequencyBand, ParticleBehaviorType, SpawnSource};
+use crate::trail::{TRAIL_FADE_DURATION_MS, TRAIL_SEGMENTS};
+use crate::types::{
+    BehaviorCoefficients, FadeProfile, FrequencyBand, ParticleBehaviorType, SpawnSource, TrailStyle,
+};
 
 // --- Particle Components ---
 
@@ -57,6 +60,13 @@ pub struct ParticleVisual {
     pub scale: f32,
     /// How much this particle contributes to the bloom post-process effect
     pub bloom_contribution: f32,
+    /// Smoothed audio-driven hue offset from `base_color`, in degrees
+    pub hue_shift_degrees: f32,
+    /// Shape of the end-of-life fade, set from `FadeProfiles` at spawn
+    pub fade_profile: FadeProfile,
+    /// Turn the pea so its "up" faces its direction of travel (off by default);
+    /// set from `ParticleSpawnRequest::orient_to_velocity` at spawn
+    pub orient_to_velocity: bool,
 }
 
 impl Default for ParticleVisual {
@@ -67,6 +77,9 @@ impl Default for ParticleVisual {
             opacity: 1.0,
             scale: 1.0,
             bloom_contribution: 0.0,
+            hue_shift_degrees: 0.0,
+            fade_profile: FadeProfile::Linear,
+            orient_to_velocity: false,
         }
     }
 }
@@ -85,6 +98,9 @@ pub struct ParticleMotion {
     pub drag: f32,
     /// Seed value for deterministic turbulence noise calculation
     pub turbulence_seed: f32,
+    /// Seconds left in which an explosion or hyperspace impulse may outrun
+    /// the act's speed cap (see `apply_velocity_changes`)
+    pub impulse_secs: f32,
 }
 
 impl Default for ParticleMotion {
@@ -94,30 +110,65 @@ impl Default for ParticleMotion {
             acceleration: Vec2::ZERO,
             drag: 0.98,
             turbulence_seed: 0.0,
+            impulse_secs: 0.0,
         }
     }
 }
 
-/// Act-specific behavior mode that changes with narrative progression.
+/// Stretches a fast pea's quad along its velocity into a light streak.
 ///
-/// Each act in the five-act structure has a corresponding behavior type
-/// that affects how particles move and interact with each other.
+/// Inserted by `update_motion_streaks` while a particle moves faster than
+/// `STREAK_MIN_SPEED` and removed once it slows, so round peas carry no
+/// extra per-frame work.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MotionStreak {
+    /// Longest the streak may grow, as a multiple of the pea's size
+    pub max_stretch: f32,
+}
+
+impl Default for MotionStreak {
+    fn default() -> Self {
+        Self { max_stretch: 4.0 }
+    }
+}
+
+/// Act-specific behavior that changes with narrative progression.
+///
+/// Carries the force-kernel weights written by act management. During an act
+/// transition `blend_target` names the other act's behavior, and both
+/// contributions are mixed by weight so motion morphs continuously.
 #[derive(Component, Debug, Clone, Copy)]
 pub struct ParticleBehavior {
-    /// The current behavior pattern for this particle
-    pub behavior_type: ParticleBehaviorType,
+    /// Force-kernel weights for this particle
+    pub coefficients: BehaviorCoefficients,
     /// How strongly the behavior affects particle motion (0.0 to 1.0)
     pub behavior_strength: f32,
     /// Optional target position for behaviors that require attraction points
+    /// (written from `AttractorPoint`s each frame; `None` means the origin)
     pub target_position: Option<Vec2>,
+    /// Behavior crossfaded in during an act transition and its weight
+    /// (0.0 to 1.0); `coefficients` carry the remaining weight
+    pub blend_target: Option<(ParticleBehaviorType, f32)>,
 }
 
 impl Default for ParticleBehavior {
     fn default() -> Self {
         Self {
-            behavior_type: ParticleBehaviorType::Drift,
+            coefficients: ParticleBehaviorType::Drift.coefficients(),
             behavior_strength: 1.0,
             target_position: None,
+            blend_target: None,
+        }
+    }
+}
+
+impl ParticleBehavior {
+    /// Kernel weights with the blend target mixed in by its weight.
+    #[must_use]
+    pub fn blended_coefficients(&self) -> BehaviorCoefficients {
+        match self.blend_target {
+            Some((target, weight)) => self.coefficients.lerp(&target.coefficients(), weight),
+            None => self.coefficients,
         }
     }
 }
@@ -138,26 +189,37 @@ pub struct TrailSegment {
     pub width: f32,
     /// Timestamp when this segment was recorded, in milliseconds
     pub timestamp_ms: f32,
+    /// Signed local turbulence when recorded (-1.0 to 1.0), used for shimmer
+    pub turbulence_modulation: f32,
 }
 
+/// Most segments a `Trail` can hold.
+///
+/// `TrailRenderer::max_segments` picks how many of them a particle uses.
+pub const MAX_TRAIL_SEGMENTS: usize = 24;
+
 /// Circular buffer storing trail segment history.
 ///
 /// Uses a fixed-size array with a head index to avoid allocations
-/// during runtime updates. The trail system overwrites the oldest
-/// segment when adding new positions.
+/// during runtime updates. Only the first `capacity` slots are in use, so
+/// trails of different lengths share one component layout. The trail system
+/// overwrites the oldest segment when adding new positions.
 #[derive(Component, Debug, Clone, Copy)]
 pub struct Trail {
-    /// Fixed-size circular buffer of trail segments
-    pub segments: [TrailSegment; 12],
+    /// Fixed-size storage for the circular buffer of trail segments
+    pub segments: [TrailSegment; MAX_TRAIL_SEGMENTS],
     /// Index of the most recently written segment
     pub head_index: usize,
+    /// Number of segments in use (1 to `MAX_TRAIL_SEGMENTS`)
+    pub capacity: usize,
 }
 
 impl Default for Trail {
     fn default() -> Self {
         Self {
-            segments: [TrailSegment::default(); 12],
+            segments: [TrailSegment::default(); MAX_TRAIL_SEGMENTS],
             head_index: 0,
+            capacity: TRAIL_SEGMENTS,
         }
     }
 }
@@ -168,17 +230,44 @@ impl Trail {
         Self::default()
     }
 
+    /// Creates an empty trail using `capacity` segments.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut trail = Self::default();
+        trail.set_capacity(capacity);
+        trail
+    }
+
+    /// Changes how many segments the trail uses, clamped to
+    /// `1..=MAX_TRAIL_SEGMENTS`.
+    ///
+    /// A changed capacity clears the history, since the old wraparound no
+    /// longer lines up.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        let capacity = capacity.clamp(1, MAX_TRAIL_SEGMENTS);
+        if capacity != self.capacity {
+            *self = Self {
+                capacity,
+                ..Self::default()
+            };
+        }
+    }
+
     /// Adds a new segment to the trail, advancing the head index.
     ///
     /// This overwrites the oldest segment in the circular buffer.
     pub fn push_segment(&mut self, segment: TrailSegment) {
-        self.head_index = (self.head_index + 1) % self.segments.len();
+        self.head_index = (self.head_index + 1) % self.capacity;
         self.segments[self.head_index] = segment;
     }
 
+    /// Returns the segments in use, in storage order.
+    pub fn active_segments_mut(&mut self) -> &mut [TrailSegment] {
+        &mut self.segments[..self.capacity]
+    }
+
     /// Returns an iterator over segments from newest to oldest.
     pub fn iter_segments(&self) -> impl Iterator<Item = &TrailSegment> {
-        let len = self.segments.len();
+        let len = self.capacity;
         (0..len).map(move |i| {
             let index = (self.head_index + len - i) % len;
             &self.segments[index]
@@ -198,6 +287,16 @@ pub struct TrailRenderer {
     pub base_width: f32,
     /// How quickly the trail tapers from head to tail (0.0 to 1.0)
     pub taper_factor: f32,
+    /// Particle speed below which no visible trail is recorded (world units per second)
+    pub min_speed: f32,
+    /// Speed range above `min_speed` over which the trail fades fully in
+    pub speed_fade_range: f32,
+    /// Whether the trail is drawn as a ribbon or as breadcrumb markers
+    pub style: TrailStyle,
+    /// Number of segments the trail keeps (set from `TrailProfiles` at spawn)
+    pub max_segments: usize,
+    /// Time for a segment to fade out, in milliseconds (set at spawn)
+    pub fade_duration_ms: f32,
 }
 
 impl Default for TrailRenderer {
@@ -206,6 +305,69 @@ impl Default for TrailRenderer {
             enabled: true,
             base_width: 2.0,
             taper_factor: 0.8,
+            min_speed: 80.0,
+            speed_fade_range: 60.0,
+            style: TrailStyle::Ribbon,
+            max_segments: TRAIL_SEGMENTS,
+            fade_duration_ms: TRAIL_FADE_DURATION_MS,
+        }
+    }
+}
+
+/// A short-lived dot left behind by a particle in `TrailStyle::Breadcrumbs`.
+///
+/// Markers are pooled sprites that fade independently of the particle that
+/// dropped them.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct BreadcrumbMarker {
+    /// Whether this marker is currently visible and fading
+    pub active: bool,
+    /// Time since the marker was dropped, in milliseconds
+    pub age_ms: f32,
+    /// Total time the marker stays visible, in milliseconds
+    pub lifetime_ms: f32,
+    /// Opacity at the moment the marker was dropped
+    pub initial_opacity: f32,
+}
+
+/// Entity drawing a particle's trail as a triangle-strip ribbon mesh.
+///
+/// Created on demand by `render_trails` for trail-bearing particles and kept
+/// with its particle across pool reuse; hidden while there is nothing to draw.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TrailRibbon {
+    /// Particle whose trail this ribbon draws
+    pub owner: Entity,
+}
+
+/// Link from a particle to its `TrailRibbon` entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TrailRibbonLink {
+    /// The ribbon entity drawing this particle's trail
+    pub ribbon: Entity,
+}
+
+/// A trail detached from its particle when the particle expired.
+///
+/// Orphans are pooled entities carrying a copy of the particle's `Trail`,
+/// `TrailRenderer`, and color, so the ribbon keeps drawing and fading on its
+/// own until it is fully transparent.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OrphanTrail {
+    /// Whether this orphan currently holds a fading trail
+    pub active: bool,
+    /// Renderer copied from the particle (width, taper, style, fade duration)
+    pub renderer: TrailRenderer,
+    /// The particle's color when it expired
+    pub color: Color,
+}
+
+impl Default for OrphanTrail {
+    fn default() -> Self {
+        Self {
+            active: false,
+            renderer: TrailRenderer::default(),
+            color: Color::WHITE,
         }
     }
 }
@@ -254,6 +416,85 @@ impl Default for Attractable {
     }
 }
 
+/// A point force source acting on `Attractable` particles.
+///
+/// Positive strength pulls particles in, negative strength pushes them away;
+/// the force falls off quadratically to zero at `radius`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Attractor {
+    /// Signed force strength in world units/second^2; the sign is the polarity
+    pub strength: f32,
+    /// Distance beyond which the attractor has no effect
+    pub radius: f32,
+}
+
+impl Default for Attractor {
+    fn default() -> Self {
+        Self {
+            strength: 2500.0,
+            radius: 600.0,
+        }
+    }
+}
+
+/// A scripted focal point for Swarm and Orbit behaviors.
+///
+/// Each frame every swarming or orbiting particle takes the weighted blend of
+/// all attractor points as its `ParticleBehavior::target_position`; with no
+/// points the target falls back to the origin. Spawn several and move their
+/// `position` over time to choreograph the field.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AttractorPoint {
+    /// World position of the focal point
+    pub position: Vec2,
+    /// Blend weight relative to the other points (negative counts as zero)
+    pub strength: f32,
+    /// Distance at which this point's weight has halved
+    pub falloff: f32,
+}
+
+impl Default for AttractorPoint {
+    fn default() -> Self {
+        Self {
+            position: Vec2::ZERO,
+            strength: 1.0,
+            falloff: 400.0,
+        }
+    }
+}
+
+impl AttractorPoint {
+    /// Creates a point at `position` with the default strength and falloff.
+    #[must_use]
+    pub fn at(position: Vec2) -> Self {
+        Self {
+            position,
+            ..Default::default()
+        }
+    }
+
+    /// Blend weight this point has for a particle at `pos`.
+    ///
+    /// `strength / (1 + (distance / falloff)^2)`: nearby points dominate, but
+    /// the weight never reaches zero, so a lone point always wins.
+    #[must_use]
+    pub fn weight_at(&self, pos: Vec2) -> f32 {
+        let falloff = self.falloff.max(1.0);
+        let scaled = pos.distance(self.position) / falloff;
+        self.strength.max(0.0) / (1.0 + scaled * scaled)
+    }
+}
+
+/// Marks one of the two Magnet Toy poles.
+///
+/// Pole 0 follows the primary pointer; pole 1 follows the second touch, the
+/// right mouse button, or the arrow keys.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MagnetPole {
+    /// Which pole this is (0 or 1)
+    pub index: usize,
+}
+
 /// Tracks origin of particle for behavior differentiation.
 ///
 /// Different spawn sources may result in different visual or behavioral
@@ -359,6 +600,14 @@ impl PulseResponder {
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct BackgroundMarker;
 
+/// Marker component for the main Whirled Peas camera.
+///
+/// Camera-dependent systems (mouse-to-world conversion, clear color sync,
+/// bloom) query `With<WhirledCamera>` rather than any `Camera`, so the
+/// crate keeps working when a host app adds its own UI or overlay cameras.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WhirledCamera;
+
 // --- Component Bundles ---
 
 /// Bundle containing all components needed for a complete particle entity.
@@ -478,6 +727,7 @@ mod tests {
                 opacity: 1.0,
                 width: 1.0,
                 timestamp_ms: i as f32 * 100.0,
+                turbulence_modulation: 0.0,
             });
         }
 
@@ -489,6 +739,35 @@ mod tests {
         assert_eq!(segments[0].position.x, 14.0);
     }
 
+    #[test]
+    fn test_trail_variable_capacity() {
+        let segment = |x: f32| TrailSegment {
+            position: Vec2::new(x, 0.0),
+            opacity: 1.0,
+            ..Default::default()
+        };
+
+        for capacity in [1, 5, 18, MAX_TRAIL_SEGMENTS] {
+            let mut trail = Trail::with_capacity(capacity);
+            for i in 0..capacity * 2 + 3 {
+                trail.push_segment(segment(i as f32));
+            }
+            let xs: Vec<f32> = trail.iter_segments().map(|s| s.position.x).collect();
+            let newest = (capacity * 2 + 2) as f32;
+            let expected: Vec<f32> = (0..capacity).map(|i| newest - i as f32).collect();
+            assert_eq!(xs, expected, "capacity {capacity}");
+        }
+
+        // Resizing clamps and clears stale history
+        let mut trail = Trail::with_capacity(6);
+        trail.push_segment(segment(1.0));
+        trail.set_capacity(100);
+        assert_eq!(trail.capacity, MAX_TRAIL_SEGMENTS);
+        assert!(trail.iter_segments().all(|s| s.opacity == 0.0));
+        trail.set_capacity(0);
+        assert_eq!(trail.capacity, 1);
+    }
+
     #[test]
     fn test_pulse_responder_trigger() {
         let mut responder = PulseResponder::default();
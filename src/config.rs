@@ -0,0 +1,515 @@
+//! Module: config
+//! Purpose: Serde-backed configuration file that overrides tunable defaults at startup
+//! Dependencies: resources, types, serde, ron, toml
+
+use std::fmt;
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::{
+    AudioVisualMapping, BeatSpawnConfig, InteractionConfig, PaintConfig, ParticlePool,
+    PostProcessSettings, TrailProfiles,
+};
+use crate::types::FalloffType;
+
+// =============================================================================
+// CONFIG ERRORS
+// =============================================================================
+
+/// Error produced when loading a [`WhirledPeasConfig`].
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file could not be read
+    Io(std::io::Error),
+    /// The config file is not valid RON or TOML for a `WhirledPeasConfig`
+    Parse(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "failed to read config: {err}"),
+            ConfigError::Parse(msg) => write!(f, "failed to parse config: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// =============================================================================
+// CONFIG SECTIONS
+// =============================================================================
+
+/// Particle pool sizing, applied to `ParticlePool` before the pool is spawned.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParticleTuning {
+    /// Total pre-allocated particle entities
+    pub pool_capacity: Option<u32>,
+    /// Maximum particles active at once (clamped to the pool capacity)
+    pub max_active: Option<u32>,
+}
+
+/// Pointer and explosion tuning, applied to `InteractionConfig`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractionTuning {
+    /// Starting interaction radius in world units
+    pub base_radius: Option<f32>,
+    /// Interaction radius after sustained engagement
+    pub max_radius: Option<f32>,
+    /// Distance falloff for pointer influence
+    pub falloff_type: Option<FalloffType>,
+    /// Peak velocity impulse of a click explosion
+    pub explosion_force: Option<f32>,
+    /// Reach of a click explosion in world units
+    pub explosion_radius: Option<f32>,
+}
+
+/// Spawn rates and counts, applied to `PaintConfig` and `BeatSpawnConfig`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpawnTuning {
+    /// Particles per second painted by a slow and a fast pointer
+    pub paint_rate_range: Option<(f32, f32)>,
+    /// Turn painted peas to face their direction of travel
+    pub orient_painted_peas: Option<bool>,
+    /// Particles spawned by a soft beat
+    pub soft_beat_count: Option<(u32, u32)>,
+    /// Particles spawned by a medium beat
+    pub medium_beat_count: Option<(u32, u32)>,
+    /// Particles spawned by a strong beat
+    pub strong_beat_count: Option<(u32, u32)>,
+}
+
+/// Trail fade times per spawn source, applied to `TrailProfiles`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrailTuning {
+    /// Fade time of painted particles' trails in milliseconds
+    pub painted_fade_duration_ms: Option<f32>,
+    /// Fade time of beat particles' trails in milliseconds
+    pub beat_fade_duration_ms: Option<f32>,
+    /// Fade time of ambient particles' trails in milliseconds (`TRAIL_FADE_DURATION_MS`)
+    pub fade_duration_ms: Option<f32>,
+}
+
+/// Audio-to-visual mapping ranges and onset detection, applied to
+/// `AudioVisualMapping`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioVisualTuning {
+    /// Amplitude to particle opacity: (min_opacity, max_opacity)
+    pub amplitude_to_opacity_range: Option<(f32, f32)>,
+    /// Amplitude to color saturation: (min_saturation, max_saturation)
+    pub amplitude_to_saturation_range: Option<(f32, f32)>,
+    /// Amplitude to particle scale: (min_scale, max_scale)
+    pub amplitude_to_scale_range: Option<(f32, f32)>,
+    /// Amplitude to bloom contribution: (min_bloom, max_bloom)
+    pub amplitude_to_bloom_range: Option<(f32, f32)>,
+    /// Frequency to spawn rate: (min_rate, max_rate) particles/second
+    pub frequency_to_spawn_rate_range: Option<(f32, f32)>,
+    /// Frequency to hue shift: (min_shift, max_shift) degrees
+    pub frequency_to_hue_shift_range: Option<(f32, f32)>,
+    /// Standard deviations above the moving mean flux that fire a beat
+    pub onset_threshold_std: Option<f32>,
+    /// Smallest spectral flux that can fire a beat
+    pub onset_min_flux: Option<f32>,
+    /// Minimum seconds between onsets
+    pub onset_min_interval_secs: Option<f32>,
+    /// Frames of energy and flux history behind the adaptive threshold
+    pub onset_history_frames: Option<usize>,
+    /// Multiplier from onset flux to the classified beat value
+    pub onset_strength_gain: Option<f32>,
+}
+
+/// Post-processing values not driven by the act scene, applied to
+/// `PostProcessSettings`.
+///
+/// Bloom intensity, vignette, and chromatic aberration change per act and
+/// are configured in the act scene file instead.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PostProcessTuning {
+    /// Luminance threshold for bloom
+    pub bloom_threshold: Option<f32>,
+    /// Bloom blur radius in pixels
+    pub bloom_radius: Option<f32>,
+    /// Film grain noise amount (0.0 disables grain)
+    pub film_grain_amount: Option<f32>,
+}
+
+// =============================================================================
+// WHIRLED PEAS CONFIG
+// =============================================================================
+
+/// Tunable values loaded from a RON or TOML file at startup.
+///
+/// Every section and field is optional: anything missing leaves the current
+/// resource value alone, so a config file only needs to name what it changes
+/// and does not undo sizes set by other plugins (such as the instanced pool).
+///
+/// ```ron
+/// (
+///     particles: (pool_capacity: 8000, max_active: 5000),
+///     interaction: (explosion_force: 1200.0),
+/// )
+/// ```
+///
+/// A `.toml` file is read as TOML:
+///
+/// ```toml
+/// [particles]
+/// pool_capacity = 8000
+///
+/// [trails]
+/// fade_duration_ms = 2000.0
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WhirledPeasConfig {
+    /// Particle pool sizing
+    pub particles: ParticleTuning,
+    /// Pointer and explosion tuning
+    pub interaction: InteractionTuning,
+    /// Paint and beat spawn rates
+    pub spawning: SpawnTuning,
+    /// Trail fade times
+    pub trails: TrailTuning,
+    /// Audio-to-visual mapping ranges
+    pub audio_visual: AudioVisualTuning,
+    /// Post-processing values outside the act scene
+    pub post_process: PostProcessTuning,
+}
+
+impl WhirledPeasConfig {
+    /// Parses a config from RON text.
+    ///
+    /// Values are written bare (`pool_capacity: 8000`, not `Some(8000)`).
+    pub fn from_ron_str(source: &str) -> Result<Self, ConfigError> {
+        ron_options()
+            .from_str(source)
+            .map_err(|err| ConfigError::Parse(err.to_string()))
+    }
+
+    /// Parses a config from TOML text.
+    pub fn from_toml_str(source: &str) -> Result<Self, ConfigError> {
+        toml::from_str(source).map_err(|err| ConfigError::Parse(err.to_string()))
+    }
+
+    /// Reads and parses a config file: TOML for a `.toml` extension, RON otherwise.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let source = std::fs::read_to_string(path).map_err(ConfigError::Io)?;
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+        {
+            Self::from_toml_str(&source)
+        } else {
+            Self::from_ron_str(&source)
+        }
+    }
+
+    /// Reads a config, logging a warning and returning an empty config (which
+    /// changes nothing) if the file is missing or malformed.
+    #[must_use]
+    pub fn load_or_default(path: &Path) -> Self {
+        match Self::load(path) {
+            Ok(config) => {
+                info!("Loaded config from {}", path.display());
+                config
+            }
+            Err(err) => {
+                warn!("{err}; using built-in defaults");
+                Self::default()
+            }
+        }
+    }
+
+    /// Serializes the config as pretty-printed RON.
+    pub fn to_ron_string(&self) -> Result<String, ConfigError> {
+        ron_options()
+            .to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| ConfigError::Parse(err.to_string()))
+    }
+
+    /// Serializes the config as TOML.
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        toml::to_string_pretty(self).map_err(|err| ConfigError::Parse(err.to_string()))
+    }
+
+    /// Writes the values the config sets into the matching resources.
+    ///
+    /// Fields left unset keep the resource's current value. Resources that are
+    /// not registered are skipped.
+    pub fn apply(&self, world: &mut World) {
+        if let Some(mut pool) = world.get_resource_mut::<ParticlePool>() {
+            let tuning = &self.particles;
+            set(&mut pool.pool_capacity, tuning.pool_capacity);
+            set(&mut pool.max_active, tuning.max_active);
+            pool.max_active = pool.max_active.min(pool.pool_capacity);
+        }
+
+        if let Some(mut interaction) = world.get_resource_mut::<InteractionConfig>() {
+            let tuning = &self.interaction;
+            set(&mut interaction.base_radius, tuning.base_radius);
+            set(&mut interaction.max_radius, tuning.max_radius);
+            set(&mut interaction.current_radius, tuning.base_radius);
+            set(&mut interaction.falloff_type, tuning.falloff_type);
+            set(&mut interaction.explosion_force, tuning.explosion_force);
+            set(&mut interaction.explosion_radius, tuning.explosion_radius);
+            interaction.max_radius = interaction.max_radius.max(interaction.base_radius);
+        }
+
+        if let Some(mut paint) = world.get_resource_mut::<PaintConfig>() {
+            set(&mut paint.spawn_rate_range, self.spawning.paint_rate_range);
+            set(
+                &mut paint.orient_to_velocity,
+                self.spawning.orient_painted_peas,
+            );
+        }
+
+        if let Some(mut beat) = world.get_resource_mut::<BeatSpawnConfig>() {
+            set(&mut beat.soft_count, self.spawning.soft_beat_count);
+            set(&mut beat.medium_count, self.spawning.medium_beat_count);
+            set(&mut beat.strong_count, self.spawning.strong_beat_count);
+        }
+
+        if let Some(mut profiles) = world.get_resource_mut::<TrailProfiles>() {
+            let tuning = &self.trails;
+            set(
+                &mut profiles.mouse.fade_duration_ms,
+                tuning.painted_fade_duration_ms,
+            );
+            set(
+                &mut profiles.beat.fade_duration_ms,
+                tuning.beat_fade_duration_ms,
+            );
+            set(
+                &mut profiles.automatic.fade_duration_ms,
+                tuning.fade_duration_ms,
+            );
+        }
+
+        if let Some(mut mapping) = world.get_resource_mut::<AudioVisualMapping>() {
+            let tuning = &self.audio_visual;
+            set(
+                &mut mapping.amplitude_to_opacity_range,
+                tuning.amplitude_to_opacity_range,
+            );
+            set(
+                &mut mapping.amplitude_to_saturation_range,
+                tuning.amplitude_to_saturation_range,
+            );
+            set(
+                &mut mapping.amplitude_to_scale_range,
+                tuning.amplitude_to_scale_range,
+            );
+            set(
+                &mut mapping.amplitude_to_bloom_range,
+                tuning.amplitude_to_bloom_range,
+            );
+            set(
+                &mut mapping.frequency_to_spawn_rate_range,
+                tuning.frequency_to_spawn_rate_range,
+            );
+            set(
+                &mut mapping.frequency_to_hue_shift_range,
+                tuning.frequency_to_hue_shift_range,
+            );
+            set(&mut mapping.onset_threshold_std, tuning.onset_threshold_std);
+            set(&mut mapping.onset_min_flux, tuning.onset_min_flux);
+            set(
+                &mut mapping.onset_min_interval_secs,
+                tuning.onset_min_interval_secs,
+            );
+            set(
+                &mut mapping.onset_history_frames,
+                tuning.onset_history_frames,
+            );
+            set(&mut mapping.onset_strength_gain, tuning.onset_strength_gain);
+        }
+
+        if let Some(mut settings) = world.get_resource_mut::<PostProcessSettings>() {
+            let tuning = &self.post_process;
+            set(&mut settings.bloom_threshold, tuning.bloom_threshold);
+            set(&mut settings.bloom_radius, tuning.bloom_radius);
+            set(&mut settings.film_grain_amount, tuning.film_grain_amount);
+        }
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// RON options that accept bare values for the optional config fields.
+fn ron_options() -> ron::Options {
+    ron::Options::default().with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+}
+
+/// Overwrites `target` when the config sets a value.
+fn set<T>(target: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *target = value;
+    }
+}
+
+/// Returns a temp-dir path for `file_name` unique to this process and call,
+/// so concurrent test runs never share a config file.
+#[cfg(test)]
+pub(crate) fn unique_temp_path(file_name: &str) -> std::path::PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let call = COUNTER.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!("{}_{call}_{file_name}", std::process::id()))
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_round_trips_through_ron() {
+        let mut config = WhirledPeasConfig::default();
+        config.particles.pool_capacity = Some(4000);
+        config.particles.max_active = Some(3000);
+        config.interaction.falloff_type = Some(FalloffType::Exponential);
+        config.interaction.explosion_force = Some(1200.0);
+        config.audio_visual.frequency_to_spawn_rate_range = Some((2.0, 20.0));
+        config.post_process.film_grain_amount = Some(0.0);
+
+        config.spawning.strong_beat_count = Some((30, 60));
+        config.trails.fade_duration_ms = Some(2000.0);
+
+        let ron = config.to_ron_string().unwrap();
+        assert_eq!(WhirledPeasConfig::from_ron_str(&ron).unwrap(), config);
+
+        let toml = config.to_toml_string().unwrap();
+        assert_eq!(WhirledPeasConfig::from_toml_str(&toml).unwrap(), config);
+    }
+
+    #[test]
+    fn test_toml_file_is_read_by_extension() {
+        let path = unique_temp_path("whirled_peas_config.toml");
+        std::fs::write(&path, "[spawning]\nsoft_beat_count = [2, 4]\n").unwrap();
+        let config = WhirledPeasConfig::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(config.spawning.soft_beat_count, Some((2, 4)));
+        assert_eq!(config.trails, TrailTuning::default());
+    }
+
+    #[test]
+    fn test_missing_fields_stay_unset() {
+        let config =
+            WhirledPeasConfig::from_ron_str("(interaction: (explosion_force: 1200.0))").unwrap();
+        assert_eq!(config.interaction.explosion_force, Some(1200.0));
+        assert_eq!(config.interaction.explosion_radius, None);
+        assert_eq!(config.particles, ParticleTuning::default());
+        assert_eq!(config.audio_visual, AudioVisualTuning::default());
+
+        let empty = WhirledPeasConfig::from_ron_str("()").unwrap();
+        assert_eq!(empty, WhirledPeasConfig::default());
+    }
+
+    #[test]
+    fn test_malformed_file_falls_back_to_defaults() {
+        let wrong_type = "(particles: (pool_capacity: \"many\"))";
+        assert!(WhirledPeasConfig::from_ron_str(wrong_type).is_err());
+
+        let path = unique_temp_path("whirled_peas_malformed_config.ron");
+        std::fs::write(&path, "(particles: (").unwrap();
+        assert_eq!(
+            WhirledPeasConfig::load_or_default(&path),
+            WhirledPeasConfig::default()
+        );
+        std::fs::remove_file(&path).ok();
+
+        let missing = unique_temp_path("whirled_peas_missing_config.ron");
+        assert_eq!(
+            WhirledPeasConfig::load_or_default(&missing),
+            WhirledPeasConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_apply_populates_resources() {
+        let mut world = World::new();
+        world.init_resource::<ParticlePool>();
+        world.init_resource::<InteractionConfig>();
+        world.init_resource::<AudioVisualMapping>();
+        world.init_resource::<PostProcessSettings>();
+        world.init_resource::<PaintConfig>();
+        world.init_resource::<BeatSpawnConfig>();
+        world.init_resource::<TrailProfiles>();
+
+        let config = WhirledPeasConfig::from_ron_str(
+            "(particles: (pool_capacity: 500, max_active: 900), \
+             interaction: (base_radius: 50.0), \
+             spawning: (paint_rate_range: (4.0, 30.0), orient_painted_peas: true, \
+              medium_beat_count: (12, 16)), \
+             trails: (fade_duration_ms: 2500.0), \
+             post_process: (film_grain_amount: 0.05))",
+        )
+        .unwrap();
+        config.apply(&mut world);
+
+        let pool = world.resource::<ParticlePool>();
+        assert_eq!(pool.pool_capacity, 500);
+        assert_eq!(pool.max_active, 500);
+
+        let interaction = world.resource::<InteractionConfig>();
+        assert_eq!(interaction.base_radius, 50.0);
+        assert_eq!(interaction.current_radius, 50.0);
+        assert_eq!(
+            interaction.max_radius,
+            InteractionConfig::default().max_radius
+        );
+
+        assert_eq!(
+            world.resource::<PostProcessSettings>().film_grain_amount,
+            0.05
+        );
+
+        let paint = world.resource::<PaintConfig>();
+        assert_eq!(paint.spawn_rate_range, (4.0, 30.0));
+        assert!(paint.orient_to_velocity);
+        let beat = world.resource::<BeatSpawnConfig>();
+        assert_eq!(beat.medium_count, (12, 16));
+        assert_eq!(beat.soft_count, BeatSpawnConfig::default().soft_count);
+        let profiles = world.resource::<TrailProfiles>();
+        assert_eq!(profiles.automatic.fade_duration_ms, 2500.0);
+        assert_eq!(profiles.mouse, TrailProfiles::default().mouse);
+    }
+
+    #[test]
+    fn test_config_without_particles_keeps_instanced_pool() {
+        let path = unique_temp_path("whirled_peas_instanced_config.ron");
+        std::fs::write(&path, "(interaction: (explosion_force: 1200.0))").unwrap();
+
+        let mut app = App::new();
+        app.add_plugins(crate::instancing::ParticleInstancingPlugin)
+            .init_resource::<InteractionConfig>();
+        WhirledPeasConfig::load_or_default(&path).apply(app.world_mut());
+        std::fs::remove_file(&path).ok();
+
+        let pool = app.world().resource::<ParticlePool>();
+        assert_eq!(
+            pool.pool_capacity,
+            crate::instancing::INSTANCED_POOL_CAPACITY
+        );
+        assert_eq!(pool.max_active, crate::instancing::INSTANCED_MAX_ACTIVE);
+        assert_eq!(
+            app.world().resource::<InteractionConfig>().explosion_force,
+            1200.0
+        );
+    }
+}
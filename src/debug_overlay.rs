@@ -0,0 +1,427 @@
+//! Module: debug_overlay
+//! Purpose: F3 on-screen HUD with live simulation stats for on-site tuning
+//! Dependencies: resources, trail, types, bevy::ui
+//!
+//! The overlay is spawned hidden at startup and only shown while toggled on,
+//! so it never appears in the finished piece. Its text and band bars are
+//! reused every frame rather than respawned.
+
+use std::fmt::Write;
+
+use bevy::prelude::*;
+
+use crate::resources::{
+    ActState, AudioAnalysis, CurrentInteractionMode, ParticlePool, PerformanceMetrics, UiFont,
+};
+use crate::trail::TrailMetrics;
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Labels of the audio band bars, in `AudioAnalysis` frequency order.
+const BAND_LABELS: [&str; 4] = ["bass", "mid", "high", "shimmer"];
+
+/// Width of a band bar at full level, in logical pixels.
+const BAND_BAR_MAX_WIDTH_PX: f32 = 120.0;
+
+/// Height of a band bar, in logical pixels.
+const BAND_BAR_HEIGHT_PX: f32 = 6.0;
+
+/// Font size of the overlay text.
+const OVERLAY_FONT_SIZE: f32 = 14.0;
+
+/// Reserved capacity of the stats string, enough for every line.
+const STATS_TEXT_CAPACITY: usize = 256;
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Whether the debug overlay is shown. Flipped by F3; hidden by default.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DebugOverlay {
+    /// Whether the overlay is drawn
+    pub visible: bool,
+}
+
+// =============================================================================
+// COMPONENTS
+// =============================================================================
+
+/// Root node of the debug overlay; its visibility follows `DebugOverlay`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DebugOverlayRoot;
+
+/// Text entity holding the overlay's stats lines.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DebugOverlayText;
+
+/// Bar showing one audio band's level, indexed like `BAND_LABELS`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DebugBandBar(pub usize);
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Simulation values shown by the overlay.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugStats<'a> {
+    /// Particle pool occupancy and cap
+    pub pool: &'a ParticlePool,
+    /// Frame timings
+    pub metrics: &'a PerformanceMetrics,
+    /// Current act and its progress
+    pub act_state: &'a ActState,
+    /// Current pointer interaction mode
+    pub mode: &'a CurrentInteractionMode,
+    /// Aggregate trail lengths and segment counts
+    pub trails: &'a TrailMetrics,
+}
+
+/// Writes the overlay's stats lines into `out`, replacing its contents.
+///
+/// Reuses `out`'s buffer, so once it has grown to fit no frame allocates.
+pub fn write_debug_stats(out: &mut String, stats: &DebugStats) {
+    out.clear();
+    // Writing to a String cannot fail
+    let _ = write!(
+        out,
+        "particles {}/{}\nfps {:.0} ({:.2} ms)\n{} {:.0}%\nmode {:?}\n\
+         trails {} (+{} fading) avg {:.0} max {:.0}\nsegments {} oldest {:.0} ms",
+        stats.pool.active_count,
+        stats.pool.active_cap(),
+        stats.metrics.current_fps,
+        stats.metrics.frame_time_ms,
+        stats.act_state.current_act.display_name(),
+        stats.act_state.act_progress * 100.0,
+        stats.mode.mode,
+        stats.trails.active_trails,
+        stats.trails.orphan_trails,
+        stats.trails.average_length,
+        stats.trails.max_length,
+        stats.trails.total_visible_segments,
+        stats.trails.oldest_segment_age_ms,
+    );
+}
+
+/// Returns the `[bass, mid, high, shimmer]` levels of `audio`, clamped to 0-1.
+#[must_use]
+pub fn band_levels(audio: &AudioAnalysis) -> [f32; 4] {
+    [
+        audio.frequency_bass,
+        audio.frequency_mid,
+        audio.frequency_high,
+        audio.frequency_shimmer,
+    ]
+    .map(|level| level.clamp(0.0, 1.0))
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Spawns the overlay hidden: a stats text plus one labelled bar per band.
+///
+/// Uses `UiFont` when it has been loaded, otherwise Bevy's default font.
+///
+/// # Stage
+/// Startup
+pub fn setup_debug_overlay(mut commands: Commands, ui_font: Option<Res<UiFont>>) {
+    let text_font = TextFont {
+        font: ui_font.map(|font| font.handle.clone()).unwrap_or_default(),
+        font_size: OVERLAY_FONT_SIZE,
+        ..default()
+    };
+    let text_color = TextColor(Color::srgba(1.0, 1.0, 1.0, 0.85));
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                left: Val::Px(8.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            GlobalZIndex(300),
+            Visibility::Hidden,
+            DebugOverlayRoot,
+            Name::new("DebugOverlay"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(String::with_capacity(STATS_TEXT_CAPACITY)),
+                text_font.clone(),
+                text_color,
+                DebugOverlayText,
+            ));
+
+            for (index, label) in BAND_LABELS.iter().enumerate() {
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(*label),
+                            text_font.clone(),
+                            text_color,
+                            Node {
+                                width: Val::Px(56.0),
+                                ..default()
+                            },
+                        ));
+                        row.spawn((
+                            Node {
+                                width: Val::Px(0.0),
+                                height: Val::Px(BAND_BAR_HEIGHT_PX),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.35, 0.9, 0.55)),
+                            DebugBandBar(index),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Flips `DebugOverlay::visible` when F3 is pressed.
+///
+/// # Stage
+/// PreUpdate
+pub fn toggle_debug_overlay(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut overlay: ResMut<DebugOverlay>,
+) {
+    if keyboard.is_some_and(|keyboard| keyboard.just_pressed(KeyCode::F3)) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// Shows or hides the overlay root to match `DebugOverlay`.
+///
+/// # Stage
+/// Update (only when `DebugOverlay` changes)
+pub fn apply_debug_overlay_visibility(
+    overlay: Res<DebugOverlay>,
+    mut roots: Query<&mut Visibility, With<DebugOverlayRoot>>,
+) {
+    let target = if overlay.visible {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in roots.iter_mut() {
+        visibility.set_if_neq(target);
+    }
+}
+
+/// Rewrites the stats text in place.
+///
+/// # Stage
+/// Update (only while the overlay is visible)
+pub fn update_debug_overlay_text(
+    pool: Res<ParticlePool>,
+    metrics: Res<PerformanceMetrics>,
+    act_state: Res<ActState>,
+    mode: Res<CurrentInteractionMode>,
+    trails: Res<TrailMetrics>,
+    mut texts: Query<&mut Text, With<DebugOverlayText>>,
+) {
+    let stats = DebugStats {
+        pool: &pool,
+        metrics: &metrics,
+        act_state: &act_state,
+        mode: &mode,
+        trails: &trails,
+    };
+    for mut text in texts.iter_mut() {
+        write_debug_stats(&mut text.0, &stats);
+    }
+}
+
+/// Sizes each band bar to its `AudioAnalysis` level.
+///
+/// # Stage
+/// Update (only while the overlay is visible)
+pub fn update_debug_band_bars(
+    audio: Res<AudioAnalysis>,
+    mut bars: Query<(&DebugBandBar, &mut Node)>,
+) {
+    let levels = band_levels(&audio);
+    for (bar, mut node) in bars.iter_mut() {
+        let width = Val::Px(levels[bar.0 % levels.len()] * BAND_BAR_MAX_WIDTH_PX);
+        if node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+/// Condition function for run_if: returns true while the overlay is shown.
+pub fn debug_overlay_visible(overlay: Res<DebugOverlay>) -> bool {
+    overlay.visible
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin for the F3 debug overlay.
+///
+/// Shows particle pool occupancy, FPS and frame time, the current act and its
+/// progress, the interaction mode, trail metrics, and audio band levels. Hidden until F3 is
+/// pressed; nothing is written while it is hidden.
+///
+/// # Systems
+/// - `setup_debug_overlay` (Startup): Spawns the hidden overlay
+/// - `toggle_debug_overlay` (PreUpdate): Flips `DebugOverlay` on F3
+/// - `apply_debug_overlay_visibility` (Update, on change): Shows or hides the root
+/// - `update_debug_overlay_text` (Update, while visible): Rewrites the stats text
+/// - `update_debug_band_bars` (Update, while visible): Sizes the band bars
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugOverlay>()
+            .init_resource::<TrailMetrics>()
+            .add_systems(Startup, setup_debug_overlay)
+            .add_systems(PreUpdate, toggle_debug_overlay)
+            .add_systems(
+                Update,
+                (
+                    apply_debug_overlay_visibility.run_if(resource_changed::<DebugOverlay>),
+                    (update_debug_overlay_text, update_debug_band_bars)
+                        .run_if(debug_overlay_visible),
+                ),
+            );
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Act, InteractionMode};
+
+    #[test]
+    fn test_debug_stats_text() {
+        let pool = ParticlePool {
+            active_count: 1234,
+            ..Default::default()
+        };
+        let metrics = PerformanceMetrics {
+            current_fps: 59.6,
+            frame_time_ms: 16.78,
+            ..Default::default()
+        };
+        let act_state = ActState {
+            current_act: Act::Crescendo,
+            act_progress: 0.42,
+            ..Default::default()
+        };
+        let mode = CurrentInteractionMode {
+            mode: InteractionMode::Intensify,
+            ..Default::default()
+        };
+        let trails = TrailMetrics {
+            active_trails: 12,
+            orphan_trails: 3,
+            average_length: 84.4,
+            max_length: 210.0,
+            total_visible_segments: 96,
+            oldest_segment_age_ms: 1480.0,
+        };
+        let stats = DebugStats {
+            pool: &pool,
+            metrics: &metrics,
+            act_state: &act_state,
+            mode: &mode,
+            trails: &trails,
+        };
+
+        let mut text = String::with_capacity(STATS_TEXT_CAPACITY);
+        write_debug_stats(&mut text, &stats);
+        assert!(text.contains(&format!("particles 1234/{}", pool.active_cap())));
+        assert!(text.contains("fps 60 (16.78 ms)"));
+        assert!(text.contains("Act III: Crescendo 42%"));
+        assert!(text.contains("mode Intensify"));
+        assert!(text.contains("trails 12 (+3 fading) avg 84 max 210"));
+        assert!(text.contains("segments 96 oldest 1480 ms"));
+
+        // Rewriting reuses the buffer
+        let buffer = text.as_ptr();
+        write_debug_stats(&mut text, &stats);
+        assert_eq!(text.as_ptr(), buffer);
+    }
+
+    #[test]
+    fn test_band_levels_clamped() {
+        let audio = AudioAnalysis {
+            frequency_bass: 1.7,
+            frequency_mid: 0.5,
+            frequency_high: -0.2,
+            frequency_shimmer: 0.25,
+            ..Default::default()
+        };
+        assert_eq!(band_levels(&audio), [1.0, 0.5, 0.0, 0.25]);
+    }
+
+    #[test]
+    fn test_overlay_hidden_until_f3() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ParticlePool>()
+            .init_resource::<PerformanceMetrics>()
+            .init_resource::<ActState>()
+            .init_resource::<CurrentInteractionMode>()
+            .init_resource::<AudioAnalysis>()
+            .add_plugins(DebugOverlayPlugin);
+        app.update();
+
+        let root_visibility = |app: &mut App| {
+            *app.world_mut()
+                .query_filtered::<&Visibility, With<DebugOverlayRoot>>()
+                .single(app.world())
+        };
+        let stats_text = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&Text, With<DebugOverlayText>>()
+                .single(app.world())
+                .0
+                .clone()
+        };
+        assert_eq!(root_visibility(&mut app), Visibility::Hidden);
+        assert!(
+            stats_text(&mut app).is_empty(),
+            "hidden overlay writes nothing"
+        );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::F3);
+        app.update();
+        assert!(app.world().resource::<DebugOverlay>().visible);
+        assert_eq!(root_visibility(&mut app), Visibility::Inherited);
+        assert!(stats_text(&mut app).starts_with("particles 0/"));
+
+        {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(KeyCode::F3);
+            keyboard.clear();
+            keyboard.press(KeyCode::F3);
+        }
+        app.update();
+        assert_eq!(root_visibility(&mut app), Visibility::Hidden);
+    }
+}
@@ -0,0 +1,623 @@
+//! Module: demo_reel
+//! Purpose: Scripted, looping demo that exercises every major interaction
+//! Dependencies: act_management, interaction, intro, resources, types, bevy::prelude
+//!
+//! For trade-show loops: while `DemoReel.enabled` is set, a timeline of
+//! `DemoCue`s paints strokes, fires explosions and hyperspace jumps, sends
+//! breath pulses, and steps through the acts, using the same events and
+//! state that real input produces. Any real press hands control back to the
+//! viewer; the reel resumes after `resume_after_idle_secs` without input.
+//!
+//! # Custom scripts
+//!
+//! Replace `DemoReel.script` with your own cues, sorted by `at_secs`, and set
+//! `loop_secs` past the last cue:
+//!
+//! ```ignore
+//! use whirled_peas::demo_reel::{DemoAction, DemoCue, DemoReel};
+//!
+//! app.insert_resource(DemoReel {
+//!     enabled: true,
+//!     script: vec![
+//!         DemoCue::new(0.0, DemoAction::GoToAct(Act::Crescendo)),
+//!         DemoCue::new(2.0, DemoAction::Explosion { origin: Vec2::ZERO, strength: 1.0 }),
+//!     ],
+//!     loop_secs: 10.0,
+//!     ..Default::default()
+//! });
+//! ```
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+
+use crate::act_management::GoToAct;
+use crate::interaction::{BreathPulse, ExplosionEvent, HyperspaceJumpEvent, HyperspaceState};
+use crate::resources::{MouseState, PaintConfig, ParticleRng};
+use crate::types::{in_fidget_state, Act};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Length of one pass of the built-in script in seconds.
+const DEFAULT_LOOP_SECS: f32 = 105.0;
+
+/// Seconds without real input before an interrupted reel resumes.
+const DEFAULT_RESUME_AFTER_IDLE_SECS: f32 = 60.0;
+
+// =============================================================================
+// TYPES
+// =============================================================================
+
+/// One scripted interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DemoAction {
+    /// Drag the pointer in a straight line, painting as it goes
+    Paint {
+        /// Stroke start in world coordinates
+        from: Vec2,
+        /// Stroke end in world coordinates
+        to: Vec2,
+        /// Seconds the stroke takes
+        duration_secs: f32,
+    },
+    /// Fire an explosion, as a left click would
+    Explosion {
+        /// World position of the blast
+        origin: Vec2,
+        /// Strength multiplier
+        strength: f32,
+    },
+    /// Start a hyperspace jump, as a right click would
+    Hyperspace {
+        /// Perspective vanishing point
+        vanishing_point: Vec2,
+    },
+    /// Send a breath pulse, as the space bar would
+    BreathPulse {
+        /// World position the pulse starts from
+        origin: Vec2,
+    },
+    /// Jump to an act
+    GoToAct(Act),
+}
+
+/// A `DemoAction` scheduled at a time within the reel's loop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DemoCue {
+    /// Seconds from the start of the loop
+    pub at_secs: f32,
+    /// What happens at that time
+    pub action: DemoAction,
+}
+
+impl DemoCue {
+    /// Creates a cue firing `action` at `at_secs`.
+    #[must_use]
+    pub fn new(at_secs: f32, action: DemoAction) -> Self {
+        Self { at_secs, action }
+    }
+}
+
+/// A scripted paint stroke in progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DemoStroke {
+    /// Stroke start in world coordinates
+    pub from: Vec2,
+    /// Stroke end in world coordinates
+    pub to: Vec2,
+    /// Seconds the stroke takes
+    pub duration_secs: f32,
+    /// Seconds drawn so far
+    pub elapsed_secs: f32,
+}
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Configuration and playback state for the scripted demo reel.
+///
+/// Disabled by default. `script` defaults to `default_script()`, which
+/// touches painting, explosions, hyperspace, breath pulses, and every act.
+#[derive(Resource, Debug, Clone)]
+pub struct DemoReel {
+    /// Whether the reel drives the experience
+    pub enabled: bool,
+    /// Cues in ascending `at_secs` order
+    pub script: Vec<DemoCue>,
+    /// Length of one loop in seconds; cues at or past it never fire
+    pub loop_secs: f32,
+    /// Seconds without real input before resuming, or `None` to stay manual
+    pub resume_after_idle_secs: Option<f32>,
+    /// Position within the current loop in seconds
+    pub elapsed_secs: f32,
+    /// Whether real input has taken over
+    pub interrupted: bool,
+    /// Seconds since real input was last seen while interrupted
+    pub idle_secs: f32,
+    /// Paint stroke currently being drawn
+    pub stroke: Option<DemoStroke>,
+}
+
+impl Default for DemoReel {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            script: default_script(),
+            loop_secs: DEFAULT_LOOP_SECS,
+            resume_after_idle_secs: Some(DEFAULT_RESUME_AFTER_IDLE_SECS),
+            elapsed_secs: 0.0,
+            interrupted: false,
+            idle_secs: 0.0,
+            stroke: None,
+        }
+    }
+}
+
+impl DemoReel {
+    /// Advances the loop by `delta_secs` and returns the cues that came due.
+    ///
+    /// A cue fires when the playhead reaches its `at_secs`; crossing the end
+    /// of the loop wraps back to the start, so the reel repeats forever.
+    pub fn advance(&mut self, delta_secs: f32) -> Vec<DemoAction> {
+        let loop_secs = self.loop_secs.max(f32::EPSILON);
+        let start = self.elapsed_secs;
+        let end = start + delta_secs.max(0.0);
+
+        let mut fired = self.cues_between(start, end.min(loop_secs));
+        if end >= loop_secs {
+            fired.extend(self.cues_between(0.0, end - loop_secs));
+        }
+
+        self.elapsed_secs = end.rem_euclid(loop_secs);
+        fired
+    }
+
+    /// Returns the actions of cues with `from <= at_secs < to`.
+    fn cues_between(&self, from: f32, to: f32) -> Vec<DemoAction> {
+        self.script
+            .iter()
+            .filter(|cue| cue.at_secs >= from && cue.at_secs < to)
+            .map(|cue| cue.action)
+            .collect()
+    }
+
+    /// Restarts playback from the top of the loop.
+    pub fn restart(&mut self) {
+        self.elapsed_secs = 0.0;
+        self.interrupted = false;
+        self.idle_secs = 0.0;
+        self.stroke = None;
+    }
+}
+
+// =============================================================================
+// HELPER FUNCTIONS
+// =============================================================================
+
+/// Returns the built-in script: each act in turn, with strokes, explosions,
+/// hyperspace jumps, and breath pulses spread across them.
+#[must_use]
+pub fn default_script() -> Vec<DemoCue> {
+    let paint = |from: Vec2, to: Vec2, duration_secs: f32| DemoAction::Paint {
+        from,
+        to,
+        duration_secs,
+    };
+    let explosion = |origin: Vec2, strength: f32| DemoAction::Explosion { origin, strength };
+
+    vec![
+        DemoCue::new(0.0, DemoAction::GoToAct(Act::Emergence)),
+        DemoCue::new(
+            2.0,
+            paint(Vec2::new(-500.0, -100.0), Vec2::new(500.0, 150.0), 4.0),
+        ),
+        DemoCue::new(8.0, DemoAction::BreathPulse { origin: Vec2::ZERO }),
+        DemoCue::new(12.0, DemoAction::GoToAct(Act::Accumulation)),
+        DemoCue::new(
+            15.0,
+            paint(Vec2::new(400.0, 300.0), Vec2::new(-400.0, -250.0), 5.0),
+        ),
+        DemoCue::new(22.0, explosion(Vec2::new(-200.0, 100.0), 1.0)),
+        DemoCue::new(30.0, DemoAction::GoToAct(Act::Crescendo)),
+        DemoCue::new(
+            33.0,
+            paint(Vec2::new(-600.0, 0.0), Vec2::new(600.0, 0.0), 6.0),
+        ),
+        DemoCue::new(40.0, explosion(Vec2::new(250.0, -150.0), 1.0)),
+        DemoCue::new(
+            45.0,
+            DemoAction::Hyperspace {
+                vanishing_point: Vec2::ZERO,
+            },
+        ),
+        DemoCue::new(55.0, DemoAction::GoToAct(Act::Release)),
+        DemoCue::new(
+            58.0,
+            paint(Vec2::new(0.0, -350.0), Vec2::new(0.0, 350.0), 4.0),
+        ),
+        DemoCue::new(
+            66.0,
+            DemoAction::BreathPulse {
+                origin: Vec2::new(0.0, 100.0),
+            },
+        ),
+        DemoCue::new(72.0, DemoAction::GoToAct(Act::Transcendence)),
+        DemoCue::new(
+            75.0,
+            paint(Vec2::new(-300.0, 200.0), Vec2::new(300.0, -200.0), 5.0),
+        ),
+        DemoCue::new(84.0, explosion(Vec2::ZERO, 0.6)),
+        DemoCue::new(
+            95.0,
+            DemoAction::Hyperspace {
+                vanishing_point: Vec2::new(150.0, 50.0),
+            },
+        ),
+    ]
+}
+
+/// Holds the left mouse button for a scripted stroke, as a real drag would.
+///
+/// The press is not reported as `just_pressed`, so it neither fires the
+/// click explosion nor reads as viewer input.
+fn press_stroke_button(mouse_buttons: &mut ButtonInput<MouseButton>) {
+    mouse_buttons.press(MouseButton::Left);
+    mouse_buttons.clear_just_pressed(MouseButton::Left);
+}
+
+/// Lets go of the button held by `press_stroke_button`.
+fn release_stroke_button(mouse_buttons: &mut ButtonInput<MouseButton>) {
+    mouse_buttons.release(MouseButton::Left);
+    mouse_buttons.clear_just_released(MouseButton::Left);
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Run condition: true when the demo reel is enabled.
+pub fn demo_reel_enabled(reel: Res<DemoReel>) -> bool {
+    reel.enabled
+}
+
+/// Event and state outputs the reel drives, bundled to keep system arity low.
+#[derive(SystemParam)]
+pub struct DemoOutputs<'w> {
+    explosions: EventWriter<'w, ExplosionEvent>,
+    hyperspace_jumps: EventWriter<'w, HyperspaceJumpEvent>,
+    hyperspace_state: ResMut<'w, HyperspaceState>,
+    breath_pulses: EventWriter<'w, BreathPulse>,
+    go_to_act: EventWriter<'w, GoToAct>,
+}
+
+/// Hands control to real input and resumes the reel after it goes idle.
+///
+/// Any just-pressed mouse button, key, or touch interrupts the reel. Mouse
+/// presses are also read from `MouseButtonInput` events, since a real left
+/// press during a scripted stroke lands on the already-held button. An
+/// interrupted stroke lets go of its button unless the viewer pressed it.
+/// While interrupted, time with nothing held counts toward
+/// `resume_after_idle_secs`, after which the loop restarts from the top.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// Runs before `run_demo_reel`.
+pub fn interrupt_demo_reel(
+    time: Res<Time>,
+    mut mouse_button_events: EventReader<MouseButtonInput>,
+    mut mouse_buttons: ResMut<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    touches: Res<Touches>,
+    mut reel: ResMut<DemoReel>,
+) {
+    let mut viewer_clicked = false;
+    let mut viewer_holds_left = false;
+    for event in mouse_button_events.read() {
+        if event.state.is_pressed() {
+            viewer_clicked = true;
+            viewer_holds_left |= event.button == MouseButton::Left;
+        }
+    }
+
+    if viewer_clicked
+        || mouse_buttons.get_just_pressed().next().is_some()
+        || keys.get_just_pressed().next().is_some()
+        || touches.iter_just_pressed().next().is_some()
+    {
+        if !reel.interrupted {
+            info!("Demo reel interrupted by viewer input");
+        }
+        reel.interrupted = true;
+        reel.idle_secs = 0.0;
+        if reel.stroke.take().is_some() && !viewer_holds_left {
+            release_stroke_button(&mut mouse_buttons);
+        }
+        return;
+    }
+
+    if !reel.interrupted {
+        return;
+    }
+
+    let holding = mouse_buttons.get_pressed().next().is_some()
+        || keys.get_pressed().next().is_some()
+        || touches.iter().next().is_some();
+    if holding {
+        reel.idle_secs = 0.0;
+        return;
+    }
+
+    reel.idle_secs += time.delta_secs();
+    if reel
+        .resume_after_idle_secs
+        .is_some_and(|resume| reel.idle_secs >= resume)
+    {
+        info!("Demo reel resuming after idle");
+        reel.restart();
+    }
+}
+
+/// Plays the reel: fires due cues and drags the pointer along scripted strokes.
+///
+/// Strokes drive `MouseState` directly and hold the left button from start
+/// to end, so painting, pointer influence, and stroke seeding behave exactly
+/// as for a real drag.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// Runs after `InteractionInputSet`, so scripted strokes override the idle
+/// cursor position read from the window.
+pub fn run_demo_reel(
+    time: Res<Time>,
+    mut reel: ResMut<DemoReel>,
+    mut mouse_state: ResMut<MouseState>,
+    mut mouse_buttons: ResMut<ButtonInput<MouseButton>>,
+    paint_config: Res<PaintConfig>,
+    mut rng: ResMut<ParticleRng>,
+    mut outputs: DemoOutputs,
+) {
+    if reel.interrupted {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    for action in reel.advance(delta) {
+        match action {
+            DemoAction::Paint {
+                from,
+                to,
+                duration_secs,
+            } => {
+                mouse_state.begin_stroke(paint_config.stroke_seed, &mut rng.0);
+                press_stroke_button(&mut mouse_buttons);
+                reel.stroke = Some(DemoStroke {
+                    from,
+                    to,
+                    duration_secs,
+                    elapsed_secs: 0.0,
+                });
+            }
+            DemoAction::Explosion { origin, strength } => {
+                outputs.explosions.send(ExplosionEvent { origin, strength });
+            }
+            DemoAction::Hyperspace { vanishing_point } => {
+                if !outputs.hyperspace_state.is_active {
+                    outputs.hyperspace_state.start(vanishing_point);
+                    outputs
+                        .hyperspace_jumps
+                        .send(HyperspaceJumpEvent { vanishing_point });
+                }
+            }
+            DemoAction::BreathPulse { origin } => {
+                outputs.breath_pulses.send(BreathPulse {
+                    origin,
+                    strength: 1.0,
+                });
+            }
+            DemoAction::GoToAct(act) => {
+                outputs.go_to_act.send(GoToAct(act));
+            }
+        }
+    }
+
+    let Some(mut stroke) = reel.stroke else {
+        return;
+    };
+    stroke.elapsed_secs += delta;
+    let duration = stroke.duration_secs.max(f32::EPSILON);
+    let t = (stroke.elapsed_secs / duration).clamp(0.0, 1.0);
+
+    if t >= 1.0 {
+        mouse_state.is_active = false;
+        mouse_state.velocity = Vec2::ZERO;
+        release_stroke_button(&mut mouse_buttons);
+        reel.stroke = None;
+        return;
+    }
+
+    mouse_state.position = stroke.from.lerp(stroke.to, t);
+    mouse_state.velocity = (stroke.to - stroke.from) / duration;
+    mouse_state.is_active = true;
+    reel.stroke = Some(stroke);
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin providing the opt-in scripted demo reel.
+///
+/// Registers the following systems (gated on `DemoReel.enabled`, Fidget state only):
+/// - PreUpdate: interrupt_demo_reel, run_demo_reel (after `InteractionInputSet`)
+pub struct DemoReelPlugin;
+
+impl Plugin for DemoReelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DemoReel>().add_systems(
+            PreUpdate,
+            (interrupt_demo_reel, run_demo_reel)
+                .chain()
+                .after(crate::interaction::InteractionInputSet)
+                .run_if(demo_reel_enabled)
+                .run_if(in_fidget_state),
+        );
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::discriminant;
+    use std::time::Duration;
+
+    #[test]
+    fn test_demo_reel_disabled_by_default() {
+        assert!(!DemoReel::default().enabled);
+    }
+
+    #[test]
+    fn test_default_reel_covers_every_major_interaction() {
+        let mut reel = DemoReel::default();
+        let mut fired = Vec::new();
+        // A power-of-two step keeps the accumulated playhead exact
+        let step = 0.125;
+        let steps = (reel.loop_secs / step) as usize;
+        for _ in 0..steps {
+            fired.extend(reel.advance(step));
+        }
+
+        let kinds = [
+            DemoAction::Paint {
+                from: Vec2::ZERO,
+                to: Vec2::ZERO,
+                duration_secs: 0.0,
+            },
+            DemoAction::Explosion {
+                origin: Vec2::ZERO,
+                strength: 0.0,
+            },
+            DemoAction::Hyperspace {
+                vanishing_point: Vec2::ZERO,
+            },
+            DemoAction::BreathPulse { origin: Vec2::ZERO },
+            DemoAction::GoToAct(Act::Emergence),
+        ];
+        for kind in kinds {
+            assert!(
+                fired
+                    .iter()
+                    .any(|action| discriminant(action) == discriminant(&kind)),
+                "default reel never fires {kind:?}"
+            );
+        }
+        for act in Act::all() {
+            assert!(
+                fired.contains(&DemoAction::GoToAct(act)),
+                "reel skips {act:?}"
+            );
+        }
+
+        // Each cue fires exactly once per loop, then the reel starts over
+        assert_eq!(fired.len(), reel.script.len());
+        assert_eq!(
+            reel.advance(step),
+            vec![DemoAction::GoToAct(Act::Emergence)]
+        );
+    }
+
+    #[test]
+    fn test_real_input_interrupts_and_idle_resumes() {
+        use bevy::input::ButtonState;
+
+        let mut app = App::new();
+        app.add_event::<MouseButtonInput>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<HyperspaceJumpEvent>()
+            .add_event::<BreathPulse>()
+            .add_event::<GoToAct>()
+            .init_resource::<Time>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Touches>()
+            .init_resource::<MouseState>()
+            .init_resource::<PaintConfig>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<HyperspaceState>()
+            .insert_resource(DemoReel {
+                enabled: true,
+                script: vec![DemoCue::new(
+                    0.0,
+                    DemoAction::Paint {
+                        from: Vec2::new(-100.0, 0.0),
+                        to: Vec2::new(100.0, 0.0),
+                        duration_secs: 10.0,
+                    },
+                )],
+                loop_secs: 20.0,
+                resume_after_idle_secs: Some(5.0),
+                ..Default::default()
+            })
+            .add_systems(Update, (interrupt_demo_reel, run_demo_reel).chain());
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+        };
+
+        // The scripted stroke drags the pointer with the button held, without a click
+        step(&mut app);
+        step(&mut app);
+        let mouse = app.world().resource::<MouseState>().clone();
+        assert!(mouse.is_active && mouse.position.x > -100.0);
+        let buttons = app.world().resource::<ButtonInput<MouseButton>>();
+        assert!(buttons.pressed(MouseButton::Left));
+        assert!(!buttons.just_pressed(MouseButton::Left));
+
+        // A real right click takes over: the reel lets go and stops moving the pointer
+        app.world_mut().send_event(MouseButtonInput {
+            button: MouseButton::Right,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        step(&mut app);
+        assert!(!app
+            .world()
+            .resource::<ButtonInput<MouseButton>>()
+            .pressed(MouseButton::Left));
+        let held_at = app.world().resource::<MouseState>().position;
+        step(&mut app);
+        assert!(app.world().resource::<DemoReel>().interrupted);
+        assert_eq!(app.world().resource::<MouseState>().position, held_at);
+
+        // Idle long enough and the reel restarts from the top
+        for _ in 0..5 {
+            step(&mut app);
+        }
+        let reel = app.world().resource::<DemoReel>();
+        assert!(!reel.interrupted);
+        assert!(reel.stroke.is_some());
+
+        // Finishing the stroke lets go of the button
+        for _ in 0..10 {
+            step(&mut app);
+        }
+        assert!(app.world().resource::<DemoReel>().stroke.is_none());
+        assert!(!app
+            .world()
+            .resource::<ButtonInput<MouseButton>>()
+            .pressed(MouseButton::Left));
+    }
+}
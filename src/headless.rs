@@ -0,0 +1,209 @@
+//! Module: headless
+//! Purpose: Windowless, fixed-step, seeded simulation for testing emergent particle behavior
+//! Dependencies: act_management, audio_reactive, particle, resources, types, bevy::time
+//!
+//! Requires the `acts` and `audio` features.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::act_management::ActManagementPlugin;
+use crate::audio_reactive::{
+    detect_beats, metronome_beats, metronome_enabled, process_audio_input, Metronome,
+};
+use crate::particle::{update_intensity, ParticlePlugin};
+use crate::resources::{ParticlePool, ParticleRng, ResourcesPlugin, RngSeed};
+use crate::types::{in_fidget_state, AppState};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Simulated time per `App::update` in headless runs (60 Hz).
+pub const HEADLESS_STEP: Duration = Duration::from_nanos(16_666_667);
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Runs the act, particle, and audio-analysis systems without a window,
+/// renderer, or audio device.
+///
+/// Add it next to `MinimalPlugins`. Every `App::update` advances time by
+/// exactly `HEADLESS_STEP`, the app starts directly in `AppState::Fidget`, and
+/// `ParticleRng` is seeded from `RngSeed` (insert it before adding the plugin;
+/// it defaults to 0), so two runs with the same seed produce the same swarm.
+///
+/// Audio comes from the procedural fallback in `process_audio_input`; enable
+/// `Metronome` for a beat grid that does not depend on the simulated signal.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut app = whirled_peas::headless::headless_app(42);
+/// let active = whirled_peas::headless::advance_fixed_steps(&mut app, 600);
+/// ```
+pub struct WhirledPeasHeadlessPlugin;
+
+impl Plugin for WhirledPeasHeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+
+        let seed = app
+            .world()
+            .get_resource::<RngSeed>()
+            .copied()
+            .unwrap_or_default();
+
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(HEADLESS_STEP))
+            .insert_state(AppState::Fidget)
+            .add_plugins((ResourcesPlugin, ActManagementPlugin, ParticlePlugin))
+            .insert_resource(seed)
+            .insert_resource(ParticleRng::with_seed(seed.0))
+            // Normally registered by AudioReactivePlugin
+            .init_resource::<Metronome>()
+            .add_systems(
+                Update,
+                (
+                    process_audio_input.before(update_intensity),
+                    detect_beats.run_if(not(metronome_enabled)),
+                    metronome_beats.run_if(metronome_enabled),
+                )
+                    .chain()
+                    .run_if(in_fidget_state)
+                    .before(crate::particle::spawn_particles_from_beat),
+            );
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Builds a headless app with `MinimalPlugins` and `WhirledPeasHeadlessPlugin`
+/// seeded with `seed`.
+#[must_use]
+pub fn headless_app(seed: u64) -> App {
+    let mut app = App::new();
+    app.insert_resource(RngSeed(seed))
+        .add_plugins((MinimalPlugins, WhirledPeasHeadlessPlugin));
+    app
+}
+
+/// Advances a headless app by `steps` fixed steps and returns the number of
+/// active particles afterwards.
+pub fn advance_fixed_steps(app: &mut App, steps: u32) -> u32 {
+    for _ in 0..steps {
+        app.update();
+    }
+    app.world().resource::<ParticlePool>().active_count
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metronome_run(seed: u64, steps: u32) -> (u32, Vec<Vec3>) {
+        let mut app = App::new();
+        app.insert_resource(RngSeed(seed))
+            .insert_resource(Metronome {
+                enabled: true,
+                ..Default::default()
+            })
+            .add_plugins((MinimalPlugins, WhirledPeasHeadlessPlugin));
+
+        let active = advance_fixed_steps(&mut app, steps);
+        let mut positions: Vec<Vec3> = app
+            .world_mut()
+            .query::<(&crate::components::ParticleState, &Transform)>()
+            .iter(app.world())
+            .filter(|(state, _)| state.active)
+            .map(|(_, transform)| transform.translation)
+            .collect();
+        positions.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        (active, positions)
+    }
+
+    #[test]
+    fn test_seeded_beat_spawns_are_deterministic() {
+        // Ten seconds at 60 BPM: ten beats' worth of spawns
+        let first = metronome_run(7, 600);
+        let second = metronome_run(7, 600);
+
+        assert!(first.0 > 0, "beats should have spawned particles");
+        assert_eq!(first.0, second.0);
+        assert_eq!(first.1, second.1);
+    }
+
+    #[test]
+    fn test_pause_holds_trails_and_rotation() {
+        use crate::components::{ParticleMotion, ParticleState, ParticleVisual, Trail};
+        use crate::resources::ExperiencePaused;
+
+        let mut app = App::new();
+        app.insert_resource(RngSeed(3))
+            .insert_resource(Metronome {
+                enabled: true,
+                ..Default::default()
+            })
+            .add_plugins((MinimalPlugins, AssetPlugin::default()))
+            // Asset types the trail and resource plugins load on build
+            .init_asset::<Font>()
+            .init_asset::<Image>()
+            .init_asset::<Mesh>()
+            .init_asset::<ColorMaterial>()
+            .add_plugins((WhirledPeasHeadlessPlugin, crate::trail::TrailPlugin));
+        advance_fixed_steps(&mut app, 120);
+        // Fast, oriented peas so both trails and turning are in play
+        for (mut visual, mut motion) in app
+            .world_mut()
+            .query::<(&mut ParticleVisual, &mut ParticleMotion)>()
+            .iter_mut(app.world_mut())
+        {
+            visual.orient_to_velocity = true;
+            motion.velocity = Vec2::new(300.0, 120.0);
+        }
+        advance_fixed_steps(&mut app, 10);
+
+        let snapshot = |app: &mut App| -> Vec<(Quat, Vec<f32>)> {
+            app.world_mut()
+                .query::<(&ParticleState, &Transform, &Trail)>()
+                .iter(app.world())
+                .filter(|(state, _, _)| state.active)
+                .map(|(_, transform, trail)| {
+                    let opacities = trail.iter_segments().map(|s| s.opacity).collect();
+                    (transform.rotation, opacities)
+                })
+                .collect()
+        };
+        let before = snapshot(&mut app);
+        assert!(before
+            .iter()
+            .any(|(_, opacities)| opacities.iter().any(|&opacity| opacity > 0.0)));
+
+        app.world_mut().resource_mut::<ExperiencePaused>().0 = true;
+        advance_fixed_steps(&mut app, 30);
+        assert_eq!(snapshot(&mut app), before);
+    }
+
+    #[test]
+    fn test_headless_app_advances_fixed_time() {
+        let mut app = headless_app(1);
+        advance_fixed_steps(&mut app, 61);
+
+        // The first update only establishes the clock
+        let elapsed = app.world().resource::<Time>().elapsed_secs();
+        assert!((elapsed - 1.0).abs() < 1e-3, "elapsed {elapsed}");
+        let act_state = app.world().resource::<crate::resources::ActState>();
+        assert!((act_state.total_elapsed_seconds - 1.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,436 @@
+//! Module: heatmap
+//! Purpose: Low-resolution accumulation of where users interact, with optional background glow
+//! Dependencies: components, resources, interaction, render_layers, visual, bevy::prelude
+//!
+//! Pointer and touch positions are deposited into a coarse grid every frame
+//! and decay exponentially, so the grid is a fading memory of attention. The
+//! grid is public for analytics logging and can optionally be drawn as a
+//! faint glow just above the background.
+
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::components::WhirledCamera;
+use crate::interaction::{world_position_from_screen, TouchState};
+use crate::render_layers;
+use crate::resources::MouseState;
+use crate::types::in_fidget_state;
+use crate::visual::{VIEWPORT_HEIGHT, VIEWPORT_WIDTH};
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Configuration for the interaction heatmap.
+#[derive(Resource, Debug, Clone)]
+pub struct InteractionHeatmapConfig {
+    /// Number of grid columns
+    pub columns: u32,
+    /// Number of grid rows
+    pub rows: u32,
+    /// World-space area covered by the grid, centered on the origin
+    pub world_size: Vec2,
+    /// Heat added per second to the cell under each active pointer
+    pub deposit_per_second: f32,
+    /// Exponential decay rate per second (0.05 is a half-life of ~14s)
+    pub decay_rate: f32,
+    /// Whether the heatmap is drawn as a background glow
+    pub render_glow: bool,
+    /// Tint of the glow
+    pub glow_color: Color,
+    /// Glow alpha for a fully saturated cell
+    pub max_glow_alpha: f32,
+}
+
+impl Default for InteractionHeatmapConfig {
+    fn default() -> Self {
+        Self {
+            columns: 32,
+            rows: 18,
+            world_size: Vec2::new(VIEWPORT_WIDTH, VIEWPORT_HEIGHT),
+            deposit_per_second: 1.0,
+            decay_rate: 0.05,
+            render_glow: false,
+            glow_color: Color::srgb(1.0, 0.92, 0.75),
+            max_glow_alpha: 0.12,
+        }
+    }
+}
+
+/// Accumulated interaction heat on a coarse world-space grid.
+///
+/// Cells are stored row-major with row 0 at the top of the screen, matching
+/// image layout. Read `cells()` / `dimensions()` for external logging.
+#[derive(Resource, Debug, Clone)]
+pub struct InteractionHeatmap {
+    columns: u32,
+    rows: u32,
+    world_size: Vec2,
+    cells: Vec<f32>,
+}
+
+impl Default for InteractionHeatmap {
+    fn default() -> Self {
+        let config = InteractionHeatmapConfig::default();
+        Self::new(config.columns, config.rows, config.world_size)
+    }
+}
+
+impl InteractionHeatmap {
+    /// Creates an empty heatmap covering `world_size`.
+    #[must_use]
+    pub fn new(columns: u32, rows: u32, world_size: Vec2) -> Self {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+        Self {
+            columns,
+            rows,
+            world_size,
+            cells: vec![0.0; (columns * rows) as usize],
+        }
+    }
+
+    /// Returns `(columns, rows)`.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.columns, self.rows)
+    }
+
+    /// Returns all cells, row-major from the top-left.
+    #[must_use]
+    pub fn cells(&self) -> &[f32] {
+        &self.cells
+    }
+
+    /// Returns the heat of a single cell.
+    #[must_use]
+    pub fn value(&self, column: u32, row: u32) -> f32 {
+        if column >= self.columns || row >= self.rows {
+            return 0.0;
+        }
+        self.cells[(row * self.columns + column) as usize]
+    }
+
+    /// Returns the `(column, row)` containing a world position, if on the grid.
+    #[must_use]
+    pub fn cell_of(&self, position: Vec2) -> Option<(u32, u32)> {
+        let u = (position.x + self.world_size.x * 0.5) / self.world_size.x.max(f32::EPSILON);
+        let v = (self.world_size.y * 0.5 - position.y) / self.world_size.y.max(f32::EPSILON);
+        if !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+            return None;
+        }
+        Some((
+            (u * self.columns as f32) as u32,
+            (v * self.rows as f32) as u32,
+        ))
+    }
+
+    /// Returns the hottest cell as `(column, row, heat)`, or `None` if all are cold.
+    #[must_use]
+    pub fn hottest_cell(&self) -> Option<(u32, u32, f32)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, heat)| **heat > 0.0)
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(index, heat)| {
+                (
+                    index as u32 % self.columns,
+                    index as u32 / self.columns,
+                    *heat,
+                )
+            })
+    }
+
+    /// Adds heat to the cell containing `position`. Off-grid positions are ignored.
+    pub fn deposit(&mut self, position: Vec2, amount: f32) {
+        if let Some((column, row)) = self.cell_of(position) {
+            self.cells[(row * self.columns + column) as usize] += amount;
+        }
+    }
+
+    /// Decays every cell exponentially over `delta_secs`.
+    pub fn decay(&mut self, delta_secs: f32, decay_rate: f32) {
+        let factor = (-decay_rate.max(0.0) * delta_secs).exp();
+        for heat in &mut self.cells {
+            *heat *= factor;
+        }
+    }
+
+    /// Returns true if the grid layout differs from `config`.
+    fn differs_from(&self, config: &InteractionHeatmapConfig) -> bool {
+        self.columns != config.columns.max(1)
+            || self.rows != config.rows.max(1)
+            || self.world_size != config.world_size
+    }
+}
+
+// =============================================================================
+// COMPONENTS
+// =============================================================================
+
+/// Marker for the sprite that draws the heatmap glow.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HeatmapGlow;
+
+// =============================================================================
+// HELPER FUNCTIONS
+// =============================================================================
+
+/// Maps accumulated heat to glow alpha, saturating smoothly toward `max_alpha`.
+#[inline]
+#[must_use]
+pub fn heatmap_glow_alpha(heat: f32, max_alpha: f32) -> f32 {
+    (1.0 - (-heat.max(0.0)).exp()) * max_alpha
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Decays the heatmap and deposits heat under the mouse and every touch.
+///
+/// The primary touch already drives `MouseState`, so it is skipped in the
+/// touch loop rather than deposited twice. Rebuilds the grid (discarding
+/// history) if the configured layout changes.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs after `InteractionInputSet` has refreshed `MouseState` in PreUpdate.
+pub fn update_interaction_heatmap(
+    time: Res<Time>,
+    config: Res<InteractionHeatmapConfig>,
+    mut heatmap: ResMut<InteractionHeatmap>,
+    mouse_state: Res<MouseState>,
+    touches: Res<Touches>,
+    touch_state: Option<Res<TouchState>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<WhirledCamera>>,
+) {
+    if heatmap.differs_from(&config) {
+        *heatmap = InteractionHeatmap::new(config.columns, config.rows, config.world_size);
+    }
+
+    let delta_secs = time.delta_secs();
+    heatmap.decay(delta_secs, config.decay_rate);
+
+    let amount = config.deposit_per_second * delta_secs;
+    if mouse_state.is_active {
+        heatmap.deposit(mouse_state.position, amount);
+    }
+
+    let primary_touch_id = touch_state.and_then(|state| state.primary_touch_id);
+    if let Ok((camera, camera_transform)) = camera_query.get_single() {
+        for touch in touches
+            .iter()
+            .filter(|touch| Some(touch.id()) != primary_touch_id)
+        {
+            if let Some(position) =
+                world_position_from_screen(touch.position(), camera, camera_transform)
+            {
+                heatmap.deposit(position, amount);
+            }
+        }
+    }
+}
+
+/// Draws the heatmap as a faint glow texture over the background.
+///
+/// The glow sprite is created on first use and hidden while
+/// `InteractionHeatmapConfig.render_glow` is off.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs after `update_interaction_heatmap`.
+pub fn render_interaction_heatmap(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    config: Res<InteractionHeatmapConfig>,
+    heatmap: Res<InteractionHeatmap>,
+    mut glow_query: Query<(&mut Sprite, &mut Visibility), With<HeatmapGlow>>,
+) {
+    let Ok((mut sprite, mut visibility)) = glow_query.get_single_mut() else {
+        if config.render_glow {
+            commands.spawn((
+                Sprite {
+                    image: images.add(heatmap_image(&heatmap, &config)),
+                    custom_size: Some(config.world_size),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, render_layers::BACKGROUND_GLOW),
+                HeatmapGlow,
+                Name::new("InteractionHeatmapGlow"),
+            ));
+        }
+        return;
+    };
+
+    visibility.set_if_neq(if config.render_glow {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    });
+    if !config.render_glow {
+        return;
+    }
+
+    if sprite.custom_size != Some(config.world_size) {
+        sprite.custom_size = Some(config.world_size);
+    }
+
+    // Grid layout changes need a new texture; otherwise rewrite in place
+    let (width, height) = heatmap.dimensions();
+    let same_layout = images.get(&sprite.image).is_some_and(|image| {
+        let size = image.texture_descriptor.size;
+        size.width == width && size.height == height
+    });
+    if same_layout {
+        if let Some(image) = images.get_mut(&sprite.image) {
+            write_heatmap_pixels(image, &heatmap, &config);
+        }
+    } else {
+        sprite.image = images.add(heatmap_image(&heatmap, &config));
+    }
+}
+
+/// Builds a glow image sized to the heatmap grid.
+fn heatmap_image(heatmap: &InteractionHeatmap, config: &InteractionHeatmapConfig) -> Image {
+    let (width, height) = heatmap.dimensions();
+    let mut image = Image::new_fill(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    write_heatmap_pixels(&mut image, heatmap, config);
+    image
+}
+
+/// Writes tinted, heat-weighted alpha into an RGBA8 image.
+fn write_heatmap_pixels(
+    image: &mut Image,
+    heatmap: &InteractionHeatmap,
+    config: &InteractionHeatmapConfig,
+) {
+    let tint = config.glow_color.to_srgba();
+    let rgb = [tint.red, tint.green, tint.blue].map(|c| (c.clamp(0.0, 1.0) * 255.0) as u8);
+    for (bytes, heat) in image.data.chunks_exact_mut(4).zip(heatmap.cells()) {
+        let alpha = heatmap_glow_alpha(*heat, config.max_glow_alpha);
+        bytes[..3].copy_from_slice(&rgb);
+        bytes[3] = (alpha.clamp(0.0, 1.0) * 255.0).round() as u8;
+    }
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that accumulates the interaction heatmap and draws its optional glow.
+///
+/// # Systems
+/// - `update_interaction_heatmap` (Update): Decay and pointer/touch deposits
+/// - `render_interaction_heatmap` (Update, after update): Background glow texture
+pub struct HeatmapPlugin;
+
+impl Plugin for HeatmapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InteractionHeatmapConfig>()
+            .init_resource::<InteractionHeatmap>()
+            .add_systems(
+                Update,
+                (update_interaction_heatmap, render_interaction_heatmap)
+                    .chain()
+                    .run_if(in_fidget_state),
+            );
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_cell_of_maps_world_to_grid() {
+        let heatmap = InteractionHeatmap::new(4, 2, Vec2::new(400.0, 200.0));
+        assert_eq!(heatmap.cell_of(Vec2::new(-199.0, 99.0)), Some((0, 0)));
+        assert_eq!(heatmap.cell_of(Vec2::new(199.0, -99.0)), Some((3, 1)));
+        assert_eq!(heatmap.cell_of(Vec2::new(10.0, 10.0)), Some((2, 0)));
+        assert_eq!(heatmap.cell_of(Vec2::new(500.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_interaction_heats_cell_and_decays() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Touches>()
+            .init_resource::<InteractionHeatmapConfig>()
+            .init_resource::<InteractionHeatmap>()
+            .insert_resource(MouseState {
+                position: Vec2::new(300.0, 200.0),
+                is_active: true,
+                ..Default::default()
+            })
+            .add_systems(Update, update_interaction_heatmap);
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+        };
+
+        for _ in 0..10 {
+            step(&mut app);
+        }
+
+        let heatmap = app.world().resource::<InteractionHeatmap>();
+        let (column, row) = heatmap.cell_of(Vec2::new(300.0, 200.0)).unwrap();
+        let heated = heatmap.value(column, row);
+        assert!(
+            heated > 0.5,
+            "cell under the pointer should heat up, got {heated}"
+        );
+        assert_eq!(
+            heatmap.hottest_cell().map(|(c, r, _)| (c, r)),
+            Some((column, row))
+        );
+        assert_eq!(
+            heatmap.cells().iter().filter(|heat| **heat > 0.0).count(),
+            1
+        );
+
+        // Pointer leaves; the cell cools but remembers
+        app.world_mut().resource_mut::<MouseState>().is_active = false;
+        for _ in 0..20 {
+            step(&mut app);
+        }
+
+        let cooled = app
+            .world()
+            .resource::<InteractionHeatmap>()
+            .value(column, row);
+        assert!(cooled < heated && cooled > 0.0);
+    }
+
+    #[test]
+    fn test_glow_alpha_saturates() {
+        assert_eq!(heatmap_glow_alpha(0.0, 0.2), 0.0);
+        assert!(heatmap_glow_alpha(1.0, 0.2) < heatmap_glow_alpha(5.0, 0.2));
+        assert!(heatmap_glow_alpha(100.0, 0.2) <= 0.2);
+    }
+}
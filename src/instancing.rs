@@ -0,0 +1,815 @@
+//! Module: instancing
+//! Purpose: Single-draw-call instanced particle renderer for `RenderMode::Instanced`
+//! Dependencies: components, particle, render_layers, resources, types, bevy::render,
+//! bevy::core_pipeline
+
+use bevy::core_pipeline::core_2d::{Transparent2d, CORE_2D_DEPTH_FORMAT};
+use bevy::ecs::system::lifetimeless::{Read, SRes};
+use bevy::ecs::system::SystemParamItem;
+use bevy::image::BevyDefault;
+use bevy::math::FloatOrd;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssets;
+use bevy::render::render_phase::{
+    AddRenderCommand, DrawFunctions, PhaseItem, PhaseItemExtraIndex, RenderCommand,
+    RenderCommandResult, SetItemPipeline, TrackedRenderPass, ViewSortedRenderPhases,
+};
+use bevy::render::render_resource::binding_types::{sampler, texture_2d, uniform_buffer};
+use bevy::render::render_resource::{
+    BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, BlendComponent,
+    BlendFactor, BlendOperation, BlendState, BufferUsages, BufferVec, ColorTargetState,
+    ColorWrites, CompareFunction, DepthStencilState, FragmentState, MultisampleState,
+    PipelineCache, PrimitiveState, RenderPipelineDescriptor, SamplerBindingType, ShaderStages,
+    ShaderType, SpecializedRenderPipeline, SpecializedRenderPipelines, StencilState, TextureFormat,
+    TextureSampleType, VertexAttribute, VertexBufferLayout, VertexFormat, VertexState,
+    VertexStepMode,
+};
+use bevy::render::renderer::{RenderDevice, RenderQueue};
+use bevy::render::sync_world::{MainEntity, TemporaryRenderEntity};
+use bevy::render::texture::GpuImage;
+use bevy::render::view::{
+    ExtractedView, Msaa, ViewTarget, ViewUniform, ViewUniformOffset, ViewUniforms,
+};
+use bevy::render::{Extract, ExtractSchedule, Render, RenderApp, RenderSet};
+
+use crate::components::{
+    MotionStreak, Particle, ParticleMotion, ParticleState, ParticleVisual, PulseResponder,
+};
+use crate::particle::{particle_appearance, streak_shape, SpatialGrid};
+use crate::render_layers;
+use crate::resources::{
+    DensityOpacityConfig, DisplayScale, ParticlePool, PeaTexture, SimFrameBudget,
+};
+use crate::types::{in_fidget_state, BlendMode};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Asset path of the instanced particle shader.
+const PARTICLE_SHADER_PATH: &str = "shaders/particle.wgsl";
+
+/// Pre-allocated particle entities when instanced.
+pub const INSTANCED_POOL_CAPACITY: u32 = 60_000;
+
+/// Maximum simultaneously active particles when instanced.
+pub const INSTANCED_MAX_ACTIVE: u32 = 50_000;
+
+/// Vertices per instance: two triangles covering the pea's quad.
+const QUAD_VERTEX_COUNT: u32 = 6;
+
+// =============================================================================
+// INSTANCE DATA
+// =============================================================================
+
+/// Per-instance vertex data for `assets/shaders/particle.wgsl`.
+///
+/// Field order mirrors the shader's `Instance` locations. `size` packs into
+/// the `vec3`'s trailing slot and the padding rounds the stride up to three
+/// 16-byte rows.
+#[derive(ShaderType, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParticleInstance {
+    /// World position; z orders peas against each other
+    pub position: Vec3,
+    /// Quad edge length in world units
+    pub size: f32,
+    /// Linear RGBA, opacity in alpha
+    pub color: Vec4,
+    /// Rotation about z in radians
+    pub rotation: f32,
+    /// Length over width along the rotated x axis (1.0 = round)
+    pub stretch: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_b: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_c: f32,
+}
+
+impl ParticleInstance {
+    /// Packs a particle's transform and `particle_appearance` output.
+    #[must_use]
+    pub fn new(transform: &Transform, color: Color, size: f32) -> Self {
+        let linear = color.to_linear();
+        Self {
+            position: transform.translation,
+            size,
+            color: Vec4::new(linear.red, linear.green, linear.blue, linear.alpha),
+            rotation: transform.rotation.to_euler(EulerRot::ZYX).0,
+            stretch: 1.0,
+            ..default()
+        }
+    }
+
+    /// Stretches the instance by `stretch` along `heading` (radians about z).
+    #[must_use]
+    pub fn with_streak(self, stretch: f32, heading: f32) -> Self {
+        Self {
+            rotation: heading,
+            stretch,
+            ..self
+        }
+    }
+
+    /// Returns the instance's color as a `Color`.
+    #[must_use]
+    pub fn color(&self) -> Color {
+        Color::linear_rgba(self.color.x, self.color.y, self.color.z, self.color.w)
+    }
+
+    /// Vertex buffer layout of one instance, stepped once per pea.
+    #[must_use]
+    pub fn vertex_layout() -> VertexBufferLayout {
+        VertexBufferLayout {
+            array_stride: Self::min_size().get(),
+            step_mode: VertexStepMode::Instance,
+            attributes: vec![
+                VertexAttribute {
+                    format: VertexFormat::Float32x3,
+                    offset: 0,
+                    shader_location: 0,
+                },
+                VertexAttribute {
+                    format: VertexFormat::Float32,
+                    offset: 12,
+                    shader_location: 1,
+                },
+                VertexAttribute {
+                    format: VertexFormat::Float32x4,
+                    offset: 16,
+                    shader_location: 2,
+                },
+                VertexAttribute {
+                    format: VertexFormat::Float32,
+                    offset: 32,
+                    shader_location: 3,
+                },
+                VertexAttribute {
+                    format: VertexFormat::Float32,
+                    offset: 36,
+                    shader_location: 4,
+                },
+            ],
+        }
+    }
+}
+
+/// This frame's instances, one per active particle.
+///
+/// Rebuilt by `build_particle_instances` and copied to the GPU by
+/// `extract_particle_instances`.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ParticleInstances {
+    /// Instances in no particular order
+    pub instances: Vec<ParticleInstance>,
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Particles `build_particle_instances` turns into instances.
+type InstanceSourceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static ParticleVisual,
+        &'static ParticleState,
+        &'static PulseResponder,
+        Option<(&'static MotionStreak, &'static ParticleMotion)>,
+        &'static Transform,
+    ),
+    With<Particle>,
+>;
+
+/// Rebuilds `ParticleInstances` from every active particle.
+///
+/// Replaces `sync_sprite_visuals` in `RenderMode::Instanced`, with the same
+/// `particle_appearance` color and size and the same `MotionStreak` shape.
+/// Opacity is then scaled by `BlendMode::opacity_scale`, so additive peas
+/// start dim and brighten with their `bloom_contribution`.
+///
+/// # Stage
+/// PostUpdate
+///
+/// # Ordering
+/// Reads the `SpatialGrid` rebuilt in Update for density softening.
+pub fn build_particle_instances(
+    query: InstanceSourceQuery,
+    density_config: Res<DensityOpacityConfig>,
+    grid: Res<SpatialGrid>,
+    display_scale: Res<DisplayScale>,
+    blend_mode: Res<BlendMode>,
+    mut instances: ResMut<ParticleInstances>,
+) {
+    instances.instances.clear();
+
+    for (visual, state, pulse_responder, streak, transform) in query.iter() {
+        if !state.active {
+            continue;
+        }
+
+        let (color, size) = particle_appearance(
+            visual,
+            state,
+            pulse_responder,
+            transform.translation.truncate(),
+            (&density_config, &grid),
+            &display_scale,
+        );
+        let opacity = color.alpha() * blend_mode.opacity_scale(visual.bloom_contribution);
+        let instance = ParticleInstance::new(transform, color.with_alpha(opacity), size);
+        instances.instances.push(match streak_shape(streak) {
+            Some((stretch, heading)) => instance.with_streak(stretch, heading),
+            None => instance,
+        });
+    }
+}
+
+// =============================================================================
+// RENDER WORLD RESOURCES
+// =============================================================================
+
+/// GPU vertex buffer of this frame's instances, plus the pea texture to draw.
+#[derive(Resource)]
+pub struct ParticleInstanceBuffer {
+    /// Instances extracted from `ParticleInstances`
+    pub instances: BufferVec<ParticleInstance>,
+    /// Render-world entity standing in for the whole batch in `Transparent2d`
+    pub entity: Option<Entity>,
+    /// The pea texture, once `PeaTexture` exists
+    pub texture: Option<AssetId<Image>>,
+    /// The main world's `BlendMode`, for pipeline specialization
+    pub blend_mode: BlendMode,
+}
+
+impl Default for ParticleInstanceBuffer {
+    fn default() -> Self {
+        Self {
+            instances: BufferVec::new(BufferUsages::VERTEX | BufferUsages::COPY_DST),
+            entity: None,
+            texture: None,
+            blend_mode: BlendMode::default(),
+        }
+    }
+}
+
+/// Bind groups for the current frame; `None` until their inputs are ready.
+#[derive(Resource, Default)]
+pub struct ParticleInstancingBindGroups {
+    /// Bevy's view uniform, bound with a per-view dynamic offset
+    pub view: Option<BindGroup>,
+    /// The pea texture and its sampler
+    pub texture: Option<BindGroup>,
+}
+
+/// Pipeline specialization: the view's target format, sample count, and blend mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParticleInstancingKey {
+    /// Whether the view renders to an HDR target
+    pub hdr: bool,
+    /// MSAA samples of the view's main texture
+    pub samples: u32,
+    /// How peas combine with what is already drawn
+    pub blend_mode: BlendMode,
+}
+
+/// Returns the color target blend state for `blend_mode`.
+///
+/// The shader outputs premultiplied color, so additive only swaps the
+/// destination factor; alpha still accumulates as coverage.
+#[must_use]
+pub fn particle_blend_state(blend_mode: BlendMode) -> BlendState {
+    match blend_mode {
+        BlendMode::AlphaBlend => BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+        BlendMode::Additive => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent::OVER,
+        },
+    }
+}
+
+/// Bind group layouts and shader for the instanced particle pipeline.
+#[derive(Resource)]
+pub struct ParticleInstancingPipeline {
+    view_layout: BindGroupLayout,
+    texture_layout: BindGroupLayout,
+    shader: Handle<Shader>,
+}
+
+impl FromWorld for ParticleInstancingPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let view_layout = render_device.create_bind_group_layout(
+            "particle_instancing_view",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::VERTEX,
+                uniform_buffer::<ViewUniform>(true),
+            ),
+        );
+        let texture_layout = render_device.create_bind_group_layout(
+            "particle_instancing_texture",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                ),
+            ),
+        );
+
+        Self {
+            view_layout,
+            texture_layout,
+            shader: world.resource::<AssetServer>().load(PARTICLE_SHADER_PATH),
+        }
+    }
+}
+
+impl SpecializedRenderPipeline for ParticleInstancingPipeline {
+    type Key = ParticleInstancingKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let format = if key.hdr {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        RenderPipelineDescriptor {
+            label: Some("particle_instancing_pipeline".into()),
+            layout: vec![self.view_layout.clone(), self.texture_layout.clone()],
+            push_constant_ranges: Vec::new(),
+            vertex: VertexState {
+                shader: self.shader.clone(),
+                shader_defs: Vec::new(),
+                entry_point: "vertex".into(),
+                buffers: vec![ParticleInstance::vertex_layout()],
+            },
+            fragment: Some(FragmentState {
+                shader: self.shader.clone(),
+                shader_defs: Vec::new(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(particle_blend_state(key.blend_mode)),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            // Matches the sprite pipeline, so the batch can share the 2D transparent pass
+            depth_stencil: Some(DepthStencilState {
+                format: CORE_2D_DEPTH_FORMAT,
+                depth_write_enabled: false,
+                depth_compare: CompareFunction::GreaterEqual,
+                stencil: StencilState::default(),
+                bias: default(),
+            }),
+            multisample: MultisampleState {
+                count: key.samples,
+                ..default()
+            },
+            zero_initialize_workgroup_memory: false,
+        }
+    }
+}
+
+// =============================================================================
+// RENDER SYSTEMS
+// =============================================================================
+
+/// Copies `ParticleInstances`, the pea texture, and the blend mode into the
+/// render world, and spawns the entity the batch is queued under.
+///
+/// # Stage
+/// ExtractSchedule
+pub fn extract_particle_instances(
+    mut commands: Commands,
+    instances: Extract<Res<ParticleInstances>>,
+    pea_texture: Extract<Option<Res<PeaTexture>>>,
+    blend_mode: Extract<Res<BlendMode>>,
+    mut buffer: ResMut<ParticleInstanceBuffer>,
+) {
+    buffer.instances.clear();
+    for instance in &instances.instances {
+        buffer.instances.push(*instance);
+    }
+    buffer.entity = Some(commands.spawn(TemporaryRenderEntity).id());
+    buffer.texture = pea_texture.as_ref().map(|texture| texture.handle.id());
+    buffer.blend_mode = **blend_mode;
+}
+
+/// Queues every instance as a single `Transparent2d` item in the
+/// `render_layers::PARTICLES` band.
+///
+/// Sorting with the sprites keeps trails and the background beneath the
+/// peas, and foreground effects and the master dimmer overlay above them. The
+/// pipeline is specialized per view target format, MSAA, and the current
+/// blend mode, so changing `BlendMode` at runtime takes effect next frame.
+/// That only holds in `RenderMode::Instanced`: the render mode is fixed when
+/// `ParticlePlugin` builds, so switching to `Additive` later under
+/// `RenderMode::Sprites` changes nothing on screen.
+///
+/// # Stage
+/// Render (`RenderSet::Queue`)
+pub fn queue_particle_instances(
+    draw_functions: Res<DrawFunctions<Transparent2d>>,
+    pipeline_cache: Res<PipelineCache>,
+    pipeline: Res<ParticleInstancingPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<ParticleInstancingPipeline>>,
+    buffer: Res<ParticleInstanceBuffer>,
+    mut transparent_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
+    views: Query<(Entity, &ExtractedView, &Msaa)>,
+) {
+    let Some(entity) = buffer.entity else {
+        return;
+    };
+    if buffer.instances.is_empty() {
+        return;
+    }
+    let draw_function = draw_functions.read().id::<DrawParticleInstances>();
+
+    for (view_entity, view, msaa) in &views {
+        let Some(transparent_phase) = transparent_phases.get_mut(&view_entity) else {
+            continue;
+        };
+        let key = ParticleInstancingKey {
+            hdr: view.hdr,
+            samples: msaa.samples(),
+            blend_mode: buffer.blend_mode,
+        };
+        transparent_phase.add(Transparent2d {
+            sort_key: FloatOrd(render_layers::PARTICLES),
+            entity: (entity, MainEntity::from(Entity::PLACEHOLDER)),
+            pipeline: pipelines.specialize(&pipeline_cache, &pipeline, key),
+            draw_function,
+            batch_range: 0..1,
+            extra_index: PhaseItemExtraIndex::NONE,
+        });
+    }
+}
+
+/// Uploads the extracted instances into the vertex buffer.
+///
+/// # Stage
+/// Render (`RenderSet::PrepareResources`)
+pub fn prepare_particle_instance_buffer(
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut buffer: ResMut<ParticleInstanceBuffer>,
+) {
+    if !buffer.instances.is_empty() {
+        buffer.instances.write_buffer(&render_device, &render_queue);
+    }
+}
+
+/// Builds the view and pea texture bind groups.
+///
+/// The texture bind group stays `None` until the pea image is uploaded.
+///
+/// # Stage
+/// Render (`RenderSet::PrepareBindGroups`)
+pub fn prepare_particle_bind_groups(
+    render_device: Res<RenderDevice>,
+    pipeline: Res<ParticleInstancingPipeline>,
+    view_uniforms: Res<ViewUniforms>,
+    gpu_images: Res<RenderAssets<GpuImage>>,
+    buffer: Res<ParticleInstanceBuffer>,
+    mut bind_groups: ResMut<ParticleInstancingBindGroups>,
+) {
+    bind_groups.view = view_uniforms.uniforms.binding().map(|binding| {
+        render_device.create_bind_group(
+            "particle_instancing_view",
+            &pipeline.view_layout,
+            &BindGroupEntries::single(binding),
+        )
+    });
+
+    bind_groups.texture = buffer
+        .texture
+        .and_then(|texture| gpu_images.get(texture))
+        .map(|image| {
+            render_device.create_bind_group(
+                "particle_instancing_texture",
+                &pipeline.texture_layout,
+                &BindGroupEntries::sequential((&image.texture_view, &image.sampler)),
+            )
+        });
+}
+
+// =============================================================================
+// DRAW COMMANDS
+// =============================================================================
+
+/// Binds the view and pea texture and draws every instance in one call.
+///
+/// Skips while the pea texture has not loaded or no instance was uploaded.
+pub struct DrawParticleInstanceBuffer;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawParticleInstanceBuffer {
+    type Param = (
+        SRes<ParticleInstanceBuffer>,
+        SRes<ParticleInstancingBindGroups>,
+    );
+    type ViewQuery = Read<ViewUniformOffset>;
+    type ItemQuery = ();
+
+    fn render<'w>(
+        _item: &P,
+        view_offset: &'w ViewUniformOffset,
+        _entity: Option<()>,
+        (buffer, bind_groups): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let buffer = buffer.into_inner();
+        let bind_groups = bind_groups.into_inner();
+        let (Some(instance_buffer), Some(view_bind_group), Some(texture_bind_group)) = (
+            buffer.instances.buffer(),
+            &bind_groups.view,
+            &bind_groups.texture,
+        ) else {
+            return RenderCommandResult::Skip;
+        };
+
+        pass.set_bind_group(0, view_bind_group, &[view_offset.offset]);
+        pass.set_bind_group(1, texture_bind_group, &[]);
+        pass.set_vertex_buffer(0, instance_buffer.slice(..));
+        pass.draw(0..QUAD_VERTEX_COUNT, 0..buffer.instances.len() as u32);
+        RenderCommandResult::Success
+    }
+}
+
+/// Draw function for the instanced batch in `Transparent2d`.
+pub type DrawParticleInstances = (SetItemPipeline, DrawParticleInstanceBuffer);
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that draws particles through one instanced draw call instead of
+/// one sprite each. Added by `ParticlePlugin` in `RenderMode::Instanced`.
+///
+/// Sizes the pool for `INSTANCED_MAX_ACTIVE` (a config file can still
+/// override it) and raises the sim budget controller's ceiling to match.
+///
+/// # Render Phase
+/// One `Transparent2d` item at `render_layers::PARTICLES`, sorted with the
+/// sprites, so the master dimmer overlay still covers the peas.
+///
+/// # Systems
+///
+/// ## PostUpdate
+/// - `build_particle_instances`: Replaces `sync_sprite_visuals`
+///
+/// ## Render
+/// - `extract_particle_instances`: Copies instances, the pea texture, and `BlendMode`
+/// - `queue_particle_instances`: Specializes per view format, MSAA, and `BlendMode`
+///   and queues the batch
+/// - `prepare_particle_instance_buffer`: Uploads the vertex buffer
+/// - `prepare_particle_bind_groups`: Binds the view and the pea texture
+///
+/// Without a `RenderApp` (headless tests) only the main-world side runs.
+pub struct ParticleInstancingPlugin;
+
+impl Plugin for ParticleInstancingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParticleInstances>()
+            .init_resource::<BlendMode>()
+            .insert_resource(ParticlePool {
+                available_entities: Vec::with_capacity(INSTANCED_POOL_CAPACITY as usize),
+                active_count: 0,
+                pool_capacity: INSTANCED_POOL_CAPACITY,
+                max_active: INSTANCED_MAX_ACTIVE,
+                ..Default::default()
+            })
+            .add_systems(PostUpdate, build_particle_instances.run_if(in_fidget_state));
+        if let Some(mut budget) = app.world_mut().get_resource_mut::<SimFrameBudget>() {
+            budget.ceiling_active = INSTANCED_MAX_ACTIVE;
+        }
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .init_resource::<ParticleInstanceBuffer>()
+            .init_resource::<ParticleInstancingBindGroups>()
+            .init_resource::<SpecializedRenderPipelines<ParticleInstancingPipeline>>()
+            .add_render_command::<Transparent2d, DrawParticleInstances>()
+            .add_systems(ExtractSchedule, extract_particle_instances)
+            .add_systems(
+                Render,
+                (
+                    queue_particle_instances.in_set(RenderSet::Queue),
+                    prepare_particle_instance_buffer.in_set(RenderSet::PrepareResources),
+                    prepare_particle_bind_groups.in_set(RenderSet::PrepareBindGroups),
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<ParticleInstancingPipeline>();
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::render_resource::encase;
+
+    use crate::particle::sync_sprite_visuals;
+
+    fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+        f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_instance_matches_vertex_layout() {
+        assert_eq!(ParticleInstance::min_size().get(), 48);
+
+        let instance = ParticleInstance {
+            position: Vec3::new(1.0, 2.0, 3.0),
+            size: 40.0,
+            color: Vec4::new(0.1, 0.2, 0.3, 0.4),
+            rotation: 0.5,
+            stretch: 2.5,
+            ..default()
+        };
+        let mut buffer = encase::StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(&instance).unwrap();
+        let bytes = buffer.into_inner();
+
+        // Every attribute reads back from the offset the layout declares
+        let layout = ParticleInstance::vertex_layout();
+        assert_eq!(layout.array_stride, bytes.len() as u64);
+        let offsets: Vec<usize> = layout
+            .attributes
+            .iter()
+            .map(|attribute| attribute.offset as usize)
+            .collect();
+        assert_eq!(read_f32(&bytes, offsets[0]), 1.0);
+        assert_eq!(read_f32(&bytes, offsets[0] + 8), 3.0);
+        assert_eq!(read_f32(&bytes, offsets[1]), 40.0);
+        assert_eq!(read_f32(&bytes, offsets[2] + 12), 0.4);
+        assert_eq!(read_f32(&bytes, offsets[3]), 0.5);
+        assert_eq!(read_f32(&bytes, offsets[4]), 2.5);
+    }
+
+    #[test]
+    fn test_instances_match_sprites_and_skip_inactive() {
+        let mut app = App::new();
+        app.init_resource::<DensityOpacityConfig>()
+            .init_resource::<SpatialGrid>()
+            .init_resource::<DisplayScale>()
+            .init_resource::<BlendMode>()
+            .init_resource::<ParticleInstances>()
+            .add_systems(PostUpdate, (sync_sprite_visuals, build_particle_instances));
+
+        let mut spawn = |active: bool, x: f32| {
+            app.world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active,
+                        lifetime_remaining_ms: 100.0,
+                        lifetime_total_ms: 1000.0,
+                    },
+                    ParticleVisual {
+                        current_color: Color::srgb(0.9, 0.4, 0.1),
+                        opacity: 0.8,
+                        scale: 0.5,
+                        ..default()
+                    },
+                    PulseResponder::default(),
+                    Sprite::default(),
+                    Transform::from_xyz(x, 10.0, 2.0).with_rotation(Quat::from_rotation_z(0.3)),
+                ))
+                .id()
+        };
+        let active = spawn(true, -40.0);
+        spawn(false, 90.0);
+        app.update();
+
+        let instances = &app.world().resource::<ParticleInstances>().instances;
+        assert_eq!(instances.len(), 1);
+        let instance = instances[0];
+        let sprite = app.world().get::<Sprite>(active).unwrap();
+
+        assert_eq!(instance.position, Vec3::new(-40.0, 10.0, 2.0));
+        assert_eq!(Some(Vec2::splat(instance.size)), sprite.custom_size);
+        assert!((instance.rotation - 0.3).abs() < 1e-5);
+        let (expected, actual) = (sprite.color.to_linear(), instance.color().to_linear());
+        assert!((expected.alpha - actual.alpha).abs() < 1e-5);
+        assert!((expected.red - actual.red).abs() < 1e-5);
+        // Half-way through the final 20% fade
+        assert!((actual.alpha - 0.4).abs() < 1e-5);
+    }
+
+    /// An app holding `INSTANCED_MAX_ACTIVE` live particles and only the instance builder.
+    fn max_active_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<DensityOpacityConfig>()
+            .init_resource::<SpatialGrid>()
+            .init_resource::<DisplayScale>()
+            .init_resource::<BlendMode>()
+            .init_resource::<ParticleInstances>()
+            .add_systems(PostUpdate, build_particle_instances);
+        app.world_mut()
+            .spawn_batch((0..INSTANCED_MAX_ACTIVE).map(|index| {
+                (
+                    Particle { id: index },
+                    ParticleState {
+                        active: true,
+                        lifetime_remaining_ms: 1000.0,
+                        lifetime_total_ms: 1000.0,
+                    },
+                    ParticleVisual::default(),
+                    PulseResponder::default(),
+                    Transform::from_xyz((index % 400) as f32, (index / 400) as f32, 0.0),
+                )
+            }));
+        app
+    }
+
+    #[test]
+    fn test_builds_one_instance_per_particle_at_max_active() {
+        let mut app = max_active_app();
+        app.update();
+
+        let instances = &app.world().resource::<ParticleInstances>().instances;
+        assert_eq!(instances.len(), INSTANCED_MAX_ACTIVE as usize);
+    }
+
+    /// Wall-clock check; only meaningful with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn test_builds_instanced_target_within_frame_budget() {
+        let mut app = max_active_app();
+
+        // Best of several frames, so one descheduled frame does not fail the check
+        let best = (0..5)
+            .map(|_| {
+                let start = std::time::Instant::now();
+                app.update();
+                start.elapsed()
+            })
+            .min()
+            .unwrap();
+
+        assert!(
+            best < std::time::Duration::from_secs_f64(1.0 / 60.0),
+            "building {INSTANCED_MAX_ACTIVE} instances took {best:?}"
+        );
+    }
+
+    #[test]
+    fn test_additive_blend_adds_light_and_dims_instances() {
+        let additive = particle_blend_state(BlendMode::Additive);
+        assert_eq!(additive.color.src_factor, BlendFactor::One);
+        assert_eq!(additive.color.dst_factor, BlendFactor::One);
+        assert_eq!(
+            particle_blend_state(BlendMode::AlphaBlend),
+            BlendState::PREMULTIPLIED_ALPHA_BLENDING
+        );
+
+        let mut app = App::new();
+        app.init_resource::<DensityOpacityConfig>()
+            .init_resource::<SpatialGrid>()
+            .init_resource::<DisplayScale>()
+            .insert_resource(BlendMode::Additive)
+            .init_resource::<ParticleInstances>()
+            .add_systems(PostUpdate, build_particle_instances);
+        app.world_mut().spawn((
+            Particle { id: 0 },
+            ParticleState {
+                active: true,
+                lifetime_remaining_ms: 1000.0,
+                lifetime_total_ms: 1000.0,
+            },
+            ParticleVisual {
+                opacity: 0.8,
+                bloom_contribution: 1.0,
+                ..default()
+            },
+            PulseResponder::default(),
+            Transform::default(),
+        ));
+        app.update();
+
+        let instance = app.world().resource::<ParticleInstances>().instances[0];
+        let expected = 0.8 * BlendMode::Additive.opacity_scale(1.0);
+        assert!((instance.color().to_linear().alpha - expected).abs() < 1e-5);
+    }
+}
@@ -2,14 +2,25 @@
 //! Purpose: Mouse, keyboard, and touch input handling for particle interaction across all acts
 //! Dependencies: bevy, crate::types, crate::resources, crate::components
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::input::touch::Touches;
 use bevy::window::PrimaryWindow;
 
-use crate::components::{MouseInfluence, Particle, ParticleMotion, ParticleState, ParticleVisual};
-use crate::intro::AppState;
-use crate::resources::{CurrentInteractionMode, InteractionConfig, MouseState};
-use crate::types::InteractionMode;
+use crate::components::{
+    Attractor, MagnetPole, MouseInfluence, Particle, ParticleMotion, ParticleState, ParticleVisual,
+    WhirledCamera,
+};
+use crate::particle::SpatialGrid;
+use crate::render_layers;
+use crate::resources::{
+    experience_running, ActState, BaseInteractionMode, ColorPalette, CurrentInteractionMode,
+    DisplayScale, ExperiencePaused, InteractionConfig, MagnetToy, MouseState, PaintColorOverride,
+    PaintConfig, ParticleRng, PeaTexture, RadiusGrowthProfile,
+};
+use crate::types::{in_fidget_state, InteractionMode};
+use crate::visual::{AutoFrame, BACKGROUND_SIZE};
 
 // =============================================================================
 // CONSTANTS
@@ -39,24 +50,49 @@ const INTENSIFY_SCALE_BOOST: f32 = 0.15;
 /// Ripple force strength for Ripple mode.
 const RIPPLE_FORCE_BASE: f32 = 25.0;
 
+/// Tangential (swirl) force strength for Vortex mode.
+const VORTEX_SWIRL_FORCE_BASE: f32 = 90.0;
+
+/// Inward pull in Vortex mode, as a fraction of the swirl force.
+const VORTEX_INWARD_FRACTION: f32 = 0.25;
+
+/// Lifetime drained per second at the center of the eraser, in milliseconds.
+const ERASE_LIFETIME_DRAIN_MS_PER_SECOND: f32 = 8000.0;
+
+/// Starting positions of the two Magnet Toy poles.
+const MAGNET_POLE_START_POSITIONS: [Vec2; 2] = [Vec2::new(-300.0, 0.0), Vec2::new(300.0, 0.0)];
+
+/// Marker colors for the attracting and repelling Magnet Toy poles.
+const MAGNET_POLE_COLORS: [Color; 2] = [Color::srgb(1.0, 0.45, 0.35), Color::srgb(0.35, 0.65, 1.0)];
+
+/// On-screen size of a Magnet Toy pole marker.
+const MAGNET_POLE_MARKER_SIZE: f32 = 48.0;
+
 /// Velocity threshold below which mouse is considered stationary (pixels/second).
 const VELOCITY_THRESHOLD_LOW: f32 = 50.0;
 
 /// Velocity threshold at which mouse influence is maximum (pixels/second).
 const VELOCITY_THRESHOLD_HIGH: f32 = 400.0;
 
-/// Explosion force strength when left-clicking.
-const EXPLOSION_FORCE: f32 = 800.0;
-
-/// Explosion radius of effect.
-const EXPLOSION_RADIUS: f32 = 400.0;
-
 /// Hyperspace acceleration strength.
 const HYPERSPACE_ACCELERATION: f32 = 2000.0;
 
 /// Duration of hyperspace effect in seconds.
 const HYPERSPACE_DURATION: f32 = 1.5;
 
+/// Time constant of pointer velocity smoothing in seconds.
+///
+/// Matches the former 0.3-per-frame blend at 60 Hz, but holds at any refresh rate.
+const POINTER_VELOCITY_TIME_CONSTANT_SECS: f32 = 0.047;
+
+/// Frame rate at which a `PointerFilter::smoothing` factor applies exactly once.
+const POINTER_SMOOTHING_REFERENCE_FPS: f32 = 60.0;
+
+/// Time constant of the radius easing toward its target in seconds.
+///
+/// Matches the former 0.05-per-frame blend at 60 Hz.
+const RADIUS_TIME_CONSTANT_SECS: f32 = 0.325;
+
 /// Maximum time for a touch to count as a "tap" (seconds).
 const TAP_MAX_DURATION: f32 = 0.3;
 
@@ -70,6 +106,36 @@ const HOLD_MIN_DURATION: f32 = 0.5;
 #[allow(dead_code)]
 const TWO_FINGER_WINDOW: f32 = 0.15;
 
+/// Default maximum time between taps of a multi-tap (seconds).
+const MULTI_TAP_WINDOW: f32 = 0.35;
+
+/// Default maximum distance between taps of a multi-tap (world units).
+const MULTI_TAP_MAX_DISTANCE: f32 = 60.0;
+
+/// Horizontal travel of the three-finger centroid that counts as a swipe (pixels).
+const THREE_FINGER_SWIPE_MIN_DISTANCE: f32 = 120.0;
+
+/// Longest gap between the first and last finger lifting in a three-finger
+/// swipe (seconds); staggered lifts are ignored.
+const THREE_FINGER_LIFT_WINDOW: f32 = 0.25;
+
+/// Smallest camera zoom (projection scale); 0.4 magnifies 2.5x.
+pub const CAMERA_MIN_ZOOM: f32 = 0.4;
+
+/// Largest camera zoom (projection scale); the default framing.
+pub const CAMERA_MAX_ZOOM: f32 = 1.0;
+
+/// Finger travel that turns a two-finger touch into a pinch or pan (pixels).
+///
+/// Anything shorter stays a candidate two-finger tap (hyperspace).
+const CAMERA_GESTURE_MIN_TRAVEL: f32 = TAP_MAX_DISTANCE;
+
+/// Zoom change per scroll-wheel line; scrolling up zooms in.
+const WHEEL_ZOOM_PER_LINE: f32 = 1.1;
+
+/// Pixels of touchpad scrolling that count as one wheel line.
+const WHEEL_PIXELS_PER_LINE: f32 = 40.0;
+
 // =============================================================================
 // EVENTS
 // =============================================================================
@@ -134,10 +200,138 @@ pub struct HyperspaceJumpEvent {
     pub vanishing_point: Vec2,
 }
 
+/// Event triggered when the user presses P to pause or resume the experience.
+///
+/// Flips `ExperiencePaused`: the timeline and particles freeze while the
+/// camera and rendering keep running, so the current frame is held.
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct TogglePause;
+
+/// Event sent when taps land in quick succession at nearly the same spot.
+///
+/// Sent for the second tap of a sequence (`count: 2`) and again for each
+/// further tap within the window (`count: 3`, ...). Mouse left-clicks and
+/// single-finger touch taps both count.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct MultiTap {
+    /// Number of taps in the sequence so far
+    pub count: u32,
+    /// World position of the latest tap
+    pub position: Vec2,
+}
+
+/// Event sent when three fingers swipe horizontally and lift together.
+///
+/// With the `acts` feature, `handle_act_swipes` seeks to the start of the next
+/// act on a right swipe and the previous act on a left swipe.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreeFingerSwipe {
+    /// Whether the fingers moved right (`true`) or left (`false`)
+    pub forward: bool,
+}
+
+/// Event requesting a PNG still of the current frame, sent by F12.
+///
+/// Handled by `ScreenshotPlugin`, which saves it under
+/// `ScreenshotConfig::output_dir`.
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct CaptureScreenshot;
+
 // =============================================================================
 // RESOURCES
 // =============================================================================
 
+/// What a double-tap does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MultiTapAction {
+    /// Only send `MultiTap`
+    None,
+    /// Step to the next interaction mode (see `InteractionModeCycle`)
+    #[default]
+    CycleInteractionMode,
+    /// Send a `BreathPulse` at the tap position
+    BreathPulse,
+}
+
+/// Multi-tap detection settings and the in-progress tap sequence.
+#[derive(Resource, Debug, Clone)]
+pub struct MultiTapDetector {
+    /// Maximum time between consecutive taps in seconds
+    pub window_secs: f32,
+    /// Maximum distance between consecutive taps in world units
+    pub max_distance: f32,
+    /// Action taken on a double-tap
+    pub double_tap_action: MultiTapAction,
+    /// Taps in the current sequence
+    pub tap_count: u32,
+    /// Time of the last tap in seconds
+    pub last_tap_secs: f32,
+    /// World position of the last tap
+    pub last_position: Vec2,
+}
+
+impl Default for MultiTapDetector {
+    fn default() -> Self {
+        Self {
+            window_secs: MULTI_TAP_WINDOW,
+            max_distance: MULTI_TAP_MAX_DISTANCE,
+            double_tap_action: MultiTapAction::default(),
+            tap_count: 0,
+            last_tap_secs: f32::NEG_INFINITY,
+            last_position: Vec2::ZERO,
+        }
+    }
+}
+
+impl MultiTapDetector {
+    /// Records a tap and returns its count within the current sequence.
+    ///
+    /// A tap too late or too far from the previous one starts a new sequence.
+    pub fn register_tap(&mut self, now_secs: f32, position: Vec2) -> u32 {
+        let continues = self.tap_count > 0
+            && now_secs - self.last_tap_secs <= self.window_secs
+            && position.distance(self.last_position) <= self.max_distance;
+
+        self.tap_count = if continues { self.tap_count + 1 } else { 1 };
+        self.last_tap_secs = now_secs;
+        self.last_position = position;
+        self.tap_count
+    }
+}
+
+/// Number of steps the interaction mode is rotated away from the act's mode.
+///
+/// Advanced by double-taps when `MultiTapAction::CycleInteractionMode` is set.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct InteractionModeCycle {
+    /// Steps along `InteractionMode::CYCLE_ORDER`
+    pub steps: usize,
+}
+
+/// Shared access for systems that detect taps.
+#[derive(SystemParam)]
+pub struct MultiTapInput<'w> {
+    /// Clock used to time tap sequences
+    pub time: Res<'w, Time>,
+    /// Detection settings and sequence state
+    pub detector: ResMut<'w, MultiTapDetector>,
+    /// Writer for completed multi-taps
+    pub events: EventWriter<'w, MultiTap>,
+}
+
+impl MultiTapInput<'_> {
+    /// Records a tap at `position`, sending `MultiTap` from the second tap on.
+    pub fn register(&mut self, position: Vec2) {
+        let count = self
+            .detector
+            .register_tap(self.time.elapsed_secs(), position);
+        if count >= 2 {
+            self.events.send(MultiTap { count, position });
+        }
+    }
+}
+
 /// Tracks the cooldown state for breath pulse input.
 #[derive(Resource, Debug, Clone)]
 pub struct BreathPulseCooldown {
@@ -210,6 +404,14 @@ impl Default for HyperspaceState {
 }
 
 impl HyperspaceState {
+    /// Starts a full-length jump toward `vanishing_point`.
+    pub fn start(&mut self, vanishing_point: Vec2) {
+        self.is_active = true;
+        self.vanishing_point = vanishing_point;
+        self.remaining_seconds = HYPERSPACE_DURATION;
+        self.total_duration = HYPERSPACE_DURATION;
+    }
+
     /// Returns the effect progress from 0.0 (just started) to 1.0 (complete).
     #[must_use]
     pub fn progress(&self) -> f32 {
@@ -235,6 +437,14 @@ pub struct TouchState {
     pub secondary_touch_id: Option<u64>,
     /// Time when secondary touch started.
     pub secondary_start_time: f32,
+    /// Tertiary touch ID (third finger for the three-finger swipe).
+    pub tertiary_touch_id: Option<u64>,
+    /// Centroid of the three fingers when they were first all down (screen coordinates).
+    pub three_finger_start: Option<Vec2>,
+    /// Latest centroid of the three fingers (screen coordinates).
+    pub three_finger_current: Vec2,
+    /// Time the first finger of a three-finger gesture lifted.
+    pub three_finger_first_lift_time: Option<f32>,
     /// Number of active touches (current frame).
     pub touch_count: usize,
     /// Peak number of simultaneous touches in current gesture.
@@ -256,6 +466,10 @@ impl Default for TouchState {
             primary_start_time: 0.0,
             secondary_touch_id: None,
             secondary_start_time: 0.0,
+            tertiary_touch_id: None,
+            three_finger_start: None,
+            three_finger_current: Vec2::ZERO,
+            three_finger_first_lift_time: None,
             touch_count: 0,
             peak_touch_count: 0,
             multi_touch_start_time: 0.0,
@@ -265,10 +479,219 @@ impl Default for TouchState {
     }
 }
 
+/// Start of a two-finger touch, kept until one finger lifts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoFingerGesture {
+    /// Midpoint between the fingers when the gesture began (screen pixels)
+    pub start_midpoint: Vec2,
+    /// Distance between the fingers when the gesture began (screen pixels)
+    pub start_distance: f32,
+    /// `CameraControlState::zoom` when the gesture began
+    pub start_zoom: f32,
+    /// `CameraControlState::pan` when the gesture began
+    pub start_pan: Vec2,
+    /// Whether the fingers travelled far enough to pinch or pan
+    pub navigating: bool,
+}
+
+/// User-controlled camera zoom and pan.
+///
+/// Fed by pinch and two-finger drag in `update_touch_state`, the scroll wheel
+/// in `handle_scroll_zoom`, and middle-button drag in `handle_middle_drag_pan`;
+/// `apply_camera_control` copies it onto the main camera. A two-finger touch
+/// only navigates once the fingers travel `CAMERA_GESTURE_MIN_TRAVEL`, so
+/// quick still two-finger taps keep triggering hyperspace.
+#[derive(Resource, Debug, Clone)]
+pub struct CameraControlState {
+    /// Projection scale (1.0 = default framing, smaller = zoomed in)
+    pub zoom: f32,
+    /// Camera position in world units
+    pub pan: Vec2,
+    /// Two-finger touch in progress, if any
+    pub gesture: Option<TwoFingerGesture>,
+}
+
+impl Default for CameraControlState {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            pan: Vec2::ZERO,
+            gesture: None,
+        }
+    }
+}
+
+impl CameraControlState {
+    /// Whether the camera sits at its default framing.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.zoom == 1.0 && self.pan == Vec2::ZERO
+    }
+
+    /// Returns the world position shown at `screen_pos` (logical pixels,
+    /// origin top-left) in a window of `window_size`, using this zoom and pan.
+    #[must_use]
+    pub fn world_position(
+        &self,
+        screen_pos: Vec2,
+        window_size: Vec2,
+        display_scale: &DisplayScale,
+    ) -> Vec2 {
+        let offset = screen_pos - window_size * 0.5;
+        self.pan + Vec2::new(offset.x, -offset.y) * world_units_per_pixel(display_scale, self.zoom)
+    }
+
+    /// Whether a two-finger touch is currently pinching or panning.
+    #[must_use]
+    pub fn is_navigating(&self) -> bool {
+        self.gesture.is_some_and(|gesture| gesture.navigating)
+    }
+
+    /// Clamps zoom to `CAMERA_MIN_ZOOM..=CAMERA_MAX_ZOOM`, then pan so the
+    /// background quad still covers a view of `world_viewport` at that zoom.
+    pub fn clamp(&mut self, world_viewport: Vec2) {
+        self.zoom = self.zoom.clamp(CAMERA_MIN_ZOOM, CAMERA_MAX_ZOOM);
+        let max_pan = ((BACKGROUND_SIZE - world_viewport * self.zoom) * 0.5).max(Vec2::ZERO);
+        self.pan = self.pan.clamp(-max_pan, max_pan);
+    }
+}
+
+/// Eraser override for the pointer.
+///
+/// While active, the current interaction mode is replaced by
+/// `InteractionMode::Erase`. Hold Alt for a momentary eraser, or press E
+/// to toggle it on and off.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct EraserOverride {
+    /// Eraser latched on by the toggle key
+    pub toggled: bool,
+    /// Eraser held on by the modifier key this frame
+    pub held: bool,
+}
+
+impl EraserOverride {
+    /// Returns true if the eraser should replace the act's interaction mode.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.toggled || self.held
+    }
+}
+
+/// Magnetic polarity of the directional pointer forces.
+///
+/// While `inverted`, Attract pushes particles away and Disperse pulls them
+/// in. Hold Shift to invert. Visual-only modes (Intensify, Ripple) ignore it.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ForcePolarity {
+    /// Directional forces are flipped this frame
+    pub inverted: bool,
+}
+
+impl ForcePolarity {
+    /// Returns the sign applied to Attract and Disperse forces.
+    #[must_use]
+    pub fn sign(&self) -> f32 {
+        if self.inverted {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Dead-zone and low-pass filtering for jittery pointer hardware.
+///
+/// Some capacitive surfaces report a resting finger wandering by several
+/// pixels, which reads as velocity. Movements shorter than `dead_zone` are
+/// ignored entirely; larger ones are smoothed toward the new sample. The
+/// defaults (both zero) leave pointer input unfiltered.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PointerFilter {
+    /// Movements shorter than this (world units) are ignored
+    pub dead_zone: f32,
+    /// Low-pass factor for accepted movement, per 60 Hz frame (0.0 = raw, 0.9 = heavy
+    /// smoothing); scaled by real delta time so it feels the same at any refresh rate
+    pub smoothing: f32,
+}
+
 // =============================================================================
 // HELPER FUNCTIONS
 // =============================================================================
 
+/// Returns the blend factor that eases a value toward its target over `delta_secs`.
+///
+/// Exponential smoothing expressed as a time constant: after `time_constant_secs`
+/// the value has covered about 63% of the gap, however many frames that took.
+/// A non-positive time constant snaps straight to the target.
+#[inline]
+#[must_use]
+pub fn smoothing_alpha(time_constant_secs: f32, delta_secs: f32) -> f32 {
+    if time_constant_secs <= 0.0 {
+        return 1.0;
+    }
+    1.0 - (-delta_secs.max(0.0) / time_constant_secs).exp()
+}
+
+/// Feeds a new pointer sample into `MouseState` position and velocity.
+///
+/// Samples within `filter.dead_zone` of the current position leave the
+/// position in place and count as zero instantaneous velocity; accepted
+/// samples move the position `1.0 - filter.smoothing` of the way there per
+/// 60 Hz frame. Velocity eases toward the instantaneous value with a fixed
+/// time constant, so both respond identically at 60 Hz and 240 Hz.
+///
+/// # Arguments
+/// * `mouse_state` - Pointer state to update
+/// * `sample` - New pointer position in world coordinates
+/// * `delta_seconds` - Time since the previous sample
+/// * `filter` - Dead-zone and smoothing settings
+pub fn record_pointer_sample(
+    mouse_state: &mut MouseState,
+    sample: Vec2,
+    delta_seconds: f32,
+    filter: &PointerFilter,
+) {
+    let raw_delta = sample - mouse_state.position;
+    let position = if raw_delta.length() < filter.dead_zone {
+        mouse_state.position
+    } else if filter.smoothing <= 0.0 {
+        sample
+    } else {
+        let retention = filter
+            .smoothing
+            .clamp(0.0, 0.99)
+            .powf(delta_seconds.max(0.0) * POINTER_SMOOTHING_REFERENCE_FPS);
+        mouse_state.position.lerp(sample, 1.0 - retention)
+    };
+
+    if delta_seconds > 0.0 {
+        // Smooth velocity calculation to avoid jitter
+        let instant_velocity = (position - mouse_state.position) / delta_seconds;
+        let alpha = smoothing_alpha(POINTER_VELOCITY_TIME_CONSTANT_SECS, delta_seconds);
+        mouse_state.velocity = mouse_state.velocity.lerp(instant_velocity, alpha);
+    }
+
+    mouse_state.position = position;
+}
+
+/// Starts a fresh pointer track at `sample`, discarding the filter history.
+///
+/// A new touch begins where the finger lands instead of gliding in from the
+/// previous pointer position, and carries no velocity from the last stroke.
+pub fn restart_pointer_filter(mouse_state: &mut MouseState, sample: Vec2) {
+    mouse_state.position = sample;
+    mouse_state.velocity = Vec2::ZERO;
+}
+
+/// Adds `delta_seconds` of engagement to `accumulated_interaction`.
+///
+/// Engagement accrues as a rate times delta time, weighted by pointer speed
+/// (10% when still, full rate at 200 units/s and above).
+pub fn accumulate_interaction(mouse_state: &mut MouseState, delta_seconds: f32) {
+    let velocity_factor = (mouse_state.velocity.length() / 200.0).clamp(0.1, 1.0);
+    mouse_state.accumulated_interaction += delta_seconds * velocity_factor;
+}
+
 /// Calculates quadratic falloff based on distance from the influence center.
 ///
 /// Returns a value in the range [0.0, 1.0] where 1.0 is full influence
@@ -293,13 +716,39 @@ pub fn quadratic_falloff(distance: f32, max_distance: f32) -> f32 {
     (1.0 - normalized * normalized).max(0.0)
 }
 
+/// Returns the counter-clockwise tangent for a unit particle→cursor direction.
+///
+/// The tangent is perpendicular to `direction`, so pushing along it makes
+/// particles circle the cursor without moving toward or away from it.
+#[inline]
+#[must_use]
+pub fn vortex_tangent(direction: Vec2) -> Vec2 {
+    direction.perp()
+}
+
+/// Calculates the Vortex mode force: a swirl around the cursor plus a weak
+/// inward pull, so particles spiral in rather than orbit forever.
+///
+/// # Arguments
+/// * `direction` - Unit vector from the particle to the cursor.
+/// * `strength` - Force scale, already including falloff and pointer speed.
+#[inline]
+#[must_use]
+pub fn vortex_force(direction: Vec2, strength: f32) -> Vec2 {
+    (vortex_tangent(direction) + direction * VORTEX_INWARD_FRACTION) * strength
+}
+
 /// Converts a screen position to world coordinates using the camera transform.
 ///
 /// Takes into account the camera's projection and global transform to
 /// accurately map 2D screen coordinates to 2D world space.
 ///
+/// Bevy reports cursor and touch positions in logical pixels on every
+/// platform, and `viewport_to_world_2d` expects logical pixels, so the result
+/// is the same world position at any window scale factor.
+///
 /// # Arguments
-/// * `screen_pos` - Position in screen/window coordinates (pixels from top-left).
+/// * `screen_pos` - Position in logical window coordinates (pixels from top-left).
 /// * `camera` - The camera component for projection calculations.
 /// * `camera_transform` - The global transform of the camera entity.
 ///
@@ -339,6 +788,73 @@ fn velocity_to_strength(velocity: Vec2) -> f32 {
     }
 }
 
+/// Returns the world units covered by one logical screen pixel at `zoom`.
+#[must_use]
+pub fn world_units_per_pixel(display_scale: &DisplayScale, zoom: f32) -> f32 {
+    display_scale.scale_factor / display_scale.physical_pixels_per_unit.max(f32::EPSILON) * zoom
+}
+
+/// Scales `camera`'s zoom by `factor` while keeping `cursor_world` at the
+/// same place on screen.
+///
+/// `factor` below 1.0 zooms in. The zoom is clamped to
+/// `CAMERA_MIN_ZOOM..=CAMERA_MAX_ZOOM` first, and the pan follows the zoom
+/// actually applied; call `CameraControlState::clamp` afterwards to keep the
+/// background covering the view.
+pub fn zoom_toward(camera: &mut CameraControlState, cursor_world: Vec2, factor: f32) {
+    let zoom = (camera.zoom * factor).clamp(CAMERA_MIN_ZOOM, CAMERA_MAX_ZOOM);
+    let applied = zoom / camera.zoom.max(f32::EPSILON);
+    camera.pan = cursor_world + (camera.pan - cursor_world) * applied;
+    camera.zoom = zoom;
+}
+
+/// Advances a two-finger gesture to the fingers' current `midpoint` and
+/// `distance` (screen pixels).
+///
+/// Once the fingers travel `CAMERA_GESTURE_MIN_TRAVEL` (apart, together, or
+/// side by side) the gesture navigates: zoom follows the pinch ratio and pan
+/// follows the midpoint, so the content moves with the fingers. Returns true
+/// on the frame navigation starts.
+pub fn update_two_finger_gesture(
+    camera_control: &mut CameraControlState,
+    midpoint: Vec2,
+    distance: f32,
+    display_scale: &DisplayScale,
+) -> bool {
+    let Some(mut gesture) = camera_control.gesture else {
+        return false;
+    };
+
+    let drag = midpoint - gesture.start_midpoint;
+    let started = !gesture.navigating
+        && drag.length().max((distance - gesture.start_distance).abs()) > CAMERA_GESTURE_MIN_TRAVEL;
+    gesture.navigating |= started;
+
+    if gesture.navigating {
+        camera_control.zoom = gesture.start_zoom * gesture.start_distance / distance.max(1.0);
+        // Screen y runs down, world y up
+        let world_drag =
+            Vec2::new(drag.x, -drag.y) * world_units_per_pixel(display_scale, gesture.start_zoom);
+        camera_control.pan = gesture.start_pan - world_drag;
+        camera_control.clamp(display_scale.world_viewport);
+    }
+
+    camera_control.gesture = Some(gesture);
+    started
+}
+
+/// Returns the direction of a three-finger swipe from its centroid `travel`
+/// (screen pixels): `Some(true)` for right, `Some(false)` for left.
+///
+/// Travel shorter than `THREE_FINGER_SWIPE_MIN_DISTANCE` or more vertical
+/// than horizontal is not a swipe.
+#[must_use]
+pub fn three_finger_swipe_direction(travel: Vec2) -> Option<bool> {
+    let horizontal = travel.x.abs();
+    (horizontal >= THREE_FINGER_SWIPE_MIN_DISTANCE && horizontal > travel.y.abs())
+        .then_some(travel.x > 0.0)
+}
+
 // =============================================================================
 // SYSTEMS - PreUpdate
 // =============================================================================
@@ -347,19 +863,25 @@ fn velocity_to_strength(velocity: Vec2) -> f32 {
 ///
 /// This system runs in PreUpdate to ensure mouse state is available for
 /// all subsequent interaction systems. It converts window mouse position
-/// to world coordinates using the camera transform.
+/// to world coordinates using the camera transform, then applies
+/// `PointerFilter`. The position it starts from is kept in
+/// `previous_position`, so paint spawns can fill the segment in between.
 ///
 /// # Stage
 /// PreUpdate
 ///
 /// # Ordering
-/// Runs before `calculate_interaction_radius`.
+/// Runs before `update_touch_state` and `calculate_interaction_radius`.
 pub fn update_mouse_state(
     mut mouse_state: ResMut<MouseState>,
     windows: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<WhirledCamera>>,
     time: Res<Time>,
+    filter: Res<PointerFilter>,
 ) {
+    // Start of this frame's segment; touch samples later this frame extend it
+    mouse_state.previous_position = mouse_state.position;
+
     let Ok(window) = windows.get_single() else {
         mouse_state.is_active = false;
         return;
@@ -384,29 +906,22 @@ pub fn update_mouse_state(
         return;
     };
 
-    // Calculate velocity from position delta
-    let delta = world_position - mouse_state.position;
+    // Update position and velocity through the pointer filter
     let delta_seconds = time.delta_secs();
-
-    if delta_seconds > 0.0 {
-        // Smooth velocity calculation to avoid jitter
-        let instant_velocity = delta / delta_seconds;
-        mouse_state.velocity = mouse_state.velocity.lerp(instant_velocity, 0.3);
-    }
-
-    // Update position
-    mouse_state.position = world_position;
+    record_pointer_sample(&mut mouse_state, world_position, delta_seconds, &filter);
     mouse_state.is_active = true;
 
     // Accumulate interaction time when mouse is active and moving
-    let velocity_factor = (mouse_state.velocity.length() / 200.0).clamp(0.1, 1.0);
-    mouse_state.accumulated_interaction += delta_seconds * velocity_factor;
+    accumulate_interaction(&mut mouse_state, delta_seconds);
 }
 
 /// Calculates and updates the interaction radius based on accumulated interaction.
 ///
-/// The radius grows from base_radius toward max_radius as the user spends more
-/// time interacting with the experience, creating a sense of growing connection.
+/// The radius grows from the act's base toward its max as the user spends
+/// more time interacting with the experience, creating a sense of growing
+/// connection. Base, max, and growth time come from `RadiusGrowthProfile` for
+/// the current act, blended through act transitions. The current radius eases
+/// toward that target with a fixed time constant.
 ///
 /// # Stage
 /// PreUpdate
@@ -414,32 +929,33 @@ pub fn update_mouse_state(
 /// # Ordering
 /// Runs after `update_mouse_state`.
 pub fn calculate_interaction_radius(
+    time: Res<Time>,
     mouse_state: Res<MouseState>,
+    act_state: Res<ActState>,
+    growth_profile: Res<RadiusGrowthProfile>,
     mut interaction_config: ResMut<InteractionConfig>,
 ) {
-    // Calculate target radius based on accumulated interaction
-    // Growth formula: base + (accumulated / 60) * (max - base)
-    // This means full radius is reached after about 60 seconds of active interaction
-    let accumulated = mouse_state.accumulated_interaction;
-    let base = interaction_config.base_radius;
-    let max = interaction_config.max_radius;
-
-    let target_radius = base + (accumulated / 60.0) * (max - base);
-    let target_radius = target_radius.clamp(base, max);
+    // Target grows linearly with accumulated interaction, at the act's rate
+    let growth = growth_profile.current(&act_state);
+    let target_radius =
+        growth.target_radius(&interaction_config, mouse_state.accumulated_interaction);
 
     // Smoothly interpolate toward target radius
-    interaction_config.current_radius = interaction_config
-        .current_radius
-        .lerp(target_radius, 0.05);
+    let alpha = smoothing_alpha(RADIUS_TIME_CONSTANT_SECS, time.delta_secs());
+    interaction_config.current_radius =
+        interaction_config.current_radius.lerp(target_radius, alpha);
 }
 
-/// Handles keyboard input for breath pulse and gentle exit.
+/// Handles keyboard input for breath pulse, pause, force polarity, and gentle exit.
 ///
 /// - Space key: Triggers a BreathPulse event (with 400ms cooldown).
+/// - P key: Triggers a TogglePause event.
+/// - Shift (held): Inverts Attract/Disperse forces via `ForcePolarity`.
 /// - Escape key: Triggers a GentleFade event for graceful exit.
 ///
 /// # Stage
 /// PreUpdate
+#[allow(clippy::too_many_arguments)]
 pub fn handle_keyboard_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     mouse_state: Res<MouseState>,
@@ -447,6 +963,8 @@ pub fn handle_keyboard_input(
     mut gentle_fade_state: ResMut<GentleFadeState>,
     mut breath_pulse_events: EventWriter<BreathPulse>,
     mut gentle_fade_events: EventWriter<GentleFade>,
+    mut toggle_pause_events: EventWriter<TogglePause>,
+    mut polarity: ResMut<ForcePolarity>,
     time: Res<Time>,
 ) {
     // Update cooldown timer
@@ -461,6 +979,17 @@ pub fn handle_keyboard_input(
         breath_cooldown.remaining_seconds = BREATH_PULSE_COOLDOWN_SECONDS;
     }
 
+    // Handle P for pause/resume
+    if keyboard.just_pressed(KeyCode::KeyP) {
+        toggle_pause_events.send(TogglePause);
+    }
+
+    // Shift held inverts the directional forces
+    let inverted = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if polarity.inverted != inverted {
+        polarity.inverted = inverted;
+    }
+
     // Handle escape for gentle fade (only trigger once)
     if keyboard.just_pressed(KeyCode::Escape) && !gentle_fade_state.is_active {
         gentle_fade_state.is_active = true;
@@ -471,11 +1000,48 @@ pub fn handle_keyboard_input(
     }
 }
 
+/// Flips `ExperiencePaused` once per `TogglePause` event.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// After `handle_keyboard_input`, so a P press takes effect the same frame.
+pub fn apply_toggle_pause(
+    mut events: EventReader<TogglePause>,
+    mut paused: ResMut<ExperiencePaused>,
+) {
+    for _ in events.read() {
+        paused.0 = !paused.0;
+        info!("Experience {}", if paused.0 { "paused" } else { "resumed" });
+    }
+}
+
+/// Sends `CaptureScreenshot` when F12 is pressed.
+///
+/// Runs in every app state, so the intro can be captured too.
+///
+/// # Stage
+/// PreUpdate
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+pub fn request_screenshot(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<CaptureScreenshot>,
+) {
+    if keyboard.just_pressed(KeyCode::F12) {
+        events.send(CaptureScreenshot);
+    }
+}
+
 /// Handles mouse button clicks for explosion and hyperspace effects.
 ///
 /// - Left click: Triggers an explosion at the cursor position
+/// - Quick repeated left clicks: Also send `MultiTap` (count 2, 3, ...)
 /// - Right click: Triggers a hyperspace jump with vanishing point at cursor
 ///
+/// Every click still explodes immediately; a double-click adds the
+/// multi-tap action on top rather than delaying the first explosion.
+///
 /// # Stage
 /// PreUpdate
 pub fn handle_mouse_clicks(
@@ -484,6 +1050,7 @@ pub fn handle_mouse_clicks(
     mut hyperspace_state: ResMut<HyperspaceState>,
     mut explosion_events: EventWriter<ExplosionEvent>,
     mut hyperspace_events: EventWriter<HyperspaceJumpEvent>,
+    mut multi_tap: MultiTapInput,
 ) {
     if !mouse_state.is_active {
         return;
@@ -495,14 +1062,12 @@ pub fn handle_mouse_clicks(
             origin: mouse_state.position,
             strength: 1.0,
         });
+        multi_tap.register(mouse_state.position);
     }
 
     // Right click: Hyperspace jump (only if not already active)
     if mouse_button.just_pressed(MouseButton::Right) && !hyperspace_state.is_active {
-        hyperspace_state.is_active = true;
-        hyperspace_state.vanishing_point = mouse_state.position;
-        hyperspace_state.remaining_seconds = HYPERSPACE_DURATION;
-        hyperspace_state.total_duration = HYPERSPACE_DURATION;
+        hyperspace_state.start(mouse_state.position);
 
         hyperspace_events.send(HyperspaceJumpEvent {
             vanishing_point: mouse_state.position,
@@ -513,16 +1078,29 @@ pub fn handle_mouse_clicks(
 /// Updates mouse state from touch input (for mobile/tablet devices).
 ///
 /// Maps single-finger touch position to mouse position, enabling the same
-/// particle interaction effects on touch devices.
+/// particle interaction effects on touch devices. Positions pass through
+/// `PointerFilter` like mouse input.
+///
+/// Two fingers feed `CameraControlState`: once they travel far enough to
+/// pinch or pan, the gesture stops painting and can no longer count as a
+/// two-finger tap.
+///
+/// A third finger turns the touch into a candidate three-finger swipe: the
+/// fingers' centroid is tracked in `TouchState`, and painting, camera
+/// navigation, and the two-finger tap are suspended until every finger lifts.
 ///
 /// # Stage
 /// PreUpdate
+#[allow(clippy::too_many_arguments)]
 pub fn update_touch_state(
     mut mouse_state: ResMut<MouseState>,
     mut touch_state: ResMut<TouchState>,
+    mut camera_control: ResMut<CameraControlState>,
     touches: Res<Touches>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<WhirledCamera>>,
     time: Res<Time>,
+    filter: Res<PointerFilter>,
+    display_scale: Res<DisplayScale>,
 ) {
     let Ok((camera, camera_transform)) = camera_query.get_single() else {
         return;
@@ -559,42 +1137,105 @@ pub fn update_touch_state(
             touch_state.primary_current_pos = touch.position();
             touch_state.primary_start_time = elapsed;
             touch_state.hold_triggered = false;
+            if let Some(world_pos) =
+                world_position_from_screen(touch.position(), camera, camera_transform)
+            {
+                restart_pointer_filter(&mut mouse_state, world_pos);
+            }
         } else if touch_state.secondary_touch_id.is_none() {
             // Second finger down (for two-finger tap)
             touch_state.secondary_touch_id = Some(touch.id());
             touch_state.secondary_start_time = elapsed;
+        } else if touch_state.tertiary_touch_id.is_none() {
+            // Third finger down (for three-finger swipe)
+            touch_state.tertiary_touch_id = Some(touch.id());
+            touch_state.three_finger_start = None;
+            touch_state.three_finger_first_lift_time = None;
+        }
+    }
+
+    // Three fingers down: track their centroid for a swipe
+    let finger_trio = [
+        touch_state.primary_touch_id,
+        touch_state.secondary_touch_id,
+        touch_state.tertiary_touch_id,
+    ]
+    .map(|id| id.and_then(|id| touches.get_pressed(id)));
+    if let [Some(first), Some(second), Some(third)] = finger_trio {
+        let centroid = (first.position() + second.position() + third.position()) / 3.0;
+        touch_state.three_finger_start.get_or_insert(centroid);
+        touch_state.three_finger_current = centroid;
+
+        // A swipe neither paints nor counts as a two-finger tap
+        touch_state.two_finger_triggered = true;
+        mouse_state.is_active = false;
+        mouse_state.velocity = Vec2::ZERO;
+    }
+    let three_finger = touch_state.three_finger_start.is_some();
+
+    // Two fingers down: pinch zooms, dragging both pans
+    let finger_pair = touch_state
+        .primary_touch_id
+        .zip(touch_state.secondary_touch_id)
+        .filter(|_| !three_finger)
+        .and_then(|(primary, secondary)| {
+            Some((
+                touches.get_pressed(primary)?,
+                touches.get_pressed(secondary)?,
+            ))
+        });
+    if let Some((first, second)) = finger_pair {
+        let midpoint = (first.position() + second.position()) * 0.5;
+        let distance = first.position().distance(second.position());
+        if camera_control.gesture.is_none() {
+            camera_control.gesture = Some(TwoFingerGesture {
+                start_midpoint: midpoint,
+                start_distance: distance,
+                start_zoom: camera_control.zoom,
+                start_pan: camera_control.pan,
+                navigating: false,
+            });
+        } else if update_two_finger_gesture(&mut camera_control, midpoint, distance, &display_scale)
+        {
+            // Navigation is not a tap and does not paint
+            touch_state.two_finger_triggered = true;
+            mouse_state.is_active = false;
+            mouse_state.velocity = Vec2::ZERO;
         }
+    } else if camera_control.gesture.is_some() {
+        camera_control.gesture = None;
     }
 
     // Handle touch movement - update position for primary touch
-    if let Some(primary_id) = touch_state.primary_touch_id {
+    let navigating = camera_control.is_navigating() || three_finger;
+    if let Some(primary_id) = touch_state.primary_touch_id.filter(|_| !navigating) {
         if let Some(touch) = touches.get_pressed(primary_id) {
             let screen_pos = touch.position();
             touch_state.primary_current_pos = screen_pos;
 
             // Convert to world coordinates and update mouse state
-            if let Some(world_pos) = world_position_from_screen(screen_pos, camera, camera_transform) {
-                // Calculate velocity
-                let delta = world_pos - mouse_state.position;
+            if let Some(world_pos) =
+                world_position_from_screen(screen_pos, camera, camera_transform)
+            {
+                // Update position and velocity through the pointer filter
                 let delta_seconds = time.delta_secs();
-
-                if delta_seconds > 0.0 {
-                    let instant_velocity = delta / delta_seconds;
-                    mouse_state.velocity = mouse_state.velocity.lerp(instant_velocity, 0.3);
-                }
-
-                mouse_state.position = world_pos;
+                record_pointer_sample(&mut mouse_state, world_pos, delta_seconds, &filter);
                 mouse_state.is_active = true;
 
                 // Accumulate interaction time
-                let velocity_factor = (mouse_state.velocity.length() / 200.0).clamp(0.1, 1.0);
-                mouse_state.accumulated_interaction += delta_seconds * velocity_factor;
+                accumulate_interaction(&mut mouse_state, delta_seconds);
             }
         }
     }
 
     // Handle touch end
     for touch in touches.iter_just_released() {
+        if three_finger && touch_state.three_finger_first_lift_time.is_none() {
+            touch_state.three_finger_first_lift_time = Some(elapsed);
+        }
+        if Some(touch.id()) == touch_state.tertiary_touch_id {
+            touch_state.tertiary_touch_id = None;
+        }
         if Some(touch.id()) == touch_state.primary_touch_id {
             touch_state.primary_touch_id = None;
             touch_state.hold_triggered = false;
@@ -622,54 +1263,260 @@ pub fn update_touch_state(
         if Some(touch.id()) == touch_state.secondary_touch_id {
             touch_state.secondary_touch_id = None;
         }
+        // An interrupted swipe is abandoned
+        if Some(touch.id()) == touch_state.tertiary_touch_id {
+            touch_state.tertiary_touch_id = None;
+        }
+        touch_state.three_finger_start = None;
     }
 }
 
-/// Handles touch gestures for explosion and hyperspace effects.
+/// Starts a new paint stroke on a left press or a first finger down.
 ///
-/// - Single tap: Quick tap triggers explosion at tap position
-/// - Press and hold: Hold for 0.5s+ triggers explosion
-/// - Two-finger tap: Triggers hyperspace jump
+/// The stroke's seed, kept in `MouseState`, drives the jitter of every
+/// particle painted until the next press. Without a configured
+/// `PaintConfig.stroke_seed` it is drawn from `ParticleRng`.
 ///
 /// # Stage
 /// PreUpdate
-pub fn handle_touch_gestures(
-    mut touch_state: ResMut<TouchState>,
-    mut hyperspace_state: ResMut<HyperspaceState>,
-    mut explosion_events: EventWriter<ExplosionEvent>,
-    mut hyperspace_events: EventWriter<HyperspaceJumpEvent>,
+///
+/// # Ordering
+/// Runs after `update_touch_state`.
+pub fn begin_paint_strokes(
+    mouse_button: Res<ButtonInput<MouseButton>>,
     touches: Res<Touches>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
-    time: Res<Time>,
+    touch_state: Res<TouchState>,
+    paint_config: Res<PaintConfig>,
+    mut mouse_state: ResMut<MouseState>,
+    mut rng: ResMut<ParticleRng>,
 ) {
-    let Ok((camera, camera_transform)) = camera_query.get_single() else {
-        return;
-    };
-
-    let elapsed = time.elapsed_secs();
+    let finger_down = touch_state.primary_touch_id.is_some_and(|primary| {
+        touches
+            .iter_just_pressed()
+            .any(|touch| touch.id() == primary)
+    });
+
+    if mouse_button.just_pressed(MouseButton::Left) || finger_down {
+        mouse_state.begin_stroke(paint_config.stroke_seed, &mut rng.0);
+    }
+}
 
-    // Check for two-finger tap (hyperspace)
-    // Trigger when: we had 2+ fingers, they're now being released, and it was quick
-    let any_just_released = touches.iter_just_released().count() > 0;
+/// Zooms the camera with the scroll wheel toward the cursor.
+///
+/// Scrolling up zooms in by `WHEEL_ZOOM_PER_LINE` per line; touchpad pixel
+/// deltas are converted to lines. The world point under the cursor stays
+/// put (up to the pan clamp); it is derived from `CameraControlState`
+/// rather than the camera's `GlobalTransform`, which lags a frame behind.
+///
+/// # Stage
+/// PreUpdate
+pub fn handle_scroll_zoom(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut camera_control: ResMut<CameraControlState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    display_scale: Res<DisplayScale>,
+) {
+    let lines: f32 = wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / WHEEL_PIXELS_PER_LINE,
+        })
+        .sum();
+    if lines == 0.0 {
+        return;
+    }
 
-    if touch_state.peak_touch_count >= 2
-        && any_just_released
-        && !touch_state.two_finger_triggered
-        && !hyperspace_state.is_active
-    {
-        let tap_duration = elapsed - touch_state.multi_touch_start_time;
+    // Without a cursor over the window, zoom about the view center
+    let cursor_world = windows
+        .get_single()
+        .ok()
+        .and_then(|window| Some((window.cursor_position()?, window.size())))
+        .map_or(camera_control.pan, |(cursor, window_size)| {
+            camera_control.world_position(cursor, window_size, &display_scale)
+        });
 
-        // Quick two-finger tap triggers hyperspace
-        if tap_duration < TAP_MAX_DURATION * 1.5 {
-            // Get position from primary touch or center of screen
-            let screen_pos = touch_state.primary_current_pos;
-            let world_pos = world_position_from_screen(screen_pos, camera, camera_transform);
+    zoom_toward(
+        &mut camera_control,
+        cursor_world,
+        WHEEL_ZOOM_PER_LINE.powf(-lines),
+    );
+    camera_control.clamp(display_scale.world_viewport);
+}
+
+/// Pans the camera while the middle mouse button is dragged.
+///
+/// The content follows the cursor: the camera moves by the cursor's movement
+/// in world units at the current zoom.
+///
+/// # Stage
+/// PreUpdate
+pub fn handle_middle_drag_pan(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut camera_control: ResMut<CameraControlState>,
+    display_scale: Res<DisplayScale>,
+    mut last_cursor: Local<Option<Vec2>>,
+) {
+    let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+    if !mouse_button.pressed(MouseButton::Middle) {
+        *last_cursor = None;
+        return;
+    }
+    let Some(cursor) = cursor else {
+        return;
+    };
+
+    if let Some(last) = last_cursor.replace(cursor) {
+        let delta = cursor - last;
+        if delta != Vec2::ZERO {
+            // Screen y runs down, world y up
+            let world_delta = Vec2::new(delta.x, -delta.y)
+                * world_units_per_pixel(&display_scale, camera_control.zoom);
+            camera_control.pan -= world_delta;
+            camera_control.clamp(display_scale.world_viewport);
+        }
+    }
+}
+
+/// Keeps `CameraControlState` on the camera's current pose while `AutoFrame`
+/// owns the camera.
+///
+/// The first pinch, pan, or wheel step then starts from the framing on
+/// screen instead of snapping back to the default. Writes bypass change
+/// detection, so `apply_camera_control` still only runs for real input.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// Before `update_touch_state`, `handle_scroll_zoom`, and `handle_middle_drag_pan`.
+pub fn seed_camera_control_from_camera(
+    auto_frame: Res<AutoFrame>,
+    mut camera_control: ResMut<CameraControlState>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<WhirledCamera>>,
+) {
+    if auto_frame.manual_control {
+        return;
+    }
+    let Ok((transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+
+    let camera_control = camera_control.bypass_change_detection();
+    if camera_control.gesture.is_none() {
+        camera_control.zoom = projection.scale;
+        camera_control.pan = transform.translation.truncate();
+    }
+}
+
+/// Copies `CameraControlState` onto the main camera's projection scale and
+/// position.
+///
+/// `AutoFrame` yields while the camera is away from its default framing.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// After `update_touch_state`, `handle_scroll_zoom`, and
+/// `handle_middle_drag_pan`; only runs when `CameraControlState` changed.
+pub fn apply_camera_control(
+    camera_control: Res<CameraControlState>,
+    mut auto_frame: ResMut<AutoFrame>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<WhirledCamera>>,
+) {
+    let Ok((mut transform, mut projection)) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    projection.scale = camera_control.zoom;
+    transform.translation.x = camera_control.pan.x;
+    transform.translation.y = camera_control.pan.y;
+
+    let manual_control = !camera_control.is_default();
+    if auto_frame.manual_control != manual_control {
+        auto_frame.manual_control = manual_control;
+    }
+}
+
+/// Handles touch gestures for explosion and hyperspace effects.
+///
+/// - Single tap: Quick tap triggers explosion at tap position
+/// - Repeated single taps: Send `MultiTap` (count 2, 3, ...)
+/// - Press and hold: Hold for 0.5s+ triggers explosion
+/// - Two-finger tap: Triggers hyperspace jump
+/// - Three-finger horizontal swipe: Sends `ThreeFingerSwipe` once every
+///   finger has lifted, if they lifted within `THREE_FINGER_LIFT_WINDOW`
+///
+/// # Stage
+/// PreUpdate
+#[allow(clippy::too_many_arguments)]
+pub fn handle_touch_gestures(
+    mut touch_state: ResMut<TouchState>,
+    mut hyperspace_state: ResMut<HyperspaceState>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut hyperspace_events: EventWriter<HyperspaceJumpEvent>,
+    mut swipe_events: EventWriter<ThreeFingerSwipe>,
+    touches: Res<Touches>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<WhirledCamera>>,
+    mut multi_tap: MultiTapInput,
+) {
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+
+    let elapsed = multi_tap.time.elapsed_secs();
+
+    // A three-finger swipe is judged once every finger has lifted
+    if touch_state.touch_count == 0 {
+        if let Some(start) = touch_state.three_finger_start.take() {
+            let lifted_together = touch_state
+                .three_finger_first_lift_time
+                .take()
+                .is_some_and(|first_lift| elapsed - first_lift <= THREE_FINGER_LIFT_WINDOW);
+            let travel = touch_state.three_finger_current - start;
+            if let Some(forward) = three_finger_swipe_direction(travel) {
+                if lifted_together {
+                    swipe_events.send(ThreeFingerSwipe { forward });
+                }
+            }
+            return;
+        }
+    }
+
+    // A lone finger lifting quickly without travelling is a tap
+    let mut released = touches.iter_just_released();
+    if let (Some(touch), None, None) = (released.next(), released.next(), touches.iter().next()) {
+        let tap_duration = elapsed - touch_state.primary_start_time;
+        if tap_duration < TAP_MAX_DURATION && touch.distance().length() < TAP_MAX_DISTANCE {
+            if let Some(world_pos) =
+                world_position_from_screen(touch.position(), camera, camera_transform)
+            {
+                multi_tap.register(world_pos);
+            }
+        }
+    }
+
+    // Check for two-finger tap (hyperspace)
+    // Trigger when: we had 2+ fingers, they're now being released, and it was quick
+    let any_just_released = touches.iter_just_released().count() > 0;
+
+    if touch_state.peak_touch_count >= 2
+        && any_just_released
+        && !touch_state.two_finger_triggered
+        && !hyperspace_state.is_active
+    {
+        let tap_duration = elapsed - touch_state.multi_touch_start_time;
+
+        // Quick two-finger tap triggers hyperspace
+        if tap_duration < TAP_MAX_DURATION * 1.5 {
+            // Get position from primary touch or center of screen
+            let screen_pos = touch_state.primary_current_pos;
+            let world_pos = world_position_from_screen(screen_pos, camera, camera_transform);
 
             if let Some(pos) = world_pos {
-                hyperspace_state.is_active = true;
-                hyperspace_state.vanishing_point = pos;
-                hyperspace_state.remaining_seconds = HYPERSPACE_DURATION;
-                hyperspace_state.total_duration = HYPERSPACE_DURATION;
+                hyperspace_state.start(pos);
 
                 hyperspace_events.send(HyperspaceJumpEvent {
                     vanishing_point: pos,
@@ -742,54 +1589,75 @@ pub fn handle_touch_gestures(
 /// interaction radius and applies appropriate forces based on the active mode:
 ///
 /// - Paint: No force (handled by spawn system)
-/// - Attract: Pull particles toward cursor
+/// - Attract: Pull particles toward cursor (pushes away while `ForcePolarity` is inverted)
 /// - Intensify: Increase saturation and scale near cursor
-/// - Disperse: Push particles away and upward
+/// - Disperse: Push particles away and upward (pulls in while inverted)
 /// - Ripple: Gentle outward wave from cursor
+/// - Erase: Drain lifetime so particles fade out and return to the pool
+/// - Vortex: Swirl particles around cursor with a weak inward pull
 ///
 /// # Stage
 /// Update
 ///
 /// # Performance
-/// CRITICAL PATH - Iterates over all active particles.
-/// Consider spatial acceleration structure for large particle counts.
+/// CRITICAL PATH - Visits only particles in `SpatialGrid` cells overlapping
+/// the interaction radius. `MouseInfluence.distance_to_cursor` is therefore
+/// only refreshed for particles near the cursor.
 pub fn apply_mouse_influence(
     mouse_state: Res<MouseState>,
     interaction_config: Res<InteractionConfig>,
     current_mode: Res<CurrentInteractionMode>,
+    polarity: Res<ForcePolarity>,
+    grid: Res<SpatialGrid>,
     mut particles: Query<
         (
             &Transform,
             &mut ParticleMotion,
             &mut MouseInfluence,
             &mut ParticleVisual,
-            &ParticleState,
+            &mut ParticleState,
         ),
         With<Particle>,
     >,
     time: Res<Time>,
+    mut affected: Local<Vec<Entity>>,
 ) {
-    // Skip if mouse is not active or gentle fade is happening
-    if !mouse_state.is_active {
-        // Reset influence state for all particles when mouse inactive
-        for (_, _, mut influence, _, _) in particles.iter_mut() {
+    // A frozen frame leaves motion, color, and influence untouched
+    let delta_seconds = crate::particle::simulation_delta(&time);
+    if mouse_state.is_active && delta_seconds.is_none() {
+        return;
+    }
+
+    // Reset last frame's influenced particles; those still in range are set again below
+    for entity in affected.drain(..) {
+        if let Ok((_, _, mut influence, _, _)) = particles.get_mut(entity) {
             influence.affected = false;
             influence.influence_strength = 0.0;
         }
+    }
+
+    // Skip if mouse is not active or gentle fade is happening
+    if !mouse_state.is_active {
         return;
     }
+    let Some(delta_seconds) = delta_seconds else {
+        return;
+    };
 
     let cursor_pos = mouse_state.position;
     let radius = interaction_config.current_radius;
-    let delta_seconds = time.delta_secs();
     let velocity_strength = velocity_to_strength(mouse_state.velocity);
 
-    // Process each particle
-    for (transform, mut motion, mut influence, mut visual, state) in particles.iter_mut() {
-        // Skip inactive particles
+    // Process each particle near the cursor
+    for entity in grid.query_radius(cursor_pos, radius) {
+        let Ok((transform, mut motion, mut influence, mut visual, mut state)) =
+            particles.get_mut(entity)
+        else {
+            continue;
+        };
+
+        // Skip particles retired since the grid was built
         if !state.active {
-            influence.affected = false;
-            influence.influence_strength = 0.0;
             continue;
         }
 
@@ -802,8 +1670,6 @@ pub fn apply_mouse_influence(
 
         // Check if within influence radius
         if distance >= radius {
-            influence.affected = false;
-            influence.influence_strength = 0.0;
             continue;
         }
 
@@ -811,6 +1677,7 @@ pub fn apply_mouse_influence(
         let falloff = quadratic_falloff(distance, radius);
         influence.affected = true;
         influence.influence_strength = falloff;
+        affected.push(entity);
 
         // Direction from particle to cursor (normalized)
         let direction = if distance > 0.001 {
@@ -819,90 +1686,434 @@ pub fn apply_mouse_influence(
             Vec2::ZERO
         };
 
-        // Apply mode-specific behavior
-        match current_mode.mode {
-            InteractionMode::Paint => {
-                // Paint mode doesn't apply forces; spawning is handled separately
-            }
+        // Apply each active mode's forces, crossfaded during act transitions
+        let pointer = PointerSample {
+            direction,
+            distance,
+            velocity_strength,
+            delta_seconds,
+            elapsed: time.elapsed_secs(),
+            polarity: polarity.sign(),
+        };
+        for (mode, weight) in current_mode.weighted_modes() {
+            apply_mode_influence(
+                mode,
+                falloff * weight,
+                &pointer,
+                &mut motion,
+                &mut visual,
+                &mut state,
+            );
+        }
+    }
+}
 
-            InteractionMode::Attract => {
-                // Pull particles toward cursor
-                let force_strength = ATTRACT_FORCE_BASE * falloff * (0.5 + 0.5 * velocity_strength);
-                let force = direction * force_strength * delta_seconds;
-                motion.velocity += force;
-            }
+/// Pointer values shared by every mode's influence on one particle.
+struct PointerSample {
+    /// Unit direction from the particle to the cursor
+    direction: Vec2,
+    /// Distance from the particle to the cursor
+    distance: f32,
+    /// Pointer speed mapped to 0.0-1.0
+    velocity_strength: f32,
+    /// Simulation delta for this frame in seconds
+    delta_seconds: f32,
+    /// Elapsed time in seconds (drives the ripple phase)
+    elapsed: f32,
+    /// `ForcePolarity` sign applied to Attract and Disperse
+    polarity: f32,
+}
 
-            InteractionMode::Intensify => {
-                // Mild attraction plus visual enhancement
-                let mild_attraction = direction * ATTRACT_FORCE_BASE * 0.3 * falloff * delta_seconds;
-                motion.velocity += mild_attraction;
-
-                // Boost saturation and scale based on proximity
-                let boost_amount = INTENSIFY_SATURATION_BOOST * falloff;
-                let scale_boost = INTENSIFY_SCALE_BOOST * falloff;
-
-                // Apply saturation boost by pushing color toward more saturated version
-                let current_srgba = visual.current_color.to_srgba();
-
-                // Simple saturation approximation: increase color component differences
-                let avg = (current_srgba.red + current_srgba.green + current_srgba.blue) / 3.0;
-                let new_red = current_srgba.red + (current_srgba.red - avg) * boost_amount;
-                let new_green = current_srgba.green + (current_srgba.green - avg) * boost_amount;
-                let new_blue = current_srgba.blue + (current_srgba.blue - avg) * boost_amount;
-
-                visual.current_color = Color::srgba(
-                    new_red.clamp(0.0, 1.0),
-                    new_green.clamp(0.0, 1.0),
-                    new_blue.clamp(0.0, 1.0),
-                    current_srgba.alpha,
-                );
+/// Applies one interaction mode's effect on a particle.
+///
+/// `falloff` is the distance falloff times the mode's crossfade weight; every
+/// effect scales with it, so two modes at weight 0.5 contribute equally.
+fn apply_mode_influence(
+    mode: InteractionMode,
+    falloff: f32,
+    pointer: &PointerSample,
+    motion: &mut ParticleMotion,
+    visual: &mut ParticleVisual,
+    state: &mut ParticleState,
+) {
+    let PointerSample {
+        direction,
+        distance,
+        velocity_strength,
+        delta_seconds,
+        elapsed,
+        polarity,
+    } = *pointer;
+
+    match mode {
+        InteractionMode::Paint => {
+            // Paint mode doesn't apply forces; spawning is handled separately
+        }
+
+        InteractionMode::Attract => {
+            // Pull particles toward cursor
+            let force_strength = ATTRACT_FORCE_BASE * falloff * (0.5 + 0.5 * velocity_strength);
+            let force = direction * force_strength * delta_seconds;
+            motion.velocity += force * polarity;
+        }
 
-                // Boost scale
-                visual.scale = (visual.scale + scale_boost * delta_seconds).min(2.5);
+        InteractionMode::Intensify => {
+            // Mild attraction plus visual enhancement
+            let mild_attraction = direction * ATTRACT_FORCE_BASE * 0.3 * falloff * delta_seconds;
+            motion.velocity += mild_attraction;
 
-                // Boost bloom contribution
-                visual.bloom_contribution = (visual.bloom_contribution + falloff * 0.2 * delta_seconds).min(1.0);
-            }
+            // Boost saturation and scale based on proximity
+            let boost_amount = INTENSIFY_SATURATION_BOOST * falloff;
+            let scale_boost = INTENSIFY_SCALE_BOOST * falloff;
 
-            InteractionMode::Disperse => {
-                // Push particles away from cursor
-                let force_strength = DISPERSE_FORCE_BASE * falloff * (0.6 + 0.4 * velocity_strength);
-                let repulsion = -direction * force_strength * delta_seconds;
+            // Apply saturation boost by pushing color toward more saturated version
+            let current_srgba = visual.current_color.to_srgba();
 
-                // Add upward bias
-                let upward = Vec2::new(0.0, DISPERSE_UPWARD_BIAS * falloff * delta_seconds);
+            // Simple saturation approximation: increase color component differences
+            let avg = (current_srgba.red + current_srgba.green + current_srgba.blue) / 3.0;
+            let new_red = current_srgba.red + (current_srgba.red - avg) * boost_amount;
+            let new_green = current_srgba.green + (current_srgba.green - avg) * boost_amount;
+            let new_blue = current_srgba.blue + (current_srgba.blue - avg) * boost_amount;
 
-                motion.velocity += repulsion + upward;
+            visual.current_color = Color::srgba(
+                new_red.clamp(0.0, 1.0),
+                new_green.clamp(0.0, 1.0),
+                new_blue.clamp(0.0, 1.0),
+                current_srgba.alpha,
+            );
 
-                // Shift colors toward luminous pastels (increase brightness)
-                let current_srgba = visual.current_color.to_srgba();
-                let lighten_amount = 0.1 * falloff * delta_seconds;
-                visual.current_color = Color::srgba(
-                    (current_srgba.red + lighten_amount).min(1.0),
-                    (current_srgba.green + lighten_amount).min(1.0),
-                    (current_srgba.blue + lighten_amount).min(1.0),
-                    current_srgba.alpha,
-                );
-            }
+            // Boost scale
+            visual.scale = (visual.scale + scale_boost * delta_seconds).min(2.5);
+
+            // Boost bloom contribution
+            visual.bloom_contribution =
+                (visual.bloom_contribution + falloff * 0.2 * delta_seconds).min(1.0);
+        }
 
-            InteractionMode::Ripple => {
-                // Gentle outward wave from cursor
-                let wave_strength = RIPPLE_FORCE_BASE * falloff * (0.3 + 0.7 * velocity_strength);
+        InteractionMode::Disperse => {
+            // Push particles away from cursor
+            let force_strength = DISPERSE_FORCE_BASE * falloff * (0.6 + 0.4 * velocity_strength);
+            let repulsion = -direction * force_strength * delta_seconds;
 
-                // Create a ripple effect that pushes particles outward then pulls back
-                let ripple_phase = (distance / 80.0 - time.elapsed_secs() * 2.0).sin();
-                let ripple_force = -direction * wave_strength * ripple_phase * delta_seconds;
+            // Add upward bias
+            let upward = Vec2::new(0.0, DISPERSE_UPWARD_BIAS * falloff * delta_seconds);
 
-                motion.velocity += ripple_force;
+            motion.velocity += (repulsion + upward) * polarity;
 
-                // Gentle opacity modulation
-                let opacity_mod = 0.05 * falloff * ripple_phase;
-                visual.opacity = (visual.opacity + opacity_mod * delta_seconds).clamp(0.1, 1.0);
+            // Shift colors toward luminous pastels (increase brightness)
+            let current_srgba = visual.current_color.to_srgba();
+            let lighten_amount = 0.1 * falloff * delta_seconds;
+            visual.current_color = Color::srgba(
+                (current_srgba.red + lighten_amount).min(1.0),
+                (current_srgba.green + lighten_amount).min(1.0),
+                (current_srgba.blue + lighten_amount).min(1.0),
+                current_srgba.alpha,
+            );
+        }
+
+        InteractionMode::Ripple => {
+            // Gentle outward wave from cursor
+            let wave_strength = RIPPLE_FORCE_BASE * falloff * (0.3 + 0.7 * velocity_strength);
+
+            // Create a ripple effect that pushes particles outward then pulls back
+            let ripple_phase = (distance / 80.0 - elapsed * 2.0).sin();
+            let ripple_force = -direction * wave_strength * ripple_phase * delta_seconds;
+
+            motion.velocity += ripple_force;
+
+            // Gentle opacity modulation
+            let opacity_mod = 0.05 * falloff * ripple_phase;
+            visual.opacity = (visual.opacity + opacity_mod * delta_seconds).clamp(0.1, 1.0);
+        }
+
+        InteractionMode::Erase => {
+            // Drain lifetime with falloff so the eraser's edge is soft;
+            // despawn_expired_particles returns emptied particles to the pool
+            state.lifetime_remaining_ms -=
+                ERASE_LIFETIME_DRAIN_MS_PER_SECOND * falloff * delta_seconds;
+        }
+
+        InteractionMode::Vortex => {
+            // Spin particles into a galaxy around the cursor
+            let swirl_strength =
+                VORTEX_SWIRL_FORCE_BASE * falloff * (0.5 + 0.5 * velocity_strength);
+            motion.velocity += vortex_force(direction, swirl_strength) * delta_seconds;
+        }
+    }
+}
+
+/// Updates the eraser override from input and applies it to the interaction mode.
+///
+/// Alt acts as a momentary eraser; E toggles it. Runs after
+/// `apply_interaction_mode_cycle` has rebuilt this frame's mode from
+/// `BaseInteractionMode`, so the override wins and releases with the key.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// After `ActManagementSet::InterpolateValues`, before `apply_mouse_influence`.
+pub fn update_eraser_override(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut eraser: ResMut<EraserOverride>,
+    mut current_mode: ResMut<CurrentInteractionMode>,
+) {
+    eraser.held = keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    if keyboard.just_pressed(KeyCode::KeyE) {
+        eraser.toggled = !eraser.toggled;
+    }
+
+    if eraser.is_active() {
+        current_mode.set(InteractionMode::Erase);
+    }
+}
+
+/// Carries out the configured double-tap action.
+///
+/// # Stage
+/// Update
+pub fn apply_multi_tap_actions(
+    mut multi_tap_events: EventReader<MultiTap>,
+    detector: Res<MultiTapDetector>,
+    mut mode_cycle: ResMut<InteractionModeCycle>,
+    mut breath_pulse_events: EventWriter<BreathPulse>,
+) {
+    for event in multi_tap_events.read() {
+        if event.count != 2 {
+            continue;
+        }
+        match detector.double_tap_action {
+            MultiTapAction::None => {}
+            MultiTapAction::CycleInteractionMode => {
+                mode_cycle.steps = (mode_cycle.steps + 1) % InteractionMode::CYCLE_ORDER.len();
+            }
+            MultiTapAction::BreathPulse => {
+                breath_pulse_events.send(BreathPulse {
+                    origin: event.position,
+                    strength: 1.0,
+                });
             }
         }
     }
 }
 
+/// Rebuilds `CurrentInteractionMode` from `BaseInteractionMode`, rotated by
+/// `InteractionModeCycle.steps`.
+///
+/// Starting from the base every frame is what releases the later overrides
+/// (MIDI, the eraser) once they end, with or without the `acts` feature.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// After `ActManagementSet::InterpolateValues`, before `update_eraser_override`
+/// so the eraser still wins.
+pub fn apply_interaction_mode_cycle(
+    base_mode: Res<BaseInteractionMode>,
+    mode_cycle: Res<InteractionModeCycle>,
+    mut current_mode: ResMut<CurrentInteractionMode>,
+) {
+    *current_mode = base_mode.0.clone();
+    if mode_cycle.steps > 0 {
+        current_mode.mode = current_mode.mode.cycled(mode_cycle.steps);
+        if let Some((mode, _)) = current_mode.blend_target.as_mut() {
+            *mode = mode.cycled(mode_cycle.steps);
+        }
+    }
+}
+
+/// Steps or clears the paint color override from the keyboard.
+///
+/// - `]`: Rotate the hue forward one step
+/// - `[`: Rotate the hue back one step
+/// - `\`: Clear the override and return to palette colors
+///
+/// The first step starts from the palette's spark accent.
+///
+/// # Stage
+/// PreUpdate
+pub fn handle_paint_color_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    palette: Res<ColorPalette>,
+    mut color_override: ResMut<PaintColorOverride>,
+) {
+    if keyboard.just_pressed(KeyCode::Backslash) {
+        color_override.color = None;
+        return;
+    }
+
+    let steps = match (
+        keyboard.just_pressed(KeyCode::BracketRight),
+        keyboard.just_pressed(KeyCode::BracketLeft),
+    ) {
+        (true, false) => 1.0,
+        (false, true) => -1.0,
+        _ => return,
+    };
+    color_override.step_hue(steps, palette.accent_spark);
+}
+
+/// Run condition: true unless the Magnet Toy has taken over the pointer.
+///
+/// While the toy is active, pointer drags move its poles, so pointer forces
+/// and click and tap gestures stand down.
+pub fn pointer_drives_field(magnet_toy: Res<MagnetToy>) -> bool {
+    !magnet_toy.active
+}
+
+/// Toggles the Magnet Toy mode with the M key.
+///
+/// # Stage
+/// Update
+pub fn toggle_magnet_toy(keyboard: Res<ButtonInput<KeyCode>>, mut magnet_toy: ResMut<MagnetToy>) {
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        magnet_toy.active = !magnet_toy.active;
+        info!(
+            "Magnet toy {}",
+            if magnet_toy.active { "on" } else { "off" }
+        );
+    }
+}
+
+/// Spawns the two Magnet Toy poles when the mode turns on and removes them
+/// when it turns off.
+///
+/// Pole 0 attracts and pole 1 repels, each with a colored marker sprite.
+///
+/// # Stage
+/// Update (runs when `MagnetToy` changes)
+pub fn sync_magnet_poles(
+    mut commands: Commands,
+    magnet_toy: Res<MagnetToy>,
+    pea_texture: Option<Res<PeaTexture>>,
+    poles: Query<Entity, With<MagnetPole>>,
+) {
+    if !magnet_toy.active {
+        for entity in poles.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    if !poles.is_empty() {
+        return;
+    }
+
+    for index in 0..2 {
+        let polarity = if index == 0 { 1.0 } else { -1.0 };
+        let mut sprite = Sprite {
+            color: MAGNET_POLE_COLORS[index],
+            custom_size: Some(Vec2::splat(MAGNET_POLE_MARKER_SIZE)),
+            ..default()
+        };
+        if let Some(texture) = pea_texture.as_ref() {
+            sprite.image = texture.handle.clone();
+        }
+
+        let position = MAGNET_POLE_START_POSITIONS[index].extend(render_layers::FOREGROUND_EFFECTS);
+        commands.spawn((
+            sprite,
+            Transform::from_translation(position),
+            Attractor {
+                strength: magnet_toy.pole_strength * polarity,
+                radius: magnet_toy.pole_radius,
+            },
+            MagnetPole { index },
+            Name::new(format!("MagnetPole{index}")),
+        ));
+    }
+}
+
+/// Moves the Magnet Toy poles with the pointer.
+///
+/// The left button or primary touch drags pole 0; the right button drags
+/// pole 1.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs before `drive_magnet_poles_from_touch_and_keys`.
+pub fn drive_magnet_poles_from_pointer(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    touch_state: Res<TouchState>,
+    mouse_state: Res<MouseState>,
+    mut poles: Query<(&MagnetPole, &mut Transform)>,
+) {
+    if !mouse_state.is_active {
+        return;
+    }
+
+    let drag_primary =
+        mouse_buttons.pressed(MouseButton::Left) || touch_state.primary_touch_id.is_some();
+    let drag_secondary = mouse_buttons.pressed(MouseButton::Right);
+
+    for (pole, mut transform) in poles.iter_mut() {
+        let dragged = match pole.index {
+            0 => drag_primary,
+            _ => drag_secondary,
+        };
+        if dragged {
+            transform.translation.x = mouse_state.position.x;
+            transform.translation.y = mouse_state.position.y;
+        }
+    }
+}
+
+/// Moves the second Magnet Toy pole with a second touch or the arrow keys.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs after `drive_magnet_poles_from_pointer`.
+pub fn drive_magnet_poles_from_touch_and_keys(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    touches: Res<Touches>,
+    touch_state: Res<TouchState>,
+    magnet_toy: Res<MagnetToy>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<WhirledCamera>>,
+    mut poles: Query<(&MagnetPole, &mut Transform)>,
+) {
+    let touch_target = touch_state
+        .secondary_touch_id
+        .and_then(|id| touches.get_pressed(id))
+        .zip(camera_query.get_single().ok())
+        .and_then(|(touch, (camera, camera_transform))| {
+            world_position_from_screen(touch.position(), camera, camera_transform)
+        });
+
+    let mut key_direction = Vec2::ZERO;
+    if keyboard.pressed(KeyCode::ArrowLeft) {
+        key_direction.x -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::ArrowRight) {
+        key_direction.x += 1.0;
+    }
+    if keyboard.pressed(KeyCode::ArrowDown) {
+        key_direction.y -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::ArrowUp) {
+        key_direction.y += 1.0;
+    }
+    let key_step =
+        key_direction.normalize_or_zero() * magnet_toy.keyboard_pole_speed * time.delta_secs();
+
+    for (pole, mut transform) in poles.iter_mut() {
+        if pole.index != 1 {
+            continue;
+        }
+        if let Some(target) = touch_target {
+            transform.translation.x = target.x;
+            transform.translation.y = target.y;
+        } else {
+            transform.translation += key_step.extend(0.0);
+        }
+    }
+}
+
 /// Updates the gentle fade state and handles application exit.
 ///
 /// When gentle fade is active, this system counts down the remaining time
@@ -925,20 +2136,28 @@ pub fn update_gentle_fade(
 
 /// Applies explosion effects when ExplosionEvent is received.
 ///
-/// Particles within the explosion radius are forcefully pushed away
-/// from the explosion origin with a radial force that falls off with distance.
+/// Particles within `InteractionConfig.explosion_radius` are forcefully pushed
+/// away from the explosion origin with a radial force that falls off with distance.
 pub fn apply_explosion(
     mut explosion_events: EventReader<ExplosionEvent>,
+    interaction_config: Res<InteractionConfig>,
+    grid: Res<SpatialGrid>,
     mut particles: Query<
         (&Transform, &mut ParticleMotion, &mut ParticleVisual, &ParticleState),
         With<Particle>,
     >,
 ) {
+    let radius = interaction_config.explosion_radius;
+
     for event in explosion_events.read() {
         let origin = event.origin;
         let strength = event.strength;
 
-        for (transform, mut motion, mut visual, state) in particles.iter_mut() {
+        // Only particles in grid cells overlapping the blast are visited
+        for entity in grid.query_radius(origin, radius) {
+            let Ok((transform, mut motion, mut visual, state)) = particles.get_mut(entity) else {
+                continue;
+            };
             if !state.active {
                 continue;
             }
@@ -948,20 +2167,22 @@ pub fn apply_explosion(
             let distance = to_particle.length();
 
             // Skip particles outside explosion radius
-            if distance >= EXPLOSION_RADIUS || distance < 0.001 {
+            if distance >= radius || distance < 0.001 {
                 continue;
             }
 
             // Calculate force with inverse-square-ish falloff (but capped near origin)
-            let normalized_dist = (distance / EXPLOSION_RADIUS).max(0.1);
-            let force_magnitude = EXPLOSION_FORCE * strength * (1.0 - normalized_dist).powi(2);
+            let normalized_dist = (distance / radius).max(0.1);
+            let force_magnitude =
+                interaction_config.explosion_force * strength * (1.0 - normalized_dist).powi(2);
 
             // Direction away from explosion origin
             let direction = to_particle / distance;
             let impulse = direction * force_magnitude;
 
-            // Apply velocity impulse
+            // Apply velocity impulse, briefly past the act's speed cap
             motion.velocity += impulse;
+            motion.impulse_secs = crate::particle::IMPULSE_CAP_EXEMPT_SECS;
 
             // Visual feedback: brief brightness boost
             visual.bloom_contribution = (visual.bloom_contribution + 0.5 * (1.0 - normalized_dist)).min(1.0);
@@ -983,7 +2204,10 @@ pub fn apply_explosion(
 ///
 /// During hyperspace, particles accelerate away from the vanishing point,
 /// creating the Star Wars-style jump to lightspeed effect where stars
-/// become elongated streaks rushing past the viewer.
+/// become elongated streaks rushing past the viewer. The push lifts each
+/// pea's speed cap to `IMPULSE_MAX_SPEED`; once it passes `STREAK_MIN_SPEED`
+/// it gains a `MotionStreak` and is drawn stretched along its velocity, so
+/// the streaks read as light trails.
 pub fn apply_hyperspace(
     mut hyperspace_state: ResMut<HyperspaceState>,
     mut particles: Query<
@@ -1001,7 +2225,9 @@ pub fn apply_hyperspace(
         return;
     }
 
-    let delta = time.delta_secs();
+    let Some(delta) = crate::particle::simulation_delta(&time) else {
+        return;
+    };
     hyperspace_state.remaining_seconds -= delta;
 
     // Check if effect has ended
@@ -1038,6 +2264,7 @@ pub fn apply_hyperspace(
         let acceleration = direction * HYPERSPACE_ACCELERATION * acceleration_multiplier * distance_factor * delta;
 
         motion.velocity += acceleration;
+        motion.impulse_secs = crate::particle::IMPULSE_CAP_EXEMPT_SECS;
 
         // Stretch effect: dramatically reduce drag during hyperspace to maintain velocity
         motion.drag = 0.9995;
@@ -1083,52 +2310,105 @@ pub struct InteractionInfluenceSet;
 ///
 /// This plugin registers:
 /// - Input systems for mouse state tracking and keyboard handling (PreUpdate)
-/// - Influence systems for applying mode-specific particle effects (Update)
-/// - Events for breath pulse, gentle fade, explosion, and hyperspace jump
+/// - Influence systems for applying mode-specific particle effects (Update, not while paused)
+/// - Events for breath pulse, pause, gentle fade, explosion, hyperspace jump, multi-tap,
+///   and three-finger swipe
 ///
 /// # Systems
 /// - `update_mouse_state` (PreUpdate): Tracks mouse position and velocity
+/// - `seed_camera_control_from_camera` (PreUpdate): Tracks the auto-framed pose until manual control
+/// - `update_touch_state` (PreUpdate): Maps touches to the pointer and feeds pinch/pan
 /// - `calculate_interaction_radius` (PreUpdate, after update_mouse_state): Grows radius with use
-/// - `handle_keyboard_input` (PreUpdate): Processes spacebar and escape
+/// - `handle_keyboard_input` (PreUpdate): Processes spacebar, P, Shift, and escape
+/// - `apply_toggle_pause` (PreUpdate, after handle_keyboard_input): Flips `ExperiencePaused`
+/// - `handle_paint_color_keys` (PreUpdate): Steps or clears the paint color override
 /// - `handle_mouse_clicks` (PreUpdate): Processes left/right mouse clicks for explosion/hyperspace
+/// - `handle_scroll_zoom` (PreUpdate): Zooms the camera toward the cursor with the scroll wheel
+/// - `handle_middle_drag_pan` (PreUpdate): Pans the camera with a middle-button drag
+/// - `apply_camera_control` (PreUpdate, on change): Moves the camera for pinch, pan, and wheel
+/// - `request_screenshot` (PreUpdate, any state, desktop only): Sends `CaptureScreenshot` on F12
+/// - `apply_multi_tap_actions` (Update): Runs the configured double-tap action
+/// - `apply_interaction_mode_cycle` (Update): Applies double-tap mode cycling
 /// - `apply_mouse_influence` (Update): Applies mode-specific forces to particles
 /// - `apply_explosion` (Update): Applies radial force from explosion events
 /// - `apply_hyperspace` (Update): Applies hyperspace acceleration effect
 /// - `update_gentle_fade` (Update): Handles graceful exit countdown
+/// - `toggle_magnet_toy`, `sync_magnet_poles` (Update, also while paused): Toggles the
+///   Magnet Toy on M and spawns or removes its poles
+/// - `drive_magnet_poles_from_pointer`, `drive_magnet_poles_from_touch_and_keys`
+///   (Update): Move the poles while the Magnet Toy is active
 pub struct InteractionPlugin;
 
 impl Plugin for InteractionPlugin {
     fn build(&self, app: &mut App) {
+        // Mode overrides read this frame's act values when acts are compiled in
+        let mode_systems = (
+            apply_interaction_mode_cycle.before(update_eraser_override),
+            update_eraser_override.before(apply_mouse_influence),
+        );
+        #[cfg(feature = "acts")]
+        let mode_systems =
+            mode_systems.after(crate::act_management::ActManagementSet::InterpolateValues);
+        // Hyperspace drag overrides the act drag synced in InterpolateValues
+        #[cfg(feature = "acts")]
+        let apply_hyperspace =
+            apply_hyperspace.after(crate::act_management::ActManagementSet::InterpolateValues);
+
         app
             // Register events
             .add_event::<BreathPulse>()
             .add_event::<GentleFade>()
+            .add_event::<TogglePause>()
             .add_event::<ExplosionEvent>()
             .add_event::<HyperspaceJumpEvent>()
+            .add_event::<MultiTap>()
+            .add_event::<ThreeFingerSwipe>()
             // Register resources
             .init_resource::<BreathPulseCooldown>()
             .init_resource::<GentleFadeState>()
             .init_resource::<HyperspaceState>()
             .init_resource::<TouchState>()
+            .init_resource::<EraserOverride>()
+            .init_resource::<ForcePolarity>()
+            .init_resource::<MultiTapDetector>()
+            .init_resource::<InteractionModeCycle>()
+            .init_resource::<PointerFilter>()
+            .init_resource::<CameraControlState>()
             // Configure system sets (only in Fidget state)
-            .configure_sets(
-                PreUpdate,
-                InteractionInputSet.run_if(in_state(AppState::Fidget)),
-            )
+            .configure_sets(PreUpdate, InteractionInputSet.run_if(in_fidget_state))
+            // Pointer forces and erasing stop while paused, so nothing builds up
+            // to burst out on resume
             .configure_sets(
                 Update,
-                InteractionInfluenceSet.run_if(in_state(AppState::Fidget)),
+                InteractionInfluenceSet
+                    .run_if(in_fidget_state)
+                    .run_if(experience_running),
             )
             // Add PreUpdate systems
             .add_systems(
                 PreUpdate,
                 (
                     update_mouse_state,
-                    update_touch_state.after(update_mouse_state),
+                    seed_camera_control_from_camera,
+                    update_touch_state
+                        .after(update_mouse_state)
+                        .after(seed_camera_control_from_camera),
                     calculate_interaction_radius.after(update_touch_state),
+                    begin_paint_strokes.after(update_touch_state),
                     handle_keyboard_input,
-                    handle_mouse_clicks,
-                    handle_touch_gestures.after(update_touch_state),
+                    apply_toggle_pause.after(handle_keyboard_input),
+                    handle_paint_color_keys,
+                    handle_mouse_clicks.run_if(pointer_drives_field),
+                    handle_touch_gestures
+                        .after(update_touch_state)
+                        .run_if(pointer_drives_field),
+                    handle_scroll_zoom.after(seed_camera_control_from_camera),
+                    handle_middle_drag_pan.after(seed_camera_control_from_camera),
+                    apply_camera_control
+                        .after(update_touch_state)
+                        .after(handle_scroll_zoom)
+                        .after(handle_middle_drag_pan)
+                        .run_if(resource_changed::<CameraControlState>),
                 )
                     .in_set(InteractionInputSet),
             )
@@ -1136,13 +2416,46 @@ impl Plugin for InteractionPlugin {
             .add_systems(
                 Update,
                 (
-                    apply_mouse_influence,
-                    apply_explosion,
+                    apply_multi_tap_actions.before(apply_interaction_mode_cycle),
+                    mode_systems,
+                    // Radius queries read this frame's spatial grid
+                    apply_mouse_influence
+                        .after(crate::particle::update_spatial_grid)
+                        .run_if(pointer_drives_field),
+                    apply_explosion.after(crate::particle::update_spatial_grid),
                     apply_hyperspace,
                     update_gentle_fade,
                 )
                     .in_set(InteractionInfluenceSet),
+            )
+            // Magnet Toy: toggle and pole lifecycle, live even while paused
+            .add_systems(
+                Update,
+                (
+                    toggle_magnet_toy,
+                    sync_magnet_poles.run_if(resource_changed::<MagnetToy>),
+                )
+                    .chain()
+                    .before(crate::particle::apply_attractor_forces)
+                    .run_if(in_fidget_state),
+            )
+            // Then pole input, which stops with the other pointer forces
+            .add_systems(
+                Update,
+                (
+                    drive_magnet_poles_from_pointer,
+                    drive_magnet_poles_from_touch_and_keys,
+                )
+                    .chain()
+                    .after(sync_magnet_poles)
+                    .before(crate::particle::apply_attractor_forces)
+                    .run_if(crate::particle::magnet_toy_active)
+                    .in_set(InteractionInfluenceSet),
             );
+
+        #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+        app.add_event::<CaptureScreenshot>()
+            .add_systems(PreUpdate, request_screenshot);
     }
 }
 
@@ -1153,6 +2466,338 @@ impl Plugin for InteractionPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::resources::ParticlePool;
+
+    #[test]
+    fn test_magnet_toy_spawns_opposite_poles() {
+        let mut app = App::new();
+        app.init_resource::<MagnetToy>().add_systems(
+            Update,
+            sync_magnet_poles.run_if(resource_changed::<MagnetToy>),
+        );
+
+        app.world_mut().resource_mut::<MagnetToy>().active = true;
+        app.update();
+
+        let mut poles: Vec<(MagnetPole, Attractor)> = app
+            .world_mut()
+            .query::<(&MagnetPole, &Attractor)>()
+            .iter(app.world())
+            .map(|(pole, attractor)| (*pole, *attractor))
+            .collect();
+        poles.sort_by_key(|(pole, _)| pole.index);
+        assert_eq!(poles.len(), 2);
+        assert!(poles[0].1.strength > 0.0);
+        assert!(poles[1].1.strength < 0.0);
+
+        // A particle between the poles is pulled toward one and pushed by the other
+        let particle = Vec2::new(0.0, 50.0);
+        let pull = crate::particle::attractor_acceleration(
+            particle,
+            MAGNET_POLE_START_POSITIONS[0],
+            &poles[0].1,
+        );
+        let push = crate::particle::attractor_acceleration(
+            particle,
+            MAGNET_POLE_START_POSITIONS[1],
+            &poles[1].1,
+        );
+        let to_pole_0 = MAGNET_POLE_START_POSITIONS[0] - particle;
+        let to_pole_1 = MAGNET_POLE_START_POSITIONS[1] - particle;
+        assert!(pull.dot(to_pole_0) > 0.0);
+        assert!(push.dot(to_pole_1) < 0.0);
+
+        // Turning the toy off removes the poles
+        app.world_mut().resource_mut::<MagnetToy>().active = false;
+        app.update();
+        let remaining = app
+            .world_mut()
+            .query::<&MagnetPole>()
+            .iter(app.world())
+            .count();
+        assert_eq!(remaining, 0);
+    }
+
+    #[test]
+    fn test_magnet_toy_toggles_while_paused() {
+        use bevy::input::keyboard::{Key, KeyboardInput};
+        use bevy::input::{ButtonState, InputPlugin};
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            crate::resources::ResourcesPlugin,
+            InteractionPlugin,
+        ))
+        // Normally registered by VisualPlugin
+        .init_resource::<AutoFrame>();
+        app.world_mut().resource_mut::<ExperiencePaused>().0 = true;
+
+        app.world_mut().send_event(KeyboardInput {
+            key_code: KeyCode::KeyM,
+            logical_key: Key::Character("m".into()),
+            state: ButtonState::Pressed,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+
+        assert!(app.world().resource::<MagnetToy>().active);
+        let poles = app
+            .world_mut()
+            .query::<&MagnetPole>()
+            .iter(app.world())
+            .count();
+        assert_eq!(poles, 2);
+    }
+
+    #[test]
+    fn test_erase_mode_reduces_active_count() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(MouseState {
+                is_active: true,
+                ..Default::default()
+            })
+            .init_resource::<InteractionConfig>()
+            .init_resource::<CurrentInteractionMode>()
+            .init_resource::<ForcePolarity>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(EraserOverride {
+                toggled: true,
+                held: false,
+            })
+            .init_resource::<ParticlePool>()
+            .init_resource::<SpatialGrid>()
+            .init_resource::<crate::trail::OrphanTrailPool>()
+            .insert_resource(crate::resources::InkBudget {
+                enabled: true,
+                ..Default::default()
+            })
+            .init_resource::<crate::resources::ParticleSpawnQueue>()
+            .init_resource::<crate::resources::InterpolatedActValues>()
+            .init_resource::<crate::resources::ColorPalette>()
+            .init_resource::<crate::resources::PaintConfig>()
+            .init_resource::<crate::resources::PaintColorOverride>()
+            .add_systems(
+                Update,
+                (
+                    update_eraser_override,
+                    crate::particle::spawn_particles_from_mouse,
+                    crate::particle::update_spatial_grid,
+                    apply_mouse_influence,
+                    crate::particle::despawn_expired_particles,
+                )
+                    .chain(),
+            );
+
+        // Three particles under the cursor, one well outside the radius
+        let positions = [
+            Vec2::ZERO,
+            Vec2::new(10.0, 0.0),
+            Vec2::new(0.0, 20.0),
+            Vec2::new(500.0, 0.0),
+        ];
+        for (id, position) in positions.iter().enumerate() {
+            app.world_mut().spawn((
+                Particle { id: id as u32 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 5000.0,
+                    lifetime_total_ms: 5000.0,
+                },
+                ParticleMotion::default(),
+                MouseInfluence::default(),
+                ParticleVisual::default(),
+                Transform::from_translation(position.extend(0.0)),
+                Visibility::Visible,
+            ));
+        }
+        app.world_mut().resource_mut::<ParticlePool>().active_count = positions.len() as u32;
+
+        for _ in 0..20 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().resource::<CurrentInteractionMode>().mode,
+            InteractionMode::Erase
+        );
+        // The three particles under the cursor were erased; the distant one survives
+        assert_eq!(app.world().resource::<ParticlePool>().active_count, 1);
+        // The eraser painted nothing and spent no ink along the way
+        let queue = app
+            .world()
+            .resource::<crate::resources::ParticleSpawnQueue>();
+        assert!(queue.pending_spawns.is_empty());
+        let ink = app.world().resource::<crate::resources::InkBudget>();
+        assert_eq!(ink.current, ink.max);
+    }
+
+    #[test]
+    fn test_overrides_release_to_base_mode_without_acts() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<BaseInteractionMode>()
+            .init_resource::<CurrentInteractionMode>()
+            .init_resource::<EraserOverride>()
+            .insert_resource(InteractionModeCycle { steps: 1 })
+            .add_systems(
+                Update,
+                (apply_interaction_mode_cycle, update_eraser_override).chain(),
+            );
+        let mode = |app: &App| app.world().resource::<CurrentInteractionMode>().mode;
+        let cycled = InteractionMode::Paint.cycled(1);
+
+        // The cycle holds steady frame after frame instead of strobing
+        app.update();
+        assert_eq!(mode(&app), cycled);
+        app.update();
+        assert_eq!(mode(&app), cycled);
+
+        // Holding Alt erases; letting go restores the cycled base mode
+        let press = |app: &mut App, pressed: bool| {
+            {
+                let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+                if pressed {
+                    keyboard.press(KeyCode::AltLeft);
+                } else {
+                    keyboard.release(KeyCode::AltLeft);
+                }
+            }
+            app.update();
+        };
+        press(&mut app, true);
+        assert_eq!(mode(&app), InteractionMode::Erase);
+        press(&mut app, false);
+        assert_eq!(mode(&app), cycled);
+    }
+
+    #[test]
+    fn test_mode_crossfade_weights_both_modes_equally_at_halfway() {
+        let pointer = PointerSample {
+            direction: Vec2::new(-1.0, 0.0),
+            distance: 40.0,
+            velocity_strength: 0.5,
+            delta_seconds: 1.0 / 60.0,
+            elapsed: 0.0,
+            polarity: 1.0,
+        };
+        let velocity_after = |modes: &CurrentInteractionMode| {
+            let mut motion = ParticleMotion::default();
+            let mut visual = ParticleVisual::default();
+            let mut state = ParticleState::default();
+            for (mode, weight) in modes.weighted_modes() {
+                let falloff = 0.8 * weight;
+                apply_mode_influence(
+                    mode,
+                    falloff,
+                    &pointer,
+                    &mut motion,
+                    &mut visual,
+                    &mut state,
+                );
+            }
+            motion.velocity
+        };
+        let only = |mode| CurrentInteractionMode {
+            mode,
+            ..Default::default()
+        };
+
+        let attract = velocity_after(&only(InteractionMode::Attract));
+        let disperse = velocity_after(&only(InteractionMode::Disperse));
+        let halfway = CurrentInteractionMode {
+            mode: InteractionMode::Attract,
+            blend_target: Some((InteractionMode::Disperse, 0.5)),
+        };
+        let weights: Vec<_> = halfway.weighted_modes().collect();
+        assert_eq!(
+            weights,
+            [
+                (InteractionMode::Attract, 0.5),
+                (InteractionMode::Disperse, 0.5)
+            ]
+        );
+
+        let blended = velocity_after(&halfway);
+        assert!((blended - (attract + disperse) * 0.5).length() < 1e-4);
+        assert!(blended.length() < attract.length().max(disperse.length()));
+    }
+
+    /// Runs one Attract/Disperse step on a particle 40 units right of the cursor
+    /// and returns the velocity it picked up.
+    fn directional_step(mode: InteractionMode, inverted: bool, pointer_velocity: Vec2) -> Vec2 {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(MouseState {
+                is_active: true,
+                velocity: pointer_velocity,
+                ..Default::default()
+            })
+            .init_resource::<InteractionConfig>()
+            .insert_resource(CurrentInteractionMode {
+                mode,
+                ..Default::default()
+            })
+            .insert_resource(ForcePolarity { inverted })
+            .init_resource::<SpatialGrid>()
+            .add_systems(
+                Update,
+                (crate::particle::update_spatial_grid, apply_mouse_influence).chain(),
+            );
+
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 5000.0,
+                    lifetime_total_ms: 5000.0,
+                },
+                ParticleMotion::default(),
+                MouseInfluence::default(),
+                ParticleVisual::default(),
+                Transform::from_xyz(40.0, 0.0, 0.0),
+                Visibility::Visible,
+            ))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_millis(100));
+        app.update();
+        app.world()
+            .get::<ParticleMotion>(particle)
+            .unwrap()
+            .velocity
+    }
+
+    #[test]
+    fn test_inverted_polarity_flips_directional_forces() {
+        let still = Vec2::ZERO;
+        let attract = directional_step(InteractionMode::Attract, false, still);
+        let repel = directional_step(InteractionMode::Attract, true, still);
+        assert!(attract.x < 0.0, "Attract pulls toward the cursor");
+        assert!(repel.x > 0.0, "inverted Attract pushes away");
+        assert!((attract + repel).length() < 1e-4);
+
+        let disperse = directional_step(InteractionMode::Disperse, false, still);
+        let gather = directional_step(InteractionMode::Disperse, true, still);
+        assert!(disperse.x > 0.0 && gather.x < 0.0);
+
+        // Pointer speed still scales the inverted force
+        let fast = Vec2::new(VELOCITY_THRESHOLD_HIGH, 0.0);
+        let fast_repel = directional_step(InteractionMode::Attract, true, fast);
+        assert!(fast_repel.x > repel.x);
+    }
 
     #[test]
     fn test_quadratic_falloff() {
@@ -1173,6 +2818,23 @@ mod tests {
         assert_eq!(quadratic_falloff(-10.0, 100.0), 0.0); // Negative distance treated as >= max
     }
 
+    #[test]
+    fn test_vortex_tangent_is_perpendicular_to_radial() {
+        for angle in [0.0_f32, 0.7, 1.9, 3.5, 5.2] {
+            let direction = Vec2::from_angle(angle);
+            let tangent = vortex_tangent(direction);
+            assert!(direction.dot(tangent).abs() < 1e-5);
+            assert!((tangent.length() - 1.0).abs() < 1e-5);
+        }
+
+        // The full force keeps a small inward component along the radial direction
+        let direction = Vec2::X;
+        let force = vortex_force(direction, 10.0);
+        assert!((force.dot(direction) - 10.0 * VORTEX_INWARD_FRACTION).abs() < 1e-5);
+        assert!((force.dot(vortex_tangent(direction)) - 10.0).abs() < 1e-5);
+        assert!(!InteractionMode::Vortex.affects_visuals());
+    }
+
     #[test]
     fn test_velocity_to_strength() {
         // Below low threshold
@@ -1188,6 +2850,375 @@ mod tests {
         assert!(mid_strength > 0.4 && mid_strength < 0.6);
     }
 
+    #[test]
+    fn test_two_quick_clicks_send_double_tap() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<MultiTapDetector>()
+            .init_resource::<HyperspaceState>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .insert_resource(MouseState {
+                is_active: true,
+                position: Vec2::new(100.0, -50.0),
+                ..Default::default()
+            })
+            .add_event::<MultiTap>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<HyperspaceJumpEvent>()
+            .add_systems(Update, handle_mouse_clicks);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        assert_eq!(app.world().resource::<Events<ExplosionEvent>>().len(), 1);
+        assert!(app.world().resource::<Events<MultiTap>>().is_empty());
+
+        let mut buttons = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        buttons.clear();
+        buttons.release(MouseButton::Left);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .clear();
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        // The second click explodes immediately too, and completes a double-tap
+        let explosions = app.world().resource::<Events<ExplosionEvent>>();
+        assert_eq!(explosions.iter_current_update_events().count(), 1);
+        let taps: Vec<MultiTap> = app
+            .world()
+            .resource::<Events<MultiTap>>()
+            .iter_current_update_events()
+            .copied()
+            .collect();
+        assert_eq!(
+            taps,
+            vec![MultiTap {
+                count: 2,
+                position: Vec2::new(100.0, -50.0),
+            }]
+        );
+    }
+
+    #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+    #[test]
+    fn test_f12_requests_screenshot() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_event::<CaptureScreenshot>()
+            .add_systems(Update, request_screenshot);
+
+        app.update();
+        assert!(app
+            .world()
+            .resource::<Events<CaptureScreenshot>>()
+            .is_empty());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::F12);
+        app.update();
+        assert_eq!(app.world().resource::<Events<CaptureScreenshot>>().len(), 1);
+    }
+
+    #[test]
+    fn test_two_finger_gesture_navigates_only_past_tap_travel() {
+        let display_scale = DisplayScale::default();
+        let mut control = CameraControlState {
+            gesture: Some(TwoFingerGesture {
+                start_midpoint: Vec2::new(500.0, 400.0),
+                start_distance: 200.0,
+                start_zoom: 1.0,
+                start_pan: Vec2::ZERO,
+                navigating: false,
+            }),
+            ..Default::default()
+        };
+
+        // A still two-finger tap wobbles a little and stays a tap
+        let started =
+            update_two_finger_gesture(&mut control, Vec2::new(505.0, 400.0), 210.0, &display_scale);
+        assert!(!started);
+        assert!(control.is_default());
+
+        // Spreading the fingers to twice the distance zooms in 2x
+        let started =
+            update_two_finger_gesture(&mut control, Vec2::new(500.0, 400.0), 400.0, &display_scale);
+        assert!(started);
+        assert!(control.is_navigating());
+        assert!((control.zoom - 0.5).abs() < 1e-6);
+
+        // Dragging right and down moves the camera left and up
+        update_two_finger_gesture(&mut control, Vec2::new(540.0, 420.0), 200.0, &display_scale);
+        assert!(control.pan.x < 0.0 && control.pan.y > 0.0);
+    }
+
+    #[test]
+    fn test_zoom_toward_keeps_cursor_point_fixed() {
+        let display_scale = DisplayScale::default();
+        let window_size = Vec2::new(1920.0, 1080.0);
+        let cursor = Vec2::new(1500.0, 300.0);
+        let mut camera = CameraControlState::default();
+
+        let before = camera.world_position(cursor, window_size, &display_scale);
+        zoom_toward(&mut camera, before, 0.5);
+        assert_eq!(camera.zoom, 0.5);
+        let after = camera.world_position(cursor, window_size, &display_scale);
+        assert!(before.distance(after) < 1e-3, "{before} moved to {after}");
+
+        // Zooming out past the limit only applies the allowed part
+        zoom_toward(&mut camera, before, 10.0);
+        assert_eq!(camera.zoom, CAMERA_MAX_ZOOM);
+        assert!(camera.pan.length() < 1e-3);
+    }
+
+    #[test]
+    fn test_three_finger_swipe_direction() {
+        assert_eq!(
+            three_finger_swipe_direction(Vec2::new(200.0, 30.0)),
+            Some(true)
+        );
+        assert_eq!(
+            three_finger_swipe_direction(Vec2::new(-150.0, -40.0)),
+            Some(false)
+        );
+
+        // Too short, or more vertical than horizontal
+        assert_eq!(three_finger_swipe_direction(Vec2::new(60.0, 0.0)), None);
+        assert_eq!(three_finger_swipe_direction(Vec2::new(150.0, 300.0)), None);
+    }
+
+    #[test]
+    fn test_camera_control_clamp_keeps_background_covering_view() {
+        let viewport = DisplayScale::default().world_viewport;
+        let mut control = CameraControlState {
+            zoom: 0.1,
+            pan: Vec2::splat(10_000.0),
+            gesture: None,
+        };
+        control.clamp(viewport);
+        assert_eq!(control.zoom, CAMERA_MIN_ZOOM);
+
+        let half_view = viewport * control.zoom * 0.5;
+        assert!(control.pan.x + half_view.x <= BACKGROUND_SIZE.x * 0.5 + 1e-3);
+        assert!(control.pan.y + half_view.y <= BACKGROUND_SIZE.y * 0.5 + 1e-3);
+
+        // At the default framing only the background margin is left to pan
+        control.zoom = 5.0;
+        control.clamp(viewport);
+        assert_eq!(control.zoom, CAMERA_MAX_ZOOM);
+        assert_eq!(control.pan, (BACKGROUND_SIZE - viewport) * 0.5);
+    }
+
+    #[test]
+    fn test_first_wheel_step_starts_from_auto_framed_pose() {
+        let mut app = App::new();
+        app.add_event::<MouseWheel>()
+            .init_resource::<AutoFrame>()
+            .init_resource::<CameraControlState>()
+            .init_resource::<DisplayScale>()
+            .add_systems(
+                Update,
+                (
+                    seed_camera_control_from_camera,
+                    handle_scroll_zoom,
+                    apply_camera_control.run_if(resource_changed::<CameraControlState>),
+                )
+                    .chain(),
+            );
+        app.update();
+
+        // Auto-framing has drifted the camera off the default framing
+        let camera = app
+            .world_mut()
+            .spawn((
+                WhirledCamera,
+                Transform::from_xyz(60.0, -40.0, 0.0),
+                OrthographicProjection {
+                    scale: 0.92,
+                    ..OrthographicProjection::default_2d()
+                },
+            ))
+            .id();
+        app.update();
+        assert!(!app.world().resource::<AutoFrame>().manual_control);
+
+        app.world_mut().send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: 1.0,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+
+        // Zooming about the view center keeps the pan and scales the zoom
+        let transform = app.world().get::<Transform>(camera).unwrap();
+        assert_eq!(transform.translation.truncate(), Vec2::new(60.0, -40.0));
+        let scale = app
+            .world()
+            .get::<OrthographicProjection>(camera)
+            .unwrap()
+            .scale;
+        assert!((scale - 0.92 / WHEEL_ZOOM_PER_LINE).abs() < 1e-5);
+        assert!(app.world().resource::<AutoFrame>().manual_control);
+    }
+
+    #[test]
+    fn test_multi_tap_resets_when_too_slow_or_too_far() {
+        let mut detector = MultiTapDetector::default();
+        assert_eq!(detector.register_tap(1.0, Vec2::ZERO), 1);
+        assert_eq!(detector.register_tap(1.2, Vec2::new(5.0, 0.0)), 2);
+        assert_eq!(detector.register_tap(1.4, Vec2::new(5.0, 5.0)), 3);
+        // Too late
+        assert_eq!(detector.register_tap(3.0, Vec2::new(5.0, 5.0)), 1);
+        // Too far
+        let far = Vec2::new(5.0 + detector.max_distance * 2.0, 5.0);
+        assert_eq!(detector.register_tap(3.1, far), 1);
+    }
+
+    #[test]
+    fn test_interaction_mode_cycle_skips_erase() {
+        assert_eq!(InteractionMode::Paint.cycled(1), InteractionMode::Attract);
+        assert_eq!(InteractionMode::Ripple.cycled(1), InteractionMode::Vortex);
+        assert_eq!(InteractionMode::Vortex.cycled(1), InteractionMode::Paint);
+        assert_eq!(InteractionMode::Disperse.cycled(9), InteractionMode::Paint);
+        assert_eq!(InteractionMode::Erase.cycled(3), InteractionMode::Erase);
+    }
+
+    #[test]
+    fn test_sub_dead_zone_jitter_records_zero_velocity() {
+        let filter = PointerFilter {
+            dead_zone: 6.0,
+            smoothing: 0.0,
+        };
+        let rest = Vec2::new(100.0, 100.0);
+        let mut state = MouseState {
+            position: rest,
+            ..Default::default()
+        };
+
+        // A resting finger wandering a few units each frame
+        for jitter in [
+            Vec2::new(3.0, -2.0),
+            Vec2::new(-4.0, 1.0),
+            Vec2::new(2.5, 4.0),
+        ] {
+            record_pointer_sample(&mut state, rest + jitter, 1.0 / 60.0, &filter);
+            assert_eq!(state.velocity, Vec2::ZERO);
+            assert_eq!(state.position, rest);
+        }
+
+        // A real movement passes through
+        record_pointer_sample(&mut state, Vec2::new(140.0, 100.0), 1.0 / 60.0, &filter);
+        assert_eq!(state.position, Vec2::new(140.0, 100.0));
+        assert!(state.velocity.x > 0.0);
+
+        // The default filter keeps raw behavior
+        let mut raw = MouseState::default();
+        record_pointer_sample(
+            &mut raw,
+            Vec2::new(1.0, 0.0),
+            0.5,
+            &PointerFilter::default(),
+        );
+        assert_eq!(raw.position, Vec2::new(1.0, 0.0));
+        let expected = 2.0 * smoothing_alpha(POINTER_VELOCITY_TIME_CONSTANT_SECS, 0.5);
+        assert!((raw.velocity.x - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pointer_response_matches_at_60_and_240_hz() {
+        use crate::particle::spawn_particles_from_mouse;
+        use crate::resources::{
+            ColorPalette, InkBudget, InterpolatedActValues, ParticleSpawnQueue,
+        };
+        use std::time::Duration;
+
+        /// Drags the pointer right at a steady 300 units/s.
+        fn drag_pointer(
+            time: Res<Time>,
+            filter: Res<PointerFilter>,
+            mut mouse_state: ResMut<MouseState>,
+        ) {
+            let dt = time.delta_secs();
+            let sample = mouse_state.position + Vec2::new(300.0 * dt, 0.0);
+            record_pointer_sample(&mut mouse_state, sample, dt, &filter);
+            accumulate_interaction(&mut mouse_state, dt);
+        }
+
+        // Returns (radius growth over 1s, spawns over 1s, velocity after 50ms)
+        let run = |hz: u32| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .init_resource::<InteractionConfig>()
+                .init_resource::<ActState>()
+                .init_resource::<RadiusGrowthProfile>()
+                .init_resource::<PointerFilter>()
+                .init_resource::<InterpolatedActValues>()
+                .init_resource::<ColorPalette>()
+                .init_resource::<ButtonInput<MouseButton>>()
+                .init_resource::<TouchState>()
+                .init_resource::<PaintConfig>()
+                .init_resource::<PaintColorOverride>()
+                .init_resource::<InkBudget>()
+                .init_resource::<ParticleSpawnQueue>()
+                .insert_resource(MouseState {
+                    is_active: true,
+                    ..Default::default()
+                })
+                .add_systems(
+                    Update,
+                    (
+                        drag_pointer,
+                        calculate_interaction_radius,
+                        spawn_particles_from_mouse,
+                    )
+                        .chain(),
+                );
+            app.world_mut()
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .press(MouseButton::Left);
+
+            let start_radius = app.world().resource::<InteractionConfig>().current_radius;
+            let frame = Duration::from_secs_f64(1.0 / f64::from(hz));
+            let mut spawns = 0;
+            let mut early_velocity = 0.0;
+            for i in 1..=hz {
+                app.world_mut().resource_mut::<Time>().advance_by(frame);
+                app.update();
+                let mut queue = app.world_mut().resource_mut::<ParticleSpawnQueue>();
+                spawns += queue.pending_spawns.drain(..).count();
+                if i == hz / 20 {
+                    early_velocity = app.world().resource::<MouseState>().velocity.x;
+                }
+            }
+            let growth = app.world().resource::<InteractionConfig>().current_radius - start_radius;
+            (growth, spawns, early_velocity)
+        };
+
+        let (growth_60, spawns_60, velocity_60) = run(60);
+        let (growth_240, spawns_240, velocity_240) = run(240);
+
+        assert!(growth_60 > 0.0);
+        assert!((growth_60 - growth_240).abs() <= growth_60 * 0.05);
+        assert!(spawns_60 > 0);
+        assert!(
+            spawns_60.abs_diff(spawns_240) <= 2,
+            "{spawns_60} vs {spawns_240}"
+        );
+        assert!(velocity_60 > 0.0 && velocity_60 < 300.0);
+        assert!((velocity_60 - velocity_240).abs() <= velocity_60 * 0.01);
+    }
+
     #[test]
     fn test_breath_pulse_event() {
         let pulse = BreathPulse {
@@ -1226,4 +3257,134 @@ mod tests {
         let fade = GentleFade::default();
         assert_eq!(fade.duration_seconds, GENTLE_FADE_DURATION_SECONDS);
     }
+
+    #[test]
+    fn test_mouse_camera_query_targets_whirled_camera() {
+        use bevy::asset::AssetEvent;
+        use bevy::render::camera::{camera_system, ManualTextureViews};
+        use bevy::window::{WindowCreated, WindowResized, WindowScaleFactorChanged};
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<MouseState>()
+            .init_resource::<PointerFilter>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<AssetEvent<Image>>()
+            .add_systems(
+                Update,
+                (camera_system::<OrthographicProjection>, update_mouse_state).chain(),
+            );
+
+        let mut window = Window {
+            resolution: (800.0, 600.0).into(),
+            ..default()
+        };
+        window.set_cursor_position(Some(Vec2::new(400.0, 300.0)));
+        app.world_mut().spawn((window, PrimaryWindow));
+
+        // A host-app overlay camera alongside the crate's own camera
+        app.world_mut().spawn((
+            Camera2d,
+            Camera {
+                order: 1,
+                ..default()
+            },
+            GlobalTransform::from_xyz(500.0, 0.0, 0.0),
+        ));
+        app.world_mut().spawn((
+            Camera2d,
+            WhirledCamera,
+            GlobalTransform::from_xyz(-200.0, 100.0, 0.0),
+        ));
+
+        app.update();
+
+        // The cursor at the window center lands under the crate's camera
+        let mouse_state = app.world().resource::<MouseState>();
+        assert!(mouse_state.is_active);
+        assert!(
+            mouse_state.position.distance(Vec2::new(-200.0, 100.0)) < 1e-3,
+            "pointer mapped through the wrong camera: {}",
+            mouse_state.position
+        );
+    }
+
+    #[test]
+    fn test_new_touch_starts_under_the_finger_with_smoothing() {
+        use bevy::asset::AssetEvent;
+        use bevy::input::touch::{touch_screen_input_system, TouchInput, TouchPhase};
+        use bevy::render::camera::{camera_system, ManualTextureViews};
+        use bevy::window::{WindowCreated, WindowResized, WindowScaleFactorChanged};
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(MouseState {
+                position: Vec2::new(600.0, -400.0),
+                velocity: Vec2::new(900.0, 0.0),
+                ..Default::default()
+            })
+            .insert_resource(PointerFilter {
+                dead_zone: 0.0,
+                smoothing: 0.8,
+            })
+            .init_resource::<TouchState>()
+            .init_resource::<CameraControlState>()
+            .init_resource::<DisplayScale>()
+            .init_resource::<Touches>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<TouchInput>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<AssetEvent<Image>>()
+            .add_systems(
+                Update,
+                (
+                    touch_screen_input_system,
+                    camera_system::<OrthographicProjection>,
+                    update_touch_state,
+                )
+                    .chain(),
+            );
+
+        let window = app
+            .world_mut()
+            .spawn((
+                Window {
+                    resolution: (800.0, 600.0).into(),
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        app.world_mut().spawn((
+            Camera2d,
+            WhirledCamera,
+            GlobalTransform::from_xyz(-200.0, 100.0, 0.0),
+        ));
+
+        // The previous stroke ended far away; a finger lands at the window center
+        app.world_mut().send_event(TouchInput {
+            phase: TouchPhase::Started,
+            position: Vec2::new(400.0, 300.0),
+            window,
+            force: None,
+            id: 1,
+        });
+        app.update();
+
+        let mouse_state = app.world().resource::<MouseState>();
+        assert!(mouse_state.is_active);
+        assert!(
+            mouse_state.position.distance(Vec2::new(-200.0, 100.0)) < 1e-3,
+            "touch glided in from {}",
+            mouse_state.position
+        );
+        assert_eq!(mouse_state.velocity, Vec2::ZERO);
+    }
 }
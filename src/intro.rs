@@ -7,20 +7,7 @@
 use bevy::prelude::*;
 
 use crate::resources::UiFont;
-
-// =============================================================================
-// APP STATE
-// =============================================================================
-
-/// Application state controlling whether intro or fidget mode is active.
-#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub enum AppState {
-    /// Intro sequence showing app information
-    #[default]
-    Intro,
-    /// Main fidget/visualizer experience
-    Fidget,
-}
+use crate::types::AppState;
 
 // =============================================================================
 // INTRO STEPS
@@ -158,7 +145,6 @@ pub struct IntroDecor;
 /// Sets up the intro UI with Bauhaus styling.
 /// Runs at Startup to ensure intro is visible before any interaction.
 pub fn setup_intro_ui(mut commands: Commands, ui_font: Res<UiFont>) {
-
     let font = ui_font.handle.clone();
 
     // Background overlay - covers entire screen
@@ -236,7 +222,6 @@ pub fn setup_intro_ui(mut commands: Commands, ui_font: Res<UiFont>) {
                 Name::new("BottomBar"),
             ));
         });
-
 }
 
 /// Returns the base color for a given intro step.
@@ -310,7 +295,6 @@ pub fn update_intro_sequence(
                         let base = step_color(next_step).to_srgba();
                         *color = TextColor(Color::srgba(base.red, base.green, base.blue, 0.0));
                     }
-
                 } else {
                     // Intro complete - transition to fidget mode
                     next_state.set(AppState::Fidget);
@@ -373,6 +357,14 @@ impl Plugin for IntroPlugin {
             .init_resource::<IntroState>()
             // Setup intro UI at Startup (more reliable than OnEnter for initial state)
             .add_systems(Startup, setup_intro_ui)
+            // Rebuild the intro UI when the experience returns to the intro (kiosk replay)
+            .add_systems(
+                OnTransition {
+                    exited: AppState::Fidget,
+                    entered: AppState::Intro,
+                },
+                setup_intro_ui,
+            )
             // Update systems for intro sequence
             .add_systems(
                 Update,
@@ -0,0 +1,489 @@
+//! Module: kiosk
+//! Purpose: Opt-in idle and frame-stall watchdog for unattended installations
+//! Dependencies: components, resources, types, intro (optional), bevy::prelude
+
+use bevy::prelude::*;
+
+use crate::components::{Particle, ParticleState};
+#[cfg(feature = "intro")]
+use crate::intro::IntroState;
+use crate::resources::{ActState, MouseState, ParticlePool, ParticleSpawnQueue};
+#[cfg(feature = "intro")]
+use crate::types::AppState;
+use crate::types::{in_fidget_state, paced_by_real_time};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Pointer speed (world units per second) below which the cursor counts as idle.
+///
+/// Compared against the smoothed `MouseState.velocity`; 30 px/s is half a
+/// pixel per frame at 60 FPS.
+const IDLE_POINTER_SPEED_THRESHOLD: f32 = 30.0;
+
+/// Fraction of the particle cap kept after each stall recovery.
+const STALL_RECOVERY_QUALITY_FACTOR: f32 = 0.75;
+
+/// Lowest cap the stall recovery will reduce the pool to.
+const STALL_RECOVERY_MIN_ACTIVE: u32 = 1000;
+
+// =============================================================================
+// TYPES
+// =============================================================================
+
+/// What the kiosk watchdog does once the idle timeout elapses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KioskIdleAction {
+    /// Only send `KioskIdleTriggered`; leave the experience running
+    None,
+    /// Clear the field, restart the act cycle, and replay the intro (same as
+    /// `ResetField` without the `intro` feature)
+    #[default]
+    ReplayIntro,
+    /// Clear the field and restart the act cycle from Act I
+    ResetField,
+}
+
+// =============================================================================
+// EVENTS
+// =============================================================================
+
+/// Event sent when nobody has interacted for `KioskWatchdog.idle_timeout_secs`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct KioskIdleTriggered {
+    /// Action the watchdog is applying
+    pub action: KioskIdleAction,
+}
+
+/// Event sent when repeated stalled frames trigger a quality reduction.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct FrameStallRecovery {
+    /// Frame time of the stall that triggered recovery, in milliseconds
+    pub frame_time_ms: f32,
+    /// New `ParticlePool::active_cap` after the reduction
+    pub max_active: u32,
+}
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Configuration and running state for the unattended-kiosk watchdog.
+///
+/// Disabled by default. When enabled, the watchdog replays the intro or resets
+/// the field after a long idle period, and lowers particle quality if frames
+/// stall badly several times in a row.
+#[derive(Resource, Debug, Clone)]
+pub struct KioskWatchdog {
+    /// Whether the watchdog runs at all
+    pub enabled: bool,
+    /// Seconds without interaction before the idle action fires
+    pub idle_timeout_secs: f32,
+    /// Action applied when the idle timeout elapses
+    pub idle_action: KioskIdleAction,
+    /// Frame time above which a frame counts as stalled, in milliseconds
+    pub stall_threshold_ms: f32,
+    /// Consecutive stalled frames required before attempting recovery
+    pub stall_frames_before_recovery: u32,
+    /// Seconds of unstalled frames before a stall reduction is eased back
+    pub stall_restore_after_secs: f32,
+    /// Seconds since the last detected interaction
+    pub idle_elapsed_secs: f32,
+    /// Current run of consecutive stalled frames
+    pub consecutive_stalls: u32,
+    /// Seconds since the last stalled frame while a stall reduction holds
+    pub healthy_elapsed_secs: f32,
+}
+
+impl Default for KioskWatchdog {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            idle_timeout_secs: 300.0,
+            idle_action: KioskIdleAction::ReplayIntro,
+            stall_threshold_ms: 250.0,
+            stall_frames_before_recovery: 3,
+            stall_restore_after_secs: 60.0,
+            idle_elapsed_secs: 0.0,
+            consecutive_stalls: 0,
+            healthy_elapsed_secs: 0.0,
+        }
+    }
+}
+
+// =============================================================================
+// HELPER FUNCTIONS
+// =============================================================================
+
+/// Returns the reduced particle cap to use after a frame stall.
+///
+/// # Arguments
+/// * `max_active` - Current particle cap
+#[must_use]
+pub fn stall_recovery_max_active(max_active: u32) -> u32 {
+    let reduced = (max_active as f32 * STALL_RECOVERY_QUALITY_FACTOR) as u32;
+    reduced.max(STALL_RECOVERY_MIN_ACTIVE).min(max_active)
+}
+
+/// Returns the frame-stall ceiling one step back toward `max_active`, or
+/// `None` once the reduction is fully lifted.
+///
+/// # Arguments
+/// * `ceiling` - Current frame-stall ceiling
+/// * `max_active` - Configured particle cap
+#[must_use]
+pub fn stall_restore_max_active(ceiling: u32, max_active: u32) -> Option<u32> {
+    let raised = (ceiling as f32 / STALL_RECOVERY_QUALITY_FACTOR).ceil() as u32;
+    (raised < max_active).then_some(raised)
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Run condition: true when the kiosk watchdog is enabled.
+pub fn kiosk_watchdog_enabled(watchdog: Res<KioskWatchdog>) -> bool {
+    watchdog.enabled
+}
+
+/// Tracks time since the last interaction and fires the idle action.
+///
+/// Any pressed mouse button, pressed key, active touch, or pointer movement
+/// resets the idle timer.
+///
+/// # Stage
+/// Update
+pub fn track_kiosk_idle(
+    time: Res<Time>,
+    mouse: Res<MouseState>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    touches: Res<Touches>,
+    mut watchdog: ResMut<KioskWatchdog>,
+    mut idle_events: EventWriter<KioskIdleTriggered>,
+) {
+    let interacted = mouse_buttons.get_pressed().next().is_some()
+        || keys.get_pressed().next().is_some()
+        || touches.iter().next().is_some()
+        || mouse.velocity.length() > IDLE_POINTER_SPEED_THRESHOLD;
+
+    if interacted {
+        watchdog.idle_elapsed_secs = 0.0;
+        return;
+    }
+
+    watchdog.idle_elapsed_secs += time.delta_secs();
+
+    if watchdog.idle_elapsed_secs >= watchdog.idle_timeout_secs {
+        watchdog.idle_elapsed_secs = 0.0;
+        info!(
+            "Kiosk idle for {:.0}s, applying {:?}",
+            watchdog.idle_timeout_secs, watchdog.idle_action
+        );
+        idle_events.send(KioskIdleTriggered {
+            action: watchdog.idle_action,
+        });
+    }
+}
+
+/// Applies the configured idle action when `KioskIdleTriggered` fires.
+///
+/// Both `ResetField` and `ReplayIntro` return every active particle to the
+/// pool, clear pending spawns, and restart the act cycle. `ReplayIntro`
+/// additionally switches back to `AppState::Intro` when the `intro` feature
+/// is enabled.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs after `track_kiosk_idle`.
+pub fn apply_kiosk_idle_action(
+    mut idle_events: EventReader<KioskIdleTriggered>,
+    mut particles: Query<(Entity, &mut ParticleState, &mut Visibility), With<Particle>>,
+    mut pool: ResMut<ParticlePool>,
+    mut spawn_queue: ResMut<ParticleSpawnQueue>,
+    mut act_state: ResMut<ActState>,
+    #[cfg(feature = "intro")] mut intro_state: ResMut<IntroState>,
+    #[cfg(feature = "intro")] mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(action) = idle_events.read().last().map(|event| event.action) else {
+        return;
+    };
+
+    if action == KioskIdleAction::None {
+        return;
+    }
+
+    for (entity, mut state, mut visibility) in particles.iter_mut() {
+        if state.active {
+            state.active = false;
+            state.lifetime_remaining_ms = 0.0;
+            *visibility = Visibility::Hidden;
+            pool.available_entities.push(entity);
+        }
+    }
+    pool.active_count = 0;
+    spawn_queue.pending_spawns.clear();
+    *act_state = ActState {
+        completed_passes: act_state.completed_passes,
+        ..Default::default()
+    };
+
+    #[cfg(feature = "intro")]
+    if action == KioskIdleAction::ReplayIntro {
+        *intro_state = IntroState::default();
+        next_state.set(AppState::Intro);
+    }
+}
+
+/// Detects badly stalled frames and lowers particle quality to recover.
+///
+/// After `stall_frames_before_recovery` consecutive frames longer than
+/// `stall_threshold_ms`, logs a warning and lowers the pool's frame-stall
+/// ceiling below the cap in effect (see `stall_recovery_max_active`). Each
+/// `stall_restore_after_secs` without a stall raises the ceiling one step
+/// back (see `stall_restore_max_active`), so isolated hitches in a long-running
+/// kiosk do not ratchet the cap down for good.
+///
+/// Frame times come from `Time<Real>`; virtual time clamps long frames to
+/// `max_delta` and would hide the stalls.
+///
+/// # Stage
+/// Update
+pub fn monitor_frame_stalls(
+    time: Res<Time<Real>>,
+    mut watchdog: ResMut<KioskWatchdog>,
+    mut pool: ResMut<ParticlePool>,
+    mut recovery_events: EventWriter<FrameStallRecovery>,
+) {
+    let frame_time_ms = time.delta_secs() * 1000.0;
+
+    if frame_time_ms <= watchdog.stall_threshold_ms {
+        watchdog.consecutive_stalls = 0;
+        let Some(ceiling) = pool.ceilings.frame_stall else {
+            return;
+        };
+        watchdog.healthy_elapsed_secs += time.delta_secs();
+        if watchdog.healthy_elapsed_secs >= watchdog.stall_restore_after_secs {
+            watchdog.healthy_elapsed_secs = 0.0;
+            let restored = stall_restore_max_active(ceiling, pool.max_active);
+            pool.ceilings.frame_stall = restored;
+            info!(
+                "Frames healthy again, raising max active particles to {}",
+                restored.unwrap_or(pool.max_active)
+            );
+        }
+        return;
+    }
+
+    watchdog.healthy_elapsed_secs = 0.0;
+    watchdog.consecutive_stalls += 1;
+    warn!("Frame stalled for {:.0}ms", frame_time_ms);
+
+    if watchdog.consecutive_stalls >= watchdog.stall_frames_before_recovery {
+        watchdog.consecutive_stalls = 0;
+        let ceiling = stall_recovery_max_active(pool.active_cap());
+        pool.ceilings.frame_stall = Some(ceiling);
+        warn!(
+            "Repeated frame stalls, reducing max active particles to {}",
+            ceiling
+        );
+        recovery_events.send(FrameStallRecovery {
+            frame_time_ms,
+            max_active: ceiling,
+        });
+    }
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin providing the opt-in kiosk watchdog.
+///
+/// Registers the following systems (all gated on `KioskWatchdog.enabled`):
+/// - Update: track_kiosk_idle, apply_kiosk_idle_action (Fidget state only)
+/// - Update: monitor_frame_stalls (not while time is stepped manually)
+pub struct KioskPlugin;
+
+impl Plugin for KioskPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<KioskIdleTriggered>()
+            .add_event::<FrameStallRecovery>()
+            .init_resource::<KioskWatchdog>()
+            .add_systems(
+                Update,
+                (track_kiosk_idle, apply_kiosk_idle_action)
+                    .chain()
+                    .run_if(kiosk_watchdog_enabled)
+                    .run_if(in_fidget_state),
+            )
+            .add_systems(
+                Update,
+                monitor_frame_stalls
+                    .run_if(kiosk_watchdog_enabled)
+                    .run_if(paced_by_real_time),
+            );
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn watchdog_app(action: KioskIdleAction) -> App {
+        let mut app = App::new();
+        app.add_event::<KioskIdleTriggered>()
+            .init_resource::<Time>()
+            .init_resource::<MouseState>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Touches>()
+            .init_resource::<ParticlePool>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ActState>()
+            .insert_resource(KioskWatchdog {
+                enabled: true,
+                idle_timeout_secs: 10.0,
+                idle_action: action,
+                ..Default::default()
+            })
+            .add_systems(Update, (track_kiosk_idle, apply_kiosk_idle_action).chain());
+        #[cfg(feature = "intro")]
+        app.init_resource::<IntroState>()
+            .init_resource::<NextState<AppState>>();
+        app
+    }
+
+    fn advance(app: &mut App, seconds: u64) {
+        for _ in 0..seconds {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+        }
+    }
+
+    #[test]
+    fn test_kiosk_watchdog_disabled_by_default() {
+        assert!(!KioskWatchdog::default().enabled);
+    }
+
+    #[test]
+    fn test_idle_timeout_resets_field() {
+        let mut app = watchdog_app(KioskIdleAction::ResetField);
+
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 60_000.0,
+                    lifetime_total_ms: 60_000.0,
+                },
+                Visibility::Visible,
+            ))
+            .id();
+        app.world_mut().resource_mut::<ParticlePool>().active_count = 1;
+        app.world_mut()
+            .resource_mut::<ActState>()
+            .total_elapsed_seconds = 400.0;
+
+        // Just under the threshold nothing happens
+        advance(&mut app, 9);
+        assert!(app.world().get::<ParticleState>(particle).unwrap().active);
+
+        // Crossing the threshold clears the field and restarts the cycle
+        advance(&mut app, 1);
+        assert!(!app.world().get::<ParticleState>(particle).unwrap().active);
+        assert_eq!(app.world().resource::<ParticlePool>().active_count, 0);
+        assert_eq!(
+            app.world().resource::<ActState>().total_elapsed_seconds,
+            0.0
+        );
+    }
+
+    #[cfg(feature = "intro")]
+    #[test]
+    fn test_interaction_resets_idle_timer() {
+        let mut app = watchdog_app(KioskIdleAction::ReplayIntro);
+
+        // A slow drift (a few px/s) is not interaction
+        app.world_mut().resource_mut::<MouseState>().velocity = Vec2::new(5.0, 0.0);
+        advance(&mut app, 8);
+        app.world_mut().resource_mut::<MouseState>().velocity = Vec2::new(120.0, 0.0);
+        advance(&mut app, 1);
+        app.world_mut().resource_mut::<MouseState>().velocity = Vec2::ZERO;
+        advance(&mut app, 8);
+
+        assert!(matches!(
+            *app.world().resource::<NextState<AppState>>(),
+            NextState::Unchanged
+        ));
+
+        advance(&mut app, 2);
+        assert!(matches!(
+            *app.world().resource::<NextState<AppState>>(),
+            NextState::Pending(AppState::Intro)
+        ));
+    }
+
+    #[test]
+    fn test_stall_recovery_publishes_ceiling() {
+        let mut app = App::new();
+        app.add_event::<FrameStallRecovery>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<ParticlePool>()
+            .insert_resource(KioskWatchdog {
+                enabled: true,
+                ..Default::default()
+            })
+            .add_systems(Update, monitor_frame_stalls);
+
+        let frame = |app: &mut App, millis: u64| {
+            app.world_mut()
+                .resource_mut::<Time<Real>>()
+                .update_with_duration(Duration::from_millis(millis));
+            app.update();
+        };
+        // The first update only starts the real clock
+        frame(&mut app, 0);
+        for _ in 0..3 {
+            frame(&mut app, 400);
+        }
+
+        let pool = app.world().resource::<ParticlePool>();
+        assert_eq!(pool.max_active, 10_000);
+        assert_eq!(pool.ceilings.frame_stall, Some(7_500));
+        assert_eq!(pool.active_cap(), 7_500);
+
+        // A minute of healthy frames lifts the reduction again
+        for _ in 0..590 {
+            frame(&mut app, 100);
+        }
+        let pool = app.world().resource::<ParticlePool>();
+        assert_eq!(pool.ceilings.frame_stall, Some(7_500));
+        for _ in 0..20 {
+            frame(&mut app, 100);
+        }
+        assert_eq!(app.world().resource::<ParticlePool>().active_cap(), 10_000);
+    }
+
+    #[test]
+    fn test_stall_recovery_max_active() {
+        assert_eq!(stall_recovery_max_active(10_000), 7_500);
+        assert_eq!(stall_recovery_max_active(1_200), 1_000);
+        assert_eq!(stall_recovery_max_active(500), 500);
+
+        assert_eq!(stall_restore_max_active(1_000, 10_000), Some(1_334));
+        assert_eq!(stall_restore_max_active(7_500, 10_000), None);
+    }
+}
@@ -23,6 +23,14 @@
 //! - [`AudioReactivePlugin`]: Audio analysis and visual synchronization
 //! - [`InteractionPlugin`]: Mouse and keyboard input handling
 //! - [`PostProcessPlugin`]: Bloom, vignette, and chromatic aberration
+//! - [`KioskPlugin`]: Opt-in idle and frame-stall watchdog for installations
+//! - [`HeatmapPlugin`]: Decaying interaction heatmap with optional background glow
+//! - [`DemoReelPlugin`]: Opt-in looping scripted demo, interruptible by real input
+//! - [`MetricsPlugin`]: Frame-time measurements and adaptive quality
+//! - [`DebugOverlayPlugin`]: F3 HUD with live simulation stats, hidden by default
+//! - [`ScreenshotPlugin`]: F12 PNG capture, optionally supersampled (not on Android or the web)
+//! - [`OfflineRenderPlugin`]: Opt-in fixed-rate PNG sequence export (not on Android or the web)
+//! - [`WhirledPeasHeadlessPlugin`]: Windowless, seeded simulation for tests
 //!
 //! ## Usage
 //!
@@ -40,6 +48,14 @@
 //! }
 //! ```
 //!
+//! Host apps spawn peas by sending [`SpawnParticles`]. To observe the pool,
+//! read [`ParticleSpawned`] and [`ParticleDespawned`], which are only sent
+//! after inserting `ParticleLifecycleEvents { enabled: true }`:
+//!
+//! ```ignore
+//! app.insert_resource(whirled_peas::ParticleLifecycleEvents { enabled: true });
+//! ```
+//!
 //! ## The Five Acts
 //!
 //! 1. **Emergence** (0-3 min): Sparse peas drift slowly, touch creates new peas
@@ -63,7 +79,11 @@ pub mod components;
 /// Global ECS resources for application state and configuration.
 pub mod resources;
 
+/// RON configuration file overriding tunable defaults at startup.
+pub mod config;
+
 /// Five-act narrative structure and state transitions.
+#[cfg(feature = "acts")]
 pub mod act_management;
 
 /// Particle lifecycle, object pooling, motion, and rendering.
@@ -73,6 +93,7 @@ pub mod particle;
 pub mod trail;
 
 /// Audio analysis and visual synchronization systems.
+#[cfg(feature = "audio")]
 pub mod audio_reactive;
 
 /// Mouse and keyboard input handling per act.
@@ -82,45 +103,152 @@ pub mod interaction;
 pub mod visual;
 
 /// Post-processing effects: bloom, chromatic aberration, vignette, film grain.
+#[cfg(feature = "post_process")]
 pub mod post_process;
 
 /// Intro sequence with Bauhaus-styled splash screens.
+#[cfg(feature = "intro")]
 pub mod intro;
 
+/// Opt-in idle and frame-stall watchdog for unattended kiosks.
+pub mod kiosk;
+
+/// Decaying interaction heatmap for analytics and an optional background glow.
+pub mod heatmap;
+
+/// Looping scripted demo reel for unattended showcases.
+#[cfg(feature = "acts")]
+pub mod demo_reel;
+
+/// Microphone and WAV file capture with FFT band analysis.
+#[cfg(feature = "audio")]
+pub mod microphone;
+
+/// Frame-time measurements and the adaptive quality controller.
+pub mod metrics;
+
+/// F3 on-screen HUD with live simulation stats for tuning.
+pub mod debug_overlay;
+
+/// Windowless, fixed-step, seeded simulation for testing emergent behavior.
+#[cfg(all(feature = "acts", feature = "audio"))]
+pub mod headless;
+
+/// 3D simplex gradient noise for turbulence and flow fields.
+pub mod noise;
+
+/// Z-depth bands that fix the draw order of every spawned sprite.
+pub mod render_layers;
+
+/// Single-draw-call instanced particle rendering for `RenderMode::Instanced`.
+pub mod instancing;
+
+/// Full-screen post-process render passes (chromatic aberration, vignette, film grain).
+#[cfg(feature = "post_process")]
+pub mod screen_pass;
+
+/// CPU-rasterized particle snapshots for thumbnails and headless rendering.
+pub mod snapshot;
+
+/// OSC-over-UDP input feeding audio analysis from an external analyzer.
+pub mod osc;
+
+/// MIDI controller input for beats, interaction modes, pulses, and explosions.
+#[cfg(all(
+    feature = "midi",
+    not(any(target_os = "android", target_arch = "wasm32"))
+))]
+pub mod midi;
+
+/// F12 PNG screenshots of the rendered frame, optionally supersampled.
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+pub mod screenshot;
+
+/// Fixed-rate numbered PNG sequence export for offline video rendering.
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+pub mod offline_render;
+
 // =============================================================================
 // RE-EXPORTS
 // =============================================================================
 
 /// Re-export all types for convenient access.
 pub use types::{
-    Act, BeatStrength, FalloffType, FrequencyBand, InteractionMode, ParticleBehaviorType,
-    SpawnSource, ACT_BOUNDARIES_SECONDS, TOTAL_DURATION_SECONDS, TRANSITION_DURATION_MS,
+    Act, AppState, BeatStrength, BehaviorCoefficients, BlendMode, BloomComposite, ColorLerpSpace,
+    ExperienceLength, FadeProfile, FalloffType, FrequencyBand, GradientStyle, InteractionMode,
+    PaletteScheme, ParticleBehaviorType, RenderMode, SpawnSource, TrailStyle,
+    ACT_BOUNDARIES_SECONDS, MIN_EXPERIENCE_SECONDS, TOTAL_DURATION_SECONDS, TRANSITION_DURATION_MS,
 };
 
 /// Re-export key resources.
 pub use resources::{
-    ActState, ActTimings, AmbientAudioState, AudioAnalysis, AudioVisualMapping, BackgroundGradients,
-    ColorPalette, CurrentBackground, CurrentInteractionMode, InteractionConfig, InterpolatedActValues,
-    MotionTiming, MouseState, ParticlePool, ParticleSpawnQueue, ParticleSpawnRequest,
-    PerformanceMetrics, PostProcessSettings, ResourcesPlugin,
+    ActState, ActTimings, AdaptiveQuality, AmbientAudioState, AudioAnalysis, AudioVisualMapping,
+    BackgroundGradients, BaseInteractionMode, BeatSpawnConfig, BehaviorTuning, ColorInterpolation,
+    ColorPalette, CurrentBackground, CurrentInteractionMode, DensityOpacityConfig, DisplayScale,
+    ExperiencePaused, FadeProfiles, InkBudget, Intensity, InteractionConfig, InterpolatedActValues,
+    MagnetToy, MotionTiming, MouseState, PaintColorOverride, PaintConfig, PaletteConfig,
+    ParticlePool, ParticleRng, ParticleSpawnQueue, ParticleSpawnRequest, PerformanceMetrics,
+    PhysicsProfile, PostProcessSettings, Quietude, RadiusGrowth, RadiusGrowthProfile,
+    ResourcesPlugin, RngSeed, SimFrameBudget, SpawnBudgetConfig, TrailProfile, TrailProfiles,
 };
 
 /// Re-export key components.
 pub use components::{
-    Attractable, AudioReactive, BackgroundMarker, MouseInfluence, Particle, ParticleBehavior,
-    ParticleBundle, ParticleMotion, ParticleState, ParticleVisual, PulseResponder, Spawnable,
-    Trail, TrailRenderer, TrailSegment, ComponentsPlugin,
+    Attractable, Attractor, AttractorPoint, AudioReactive, BackgroundMarker, BreadcrumbMarker,
+    ComponentsPlugin, MagnetPole, MotionStreak, MouseInfluence, OrphanTrail, Particle,
+    ParticleBehavior, ParticleBundle, ParticleMotion, ParticleState, ParticleVisual,
+    PulseResponder, Spawnable, Trail, TrailRenderer, TrailSegment, WhirledCamera,
 };
 
 /// Re-export plugins for selective use.
-pub use act_management::ActManagementPlugin;
-pub use audio_reactive::AudioReactivePlugin;
-pub use interaction::InteractionPlugin;
-pub use intro::{AppState, IntroPlugin};
-pub use particle::ParticlePlugin;
+#[cfg(feature = "acts")]
+pub use act_management::{
+    ActManagementPlugin, ActScene, ActScenePath, ActTransitionCompleted, ActTransitionStarted,
+    AdvanceAct, ExperienceCompleted, GoToAct, SeekTo,
+};
+#[cfg(feature = "audio")]
+pub use audio_reactive::{AmbientAudioConfig, AudioReactivePlugin, Sfx};
+pub use config::{ConfigError, WhirledPeasConfig};
+pub use debug_overlay::{DebugOverlay, DebugOverlayPlugin};
+#[cfg(feature = "acts")]
+pub use demo_reel::{DemoAction, DemoCue, DemoReel, DemoReelPlugin};
+#[cfg(all(feature = "acts", feature = "audio"))]
+pub use headless::WhirledPeasHeadlessPlugin;
+pub use heatmap::{HeatmapPlugin, InteractionHeatmap, InteractionHeatmapConfig};
+pub use instancing::ParticleInstancingPlugin;
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+pub use interaction::CaptureScreenshot;
+pub use interaction::{
+    EraserOverride, ForcePolarity, InteractionPlugin, MultiTap, MultiTapAction, MultiTapDetector,
+    PointerFilter, ThreeFingerSwipe, TogglePause,
+};
+#[cfg(feature = "intro")]
+pub use intro::IntroPlugin;
+pub use kiosk::{KioskIdleAction, KioskPlugin, KioskWatchdog};
+pub use metrics::MetricsPlugin;
+#[cfg(feature = "audio")]
+pub use microphone::{AudioInputConfig, BandLevels, CaptureStatus, MicrophoneInput};
+#[cfg(all(
+    feature = "midi",
+    not(any(target_os = "android", target_arch = "wasm32"))
+))]
+pub use midi::{MidiInputConfig, MidiInputPlugin, MidiMapping};
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+pub use offline_render::{OfflineRenderConfig, OfflineRenderPlugin};
+pub use osc::{OscInput, OscInputConfig, OscInputPlugin};
+pub use particle::{
+    BeatDetected, ParticleDespawned, ParticleLifecycleEvents, ParticlePlugin, ParticleSpawned,
+    PoolExhausted, SpawnParticles,
+};
+#[cfg(feature = "post_process")]
 pub use post_process::PostProcessPlugin;
-pub use trail::TrailPlugin;
-pub use visual::VisualPlugin;
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+pub use screenshot::{ScreenshotConfig, ScreenshotPlugin};
+pub use snapshot::{collect_snapshot_points, render_snapshot, SnapshotConfig, SnapshotPoint};
+pub use trail::{OrphanTrailPool, TrailPlugin};
+pub use visual::{
+    AutoFrame, BackgroundGradientMaterial, ColorTemperatureDrift, SwitchPalette, VisualPlugin,
+};
 
 // =============================================================================
 // MAIN PLUGIN
@@ -137,13 +265,55 @@ pub use visual::VisualPlugin;
 /// Plugins are registered in dependency order:
 /// 1. Resources - Global state initialization
 /// 2. Components - Component type registration
-/// 3. Visual - Camera and rendering setup
-/// 4. Act Management - Narrative structure
-/// 5. Particle - Core particle systems
-/// 6. Trail - Particle trail rendering
-/// 7. Audio Reactive - Audio-visual synchronization
-/// 8. Interaction - User input handling
-/// 9. Post Process - Visual effects
+/// 3. Intro - Splash screens and `AppState` (`intro` feature)
+/// 4. Visual - Camera and rendering setup
+/// 5. Act Management - Narrative structure (`acts` feature)
+/// 6. Particle - Core particle systems
+/// 7. Trail - Particle trail rendering
+/// 8. Audio Reactive - Audio-visual synchronization (`audio` feature)
+/// 9. Interaction - User input handling
+/// 10. Post Process - Visual effects (`post_process` feature)
+/// 11. Kiosk - Opt-in unattended watchdog
+/// 12. Heatmap - Interaction heatmap and glow
+/// 13. Demo Reel - Opt-in scripted demo loop (`acts` feature)
+/// 14. Metrics - Frame-time measurements and adaptive quality
+/// 15. Debug Overlay - F3 stats HUD
+/// 16. Screenshot - F12 PNG capture (not on Android or the web)
+///
+/// # Cargo Features
+///
+/// These are enabled by default; turn off `default-features` to compile them out:
+/// - `acts`: five-act timeline, `ActScene`, and the demo reel
+/// - `audio`: beat detection, microphone/WAV capture, ambient audio, and sound
+///   effects (pulls in `rustfft`, `hound`, and `cpal`)
+/// - `intro`: splash screens; without it the app starts in `AppState::Fidget`
+/// - `post_process`: bloom tuning and the full-screen passes
+///
+/// The opt-in `midi` feature adds `MidiInputPlugin` (desktop only).
+///
+/// `WhirledPeasHeadlessPlugin` needs both `acts` and `audio`.
+///
+/// `ParticlePlugin` and `InteractionPlugin` also run on their own next to
+/// `ResourcesPlugin`: systems gated on `AppState::Fidget` treat a missing
+/// state as Fidget (see `in_fidget_state`), and input or trail resources
+/// they do not own are optional.
+///
+/// # Optional Sub-plugins
+///
+/// Every sub-plugin above is registered, but several stay inert until a host
+/// switches them on, so an embedding app pays nothing for them:
+/// - Kiosk: idle until `KioskWatchdog::enabled` is set
+/// - Heatmap: records, but draws no glow until `InteractionHeatmapConfig::render_glow`
+/// - Demo Reel: plays only while `DemoReel::enabled` is set
+/// - Debug Overlay: hidden until F3 toggles `DebugOverlay::visible`
+/// - Screenshot: captures only on F12 (`CaptureScreenshot`)
+///
+/// With the `audio` feature, Audio Reactive is always registered, since
+/// spawning and pulsing read its beats, but
+/// [`WhirledPeasPluginBuilder::enable_audio`] can keep it from ever playing
+/// sound. `MidiInputPlugin`, `OfflineRenderPlugin`, `OscInputPlugin`,
+/// `ParticleInstancingPlugin` (via `RenderMode::Instanced`), and
+/// `WhirledPeasHeadlessPlugin` are added separately.
 ///
 /// # Example
 ///
@@ -156,23 +326,288 @@ pub use visual::VisualPlugin;
 ///     .add_plugins(WhirledPeasPlugin)
 ///     .run();
 /// ```
+///
+/// To override tunable defaults from a RON file:
+///
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(WhirledPeasPlugin::with_config("config.ron"))
+///     .run();
+/// ```
+///
+/// Each of these returns a [`ConfiguredWhirledPeasPlugin`]. For reproducible
+/// runs (screenshots, replays), seed the particle RNG; `with_seed` chains onto
+/// any constructor:
+///
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(WhirledPeasPlugin::with_config("config.ron").with_seed(42))
+///     .run();
+/// ```
+///
+/// For very dense swarms (up to 50k peas), draw them in one instanced call:
+///
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(WhirledPeasPlugin::with_render_mode(RenderMode::Instanced))
+///     .run();
+/// ```
+///
+/// To embed the visualizer in a larger app, combine overrides with the builder:
+///
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(
+///         WhirledPeasPlugin::builder()
+///             .pool_capacity(3000)
+///             .max_active(2000)
+///             .enable_audio(false)
+///             .palette(ColorPalette::preset("Aurora").unwrap())
+///             .build(),
+///     )
+///     .run();
+/// ```
 pub struct WhirledPeasPlugin;
 
+impl WhirledPeasPlugin {
+    /// Creates the plugin with a [`WhirledPeasConfig`] loaded from `path`.
+    ///
+    /// A missing or malformed file logs a warning and changes nothing.
+    #[must_use]
+    pub fn with_config(path: impl Into<std::path::PathBuf>) -> ConfiguredWhirledPeasPlugin {
+        ConfiguredWhirledPeasPlugin {
+            config_path: Some(path.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Seeds `ParticleRng` from `seed`, so spawn colors, jitter, stroke
+    /// seeds, and turbulence replay identically.
+    #[must_use]
+    pub fn with_seed(self, seed: u64) -> ConfiguredWhirledPeasPlugin {
+        ConfiguredWhirledPeasPlugin::default().with_seed(seed)
+    }
+
+    /// Creates the plugin drawing particles with `render_mode`.
+    ///
+    /// `RenderMode::Instanced` draws every pea in one call and sizes the
+    /// pool for 50k active particles.
+    #[must_use]
+    pub fn with_render_mode(render_mode: RenderMode) -> ConfiguredWhirledPeasPlugin {
+        ConfiguredWhirledPeasPlugin {
+            render_mode,
+            ..Default::default()
+        }
+    }
+
+    /// Starts a [`WhirledPeasPluginBuilder`] from the default settings.
+    #[must_use]
+    pub fn builder() -> WhirledPeasPluginBuilder {
+        WhirledPeasPluginBuilder::default()
+    }
+}
+
 impl Plugin for WhirledPeasPlugin {
     fn build(&self, app: &mut App) {
-        // Register all sub-plugins in correct dependency order
-        app.add_plugins((
-            ResourcesPlugin,
-            ComponentsPlugin,
-            IntroPlugin,      // Intro first - manages AppState
-            VisualPlugin,
-            ActManagementPlugin,
-            ParticlePlugin,
-            TrailPlugin,
-            AudioReactivePlugin,
-            InteractionPlugin,
-            PostProcessPlugin,
-        ));
+        ConfiguredWhirledPeasPlugin::default().build(app);
+    }
+}
+
+/// [`WhirledPeasPlugin`] with a config file, seed, render mode, or builder
+/// overrides.
+///
+/// Created by [`WhirledPeasPlugin::with_config`],
+/// [`WhirledPeasPlugin::with_render_mode`], [`WhirledPeasPlugin::with_seed`],
+/// or [`WhirledPeasPluginBuilder::build`]; registers the same sub-plugins.
+#[derive(Debug, Clone, Default)]
+pub struct ConfiguredWhirledPeasPlugin {
+    /// Optional RON or TOML file whose values override the built-in defaults
+    pub config_path: Option<std::path::PathBuf>,
+    /// Optional seed for `ParticleRng`; `None` seeds from entropy
+    pub seed: Option<u64>,
+    /// How particles are drawn
+    pub render_mode: RenderMode,
+    /// How overlapping peas combine; `Additive` selects `RenderMode::Instanced`
+    pub blend_mode: BlendMode,
+    /// Pre-allocated particle entities; `None` keeps the render mode's default
+    pub pool_capacity: Option<u32>,
+    /// Simultaneously active particles; `None` keeps the render mode's default
+    pub max_active: Option<u32>,
+    /// Never play ambient audio or sound effects (no effect without `audio`)
+    pub disable_audio: bool,
+    /// Starting palette; `None` keeps `ColorPalette::default()`
+    pub palette: Option<ColorPalette>,
+}
+
+impl ConfiguredWhirledPeasPlugin {
+    /// Seeds `ParticleRng` from `seed`, keeping the other settings.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Writes the config file, builder overrides, and seed into the
+    /// resources the sub-plugins registered; the code wins over the file.
+    fn apply_settings(&self, world: &mut World) {
+        if let Some(path) = &self.config_path {
+            WhirledPeasConfig::load_or_default(path).apply(world);
+        }
+        self.apply_overrides(world);
+
+        if let Some(seed) = self.seed {
+            world.insert_resource(RngSeed(seed));
+            world.insert_resource(ParticleRng::with_seed(seed));
+        }
+    }
+
+    /// Writes the builder overrides into the resources the sub-plugins
+    /// registered.
+    fn apply_overrides(&self, world: &mut World) {
+        if let Some(mut pool) = world.get_resource_mut::<ParticlePool>() {
+            if let Some(capacity) = self.pool_capacity {
+                pool.pool_capacity = capacity;
+            }
+            if let Some(max_active) = self.max_active {
+                pool.max_active = max_active;
+            }
+            pool.max_active = pool.max_active.min(pool.pool_capacity);
+        }
+
+        // Keep the budget controller from raising the cap past the override
+        if let Some(max_active) = self.max_active {
+            if let Some(mut budget) = world.get_resource_mut::<SimFrameBudget>() {
+                budget.ceiling_active = budget.ceiling_active.min(max_active);
+            }
+        }
+
+        #[cfg(feature = "audio")]
+        if self.disable_audio {
+            world.insert_resource(audio_reactive::AudioDisabled);
+        }
+
+        if let Some(palette) = &self.palette {
+            world.insert_resource(palette.clone());
+        }
+    }
+}
+
+/// Builder for a [`ConfiguredWhirledPeasPlugin`] with custom settings.
+///
+/// Every setting left alone keeps the zero-argument plugin's default.
+/// Overrides are written into their resources while the plugin builds,
+/// before any startup system sizes the pool or picks colors.
+#[derive(Debug, Clone, Default)]
+pub struct WhirledPeasPluginBuilder {
+    plugin: ConfiguredWhirledPeasPlugin,
+}
+
+impl WhirledPeasPluginBuilder {
+    /// Loads a [`WhirledPeasConfig`] from `path`; builder settings win over it.
+    #[must_use]
+    pub fn config(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.plugin.config_path = Some(path.into());
+        self
+    }
+
+    /// Seeds `ParticleRng` for reproducible runs.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.plugin.seed = Some(seed);
+        self
+    }
+
+    /// Draws particles with `render_mode`.
+    #[must_use]
+    pub fn render_mode(mut self, render_mode: RenderMode) -> Self {
+        self.plugin.render_mode = render_mode;
+        self
+    }
+
+    /// Blends peas with `blend_mode`; `BlendMode::Additive` makes dense
+    /// clusters glow and switches to `RenderMode::Instanced`, the only
+    /// renderer that can change blend state.
+    #[must_use]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.plugin.blend_mode = blend_mode;
+        self
+    }
+
+    /// Pre-allocates `capacity` particle entities instead of the default pool.
+    #[must_use]
+    pub fn pool_capacity(mut self, capacity: u32) -> Self {
+        self.plugin.pool_capacity = Some(capacity);
+        self
+    }
+
+    /// Caps simultaneously active particles; never above the pool capacity.
+    #[must_use]
+    pub fn max_active(mut self, max_active: u32) -> Self {
+        self.plugin.max_active = Some(max_active);
+        self
+    }
+
+    /// With `false`, ambient audio and sound effects never play, so the
+    /// visualizer never takes audio focus from the host app.
+    #[must_use]
+    pub fn enable_audio(mut self, enabled: bool) -> Self {
+        self.plugin.disable_audio = !enabled;
+        self
+    }
+
+    /// Starts with `palette` instead of `ColorPalette::default()`.
+    #[must_use]
+    pub fn palette(mut self, palette: ColorPalette) -> Self {
+        self.plugin.palette = Some(palette);
+        self
+    }
+
+    /// Returns the configured plugin.
+    #[must_use]
+    pub fn build(self) -> ConfiguredWhirledPeasPlugin {
+        self.plugin
+    }
+}
+
+impl Plugin for ConfiguredWhirledPeasPlugin {
+    fn build(&self, app: &mut App) {
+        // Read by `ParticlePlugin` while it builds
+        app.insert_resource(self.render_mode)
+            .insert_resource(self.blend_mode);
+
+        // Register all sub-plugins in correct dependency order; feature-gated
+        // ones are skipped when compiled out
+        app.add_plugins((ResourcesPlugin, ComponentsPlugin));
+
+        // Intro first - manages AppState. Without it the experience starts directly.
+        #[cfg(feature = "intro")]
+        app.add_plugins(IntroPlugin);
+        #[cfg(not(feature = "intro"))]
+        app.insert_state(AppState::Fidget);
+
+        app.add_plugins(VisualPlugin);
+        #[cfg(feature = "acts")]
+        app.add_plugins(ActManagementPlugin);
+        app.add_plugins((ParticlePlugin, TrailPlugin));
+        #[cfg(feature = "audio")]
+        app.add_plugins(AudioReactivePlugin);
+        app.add_plugins(InteractionPlugin);
+        #[cfg(feature = "post_process")]
+        app.add_plugins(PostProcessPlugin);
+        app.add_plugins((KioskPlugin, HeatmapPlugin));
+        #[cfg(feature = "acts")]
+        app.add_plugins(DemoReelPlugin);
+        app.add_plugins((MetricsPlugin, DebugOverlayPlugin));
+
+        #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+        app.add_plugins(screenshot::ScreenshotPlugin);
+
+        // Sub-plugins have registered their resources; override them before Startup
+        self.apply_settings(app.world_mut());
 
         info!("Whirled Peas Visualiser initialized - a wordless poem in light and sound");
     }
@@ -314,4 +749,99 @@ mod tests {
         // Verify the plugin struct exists and can be instantiated
         let _plugin = WhirledPeasPlugin;
     }
+
+    #[test]
+    fn test_constructors_keep_config_path_and_seed() {
+        let configured = WhirledPeasPlugin::with_config("config.ron");
+        assert_eq!(
+            configured.config_path.as_deref(),
+            Some(std::path::Path::new("config.ron"))
+        );
+
+        // A seed chains onto any constructor without dropping its settings
+        let seeded = WhirledPeasPlugin::with_config("config.ron").with_seed(42);
+        assert_eq!(seeded.config_path, configured.config_path);
+        assert_eq!(seeded.seed, Some(42));
+        assert_eq!(WhirledPeasPlugin.with_seed(7).seed, Some(7));
+    }
+
+    #[test]
+    fn test_config_keeps_instanced_pool_sizes() {
+        let path = config::unique_temp_path("whirled_peas_plugin_instanced_config.ron");
+        std::fs::write(&path, "(interaction: (explosion_force: 1200.0))").unwrap();
+        let plugin = ConfiguredWhirledPeasPlugin {
+            render_mode: RenderMode::Instanced,
+            ..WhirledPeasPlugin::with_config(&path)
+        };
+
+        let mut app = App::new();
+        app.insert_resource(plugin.render_mode)
+            .add_plugins(ParticlePlugin)
+            .init_resource::<InteractionConfig>();
+        plugin.apply_settings(app.world_mut());
+        std::fs::remove_file(&path).ok();
+
+        let pool = app.world().resource::<ParticlePool>();
+        assert_eq!(pool.pool_capacity, instancing::INSTANCED_POOL_CAPACITY);
+        assert_eq!(pool.max_active, instancing::INSTANCED_MAX_ACTIVE);
+        assert_eq!(
+            app.world().resource::<InteractionConfig>().explosion_force,
+            1200.0
+        );
+    }
+
+    #[test]
+    fn test_builder_defaults_match_zero_arg_plugin() {
+        let built = WhirledPeasPlugin::builder().build();
+        let plain = ConfiguredWhirledPeasPlugin::default();
+        assert_eq!(built.pool_capacity, plain.pool_capacity);
+        assert_eq!(built.max_active, plain.max_active);
+        assert_eq!(built.disable_audio, plain.disable_audio);
+        assert!(built.palette.is_none());
+
+        let mut world = World::new();
+        world.init_resource::<ParticlePool>();
+        built.apply_overrides(&mut world);
+        let pool = world.resource::<ParticlePool>();
+        assert_eq!(pool.pool_capacity, ParticlePool::default().pool_capacity);
+        assert_eq!(pool.max_active, ParticlePool::default().max_active);
+        #[cfg(feature = "audio")]
+        assert!(!world.contains_resource::<audio_reactive::AudioDisabled>());
+    }
+
+    #[test]
+    fn test_builder_values_land_in_resources() {
+        let aurora = ColorPalette::preset("Aurora").unwrap();
+        let plugin = WhirledPeasPlugin::builder()
+            .pool_capacity(3000)
+            .max_active(2000)
+            .enable_audio(false)
+            .palette(aurora.clone())
+            .seed(9)
+            .build();
+
+        let mut world = World::new();
+        world.init_resource::<ParticlePool>();
+        world.init_resource::<SimFrameBudget>();
+        world.init_resource::<ColorPalette>();
+        plugin.apply_overrides(&mut world);
+
+        let pool = world.resource::<ParticlePool>();
+        assert_eq!(pool.pool_capacity, 3000);
+        assert_eq!(pool.max_active, 2000);
+        assert_eq!(world.resource::<SimFrameBudget>().ceiling_active, 2000);
+        #[cfg(feature = "audio")]
+        assert!(world.contains_resource::<audio_reactive::AudioDisabled>());
+        assert_eq!(*world.resource::<ColorPalette>(), aurora);
+        assert_eq!(plugin.seed, Some(9));
+    }
+
+    #[test]
+    fn test_builder_max_active_never_exceeds_pool() {
+        let plugin = WhirledPeasPlugin::builder().pool_capacity(500).build();
+        let mut world = World::new();
+        world.init_resource::<ParticlePool>();
+        plugin.apply_overrides(&mut world);
+        assert_eq!(world.resource::<ParticlePool>().max_active, 500);
+    }
 }
@@ -6,13 +6,17 @@
 
 use bevy::prelude::*;
 
-#[cfg(not(target_os = "android"))]
-use bevy::render::settings::{RenderCreation, WgpuSettings};
-#[cfg(not(target_os = "android"))]
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+use bevy::render::settings::{Backends, RenderCreation, WgpuSettings};
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
 use bevy::render::RenderPlugin;
 
 use whirled_peas::WhirledPeasPlugin;
 
+/// Optional config file read from the working directory at startup (not on the web).
+#[cfg(not(target_arch = "wasm32"))]
+const CONFIG_PATH: &str = "config.ron";
+
 /// Application entry point.
 ///
 /// On desktop: Initializes the Bevy app with custom window configuration
@@ -20,6 +24,10 @@ use whirled_peas::WhirledPeasPlugin;
 ///
 /// On Android: Uses the native window provided by GameActivity with
 /// automatic rendering backend selection.
+///
+/// On the web (`wasm32`): Renders into the `#whirled-peas` canvas with WebGL2,
+/// or with WebGPU when built with the `webgpu` feature. The backend is fixed
+/// at compile time; a `webgpu` build does not fall back to WebGL2.
 #[bevy_main]
 fn main() {
     let mut app = App::new();
@@ -42,7 +50,26 @@ fn main() {
         );
     }
 
-    #[cfg(not(target_os = "android"))]
+    #[cfg(target_arch = "wasm32")]
+    {
+        // Web: Fill the host page's canvas; the `webgpu` feature picks the backend
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: "Whirled Peas Visualiser: A Wordless Poem in Light and Sound".into(),
+                        canvas: Some("#whirled-peas".into()),
+                        fit_canvas_to_parent: true,
+                        prevent_default_event_handling: true,
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(ImagePlugin::default_nearest()),
+        );
+    }
+
+    #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
     {
         // Desktop: Custom window and render configuration
         app.add_plugins(
@@ -59,7 +86,7 @@ fn main() {
                 })
                 .set(RenderPlugin {
                     render_creation: RenderCreation::Automatic(WgpuSettings {
-                        backends: Some(bevy::render::settings::Backends::VULKAN),
+                        backends: Some(Backends::VULKAN),
                         ..default()
                     }),
                     ..default()
@@ -68,5 +95,14 @@ fn main() {
         );
     }
 
-    app.add_plugins(WhirledPeasPlugin).run();
+    // The browser sandbox has no working directory to read a config file from
+    #[cfg(target_arch = "wasm32")]
+    app.add_plugins(WhirledPeasPlugin);
+    #[cfg(not(target_arch = "wasm32"))]
+    if std::path::Path::new(CONFIG_PATH).exists() {
+        app.add_plugins(WhirledPeasPlugin::with_config(CONFIG_PATH));
+    } else {
+        app.add_plugins(WhirledPeasPlugin);
+    }
+    app.run();
 }
@@ -0,0 +1,278 @@
+//! Module: metrics
+//! Purpose: Fills `PerformanceMetrics` frame timings from Bevy's frame-time diagnostics
+//! Dependencies: resources, bevy::diagnostic
+
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+
+use crate::resources::{AdaptiveQuality, ParticlePool, PerformanceMetrics, PostProcessSettings};
+use crate::types::paced_by_real_time;
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Copies the latest FPS and frame time from `DiagnosticsStore` into
+/// `PerformanceMetrics`, advancing its slow-frame streak.
+///
+/// Frames before the diagnostics have a measurement are skipped.
+///
+/// # Stage
+/// First
+pub fn update_frame_metrics(
+    diagnostics: Res<DiagnosticsStore>,
+    mut metrics: ResMut<PerformanceMetrics>,
+) {
+    let latest = |path: &DiagnosticPath| {
+        diagnostics
+            .get(path)
+            .and_then(|diagnostic| diagnostic.value())
+    };
+    let (Some(fps), Some(frame_time_ms)) = (
+        latest(&FrameTimeDiagnosticsPlugin::FPS),
+        latest(&FrameTimeDiagnosticsPlugin::FRAME_TIME),
+    ) else {
+        return;
+    };
+
+    metrics.record_frame(fps as f32, frame_time_ms as f32);
+}
+
+/// Sheds or restores particles and heavy post effects from the measured frame rate.
+///
+/// See `AdaptiveQuality` for the ramp rates and hysteresis. The particle cap
+/// is published as `ParticlePool.ceilings.adaptive_quality`. Only writes
+/// `ParticlePool` and `PostProcessSettings` when a value actually changes.
+///
+/// # Ordering
+/// Runs after `update_frame_metrics`, only while the controller is enabled
+/// and time follows the wall clock (see `paced_by_real_time`).
+pub fn apply_adaptive_quality(
+    time: Res<Time>,
+    metrics: Res<PerformanceMetrics>,
+    mut quality: ResMut<AdaptiveQuality>,
+    mut pool: ResMut<ParticlePool>,
+    mut post_process: ResMut<PostProcessSettings>,
+) {
+    let was_reduced = quality.effects_reduced;
+    let current = pool.ceilings.adaptive_quality.unwrap_or(pool.max_active);
+    let ceiling = quality.step(metrics.current_fps, time.delta_secs(), current);
+    if pool.ceilings.adaptive_quality != Some(ceiling) {
+        pool.ceilings.adaptive_quality = Some(ceiling);
+    }
+
+    if quality.effects_reduced != post_process.reduced_effects {
+        post_process.reduced_effects = quality.effects_reduced;
+    }
+    if quality.effects_reduced != was_reduced {
+        info!(
+            "Adaptive quality: film grain and chromatic aberration {}",
+            if quality.effects_reduced {
+                "off"
+            } else {
+                "restored"
+            }
+        );
+    }
+}
+
+/// Condition function for run_if: returns true when adaptive quality is on.
+pub fn adaptive_quality_enabled(quality: Res<AdaptiveQuality>) -> bool {
+    quality.enabled
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that keeps `PerformanceMetrics` frame timings current and runs
+/// adaptive quality from them.
+///
+/// Adds `FrameTimeDiagnosticsPlugin` unless the host app already has it.
+/// `particle_update_time_ms` is measured by `ParticlePlugin`.
+///
+/// # Systems
+/// - `update_frame_metrics` (First): Records FPS and frame time each frame
+/// - `apply_adaptive_quality` (First, after update_frame_metrics): Adjusts the particle cap
+pub struct MetricsPlugin;
+
+impl Plugin for MetricsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+
+        app.add_systems(
+            First,
+            (
+                update_frame_metrics,
+                apply_adaptive_quality
+                    .run_if(adaptive_quality_enabled)
+                    .run_if(paced_by_real_time),
+            )
+                .chain(),
+        );
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::diagnostic::{Diagnostic, RegisterDiagnostic};
+
+    #[test]
+    fn test_frame_metrics_follow_diagnostics() {
+        let mut app = App::new();
+        app.init_resource::<PerformanceMetrics>()
+            .register_diagnostic(Diagnostic::new(FrameTimeDiagnosticsPlugin::FPS))
+            .register_diagnostic(Diagnostic::new(FrameTimeDiagnosticsPlugin::FRAME_TIME))
+            .add_systems(Update, update_frame_metrics);
+
+        // No measurements yet: defaults are kept
+        app.update();
+        assert_eq!(
+            app.world().resource::<PerformanceMetrics>().current_fps,
+            60.0
+        );
+
+        {
+            let mut store = app.world_mut().resource_mut::<DiagnosticsStore>();
+            store
+                .get_mut(&FrameTimeDiagnosticsPlugin::FPS)
+                .unwrap()
+                .add_measurement(measurement(30.0));
+            store
+                .get_mut(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+                .unwrap()
+                .add_measurement(measurement(33.0));
+        }
+        app.update();
+
+        let metrics = app.world().resource::<PerformanceMetrics>();
+        assert_eq!(metrics.current_fps, 30.0);
+        assert_eq!(metrics.frame_time_ms, 33.0);
+        assert_eq!(metrics.slow_frame_streak, 1);
+    }
+
+    fn quality_app(fps: f32) -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(PerformanceMetrics {
+                current_fps: fps,
+                ..Default::default()
+            })
+            .init_resource::<AdaptiveQuality>()
+            .init_resource::<ParticlePool>()
+            .init_resource::<PostProcessSettings>()
+            .add_systems(Update, apply_adaptive_quality);
+        app
+    }
+
+    fn run_seconds(app: &mut App, seconds: u32) {
+        for _ in 0..seconds * 10 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(100));
+            app.update();
+        }
+    }
+
+    fn set_fps(app: &mut App, fps: f32) {
+        app.world_mut()
+            .resource_mut::<PerformanceMetrics>()
+            .current_fps = fps;
+    }
+
+    #[test]
+    fn test_adaptive_quality_ramps_down_gradually() {
+        let mut app = quality_app(40.0);
+        let ceiling = app.world().resource::<ParticlePool>().active_cap();
+
+        // The first second only establishes that the drop is sustained
+        run_seconds(&mut app, 1);
+        let after_sustain = app.world().resource::<ParticlePool>().active_cap();
+        assert!(ceiling - after_sustain <= 50);
+
+        // Then roughly 500 particles per second come off
+        run_seconds(&mut app, 2);
+        let reduced = app.world().resource::<ParticlePool>().active_cap();
+        let shed = after_sustain - reduced;
+        assert!((950..=1050).contains(&shed), "shed {shed}");
+
+        // Never below the floor
+        run_seconds(&mut app, 60);
+        let quality = app.world().resource::<AdaptiveQuality>().clone();
+        assert_eq!(
+            app.world().resource::<ParticlePool>().active_cap(),
+            quality.min_active
+        );
+
+        // Not severe, so the effects stay on
+        assert!(
+            !app.world()
+                .resource::<PostProcessSettings>()
+                .reduced_effects
+        );
+    }
+
+    #[test]
+    fn test_adaptive_quality_hysteresis_and_recovery() {
+        let mut app = quality_app(40.0);
+        let ceiling = app.world().resource::<ParticlePool>().active_cap();
+        run_seconds(&mut app, 4);
+        let reduced = app.world().resource::<ParticlePool>().active_cap();
+        assert!(reduced < ceiling);
+
+        // Inside the hysteresis band the cap holds
+        set_fps(&mut app, 50.0);
+        run_seconds(&mut app, 5);
+        assert_eq!(app.world().resource::<ParticlePool>().active_cap(), reduced);
+
+        // Healthy frame rate restores the cap up to the ceiling
+        set_fps(&mut app, 60.0);
+        run_seconds(&mut app, 2);
+        let restoring = app.world().resource::<ParticlePool>().active_cap();
+        assert!(restoring > reduced);
+        run_seconds(&mut app, 60);
+        assert_eq!(app.world().resource::<ParticlePool>().active_cap(), ceiling);
+    }
+
+    #[test]
+    fn test_severe_load_sheds_heavy_effects() {
+        let mut app = quality_app(20.0);
+        run_seconds(&mut app, 2);
+        assert!(
+            app.world()
+                .resource::<PostProcessSettings>()
+                .reduced_effects
+        );
+
+        // Effects stay off until the frame rate fully recovers
+        set_fps(&mut app, 50.0);
+        run_seconds(&mut app, 2);
+        assert!(
+            app.world()
+                .resource::<PostProcessSettings>()
+                .reduced_effects
+        );
+
+        set_fps(&mut app, 60.0);
+        run_seconds(&mut app, 2);
+        assert!(
+            !app.world()
+                .resource::<PostProcessSettings>()
+                .reduced_effects
+        );
+    }
+
+    fn measurement(value: f64) -> bevy::diagnostic::DiagnosticMeasurement {
+        bevy::diagnostic::DiagnosticMeasurement {
+            time: bevy::utils::Instant::now(),
+            value,
+        }
+    }
+}
@@ -0,0 +1,701 @@
+//! Module: microphone
+//! Purpose: Live microphone (or WAV file) capture with FFT band analysis for `AudioAnalysis`
+//! Dependencies: resources, cpal, hound, rustfft, bevy::prelude
+//!
+//! Capture and analysis run on a dedicated thread so the Bevy main loop never
+//! waits on the audio device. The thread windows the incoming samples, runs an
+//! FFT every half-window, and sends `BandLevels` through a channel that
+//! `process_audio_input` drains each frame. If the device cannot be opened or
+//! disconnects mid-session, `MicrophoneInput` reports `CaptureStatus::Failed`
+//! and the procedural generator takes over again. Web builds (`wasm32`) have
+//! no capture threads, so every non-procedural source fails the same way.
+//!
+//! Bands follow the ranges documented on `AudioAnalysis`: bass 20-150 Hz,
+//! mid 150-4000 Hz, high 4000-12000 Hz, shimmer 12000-20000 Hz. Rather than
+//! resampling, bin-to-band mapping uses the device's actual sample rate, so a
+//! 16 kHz device simply reports no shimmer above its Nyquist limit.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+
+use crate::resources::AudioAnalysis;
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Band edges in Hz: bass, mid, high, and shimmer lie between consecutive entries.
+pub const BAND_EDGES_HZ: [f32; 5] = [20.0, 150.0, 4000.0, 12000.0, 20000.0];
+
+/// Default FFT window length in samples.
+const DEFAULT_FFT_SIZE: usize = 2048;
+
+/// Default level (dBFS) that maps to a band level of zero.
+const DEFAULT_FLOOR_DB: f32 = -60.0;
+
+/// How long the capture thread waits for samples before checking for shutdown.
+#[cfg_attr(any(target_os = "android", target_arch = "wasm32"), allow(dead_code))]
+const CAPTURE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Seconds without samples from the device before it counts as disconnected.
+#[cfg_attr(any(target_os = "android", target_arch = "wasm32"), allow(dead_code))]
+const CAPTURE_STALL_SECS: f32 = 2.0;
+
+// =============================================================================
+// TYPES
+// =============================================================================
+
+/// Where `AudioAnalysis` gets its data.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum AudioSource {
+    /// Procedurally generated levels (no audio hardware needed)
+    #[default]
+    Procedural,
+    /// The default input device, via `cpal`
+    Microphone,
+    /// A WAV file on disk, analyzed in real time and looped
+    File(PathBuf),
+}
+
+/// Per-band levels from one FFT window, each in 0.0-1.0.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BandLevels {
+    /// Bass energy (20-150 Hz)
+    pub bass: f32,
+    /// Mid energy (150-4000 Hz)
+    pub mid: f32,
+    /// High energy (4000-12000 Hz)
+    pub high: f32,
+    /// Shimmer energy (12000-20000 Hz)
+    pub shimmer: f32,
+}
+
+impl BandLevels {
+    /// Writes these levels into the frequency and amplitude fields of `analysis`.
+    pub fn apply_to(&self, analysis: &mut AudioAnalysis) {
+        analysis.frequency_bass = self.bass;
+        analysis.frequency_mid = self.mid;
+        analysis.frequency_high = self.high;
+        analysis.frequency_shimmer = self.shimmer;
+        analysis.amplitude_low = self.bass;
+        analysis.amplitude_mid = self.mid;
+        analysis.amplitude_high = self.high.max(self.shimmer);
+        analysis.amplitude_peak = analysis
+            .amplitude_low
+            .max(analysis.amplitude_mid)
+            .max(analysis.amplitude_high);
+    }
+}
+
+/// State of the capture thread as seen from the main loop.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum CaptureStatus {
+    /// No capture requested (procedural source)
+    #[default]
+    Idle,
+    /// Capture thread started but no levels received yet
+    Starting,
+    /// Levels are arriving
+    Running,
+    /// The device or file failed; procedural data is used instead
+    Failed(String),
+}
+
+/// Message from the capture thread to the main loop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureMessage {
+    /// Levels from the most recent FFT window
+    Levels(BandLevels),
+    /// Capture stopped for good; the reason is logged
+    Disconnected(String),
+}
+
+/// Message from a device callback to the capture thread.
+#[cfg_attr(any(target_os = "android", target_arch = "wasm32"), allow(dead_code))]
+enum CaptureEvent {
+    /// Mono samples in -1.0..=1.0
+    Samples(Vec<f32>),
+    /// The stream reported an error
+    Error(String),
+}
+
+/// Windowed FFT that bins spectral energy into the four `AudioAnalysis` bands.
+///
+/// Samples are buffered until a full window is available, then analyzed every
+/// half-window (50% overlap) with a Hann window.
+pub struct SpectrumAnalyzer {
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    window_sum: f32,
+    buffer: Vec<Complex<f32>>,
+    history: Vec<f32>,
+    sample_rate: u32,
+    floor_db: f32,
+}
+
+impl SpectrumAnalyzer {
+    /// Creates an analyzer for `fft_size`-sample windows at `sample_rate` Hz.
+    #[must_use]
+    pub fn new(fft_size: usize, sample_rate: u32, floor_db: f32) -> Self {
+        let fft_size = fft_size.max(2);
+        let window: Vec<f32> = (0..fft_size)
+            .map(|i| {
+                let phase = std::f32::consts::TAU * i as f32 / fft_size as f32;
+                0.5 - 0.5 * phase.cos()
+            })
+            .collect();
+        let window_sum = window.iter().sum::<f32>().max(f32::EPSILON);
+
+        Self {
+            fft: FftPlanner::<f32>::new().plan_fft_forward(fft_size),
+            window,
+            window_sum,
+            buffer: vec![Complex::default(); fft_size],
+            history: Vec::with_capacity(fft_size * 2),
+            sample_rate: sample_rate.max(1),
+            floor_db: floor_db.min(-1.0),
+        }
+    }
+
+    /// Number of new samples between successive analyses.
+    #[must_use]
+    pub fn hop_len(&self) -> usize {
+        (self.window.len() / 2).max(1)
+    }
+
+    /// Buffers `samples` and returns the levels of the newest complete window, if any.
+    pub fn push(&mut self, samples: &[f32]) -> Option<BandLevels> {
+        self.history.extend_from_slice(samples);
+
+        let fft_size = self.window.len();
+        let hop = self.hop_len();
+        let mut latest = None;
+        while self.history.len() >= fft_size {
+            let frame: Vec<f32> = self.history[..fft_size].to_vec();
+            latest = Some(self.analyze(&frame));
+            self.history.drain(..hop);
+        }
+        latest
+    }
+
+    /// Analyzes exactly one window of samples (extra samples are ignored,
+    /// missing ones are treated as silence).
+    pub fn analyze(&mut self, frame: &[f32]) -> BandLevels {
+        for (i, slot) in self.buffer.iter_mut().enumerate() {
+            let sample = frame.get(i).copied().unwrap_or(0.0);
+            *slot = Complex::new(sample * self.window[i], 0.0);
+        }
+        self.fft.process(&mut self.buffer);
+
+        BandLevels {
+            bass: self.band_level(BAND_EDGES_HZ[0], BAND_EDGES_HZ[1]),
+            mid: self.band_level(BAND_EDGES_HZ[1], BAND_EDGES_HZ[2]),
+            high: self.band_level(BAND_EDGES_HZ[2], BAND_EDGES_HZ[3]),
+            shimmer: self.band_level(BAND_EDGES_HZ[3], BAND_EDGES_HZ[4]),
+        }
+    }
+
+    /// Maps the combined amplitude of bins in `[min_hz, max_hz)` onto 0.0-1.0.
+    ///
+    /// Bins are spaced `sample_rate / fft_size` apart, so the mapping follows
+    /// whatever rate the device runs at; a band above Nyquist reads zero.
+    fn band_level(&self, min_hz: f32, max_hz: f32) -> f32 {
+        let fft_size = self.window.len();
+        let bin_hz = self.sample_rate as f32 / fft_size as f32;
+        let nyquist_bin = fft_size / 2;
+        let first = ((min_hz / bin_hz).ceil() as usize).max(1);
+        let last = ((max_hz / bin_hz).ceil() as usize).min(nyquist_bin);
+        if first >= last {
+            return 0.0;
+        }
+
+        // Single-sided amplitude: a full-scale sine reads about 1.0
+        let scale = 2.0 / self.window_sum;
+        let energy: f32 = self.buffer[first..last]
+            .iter()
+            .map(|bin| (bin.norm() * scale).powi(2))
+            .sum();
+        let amplitude = energy.sqrt();
+        if amplitude <= 0.0 {
+            return 0.0;
+        }
+
+        let db = 20.0 * amplitude.log10();
+        ((db - self.floor_db) / -self.floor_db).clamp(0.0, 1.0)
+    }
+}
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Selects the audio source and tunes the capture analysis.
+///
+/// Changing `source` at runtime restarts capture. The procedural source is
+/// the default, so no device is opened unless asked for.
+#[derive(Resource, Debug, Clone)]
+pub struct AudioInputConfig {
+    /// Where audio levels come from
+    pub source: AudioSource,
+    /// FFT window length in samples (larger = finer bass resolution, more latency)
+    pub fft_size: usize,
+    /// Level in dBFS that reads as silence; 0 dBFS reads as full scale
+    pub floor_db: f32,
+}
+
+impl Default for AudioInputConfig {
+    fn default() -> Self {
+        Self {
+            source: AudioSource::Procedural,
+            fft_size: DEFAULT_FFT_SIZE,
+            floor_db: DEFAULT_FLOOR_DB,
+        }
+    }
+}
+
+/// Main-loop end of the capture thread.
+///
+/// Holds the channel receiver and the thread's stop flag. `drain` is called
+/// by `process_audio_input` each frame; it never blocks.
+#[derive(Resource, Debug, Default)]
+pub struct MicrophoneInput {
+    receiver: Option<Mutex<Receiver<CaptureMessage>>>,
+    stop: Option<Arc<AtomicBool>>,
+    /// Levels from the most recent FFT window, while capture is live
+    pub latest: Option<BandLevels>,
+    /// Current capture state
+    pub status: CaptureStatus,
+}
+
+impl MicrophoneInput {
+    /// Starts capturing from `config.source`, stopping any previous capture.
+    ///
+    /// Returns immediately; device errors arrive later as
+    /// `CaptureStatus::Failed`. The procedural source just stops capture.
+    pub fn start(&mut self, config: &AudioInputConfig) {
+        self.stop();
+
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let fft_size = config.fft_size;
+        let floor_db = config.floor_db;
+        let thread_stop = Arc::clone(&stop);
+
+        let spawned = match config.source.clone() {
+            AudioSource::Procedural => return,
+            source => spawn_capture_thread(source, fft_size, floor_db, sender, thread_stop),
+        };
+
+        match spawned {
+            Ok(_) => {
+                *self = Self::from_receiver(receiver);
+                self.stop = Some(stop);
+            }
+            Err(err) => self.status = CaptureStatus::Failed(err.to_string()),
+        }
+    }
+
+    /// Wraps a channel receiver without a capture thread of its own.
+    #[must_use]
+    pub fn from_receiver(receiver: Receiver<CaptureMessage>) -> Self {
+        Self {
+            receiver: Some(Mutex::new(receiver)),
+            stop: None,
+            latest: None,
+            status: CaptureStatus::Starting,
+        }
+    }
+
+    /// Signals the capture thread to exit and forgets its channel.
+    pub fn stop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            stop.store(true, Ordering::Relaxed);
+        }
+        self.receiver = None;
+        self.latest = None;
+        self.status = CaptureStatus::Idle;
+    }
+
+    /// Takes every pending message and returns the newest levels, if capture is live.
+    ///
+    /// A disconnect drops the channel, marks the status `Failed`, and returns
+    /// `None` so the caller falls back to procedural data.
+    pub fn drain(&mut self) -> Option<BandLevels> {
+        let receiver = self.receiver.as_ref()?;
+        let mut disconnected = None;
+        {
+            let receiver = receiver.lock().ok()?;
+            loop {
+                match receiver.try_recv() {
+                    Ok(CaptureMessage::Levels(levels)) => {
+                        self.latest = Some(levels);
+                        self.status = CaptureStatus::Running;
+                    }
+                    Ok(CaptureMessage::Disconnected(reason)) => {
+                        disconnected = Some(reason);
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        disconnected = Some("capture thread exited".to_string());
+                        break;
+                    }
+                }
+            }
+        }
+
+        if let Some(reason) = disconnected {
+            warn!("Audio capture stopped ({reason}); falling back to procedural audio");
+            self.stop();
+            self.status = CaptureStatus::Failed(reason);
+            return None;
+        }
+        self.latest
+    }
+}
+
+impl Drop for MicrophoneInput {
+    fn drop(&mut self) {
+        if let Some(stop) = &self.stop {
+            stop.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+// =============================================================================
+// CAPTURE THREADS
+// =============================================================================
+
+/// Spawns the capture thread for a microphone or WAV file source.
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_capture_thread(
+    source: AudioSource,
+    fft_size: usize,
+    floor_db: f32,
+    sender: Sender<CaptureMessage>,
+    stop: Arc<AtomicBool>,
+) -> std::io::Result<()> {
+    match source {
+        AudioSource::Procedural => Ok(()),
+        AudioSource::Microphone => thread::Builder::new()
+            .name("whirled-peas-microphone".into())
+            .spawn(move || run_microphone(fft_size, floor_db, &sender, &stop))
+            .map(drop),
+        AudioSource::File(path) => thread::Builder::new()
+            .name("whirled-peas-audio-file".into())
+            .spawn(move || run_file(&path, fft_size, floor_db, &sender, &stop))
+            .map(drop),
+    }
+}
+
+/// Browsers give wasm32 no threads, so capture fails and procedural audio stays on.
+#[cfg(target_arch = "wasm32")]
+fn spawn_capture_thread(
+    _source: AudioSource,
+    _fft_size: usize,
+    _floor_db: f32,
+    _sender: Sender<CaptureMessage>,
+    _stop: Arc<AtomicBool>,
+) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "audio capture threads are not supported on the web",
+    ))
+}
+
+/// Capture thread body for the default input device.
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+fn run_microphone(
+    fft_size: usize,
+    floor_db: f32,
+    sender: &Sender<CaptureMessage>,
+    stop: &AtomicBool,
+) {
+    use cpal::traits::StreamTrait;
+
+    let (event_sender, events) = mpsc::channel();
+    // The stream lives on this thread: cpal streams are not `Send` on every backend
+    let (stream, sample_rate) = match open_default_input(event_sender) {
+        Ok(opened) => opened,
+        Err(reason) => {
+            let _ = sender.send(CaptureMessage::Disconnected(reason));
+            return;
+        }
+    };
+    if let Err(err) = stream.play() {
+        let _ = sender.send(CaptureMessage::Disconnected(err.to_string()));
+        return;
+    }
+    info!("Microphone capture started at {sample_rate} Hz");
+
+    let mut analyzer = SpectrumAnalyzer::new(fft_size, sample_rate, floor_db);
+    let mut silent_secs = 0.0;
+    while !stop.load(Ordering::Relaxed) {
+        match events.recv_timeout(CAPTURE_POLL_INTERVAL) {
+            Ok(CaptureEvent::Samples(samples)) => {
+                silent_secs = 0.0;
+                if let Some(levels) = analyzer.push(&samples) {
+                    if sender.send(CaptureMessage::Levels(levels)).is_err() {
+                        return;
+                    }
+                }
+            }
+            Ok(CaptureEvent::Error(reason)) => {
+                let _ = sender.send(CaptureMessage::Disconnected(reason));
+                return;
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                // Some backends stop calling back on unplug without reporting an error
+                silent_secs += CAPTURE_POLL_INTERVAL.as_secs_f32();
+                if silent_secs >= CAPTURE_STALL_SECS {
+                    let reason = "input device stopped delivering samples".to_string();
+                    let _ = sender.send(CaptureMessage::Disconnected(reason));
+                    return;
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+/// Capture thread body where `cpal` is unavailable.
+#[cfg(any(target_os = "android", target_arch = "wasm32"))]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn run_microphone(
+    _fft_size: usize,
+    _floor_db: f32,
+    sender: &Sender<CaptureMessage>,
+    _stop: &AtomicBool,
+) {
+    let reason = "microphone capture is not supported on this platform".to_string();
+    let _ = sender.send(CaptureMessage::Disconnected(reason));
+}
+
+/// Opens the default input device and returns its stream and sample rate.
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+fn open_default_input(events: Sender<CaptureEvent>) -> Result<(cpal::Stream, u32), String> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+
+    let device = cpal::default_host()
+        .default_input_device()
+        .ok_or_else(|| "no input device available".to_string())?;
+    let supported = device
+        .default_input_config()
+        .map_err(|err| err.to_string())?;
+    let sample_format = supported.sample_format();
+    let config: cpal::StreamConfig = supported.into();
+    let sample_rate = config.sample_rate.0;
+
+    let stream = match sample_format {
+        cpal::SampleFormat::F32 => build_input_stream::<f32>(&device, &config, events),
+        cpal::SampleFormat::I16 => build_input_stream::<i16>(&device, &config, events),
+        cpal::SampleFormat::U16 => build_input_stream::<u16>(&device, &config, events),
+        other => Err(format!("unsupported input sample format {other:?}")),
+    }?;
+    Ok((stream, sample_rate))
+}
+
+/// Builds an input stream that downmixes each callback to mono `f32` samples.
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+fn build_input_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    events: Sender<CaptureEvent>,
+) -> Result<cpal::Stream, String>
+where
+    T: cpal::SizedSample,
+    f32: cpal::FromSample<T>,
+{
+    use cpal::traits::DeviceTrait;
+
+    let channels = usize::from(config.channels.max(1));
+    let error_events = events.clone();
+    device
+        .build_input_stream(
+            config,
+            move |data: &[T], _: &cpal::InputCallbackInfo| {
+                let mono = data
+                    .chunks(channels)
+                    .map(|frame| {
+                        frame
+                            .iter()
+                            .map(|sample| sample.to_sample::<f32>())
+                            .sum::<f32>()
+                            / frame.len() as f32
+                    })
+                    .collect();
+                let _ = events.send(CaptureEvent::Samples(mono));
+            },
+            move |err| {
+                let _ = error_events.send(CaptureEvent::Error(err.to_string()));
+            },
+            None,
+        )
+        .map_err(|err| err.to_string())
+}
+
+/// Capture thread body for a WAV file: analyzes it in real time, looping.
+#[cfg(not(target_arch = "wasm32"))]
+fn run_file(
+    path: &Path,
+    fft_size: usize,
+    floor_db: f32,
+    sender: &Sender<CaptureMessage>,
+    stop: &AtomicBool,
+) {
+    let (samples, sample_rate) = match read_wav_mono(path) {
+        Ok(decoded) if !decoded.0.is_empty() => decoded,
+        Ok(_) => {
+            let reason = format!("{} contains no samples", path.display());
+            let _ = sender.send(CaptureMessage::Disconnected(reason));
+            return;
+        }
+        Err(reason) => {
+            let _ = sender.send(CaptureMessage::Disconnected(reason));
+            return;
+        }
+    };
+
+    let mut analyzer = SpectrumAnalyzer::new(fft_size, sample_rate, floor_db);
+    let hop = analyzer.hop_len();
+    let hop_duration = Duration::from_secs_f64(hop as f64 / f64::from(sample_rate));
+    let mut cursor = 0;
+    while !stop.load(Ordering::Relaxed) {
+        let end = (cursor + hop).min(samples.len());
+        if let Some(levels) = analyzer.push(&samples[cursor..end]) {
+            if sender.send(CaptureMessage::Levels(levels)).is_err() {
+                return;
+            }
+        }
+        cursor = if end == samples.len() { 0 } else { end };
+        thread::sleep(hop_duration);
+    }
+}
+
+/// Decodes a WAV file into mono `f32` samples and its sample rate.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_wav_mono(path: &Path) -> Result<(Vec<f32>, u32), String> {
+    let describe = |err: hound::Error| format!("{}: {err}", path.display());
+    let mut reader = hound::WavReader::open(path).map_err(describe)?;
+    let spec = reader.spec();
+
+    let interleaved: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<_, _>>()
+            .map_err(describe)?,
+        hound::SampleFormat::Int => {
+            let full_scale = (1_i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.map(|value| value as f32 / full_scale))
+                .collect::<Result<_, _>>()
+                .map_err(describe)?
+        }
+    };
+
+    let channels = usize::from(spec.channels.max(1));
+    let mono = interleaved
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    Ok((mono, spec.sample_rate))
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Starts or restarts capture whenever `AudioInputConfig` changes.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs before `process_audio_input`, only when the config changed (including
+/// the first frame).
+pub fn sync_audio_capture(config: Res<AudioInputConfig>, mut microphone: ResMut<MicrophoneInput>) {
+    microphone.start(&config);
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One FFT window of a full-scale sine at `hz`.
+    fn sine(hz: f32, sample_rate: u32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (std::f32::consts::TAU * hz * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_sine_lands_in_its_band() {
+        let mut analyzer = SpectrumAnalyzer::new(DEFAULT_FFT_SIZE, 48_000, DEFAULT_FLOOR_DB);
+
+        let bass = analyzer.analyze(&sine(80.0, 48_000, DEFAULT_FFT_SIZE));
+        assert!(bass.bass > 0.9, "{bass:?}");
+        assert!(bass.high < 0.3 && bass.shimmer < 0.3, "{bass:?}");
+
+        let high = analyzer.analyze(&sine(6000.0, 48_000, DEFAULT_FFT_SIZE));
+        assert!(high.high > 0.9, "{high:?}");
+        assert!(high.bass < 0.3, "{high:?}");
+
+        let silence = analyzer.analyze(&[0.0; DEFAULT_FFT_SIZE]);
+        assert_eq!(silence, BandLevels::default());
+    }
+
+    #[test]
+    fn test_bin_mapping_follows_sample_rate() {
+        // At 16 kHz, Nyquist is 8 kHz: 6 kHz is still "high" and shimmer is empty
+        let mut analyzer = SpectrumAnalyzer::new(1024, 16_000, DEFAULT_FLOOR_DB);
+        let levels = analyzer.analyze(&sine(6000.0, 16_000, 1024));
+        assert!(levels.high > 0.9, "{levels:?}");
+        assert_eq!(levels.shimmer, 0.0);
+    }
+
+    #[test]
+    fn test_push_analyzes_every_half_window() {
+        let mut analyzer = SpectrumAnalyzer::new(256, 48_000, DEFAULT_FLOOR_DB);
+        assert!(analyzer.push(&[0.1; 200]).is_none());
+        assert!(analyzer.push(&[0.1; 56]).is_some());
+        assert!(analyzer.push(&[0.1; 127]).is_none());
+        assert!(analyzer.push(&[0.1; 1]).is_some());
+    }
+
+    #[test]
+    fn test_disconnect_falls_back_to_procedural() {
+        let (sender, receiver) = mpsc::channel();
+        let mut microphone = MicrophoneInput::from_receiver(receiver);
+        let levels = BandLevels {
+            bass: 0.8,
+            ..Default::default()
+        };
+
+        sender.send(CaptureMessage::Levels(levels)).unwrap();
+        assert_eq!(microphone.drain(), Some(levels));
+        assert_eq!(microphone.status, CaptureStatus::Running);
+
+        sender
+            .send(CaptureMessage::Disconnected("unplugged".into()))
+            .unwrap();
+        assert_eq!(microphone.drain(), None);
+        assert_eq!(microphone.status, CaptureStatus::Failed("unplugged".into()));
+        assert_eq!(microphone.drain(), None);
+    }
+}
@@ -0,0 +1,595 @@
+//! Module: midi
+//! Purpose: MIDI controller input for beats, interaction modes, pulses, and explosions
+//! Dependencies: interaction, particle, resources, types, midir, bevy::prelude
+//!
+//! Requires the `midi` feature; desktop only. midir delivers messages on its
+//! own callback thread, which forwards them through a channel that
+//! `apply_midi_input` drains each frame, so the frame never waits on the
+//! device.
+//!
+//! # Default mapping
+//!
+//! | Input                  | Effect                                                  |
+//! |------------------------|---------------------------------------------------------|
+//! | Any note-on            | `BeatDetected`, strength from velocity / 127            |
+//! | CC 20-26 (value >= 64) | Mode: Paint, Attract, Intensify, Disperse, Ripple, Erase, Vortex |
+//! | CC 27 (value >= 64)    | Clear the mode override; follow the act again           |
+//! | CC 28 (value >= 64)    | `BreathPulse` at the pointer                            |
+//! | CC 29 (value >= 64)    | `ExplosionEvent` at the pointer                         |
+//!
+//! Controls fire when their value rises through 64, so buttons and pedals
+//! trigger once per press. Replace the `MidiMapping` resource to remap.
+
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use midir::{Ignore, MidiInputConnection};
+
+use crate::interaction::{BreathPulse, ExplosionEvent};
+use crate::particle::BeatDetected;
+use crate::resources::{CurrentInteractionMode, MouseState};
+use crate::types::{in_fidget_state, BeatStrength, InteractionMode};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Control values at or above this count as "pressed".
+const CONTROL_ON_THRESHOLD: u8 = 64;
+
+/// Client name shown to the system MIDI service.
+const MIDI_CLIENT_NAME: &str = "whirled-peas";
+
+// =============================================================================
+// MIDI MESSAGES
+// =============================================================================
+
+/// A decoded MIDI channel message this module reacts to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidiEvent {
+    /// Note-on with non-zero velocity
+    NoteOn {
+        /// Channel 0-15
+        channel: u8,
+        /// Note number 0-127
+        note: u8,
+        /// Velocity 1-127
+        velocity: u8,
+    },
+    /// Control change
+    ControlChange {
+        /// Channel 0-15
+        channel: u8,
+        /// Controller number 0-127
+        controller: u8,
+        /// Value 0-127
+        value: u8,
+    },
+}
+
+impl MidiEvent {
+    /// Channel the message arrived on.
+    #[must_use]
+    pub fn channel(&self) -> u8 {
+        match self {
+            MidiEvent::NoteOn { channel, .. } | MidiEvent::ControlChange { channel, .. } => {
+                *channel
+            }
+        }
+    }
+}
+
+/// Decodes a raw MIDI message, or `None` for anything but note-on and control
+/// change. A note-on with velocity 0 is a note-off and is ignored.
+#[must_use]
+pub fn parse_midi_message(bytes: &[u8]) -> Option<MidiEvent> {
+    let (&status, data) = bytes.split_first()?;
+    let channel = status & 0x0F;
+    match (status & 0xF0, data) {
+        (0x90, &[note, velocity, ..]) if velocity > 0 => Some(MidiEvent::NoteOn {
+            channel,
+            note: note & 0x7F,
+            velocity: velocity & 0x7F,
+        }),
+        (0xB0, &[controller, value, ..]) => Some(MidiEvent::ControlChange {
+            channel,
+            controller: controller & 0x7F,
+            value: value & 0x7F,
+        }),
+        _ => None,
+    }
+}
+
+/// Beat strength for a note-on `velocity`.
+#[must_use]
+pub fn velocity_beat_strength(velocity: u8) -> BeatStrength {
+    BeatStrength::from_amplitude(f32::from(velocity.min(127)) / 127.0)
+}
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// What a mapped control-change number does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidiControlAction {
+    /// Hold this interaction mode regardless of the act
+    SetMode(InteractionMode),
+    /// Drop the mode override and follow the act again
+    ClearMode,
+    /// Send a `BreathPulse` at the pointer
+    BreathPulse,
+    /// Send an `ExplosionEvent` at the pointer
+    Explosion,
+}
+
+/// Note and control-change mapping; insert your own to remap a controller.
+#[derive(Resource, Debug, Clone)]
+pub struct MidiMapping {
+    /// Only messages on this channel (0-15) are used; `None` accepts all
+    pub channel: Option<u8>,
+    /// Notes that fire beats; empty means every note
+    pub beat_notes: Vec<u8>,
+    /// Control-change number to action
+    pub controls: HashMap<u8, MidiControlAction>,
+}
+
+impl Default for MidiMapping {
+    fn default() -> Self {
+        let modes = [
+            InteractionMode::Paint,
+            InteractionMode::Attract,
+            InteractionMode::Intensify,
+            InteractionMode::Disperse,
+            InteractionMode::Ripple,
+            InteractionMode::Erase,
+            InteractionMode::Vortex,
+        ];
+        let mut controls: HashMap<u8, MidiControlAction> = (20..)
+            .zip(modes)
+            .map(|(controller, mode)| (controller, MidiControlAction::SetMode(mode)))
+            .collect();
+        controls.insert(27, MidiControlAction::ClearMode);
+        controls.insert(28, MidiControlAction::BreathPulse);
+        controls.insert(29, MidiControlAction::Explosion);
+
+        Self {
+            channel: None,
+            beat_notes: Vec::new(),
+            controls,
+        }
+    }
+}
+
+impl MidiMapping {
+    /// Whether `event` arrived on an accepted channel.
+    #[must_use]
+    pub fn accepts(&self, event: &MidiEvent) -> bool {
+        self.channel
+            .is_none_or(|channel| channel == event.channel())
+    }
+
+    /// Whether a note-on for `note` fires a beat.
+    #[must_use]
+    pub fn is_beat_note(&self, note: u8) -> bool {
+        self.beat_notes.is_empty() || self.beat_notes.contains(&note)
+    }
+}
+
+/// Which MIDI input port to open.
+///
+/// Changing it at runtime reconnects.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MidiInputConfig {
+    /// Case-insensitive substring of the port name; `None` opens the first port
+    pub port_name: Option<String>,
+}
+
+/// State of the MIDI connection.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum MidiStatus {
+    /// No connection attempted yet
+    #[default]
+    Idle,
+    /// Connected to the named port
+    Connected(String),
+    /// No port, or the port failed to open; MIDI input is off
+    Unavailable(String),
+}
+
+/// Main-loop end of the MIDI callback thread.
+#[derive(Resource, Default)]
+pub struct MidiInput {
+    connection: Option<Mutex<MidiInputConnection<()>>>,
+    receiver: Option<Mutex<Receiver<MidiEvent>>>,
+    /// Last value seen per controller, for edge detection
+    control_values: HashMap<u8, u8>,
+    /// Current connection state
+    pub status: MidiStatus,
+}
+
+impl std::fmt::Debug for MidiInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MidiInput")
+            .field("connected", &self.connection.is_some())
+            .field("status", &self.status)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MidiInput {
+    /// Opens the port chosen by `config`, closing any previous connection.
+    ///
+    /// No MIDI service, no matching port, or a failed open leaves
+    /// `MidiStatus::Unavailable` with the reason.
+    pub fn connect(&mut self, config: &MidiInputConfig) {
+        *self = Self::default();
+
+        let mut midi_in = match midir::MidiInput::new(MIDI_CLIENT_NAME) {
+            Ok(midi_in) => midi_in,
+            Err(error) => {
+                self.unavailable(error.to_string());
+                return;
+            }
+        };
+        midi_in.ignore(Ignore::All);
+
+        let wanted = config.port_name.as_deref().map(str::to_lowercase);
+        let port = midi_in.ports().into_iter().find_map(|port| {
+            let name = midi_in.port_name(&port).ok()?;
+            let matches = wanted
+                .as_deref()
+                .is_none_or(|wanted| name.to_lowercase().contains(wanted));
+            matches.then_some((port, name))
+        });
+        let Some((port, name)) = port else {
+            self.unavailable(match &config.port_name {
+                Some(wanted) => format!("no MIDI input port matching \"{wanted}\""),
+                None => "no MIDI input ports".to_string(),
+            });
+            return;
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let connected = midi_in.connect(
+            &port,
+            "whirled-peas-input",
+            move |_timestamp, message, _| {
+                if let Some(event) = parse_midi_message(message) {
+                    // The main loop may have dropped the receiver; nothing to do then
+                    let _ = sender.send(event);
+                }
+            },
+            (),
+        );
+
+        match connected {
+            Ok(connection) => {
+                info!("MIDI input connected to {name}");
+                self.connection = Some(Mutex::new(connection));
+                self.receiver = Some(Mutex::new(receiver));
+                self.status = MidiStatus::Connected(name);
+            }
+            Err(error) => self.unavailable(format!("could not open {name}: {error}")),
+        }
+    }
+
+    /// Wraps a channel receiver without a device of its own.
+    #[must_use]
+    pub fn from_receiver(receiver: Receiver<MidiEvent>) -> Self {
+        Self {
+            receiver: Some(Mutex::new(receiver)),
+            status: MidiStatus::Connected("channel".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn unavailable(&mut self, reason: String) {
+        warn!("MIDI input unavailable: {reason}");
+        self.status = MidiStatus::Unavailable(reason);
+    }
+
+    /// Moves every pending event into `events`, which the caller reuses
+    /// across frames. A disconnected callback thread marks the input
+    /// unavailable.
+    pub fn drain_into(&mut self, events: &mut Vec<MidiEvent>) {
+        let mut disconnected = false;
+        if let Some(receiver) = self.receiver.as_ref().and_then(|r| r.lock().ok()) {
+            loop {
+                match receiver.try_recv() {
+                    Ok(event) => events.push(event),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        disconnected = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if disconnected {
+            self.connection = None;
+            self.receiver = None;
+            self.unavailable("MIDI device disconnected".to_string());
+        }
+    }
+
+    /// Records a controller value, returning true when it rose through
+    /// `CONTROL_ON_THRESHOLD`.
+    fn control_pressed(&mut self, controller: u8, value: u8) -> bool {
+        let previous = self.control_values.insert(controller, value).unwrap_or(0);
+        previous < CONTROL_ON_THRESHOLD && value >= CONTROL_ON_THRESHOLD
+    }
+}
+
+/// Interaction mode held by MIDI, replacing the act's mode while set.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MidiModeOverride {
+    /// Mode to hold; `None` follows the act
+    pub mode: Option<InteractionMode>,
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Connects (or reconnects) to the port chosen by `MidiInputConfig`.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// Runs before `apply_midi_input`, only when the config changed (including
+/// the first frame).
+pub fn connect_midi_input(config: Res<MidiInputConfig>, mut midi: ResMut<MidiInput>) {
+    midi.connect(&config);
+}
+
+/// Drains pending MIDI events and turns them into beats and actions.
+///
+/// Note-ons on beat notes send `BeatDetected` with
+/// `BeatStrength::from_amplitude(velocity / 127)`; silent strengths are
+/// dropped. Mapped controls send `BreathPulse`/`ExplosionEvent` at the
+/// pointer or update `MidiModeOverride`.
+///
+/// Events are drained into a `Local` buffer that keeps its capacity, so a
+/// quiet frame allocates nothing.
+///
+/// # Stage
+/// PreUpdate
+#[allow(clippy::too_many_arguments)]
+pub fn apply_midi_input(
+    mut midi: ResMut<MidiInput>,
+    mut events: Local<Vec<MidiEvent>>,
+    mapping: Res<MidiMapping>,
+    mouse_state: Res<MouseState>,
+    mut mode_override: ResMut<MidiModeOverride>,
+    mut beat_events: EventWriter<BeatDetected>,
+    mut breath_pulse_events: EventWriter<BreathPulse>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    midi.drain_into(&mut events);
+    for event in events.drain(..) {
+        if !mapping.accepts(&event) {
+            continue;
+        }
+        match event {
+            MidiEvent::NoteOn { note, velocity, .. } => {
+                let strength = velocity_beat_strength(velocity);
+                if mapping.is_beat_note(note) && strength.should_spawn() {
+                    beat_events.send(BeatDetected { strength });
+                }
+            }
+            MidiEvent::ControlChange {
+                controller, value, ..
+            } => {
+                if !midi.control_pressed(controller, value) {
+                    continue;
+                }
+                match mapping.controls.get(&controller) {
+                    Some(MidiControlAction::SetMode(mode)) => mode_override.mode = Some(*mode),
+                    Some(MidiControlAction::ClearMode) => mode_override.mode = None,
+                    Some(MidiControlAction::BreathPulse) => {
+                        breath_pulse_events.send(BreathPulse {
+                            origin: mouse_state.position,
+                            strength: 1.0,
+                        });
+                    }
+                    Some(MidiControlAction::Explosion) => {
+                        explosion_events.send(ExplosionEvent {
+                            origin: mouse_state.position,
+                            strength: 1.0,
+                        });
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+}
+
+/// Replaces the act's interaction mode with `MidiModeOverride.mode`.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// After `apply_interaction_mode_cycle`, before `update_eraser_override` so
+/// the eraser still wins.
+pub fn apply_midi_mode_override(
+    mode_override: Res<MidiModeOverride>,
+    mut current_mode: ResMut<CurrentInteractionMode>,
+) {
+    if let Some(mode) = mode_override.mode {
+        current_mode.set(mode);
+    }
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that plays the swarm from a MIDI controller.
+///
+/// Not part of `WhirledPeasPlugin`; add it alongside. Insert a
+/// `MidiInputConfig` to pick a port and a `MidiMapping` to remap notes and
+/// controls. Without a MIDI device the plugin logs a warning and stays idle.
+///
+/// # Systems
+/// - `connect_midi_input` (PreUpdate): Opens the port when the config changes
+/// - `apply_midi_input` (PreUpdate): Drains events into beats and actions
+/// - `apply_midi_mode_override` (Update): Holds the MIDI-selected mode
+pub struct MidiInputPlugin;
+
+impl Plugin for MidiInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MidiInputConfig>()
+            .init_resource::<MidiMapping>()
+            .init_resource::<MidiInput>()
+            .init_resource::<MidiModeOverride>()
+            .add_event::<BeatDetected>()
+            .add_event::<BreathPulse>()
+            .add_event::<ExplosionEvent>()
+            .add_systems(
+                PreUpdate,
+                (
+                    connect_midi_input.run_if(resource_changed::<MidiInputConfig>),
+                    apply_midi_input,
+                )
+                    .chain()
+                    .run_if(in_fidget_state),
+            )
+            .add_systems(
+                Update,
+                apply_midi_mode_override
+                    .after(crate::interaction::apply_interaction_mode_cycle)
+                    .before(crate::interaction::update_eraser_override)
+                    .run_if(in_fidget_state),
+            );
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::Sender;
+
+    #[test]
+    fn test_parse_midi_messages() {
+        assert_eq!(
+            parse_midi_message(&[0x92, 60, 100]),
+            Some(MidiEvent::NoteOn {
+                channel: 2,
+                note: 60,
+                velocity: 100,
+            })
+        );
+        // Velocity 0 is a note-off
+        assert_eq!(parse_midi_message(&[0x90, 60, 0]), None);
+        assert_eq!(parse_midi_message(&[0x80, 60, 64]), None);
+        assert_eq!(
+            parse_midi_message(&[0xB0, 28, 127]),
+            Some(MidiEvent::ControlChange {
+                channel: 0,
+                controller: 28,
+                value: 127,
+            })
+        );
+        assert_eq!(parse_midi_message(&[0xB0, 28]), None);
+        assert_eq!(parse_midi_message(&[]), None);
+    }
+
+    #[test]
+    fn test_velocity_maps_through_from_amplitude() {
+        assert_eq!(velocity_beat_strength(5), BeatStrength::Silence);
+        assert_eq!(velocity_beat_strength(30), BeatStrength::Soft);
+        assert_eq!(velocity_beat_strength(70), BeatStrength::Medium);
+        assert_eq!(velocity_beat_strength(127), BeatStrength::Strong);
+    }
+
+    fn midi_app() -> (App, Sender<MidiEvent>) {
+        let (sender, receiver) = mpsc::channel();
+        let mut app = App::new();
+        app.insert_resource(MidiInput::from_receiver(receiver))
+            .init_resource::<MidiMapping>()
+            .init_resource::<MidiModeOverride>()
+            .init_resource::<MouseState>()
+            .init_resource::<CurrentInteractionMode>()
+            .add_event::<BeatDetected>()
+            .add_event::<BreathPulse>()
+            .add_event::<ExplosionEvent>()
+            .add_systems(Update, (apply_midi_input, apply_midi_mode_override).chain());
+        (app, sender)
+    }
+
+    #[test]
+    fn test_default_mapping_drives_beats_and_actions() {
+        let (mut app, sender) = midi_app();
+        let control = |controller, value| MidiEvent::ControlChange {
+            channel: 0,
+            controller,
+            value,
+        };
+
+        sender
+            .send(MidiEvent::NoteOn {
+                channel: 9,
+                note: 36,
+                velocity: 127,
+            })
+            .unwrap();
+        sender.send(control(21, 127)).unwrap();
+        sender.send(control(28, 127)).unwrap();
+        // Held past the threshold: no second pulse until released
+        sender.send(control(28, 100)).unwrap();
+        app.update();
+
+        let world = app.world();
+        let beats: Vec<_> = world
+            .resource::<Events<BeatDetected>>()
+            .iter_current_update_events()
+            .map(|beat| beat.strength)
+            .collect();
+        assert_eq!(beats, vec![BeatStrength::Strong]);
+        assert_eq!(world.resource::<Events<BreathPulse>>().len(), 1);
+        assert_eq!(
+            world.resource::<CurrentInteractionMode>().mode,
+            InteractionMode::Attract
+        );
+
+        sender.send(control(28, 0)).unwrap();
+        sender.send(control(29, 127)).unwrap();
+        sender.send(control(27, 127)).unwrap();
+        app.update();
+
+        assert_eq!(app.world().resource::<Events<ExplosionEvent>>().len(), 1);
+        assert_eq!(app.world().resource::<MidiModeOverride>().mode, None);
+    }
+
+    #[test]
+    fn test_channel_filter_and_disconnect() {
+        let (mut app, sender) = midi_app();
+        app.world_mut().resource_mut::<MidiMapping>().channel = Some(1);
+
+        sender
+            .send(MidiEvent::NoteOn {
+                channel: 0,
+                note: 60,
+                velocity: 127,
+            })
+            .unwrap();
+        drop(sender);
+        app.update();
+
+        assert!(app.world().resource::<Events<BeatDetected>>().is_empty());
+        assert!(matches!(
+            app.world().resource::<MidiInput>().status,
+            MidiStatus::Unavailable(_)
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+//! Module: noise
+//! Purpose: 3D simplex gradient noise for turbulence and flow fields
+//! Dependencies: bevy::math
+
+use bevy::prelude::*;
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Skew factor from input space onto the simplex grid (1/3 in 3D).
+const SKEW_3D: f32 = 1.0 / 3.0;
+
+/// Unskew factor from the simplex grid back to input space (1/6 in 3D).
+const UNSKEW_3D: f32 = 1.0 / 6.0;
+
+/// Squared radius of each corner's contribution kernel.
+const KERNEL_RADIUS_SQUARED: f32 = 0.6;
+
+/// Scales the summed contributions to roughly [-1.0, 1.0].
+const OUTPUT_SCALE: f32 = 32.0;
+
+/// Step used for the central differences in `curl_noise`.
+const CURL_EPSILON: f32 = 0.01;
+
+/// Gradient directions: the midpoints of a cube's twelve edges.
+const GRADIENTS: [Vec3; 12] = [
+    Vec3::new(1.0, 1.0, 0.0),
+    Vec3::new(-1.0, 1.0, 0.0),
+    Vec3::new(1.0, -1.0, 0.0),
+    Vec3::new(-1.0, -1.0, 0.0),
+    Vec3::new(1.0, 0.0, 1.0),
+    Vec3::new(-1.0, 0.0, 1.0),
+    Vec3::new(1.0, 0.0, -1.0),
+    Vec3::new(-1.0, 0.0, -1.0),
+    Vec3::new(0.0, 1.0, 1.0),
+    Vec3::new(0.0, -1.0, 1.0),
+    Vec3::new(0.0, 1.0, -1.0),
+    Vec3::new(0.0, -1.0, -1.0),
+];
+
+/// Ken Perlin's reference permutation of 0..=255.
+const PERMUTATION: [u8; 256] = [
+    151, 160, 137, 91, 90, 15, 131, 13, 201, 95, 96, 53, 194, 233, 7, 225, 140, 36, 103, 30, 69,
+    142, 8, 99, 37, 240, 21, 10, 23, 190, 6, 148, 247, 120, 234, 75, 0, 26, 197, 62, 94, 252, 219,
+    203, 117, 35, 11, 32, 57, 177, 33, 88, 237, 149, 56, 87, 174, 20, 125, 136, 171, 168, 68, 175,
+    74, 165, 71, 134, 139, 48, 27, 166, 77, 146, 158, 231, 83, 111, 229, 122, 60, 211, 133, 230,
+    220, 105, 92, 41, 55, 46, 245, 40, 244, 102, 143, 54, 65, 25, 63, 161, 1, 216, 80, 73, 209, 76,
+    132, 187, 208, 89, 18, 169, 200, 196, 135, 130, 116, 188, 159, 86, 164, 100, 109, 198, 173,
+    186, 3, 64, 52, 217, 226, 250, 124, 123, 5, 202, 38, 147, 118, 126, 255, 82, 85, 212, 207, 206,
+    59, 227, 47, 16, 58, 17, 182, 189, 28, 42, 223, 183, 170, 213, 119, 248, 152, 2, 44, 154, 163,
+    70, 221, 153, 101, 155, 167, 43, 172, 9, 129, 22, 39, 253, 19, 98, 108, 110, 79, 113, 224, 232,
+    178, 185, 112, 104, 218, 246, 97, 228, 251, 34, 242, 193, 238, 210, 144, 12, 191, 179, 162,
+    241, 81, 51, 145, 235, 249, 14, 239, 107, 49, 192, 214, 31, 181, 199, 106, 157, 184, 84, 204,
+    176, 115, 121, 50, 45, 127, 4, 150, 254, 138, 236, 205, 93, 222, 114, 67, 29, 24, 72, 243, 141,
+    128, 195, 78, 66, 215, 61, 156, 180,
+];
+
+// =============================================================================
+// NOISE
+// =============================================================================
+
+/// Looks up the permutation table, wrapping the index into 0..=255.
+#[inline]
+fn permute(index: i32) -> i32 {
+    i32::from(PERMUTATION[(index & 255) as usize])
+}
+
+/// Returns the gradient assigned to a lattice corner.
+#[inline]
+fn corner_gradient(corner: IVec3) -> Vec3 {
+    let hash = permute(corner.x + permute(corner.y + permute(corner.z)));
+    GRADIENTS[hash as usize % GRADIENTS.len()]
+}
+
+/// Contribution of one simplex corner at `offset` from the sample point.
+#[inline]
+fn corner_contribution(corner: IVec3, offset: Vec3) -> f32 {
+    let falloff = KERNEL_RADIUS_SQUARED - offset.length_squared();
+    if falloff <= 0.0 {
+        return 0.0;
+    }
+    let falloff = falloff * falloff;
+    falloff * falloff * corner_gradient(corner).dot(offset)
+}
+
+/// Samples 3D simplex noise at `point`.
+///
+/// Smooth gradient noise in [-1.0, 1.0] with no visible axis-aligned or
+/// periodic banding at feature scales below the 256-unit lattice wrap. Equal
+/// inputs always give equal outputs.
+#[must_use]
+pub fn simplex3(point: Vec3) -> f32 {
+    // Skew into simplex space to find the containing cell
+    let skew = point.element_sum() * SKEW_3D;
+    let cell = (point + Vec3::splat(skew)).floor();
+    let unskew = cell.element_sum() * UNSKEW_3D;
+    let offset0 = point - (cell - Vec3::splat(unskew));
+
+    // Pick the simplex (one of six tetrahedra) by ranking the offset's axes
+    let (step1, step2) = if offset0.x >= offset0.y {
+        if offset0.y >= offset0.z {
+            (IVec3::X, IVec3::new(1, 1, 0))
+        } else if offset0.x >= offset0.z {
+            (IVec3::X, IVec3::new(1, 0, 1))
+        } else {
+            (IVec3::Z, IVec3::new(1, 0, 1))
+        }
+    } else if offset0.y < offset0.z {
+        (IVec3::Z, IVec3::new(0, 1, 1))
+    } else if offset0.x < offset0.z {
+        (IVec3::Y, IVec3::new(0, 1, 1))
+    } else {
+        (IVec3::Y, IVec3::new(1, 1, 0))
+    };
+
+    let offset1 = offset0 - step1.as_vec3() + Vec3::splat(UNSKEW_3D);
+    let offset2 = offset0 - step2.as_vec3() + Vec3::splat(2.0 * UNSKEW_3D);
+    let offset3 = offset0 - Vec3::ONE + Vec3::splat(3.0 * UNSKEW_3D);
+
+    let base = cell.as_ivec3();
+    let sum = corner_contribution(base, offset0)
+        + corner_contribution(base + step1, offset1)
+        + corner_contribution(base + step2, offset2)
+        + corner_contribution(base + IVec3::ONE, offset3);
+
+    (sum * OUTPUT_SCALE).clamp(-1.0, 1.0)
+}
+
+/// Samples the 2D curl of `simplex3`, treating the noise as a stream function.
+///
+/// Returns `(dN/dy, -dN/dx)` at `point.xy`, with `point.z` as the time axis.
+/// The field is divergence-free, so anything following it swirls in laminar
+/// currents instead of bunching up or thinning out. Magnitudes average about
+/// 2.0 and rarely exceed 6.0.
+#[must_use]
+pub fn curl_noise(point: Vec3) -> Vec2 {
+    let dx = Vec3::new(CURL_EPSILON, 0.0, 0.0);
+    let dy = Vec3::new(0.0, CURL_EPSILON, 0.0);
+    let d_dx = (simplex3(point + dx) - simplex3(point - dx)) / (2.0 * CURL_EPSILON);
+    let d_dy = (simplex3(point + dy) - simplex3(point - dy)) / (2.0 * CURL_EPSILON);
+    Vec2::new(d_dy, -d_dx)
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permutation_is_a_permutation() {
+        let mut seen = [false; 256];
+        for value in PERMUTATION {
+            assert!(!seen[value as usize], "{value} repeats");
+            seen[value as usize] = true;
+        }
+    }
+
+    #[test]
+    fn test_simplex_is_bounded_deterministic_and_smooth() {
+        let mut max_abs: f32 = 0.0;
+        for step in 0..2000 {
+            let t = step as f32 * 0.173;
+            let point = Vec3::new(t, t * 0.61 - 40.0, 17.0 - t * 0.29);
+            let value = simplex3(point);
+            assert!((-1.0..=1.0).contains(&value));
+            assert_eq!(value, simplex3(point));
+
+            // A tiny step moves the value only a little
+            let nudged = simplex3(point + Vec3::splat(0.001));
+            assert!((value - nudged).abs() < 0.05);
+            max_abs = max_abs.max(value.abs());
+        }
+        // Not degenerate: the field actually varies
+        assert!(max_abs > 0.5, "max {max_abs}");
+    }
+
+    #[test]
+    fn test_curl_noise_is_divergence_free() {
+        let step = 0.05;
+        for sample in 0..200 {
+            let s = sample as f32;
+            let point = Vec3::new(s * 0.37 - 30.0, 11.0 - s * 0.21, s * 0.13);
+            let ddx = Vec3::new(step, 0.0, 0.0);
+            let ddy = Vec3::new(0.0, step, 0.0);
+
+            let divergence = (curl_noise(point + ddx).x - curl_noise(point - ddx).x
+                + curl_noise(point + ddy).y
+                - curl_noise(point - ddy).y)
+                / (2.0 * step);
+            // The individual partials reach ~30; their sum cancels
+            assert!(divergence.abs() < 1.5, "divergence {divergence} at {point}");
+        }
+    }
+}
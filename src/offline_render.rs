@@ -0,0 +1,417 @@
+//! Module: offline_render
+//! Purpose: Fixed-rate PNG frame sequence export for offline video rendering
+//! Dependencies: resources, screenshot, bevy::time, bevy::render::view::screenshot
+//!
+//! Desktop only; the module is compiled out on Android.
+//!
+//! Assemble a finished sequence with ffmpeg (match `-framerate` to `fps`):
+//!
+//! ```text
+//! ffmpeg -framerate 60 -i render/frame_%05d.png -c:v libx264 -pix_fmt yuv420p -crf 16 peas.mp4
+//! ```
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::tasks::IoTaskPool;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::resources::{ActState, ParticleRng, RngSeed};
+use crate::screenshot::write_png;
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Captured frames allowed to wait on the IO task pool before simulated time
+/// is held, so a slow disk cannot queue an unbounded number of images.
+const MAX_FRAMES_IN_FLIGHT: u32 = 8;
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Frame rate, destination, and length of an offline render.
+#[derive(Resource, Debug, Clone)]
+pub struct OfflineRenderConfig {
+    /// Frames per simulated second; each `App::update` advances `1 / fps`
+    pub fps: u32,
+    /// Directory receiving `frame_00001.png`, `frame_00002.png`, ...
+    pub out_dir: PathBuf,
+    /// Simulated seconds to render before exiting; `None` renders until the
+    /// first pass of the experience completes, so the intro and the configured
+    /// `ExperienceLength` are included
+    pub seconds: Option<f32>,
+}
+
+impl Default for OfflineRenderConfig {
+    fn default() -> Self {
+        Self {
+            fps: 60,
+            out_dir: PathBuf::from("render"),
+            seconds: None,
+        }
+    }
+}
+
+impl OfflineRenderConfig {
+    /// Simulated time per frame.
+    #[must_use]
+    pub fn frame_step(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / f64::from(self.fps.max(1)))
+    }
+
+    /// Number of frames in the sequence, or `None` when it runs until the
+    /// experience completes.
+    #[must_use]
+    pub fn total_frames(&self) -> Option<u32> {
+        self.seconds
+            .map(|seconds| (seconds.max(0.0) * self.fps.max(1) as f32).round() as u32)
+    }
+}
+
+/// Progress of the running offline render.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct OfflineRenderProgress {
+    /// Frames whose capture has been requested
+    pub requested: u32,
+    /// Frames written to disk (or failed); shared with the IO tasks
+    pub written: Arc<AtomicU32>,
+    /// Whether capture has stopped because the sequence is complete
+    pub finished: bool,
+}
+
+impl OfflineRenderProgress {
+    /// Frames finished writing so far.
+    #[must_use]
+    pub fn written(&self) -> u32 {
+        self.written.load(Ordering::Acquire)
+    }
+
+    /// Frames requested but not yet written.
+    #[must_use]
+    pub fn in_flight(&self) -> u32 {
+        self.requested.saturating_sub(self.written())
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Returns the file name of 1-based frame `number`.
+#[must_use]
+pub fn frame_file_name(number: u32) -> String {
+    format!("frame_{number:05}.png")
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Captures one window frame per update until the sequence is complete, then
+/// exits once every frame has been written.
+///
+/// The sequence is complete after `OfflineRenderConfig.seconds`, or when
+/// `ActState.completed_passes` first rises if no length is set. Frames are
+/// read back asynchronously and encoded on the IO task pool. Once
+/// `MAX_FRAMES_IN_FLIGHT` frames are waiting, virtual time is paused until
+/// the writes catch up; the frame drawn on resuming repeats the last captured
+/// one and is skipped. Exit waits for the last write.
+///
+/// # Stage
+/// Last
+pub fn capture_offline_frames(
+    mut commands: Commands,
+    config: Res<OfflineRenderConfig>,
+    act_state: Res<ActState>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut progress: ResMut<OfflineRenderProgress>,
+    mut exit_events: EventWriter<AppExit>,
+) {
+    if !progress.finished {
+        progress.finished = match config.total_frames() {
+            Some(total) => progress.requested >= total,
+            None => act_state.completed_passes > 0,
+        };
+    }
+
+    if progress.finished {
+        if virtual_time.is_paused() {
+            virtual_time.unpause();
+        }
+        if progress.in_flight() == 0 {
+            info!(
+                "Offline render complete: {} frames in {}",
+                progress.requested,
+                config.out_dir.display()
+            );
+            exit_events.send(AppExit::Success);
+        }
+        return;
+    }
+
+    if virtual_time.is_paused() {
+        if progress.in_flight() < MAX_FRAMES_IN_FLIGHT {
+            virtual_time.unpause();
+        }
+        return;
+    }
+
+    progress.requested += 1;
+    let path = config.out_dir.join(frame_file_name(progress.requested));
+    let written = Arc::clone(&progress.written);
+
+    commands.spawn(Screenshot::primary_window()).observe(
+        move |trigger: Trigger<ScreenshotCaptured>| {
+            let image = trigger.event().0.clone();
+            let path = path.clone();
+            let written = Arc::clone(&written);
+            IoTaskPool::get()
+                .spawn(async move {
+                    if let Err(error) = write_png(image, &path) {
+                        warn!("Failed to write frame {}: {error}", path.display());
+                    }
+                    written.fetch_add(1, Ordering::Release);
+                })
+                .detach();
+        },
+    );
+
+    if progress.in_flight() >= MAX_FRAMES_IN_FLIGHT {
+        virtual_time.pause();
+    }
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that renders the piece to a numbered PNG sequence.
+///
+/// Add it after `WhirledPeasPlugin`. Every `App::update` advances `Time` by
+/// exactly `1 / fps` however long the frame took to draw, so the sequence
+/// plays back at `fps` even when rendering runs far slower than real time.
+/// `ParticleRng` is seeded from `RngSeed` (`WhirledPeasPlugin::with_seed`;
+/// it defaults to 0), so a render can be repeated frame for frame. Live
+/// microphone input is not reproducible; leave it off for offline renders.
+///
+/// The manual time step also pauses `AdaptiveQuality`, `SimFrameBudget`,
+/// and the kiosk stall monitor (see `paced_by_real_time`), so a slow machine
+/// renders the same particles and effects as a fast one.
+///
+/// # Example
+///
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(WhirledPeasPlugin.with_seed(7))
+///     .add_plugins(OfflineRenderPlugin::default())
+///     .run();
+/// ```
+///
+/// # Systems
+/// - `capture_offline_frames` (Last): Captures each frame and exits when done
+#[derive(Debug, Clone, Default)]
+pub struct OfflineRenderPlugin {
+    /// Frame rate, destination, and length of the render
+    pub config: OfflineRenderConfig,
+}
+
+impl Plugin for OfflineRenderPlugin {
+    fn build(&self, app: &mut App) {
+        let seed = app
+            .world()
+            .get_resource::<RngSeed>()
+            .copied()
+            .unwrap_or_default();
+
+        match self.config.total_frames() {
+            Some(total) => info!(
+                "Offline render: {total} frames at {} fps into {}",
+                self.config.fps,
+                self.config.out_dir.display()
+            ),
+            None => info!(
+                "Offline render: one experience pass at {} fps into {}",
+                self.config.fps,
+                self.config.out_dir.display()
+            ),
+        }
+
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(self.config.frame_step()))
+            .insert_resource(seed)
+            .insert_resource(ParticleRng::with_seed(seed.0))
+            .insert_resource(self.config.clone())
+            .init_resource::<OfflineRenderProgress>()
+            .add_systems(Last, capture_offline_frames);
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::{apply_adaptive_quality, update_frame_metrics, MetricsPlugin};
+    use crate::resources::{
+        AdaptiveQuality, ParticlePool, PerformanceMetrics, PostProcessSettings,
+    };
+
+    #[test]
+    fn test_frame_numbering_and_counts() {
+        assert_eq!(frame_file_name(1), "frame_00001.png");
+        assert_eq!(frame_file_name(54_000), "frame_54000.png");
+
+        let config = OfflineRenderConfig::default();
+        assert_eq!(config.total_frames(), None);
+        assert_eq!(config.frame_step(), Duration::from_secs_f64(1.0 / 60.0));
+
+        let fixed = OfflineRenderConfig {
+            seconds: Some(900.0),
+            ..Default::default()
+        };
+        assert_eq!(fixed.total_frames(), Some(54_000));
+    }
+
+    fn render_app(seconds: Option<f32>) -> App {
+        let mut app = App::new();
+        app.insert_resource(OfflineRenderConfig {
+            fps: 10,
+            seconds,
+            ..Default::default()
+        })
+        .init_resource::<ActState>()
+        .init_resource::<Time<Virtual>>()
+        .init_resource::<OfflineRenderProgress>()
+        .add_event::<AppExit>()
+        .add_systems(Update, capture_offline_frames);
+        app
+    }
+
+    fn mark_written(app: &mut App, frames: u32) {
+        app.world()
+            .resource::<OfflineRenderProgress>()
+            .written
+            .store(frames, Ordering::Release);
+    }
+
+    #[test]
+    fn test_exits_only_after_every_frame_is_written() {
+        let mut app = render_app(Some(0.2));
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<OfflineRenderProgress>().requested, 2);
+        assert!(app.world().resource::<Events<AppExit>>().is_empty());
+
+        mark_written(&mut app, 2);
+        app.update();
+        assert_eq!(app.world().resource::<Events<AppExit>>().len(), 1);
+    }
+
+    #[test]
+    fn test_default_length_follows_experience_completion() {
+        let mut app = render_app(None);
+
+        for _ in 0..3 {
+            app.update();
+            let requested = app.world().resource::<OfflineRenderProgress>().requested;
+            mark_written(&mut app, requested);
+        }
+        assert_eq!(app.world().resource::<OfflineRenderProgress>().requested, 3);
+
+        app.world_mut().resource_mut::<ActState>().completed_passes = 1;
+        app.update();
+        assert_eq!(app.world().resource::<OfflineRenderProgress>().requested, 3);
+        assert_eq!(app.world().resource::<Events<AppExit>>().len(), 1);
+    }
+
+    #[test]
+    fn test_slow_writes_hold_simulated_time() {
+        let mut app = render_app(Some(10.0));
+
+        for _ in 0..MAX_FRAMES_IN_FLIGHT + 4 {
+            app.update();
+        }
+        // Capture stops at the in-flight limit and time is held
+        assert_eq!(
+            app.world().resource::<OfflineRenderProgress>().requested,
+            MAX_FRAMES_IN_FLIGHT
+        );
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+
+        // Once the writes catch up, time resumes; the stale frame is skipped
+        mark_written(&mut app, MAX_FRAMES_IN_FLIGHT);
+        app.update();
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+        assert_eq!(
+            app.world().resource::<OfflineRenderProgress>().requested,
+            MAX_FRAMES_IN_FLIGHT
+        );
+        app.update();
+        assert_eq!(
+            app.world().resource::<OfflineRenderProgress>().requested,
+            MAX_FRAMES_IN_FLIGHT + 1
+        );
+    }
+
+    #[test]
+    fn test_slow_offline_render_keeps_full_quality() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy::core::FrameCountPlugin,
+            bevy::time::TimePlugin,
+            MetricsPlugin,
+        ))
+        .add_event::<AppExit>()
+        .init_resource::<ActState>()
+        .init_resource::<AdaptiveQuality>()
+        .init_resource::<ParticlePool>()
+        .init_resource::<PerformanceMetrics>()
+        .init_resource::<PostProcessSettings>()
+        .add_plugins(OfflineRenderPlugin {
+            config: OfflineRenderConfig {
+                seconds: Some(5.0),
+                ..Default::default()
+            },
+        })
+        // Rendering crawls at 5 fps of wall-clock time
+        .add_systems(
+            First,
+            (|mut metrics: ResMut<PerformanceMetrics>| metrics.current_fps = 5.0)
+                .after(update_frame_metrics)
+                .before(apply_adaptive_quality),
+        )
+        // Frames write back instantly, so time never waits on the disk
+        .add_systems(
+            Last,
+            (|progress: Res<OfflineRenderProgress>| {
+                progress
+                    .written
+                    .store(progress.requested, Ordering::Release);
+            })
+            .after(capture_offline_frames),
+        );
+
+        for _ in 0..300 {
+            app.update();
+        }
+
+        assert!(app.world().resource::<AdaptiveQuality>().enabled);
+        let pool = app.world().resource::<ParticlePool>();
+        assert_eq!(pool.active_cap(), pool.max_active);
+        assert!(
+            !app.world()
+                .resource::<PostProcessSettings>()
+                .reduced_effects
+        );
+    }
+}
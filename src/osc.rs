@@ -0,0 +1,706 @@
+//! Module: osc
+//! Purpose: OSC-over-UDP input that feeds `AudioAnalysis` from an external analyzer
+//! Dependencies: particle, resources, types, bevy::prelude, std::net
+//!
+//! For live shows where the audio is analyzed elsewhere. `OscInputPlugin`
+//! binds a non-blocking UDP socket and drains it once per frame, so a quiet
+//! or flooded port never stalls the frame.
+//!
+//! # Address schema
+//!
+//! | Address          | Arguments                     | Effect                                   |
+//! |------------------|-------------------------------|------------------------------------------|
+//! | `/audio/bass`    | `f` (0.0-1.0)                 | `frequency_bass`, `amplitude_low`        |
+//! | `/audio/mid`     | `f` (0.0-1.0)                 | `frequency_mid`, `amplitude_mid`         |
+//! | `/audio/high`    | `f` (0.0-1.0)                 | `frequency_high`, `amplitude_high`       |
+//! | `/audio/shimmer` | `f` (0.0-1.0)                 | `frequency_shimmer`                      |
+//! | `/audio/peak`    | `f` (0.0-1.0)                 | `amplitude_peak` (else the band maximum) |
+//! | `/audio/beat`    | none, `s`, or `f` / `i`       | Fires `BeatDetected`                     |
+//! | `/intensity`     | `f` (0.0-1.0)                 | `Intensity::external_override`           |
+//!
+//! Level arguments may be `f`, `d`, or `i`; values are clamped to 0.0-1.0.
+//! A beat's strength is a name (`silence`, `soft`, `medium`, `strong`), a
+//! 0.0-1.0 amplitude classified with `BeatStrength::from_amplitude`, or
+//! `medium` when the message has no argument. Bundles are unpacked and their
+//! time tags ignored; unknown addresses are skipped.
+//!
+//! While messages keep arriving (see `OscInputConfig::live_timeout_secs`) OSC
+//! replaces the microphone or procedural source and onset detection; once
+//! the sender goes quiet the usual source takes over again and a pushed
+//! intensity is released back to the act and audio blend.
+
+use std::fmt;
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+
+use bevy::prelude::*;
+
+use crate::particle::BeatDetected;
+use crate::resources::{AudioAnalysis, Intensity};
+use crate::types::{BeatStrength, FrequencyBand};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Largest datagram read from the socket; larger packets are truncated and
+/// fail to parse.
+const MAX_PACKET_BYTES: usize = 4096;
+
+/// Datagrams drained per frame at most, so a flooding sender cannot hold the
+/// frame; the rest wait in the socket buffer for the next frame.
+const MAX_PACKETS_PER_FRAME: usize = 256;
+
+/// Bundles nested deeper than this are rejected.
+const MAX_BUNDLE_DEPTH: usize = 8;
+
+// =============================================================================
+// OSC PACKETS
+// =============================================================================
+
+/// One decoded OSC argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OscArg {
+    /// `i` (int32) or `h` (int64, saturated)
+    Int(i32),
+    /// `f` (float32) or `d` (float64)
+    Float(f32),
+    /// `s` or `S` string
+    Str(String),
+    /// `T` or `F`
+    Bool(bool),
+}
+
+impl OscArg {
+    /// Returns numeric arguments as `f32`.
+    #[must_use]
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            OscArg::Int(value) => Some(*value as f32),
+            OscArg::Float(value) => Some(*value),
+            OscArg::Str(_) | OscArg::Bool(_) => None,
+        }
+    }
+}
+
+/// One decoded OSC message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OscMessage {
+    /// Address pattern, e.g. `/audio/bass`
+    pub address: String,
+    /// Arguments in type-tag order
+    pub args: Vec<OscArg>,
+}
+
+/// Error produced when decoding an OSC packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OscError {
+    /// The packet ended in the middle of a field
+    Truncated,
+    /// A string is not null-terminated UTF-8
+    InvalidString,
+    /// The packet is neither a message (`/...`) nor a bundle (`#bundle`)
+    NotOsc,
+    /// The type tag string names a type this decoder does not handle
+    UnsupportedType(char),
+    /// Bundles nest deeper than `MAX_BUNDLE_DEPTH`
+    TooDeep,
+}
+
+impl fmt::Display for OscError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OscError::Truncated => write!(f, "packet truncated"),
+            OscError::InvalidString => write!(f, "invalid OSC string"),
+            OscError::NotOsc => write!(f, "not an OSC message or bundle"),
+            OscError::UnsupportedType(tag) => write!(f, "unsupported OSC type tag '{tag}'"),
+            OscError::TooDeep => write!(f, "bundles nested too deeply"),
+        }
+    }
+}
+
+impl std::error::Error for OscError {}
+
+/// Sequential reader over a packet's 4-byte-aligned fields.
+struct OscReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> OscReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.offset >= self.bytes.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], OscError> {
+        let end = self.offset.checked_add(len).ok_or(OscError::Truncated)?;
+        let slice = self
+            .bytes
+            .get(self.offset..end)
+            .ok_or(OscError::Truncated)?;
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn word(&mut self) -> Result<[u8; 4], OscError> {
+        let mut word = [0; 4];
+        word.copy_from_slice(self.take(4)?);
+        Ok(word)
+    }
+
+    fn long(&mut self) -> Result<[u8; 8], OscError> {
+        let mut long = [0; 8];
+        long.copy_from_slice(self.take(8)?);
+        Ok(long)
+    }
+
+    /// Reads a null-terminated string padded to a multiple of four bytes.
+    fn string(&mut self) -> Result<&'a str, OscError> {
+        let rest = self.bytes.get(self.offset..).ok_or(OscError::Truncated)?;
+        let len = rest
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(OscError::InvalidString)?;
+        let text = std::str::from_utf8(&rest[..len]).map_err(|_| OscError::InvalidString)?;
+        self.take((len + 4) & !3)?;
+        Ok(text)
+    }
+}
+
+/// Decodes a UDP payload into its messages, unpacking bundles in order.
+pub fn parse_osc_packet(bytes: &[u8]) -> Result<Vec<OscMessage>, OscError> {
+    let mut messages = Vec::new();
+    parse_packet_into(bytes, 0, &mut messages)?;
+    Ok(messages)
+}
+
+fn parse_packet_into(
+    bytes: &[u8],
+    depth: usize,
+    messages: &mut Vec<OscMessage>,
+) -> Result<(), OscError> {
+    match bytes.first() {
+        Some(b'/') => {
+            messages.push(parse_message(bytes)?);
+            Ok(())
+        }
+        Some(b'#') => {
+            if depth >= MAX_BUNDLE_DEPTH {
+                return Err(OscError::TooDeep);
+            }
+            let mut reader = OscReader::new(bytes);
+            if reader.string()? != "#bundle" {
+                return Err(OscError::NotOsc);
+            }
+            // Time tag: everything is applied on arrival
+            reader.long()?;
+            while !reader.is_empty() {
+                let size = i32::from_be_bytes(reader.word()?);
+                let size = usize::try_from(size).map_err(|_| OscError::Truncated)?;
+                parse_packet_into(reader.take(size)?, depth + 1, messages)?;
+            }
+            Ok(())
+        }
+        _ => Err(OscError::NotOsc),
+    }
+}
+
+fn parse_message(bytes: &[u8]) -> Result<OscMessage, OscError> {
+    let mut reader = OscReader::new(bytes);
+    let address = reader.string()?.to_string();
+
+    // Very old senders omit the type tag string entirely
+    if reader.is_empty() {
+        return Ok(OscMessage {
+            address,
+            args: Vec::new(),
+        });
+    }
+
+    let tags = reader.string()?;
+    let tags = tags.strip_prefix(',').ok_or(OscError::NotOsc)?;
+    let mut args = Vec::with_capacity(tags.len());
+    for tag in tags.chars() {
+        let arg = match tag {
+            'i' => OscArg::Int(i32::from_be_bytes(reader.word()?)),
+            'h' => {
+                let value = i64::from_be_bytes(reader.long()?);
+                OscArg::Int(value.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32)
+            }
+            'f' => OscArg::Float(f32::from_be_bytes(reader.word()?)),
+            'd' => OscArg::Float(f64::from_be_bytes(reader.long()?) as f32),
+            's' | 'S' => OscArg::Str(reader.string()?.to_string()),
+            'T' => OscArg::Bool(true),
+            'F' => OscArg::Bool(false),
+            other => return Err(OscError::UnsupportedType(other)),
+        };
+        args.push(arg);
+    }
+
+    Ok(OscMessage { address, args })
+}
+
+// =============================================================================
+// AUDIO MAPPING
+// =============================================================================
+
+/// What one `/audio/...` message asks for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OscAudioCommand {
+    /// Set one band's level
+    Band(FrequencyBand, f32),
+    /// Set the overall peak amplitude
+    Peak(f32),
+    /// Fire a beat
+    Beat(BeatStrength),
+    /// Push the global intensity
+    Intensity(f32),
+}
+
+/// Maps a message to its audio command, or `None` for unknown addresses and
+/// level messages without a numeric argument.
+#[must_use]
+pub fn osc_audio_command(message: &OscMessage) -> Option<OscAudioCommand> {
+    let level = || {
+        message
+            .args
+            .first()
+            .and_then(OscArg::as_f32)
+            .map(|value| value.clamp(0.0, 1.0))
+    };
+
+    match message.address.as_str() {
+        "/audio/bass" => level().map(|value| OscAudioCommand::Band(FrequencyBand::Bass, value)),
+        "/audio/mid" => level().map(|value| OscAudioCommand::Band(FrequencyBand::Mid, value)),
+        "/audio/high" => level().map(|value| OscAudioCommand::Band(FrequencyBand::High, value)),
+        "/audio/shimmer" => {
+            level().map(|value| OscAudioCommand::Band(FrequencyBand::Shimmer, value))
+        }
+        "/audio/peak" => level().map(OscAudioCommand::Peak),
+        "/intensity" => level().map(OscAudioCommand::Intensity),
+        "/audio/beat" => {
+            let strength = match message.args.first() {
+                None => Some(BeatStrength::Medium),
+                Some(OscArg::Str(name)) => BeatStrength::from_name(name),
+                Some(arg) => arg.as_f32().map(BeatStrength::from_amplitude),
+            };
+            strength.map(OscAudioCommand::Beat)
+        }
+        _ => None,
+    }
+}
+
+/// Writes a band or peak command into `analysis`.
+///
+/// A band update recomputes `amplitude_peak` as the loudest amplitude; an
+/// explicit `/audio/peak` later in the frame overrides that.
+fn apply_level(analysis: &mut AudioAnalysis, command: OscAudioCommand) {
+    match command {
+        OscAudioCommand::Band(FrequencyBand::Bass, value) => {
+            analysis.frequency_bass = value;
+            analysis.amplitude_low = value;
+        }
+        OscAudioCommand::Band(FrequencyBand::Mid, value) => {
+            analysis.frequency_mid = value;
+            analysis.amplitude_mid = value;
+        }
+        OscAudioCommand::Band(FrequencyBand::High, value) => {
+            analysis.frequency_high = value;
+            analysis.amplitude_high = value;
+        }
+        OscAudioCommand::Band(FrequencyBand::Shimmer, value) => {
+            analysis.frequency_shimmer = value;
+        }
+        OscAudioCommand::Peak(value) => {
+            analysis.amplitude_peak = value;
+            return;
+        }
+        OscAudioCommand::Beat(_) | OscAudioCommand::Intensity(_) => return,
+    }
+    analysis.amplitude_peak = analysis
+        .amplitude_low
+        .max(analysis.amplitude_mid)
+        .max(analysis.amplitude_high);
+}
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Where the OSC listener binds and how long it stays in charge.
+///
+/// Changing it at runtime rebinds the socket.
+#[derive(Resource, Debug, Clone)]
+pub struct OscInputConfig {
+    /// Local address to listen on; `0.0.0.0` accepts senders on any interface
+    pub bind_address: IpAddr,
+    /// UDP port to listen on
+    pub port: u16,
+    /// Seconds after the last message before the usual audio source resumes
+    pub live_timeout_secs: f32,
+}
+
+impl Default for OscInputConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            port: 9000,
+            live_timeout_secs: 2.0,
+        }
+    }
+}
+
+impl OscInputConfig {
+    /// Socket address the listener binds.
+    #[must_use]
+    pub fn socket_address(&self) -> SocketAddr {
+        SocketAddr::new(self.bind_address, self.port)
+    }
+}
+
+/// State of the OSC listener.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum OscStatus {
+    /// No socket bound yet
+    #[default]
+    Idle,
+    /// Bound and waiting for datagrams
+    Listening(SocketAddr),
+    /// The socket could not be bound; OSC input is off until the config changes
+    Failed(String),
+}
+
+/// The bound socket and whether OSC currently drives `AudioAnalysis`.
+#[derive(Resource, Debug, Default)]
+pub struct OscInput {
+    socket: Option<UdpSocket>,
+    /// Decoded commands waiting for the next `receive_osc_input`
+    pending: Vec<OscAudioCommand>,
+    /// Listener state, for diagnostics
+    pub status: OscStatus,
+    /// Seconds since the last `/audio/...` message was applied
+    pub seconds_since_message: Option<f32>,
+    /// True while messages arrive within `OscInputConfig::live_timeout_secs`
+    pub live: bool,
+    /// Packets that failed to decode since startup
+    pub malformed_packets: u32,
+}
+
+/// Condition function for run_if: returns true while OSC drives the audio.
+pub fn osc_input_live(input: Option<Res<OscInput>>) -> bool {
+    input.is_some_and(|input| input.live)
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Binds (or rebinds) the non-blocking listener for `OscInputConfig`.
+///
+/// A failed bind logs a warning and leaves `OscStatus::Failed`; the rest of
+/// the experience keeps its usual audio source.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// Runs before `receive_osc_input`, only when the config changed.
+pub fn bind_osc_socket(config: Res<OscInputConfig>, mut input: ResMut<OscInput>) {
+    let address = config.socket_address();
+    let bound = UdpSocket::bind(address).and_then(|socket| {
+        socket.set_nonblocking(true)?;
+        Ok(socket)
+    });
+
+    match bound {
+        Ok(socket) => {
+            info!("Listening for OSC audio on udp://{address}");
+            input.socket = Some(socket);
+            input.status = OscStatus::Listening(address);
+        }
+        Err(error) => {
+            warn!("Could not bind OSC listener on {address}: {error}");
+            input.socket = None;
+            input.status = OscStatus::Failed(error.to_string());
+        }
+    }
+    input.live = false;
+}
+
+/// Drains the socket and applies every `/audio/...` message, after any
+/// commands already queued on `OscInput`.
+///
+/// Levels are written straight into `AudioAnalysis`; each beat message sends
+/// `BeatDetected` and marks this frame's `beat_detected`/`beat_strength`.
+/// `/intensity` sets `Intensity::external_override` until OSC goes idle.
+/// Reading stops at the first `WouldBlock` or after `MAX_PACKETS_PER_FRAME`
+/// datagrams. Malformed packets are counted and skipped.
+///
+/// # Stage
+/// PreUpdate
+pub fn receive_osc_input(
+    time: Res<Time>,
+    config: Res<OscInputConfig>,
+    mut input: ResMut<OscInput>,
+    mut analysis: ResMut<AudioAnalysis>,
+    mut intensity: ResMut<Intensity>,
+    mut beat_events: EventWriter<BeatDetected>,
+) {
+    let mut commands = std::mem::take(&mut input.pending);
+    if let Some(socket) = &input.socket {
+        let mut buffer = [0u8; MAX_PACKET_BYTES];
+        let mut malformed = 0;
+        for _ in 0..MAX_PACKETS_PER_FRAME {
+            match socket.recv_from(&mut buffer) {
+                Ok((len, _)) => match parse_osc_packet(&buffer[..len]) {
+                    Ok(messages) => commands.extend(messages.iter().filter_map(osc_audio_command)),
+                    Err(error) => {
+                        debug!("Dropping OSC packet: {error}");
+                        malformed += 1;
+                    }
+                },
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                Err(error) => {
+                    debug!("OSC receive failed: {error}");
+                    break;
+                }
+            }
+        }
+        input.malformed_packets += malformed;
+    }
+
+    input.seconds_since_message = if commands.is_empty() {
+        input
+            .seconds_since_message
+            .map(|secs| secs + time.delta_secs())
+    } else {
+        Some(0.0)
+    };
+    let live = input
+        .seconds_since_message
+        .is_some_and(|secs| secs < config.live_timeout_secs);
+    if input.live != live {
+        input.live = live;
+        info!("OSC audio input {}", if live { "live" } else { "idle" });
+    }
+    if !live {
+        if intensity.external_override.is_some() {
+            intensity.external_override = None;
+        }
+        return;
+    }
+
+    let mut beat = None;
+    for command in commands {
+        match command {
+            OscAudioCommand::Beat(strength) => {
+                if strength.should_spawn() {
+                    beat_events.send(BeatDetected { strength });
+                }
+                beat = Some(strength);
+            }
+            OscAudioCommand::Intensity(value) => intensity.external_override = Some(value),
+            level => apply_level(&mut analysis, level),
+        }
+    }
+    analysis.beat_detected = beat.is_some_and(|strength| strength.should_spawn());
+    analysis.beat_strength = beat.unwrap_or(BeatStrength::Silence);
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that feeds `AudioAnalysis` and `BeatDetected` from OSC over UDP.
+///
+/// Not part of `WhirledPeasPlugin`; add it alongside, optionally inserting
+/// an `OscInputConfig` first:
+///
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(WhirledPeasPlugin)
+///     .insert_resource(OscInputConfig { port: 7000, ..default() })
+///     .add_plugins(OscInputPlugin)
+///     .run();
+/// ```
+///
+/// # Systems
+/// - `bind_osc_socket` (PreUpdate): Binds the listener when the config changes
+/// - `receive_osc_input` (PreUpdate): Drains the socket into `AudioAnalysis`
+pub struct OscInputPlugin;
+
+impl Plugin for OscInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OscInputConfig>()
+            .init_resource::<OscInput>()
+            .init_resource::<AudioAnalysis>()
+            .init_resource::<Intensity>()
+            .add_event::<BeatDetected>()
+            .add_systems(
+                PreUpdate,
+                (
+                    bind_osc_socket.run_if(resource_changed::<OscInputConfig>),
+                    receive_osc_input,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Appends `text` as a null-terminated, 4-byte-padded OSC string.
+    fn push_string(packet: &mut Vec<u8>, text: &str) {
+        packet.extend_from_slice(text.as_bytes());
+        packet.push(0);
+        while !packet.len().is_multiple_of(4) {
+            packet.push(0);
+        }
+    }
+
+    fn float_message(address: &str, value: f32) -> Vec<u8> {
+        let mut packet = Vec::new();
+        push_string(&mut packet, address);
+        push_string(&mut packet, ",f");
+        packet.extend_from_slice(&value.to_be_bytes());
+        packet
+    }
+
+    #[test]
+    fn test_parses_sample_packet_into_audio_fields() {
+        let messages = parse_osc_packet(&float_message("/audio/bass", 0.75)).unwrap();
+        assert_eq!(
+            messages,
+            vec![OscMessage {
+                address: "/audio/bass".to_string(),
+                args: vec![OscArg::Float(0.75)],
+            }]
+        );
+
+        let mut analysis = AudioAnalysis::default();
+        let command = osc_audio_command(&messages[0]).unwrap();
+        assert_eq!(command, OscAudioCommand::Band(FrequencyBand::Bass, 0.75));
+        apply_level(&mut analysis, command);
+        assert_eq!(analysis.frequency_bass, 0.75);
+        assert_eq!(analysis.amplitude_low, 0.75);
+        assert_eq!(analysis.amplitude_peak, 0.75);
+
+        let mut beat = Vec::new();
+        push_string(&mut beat, "/audio/beat");
+        push_string(&mut beat, ",s");
+        push_string(&mut beat, "Strong");
+        let beat = parse_osc_packet(&beat).unwrap();
+        assert_eq!(
+            osc_audio_command(&beat[0]),
+            Some(OscAudioCommand::Beat(BeatStrength::Strong))
+        );
+    }
+
+    #[test]
+    fn test_bundles_unpack_in_order() {
+        let mid = float_message("/audio/mid", 0.5);
+        let peak = float_message("/audio/peak", 2.0);
+
+        let mut bundle = Vec::new();
+        push_string(&mut bundle, "#bundle");
+        bundle.extend_from_slice(&1u64.to_be_bytes());
+        for element in [&mid, &peak] {
+            bundle.extend_from_slice(&(element.len() as i32).to_be_bytes());
+            bundle.extend_from_slice(element);
+        }
+
+        let commands: Vec<_> = parse_osc_packet(&bundle)
+            .unwrap()
+            .iter()
+            .filter_map(osc_audio_command)
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                OscAudioCommand::Band(FrequencyBand::Mid, 0.5),
+                // Levels are clamped into 0.0-1.0
+                OscAudioCommand::Peak(1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_beat_strength_mapping() {
+        let beat = |args: Vec<OscArg>| {
+            osc_audio_command(&OscMessage {
+                address: "/audio/beat".to_string(),
+                args,
+            })
+        };
+        assert_eq!(
+            beat(vec![]),
+            Some(OscAudioCommand::Beat(BeatStrength::Medium))
+        );
+        assert_eq!(
+            beat(vec![OscArg::Str("soft".to_string())]),
+            Some(OscAudioCommand::Beat(BeatStrength::Soft))
+        );
+        assert_eq!(
+            beat(vec![OscArg::Float(0.9)]),
+            Some(OscAudioCommand::Beat(BeatStrength::Strong))
+        );
+        assert_eq!(beat(vec![OscArg::Str("loud".to_string())]), None);
+    }
+
+    #[test]
+    fn test_intensity_override_follows_live_input() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<OscInputConfig>()
+            .init_resource::<OscInput>()
+            .init_resource::<AudioAnalysis>()
+            .init_resource::<Intensity>()
+            .add_event::<BeatDetected>()
+            .add_systems(Update, receive_osc_input);
+
+        let packet = parse_osc_packet(&float_message("/intensity", 0.85)).unwrap();
+        app.world_mut()
+            .resource_mut::<OscInput>()
+            .pending
+            .extend(packet.iter().filter_map(osc_audio_command));
+        app.update();
+        assert_eq!(
+            app.world().resource::<Intensity>().external_override,
+            Some(0.85)
+        );
+
+        // Once the sender goes quiet the blend takes over again
+        let timeout = app.world().resource::<OscInputConfig>().live_timeout_secs;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(timeout + 0.1));
+        app.update();
+        assert_eq!(app.world().resource::<Intensity>().external_override, None);
+    }
+
+    #[test]
+    fn test_malformed_packets_are_rejected() {
+        let packet = float_message("/audio/high", 0.3);
+        assert_eq!(
+            parse_osc_packet(&packet[..packet.len() - 2]),
+            Err(OscError::Truncated)
+        );
+        assert_eq!(parse_osc_packet(b"hello"), Err(OscError::NotOsc));
+
+        let mut blob = Vec::new();
+        push_string(&mut blob, "/audio/high");
+        push_string(&mut blob, ",b");
+        assert_eq!(parse_osc_packet(&blob), Err(OscError::UnsupportedType('b')));
+    }
+}
@@ -2,114 +2,463 @@
 //! Purpose: Particle lifecycle, pooling, motion simulation, and behavior systems
 //! Dependencies: components, resources, types
 
+use bevy::asset::LoadState;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::utils::{HashMap, Instant};
 
 use crate::components::{
-    Particle, ParticleBehavior, ParticleBundle, ParticleMotion, ParticleState, ParticleVisual,
-    PulseResponder, Spawnable,
+    Attractable, Attractor, AttractorPoint, MotionStreak, OrphanTrail, Particle, ParticleBehavior,
+    ParticleBundle, ParticleMotion, ParticleState, ParticleVisual, PulseResponder, Spawnable,
+    Trail, TrailRenderer,
 };
-use crate::intro::AppState;
+use crate::instancing::ParticleInstancingPlugin;
+use crate::noise::curl_noise;
+use crate::render_layers;
 use crate::resources::{
-    ActState, ColorPalette, CurrentInteractionMode, InterpolatedActValues,
-    MouseState, ParticlePool, ParticleSpawnQueue, ParticleSpawnRequest, PeaTexture,
+    experience_running, ActState, AudioAnalysis, BeatSpawnConfig, BehaviorTuning, ColorPalette,
+    CurrentInteractionMode, DensityOpacityConfig, DisplayScale, FadeProfiles, InkBudget, Intensity,
+    InterpolatedActValues, MagnetToy, MouseState, PaintColorOverride, PaintConfig, ParticlePool,
+    ParticleRng, ParticleSpawnQueue, ParticleSpawnRequest, PeaTexture, PerformanceMetrics,
+    Quietude, SimFrameBudget, SpawnBudgetConfig, TrailProfiles,
+};
+use crate::trail::{reset_trail, trail_is_visible, OrphanTrailPool};
+use crate::types::{
+    in_fidget_state, paced_by_real_time, Act, BeatStrength, BehaviorCoefficients, BlendMode,
+    InteractionMode, ParticleBehaviorType, RenderMode, SpawnSource,
 };
-use crate::types::{Act, BeatStrength, InteractionMode, ParticleBehaviorType, SpawnSource};
 
 // =============================================================================
 // CONSTANTS
 // =============================================================================
 
-/// Pool capacity - total pre-allocated particle entities.
-const POOL_CAPACITY: u32 = 15000;
-
-/// Maximum active particles at any time.
-const MAX_ACTIVE: u32 = 10000;
-
-/// Base size for pea particles (in pixels, before any scaling).
-/// Sized for visibility on mobile devices.
+/// Base size for pea particles (in world units, before any scaling).
+/// Sized for visibility on mobile devices; world units are DPI-independent.
 const PEA_BASE_SIZE: f32 = 80.0;
 
 /// Base particle lifetime in milliseconds.
 const BASE_LIFETIME_MS: f32 = 5000.0;
 
-/// Minimum spawn rate (particles per second) from mouse movement.
-const MOUSE_SPAWN_RATE_MIN: f32 = 8.0;
-
-/// Maximum spawn rate (particles per second) from mouse movement.
-const MOUSE_SPAWN_RATE_MAX: f32 = 15.0;
-
 /// Spawn rate multiplier when holding mouse button/finger while moving.
 const HELD_SPAWN_RATE_MULTIPLIER: f32 = 8.0;
 
 /// Maximum spawn rate when holding (particles per second).
 const HELD_SPAWN_RATE_MAX: f32 = 120.0;
 
-/// Spawn count range for soft beats.
-const SOFT_BEAT_SPAWN_RANGE: (u32, u32) = (5, 10);
-
-/// Spawn count range for medium beats.
-const MEDIUM_BEAT_SPAWN_RANGE: (u32, u32) = (10, 20);
-
-/// Spawn count range for strong beats.
-const STRONG_BEAT_SPAWN_RANGE: (u32, u32) = (20, 40);
+/// Viewport the beat spawn offsets were tuned for (world units).
+const BEAT_SPAWN_REFERENCE_VIEWPORT: Vec2 = Vec2::new(1920.0, 1080.0);
 
 /// Base turbulence strength.
 const BASE_TURBULENCE_STRENGTH: f32 = 15.0;
 
+/// Pointer speed (world units/s) that counts as full interaction for `Intensity`.
+const FULL_INTERACTION_SPEED: f32 = 800.0;
+
 /// Turbulence time scale for noise evolution.
 const TURBULENCE_TIME_SCALE: f32 = 0.5;
 
+/// World-to-noise scale of the turbulence field (features roughly 200px across).
+const TURBULENCE_SPATIAL_SCALE: f32 = 0.005;
+
+/// Scales `curl_noise` (mean magnitude ~2.0) down to roughly the unit range
+/// the act turbulence strengths are tuned for.
+const TURBULENCE_CURL_GAIN: f32 = 0.25;
+
+/// World speed of the Flow current per unit of curl (mean speed ~60px/s).
+const FLOW_SPEED: f32 = 30.0;
+
+/// Rate per second at which a full-weight Flow particle's velocity converges
+/// on the current.
+const FLOW_STEER_RATE: f32 = 3.0;
+
+/// Maximum lifetime drain multiplier applied when the population exceeds the
+/// act's density target. Caps how quickly excess particles can be retired.
+const DENSITY_EXPIRY_MAX_BOOST: f32 = 4.0;
+
+/// Fractional overshoot of the density target at which the drain boost is maxed.
+const DENSITY_EXPIRY_FULL_EXCESS: f32 = 0.5;
+
+/// Default `SpatialGrid` cell size in world units.
+const SPATIAL_GRID_CELL_SIZE: f32 = 100.0;
+
+/// Speed (world units per second) above which a pea streaks along its velocity.
+pub const STREAK_MIN_SPEED: f32 = 600.0;
+
+/// Fraction of `STREAK_MIN_SPEED` a streaking pea must drop below to turn
+/// round again, so peas hovering at the threshold do not flicker.
+const STREAK_RELEASE_FRACTION: f32 = 0.8;
+
+/// Extra speed above `STREAK_MIN_SPEED` that adds one pea length of streak.
+const STREAK_SPEED_PER_STRETCH: f32 = 500.0;
+
+/// Hard ceiling on any streak, whatever `MotionStreak::max_stretch` asks for.
+const MAX_STREAK_STRETCH: f32 = 8.0;
+
+/// Speed cap right after an explosion or hyperspace impulse, well past
+/// `STREAK_MIN_SPEED` so impulse-driven peas streak.
+pub const IMPULSE_MAX_SPEED: f32 = 2400.0;
+
+/// Seconds over which the impulse cap eases back down to the act's cap.
+pub const IMPULSE_CAP_EXEMPT_SECS: f32 = 0.75;
+
+/// Speed (world units per second) below which an oriented pea holds its last
+/// heading, so near-stationary jitter does not spin it.
+pub const ORIENT_MIN_SPEED: f32 = 20.0;
+
+/// Rate (per second) at which an oriented pea turns toward its velocity.
+const ORIENT_TURN_RATE: f32 = 10.0;
+
+/// Largest |x| or |y| accepted from `SpawnParticles`, well past any display edge.
+const SPAWN_POSITION_LIMIT: f32 = 4096.0;
+
+/// Lifetime range accepted from `SpawnParticles`, in milliseconds.
+const SPAWN_LIFETIME_RANGE_MS: (f32, f32) = (100.0, 60_000.0);
+
+/// Outward speed of the peas in a `SpawnParticles::burst`.
+const BURST_SPEED: f32 = 120.0;
+
 // =============================================================================
 // EVENTS
 // =============================================================================
 
-/// Event fired when a beat is detected for particle spawning.
-#[derive(Event, Debug, Clone)]
+/// Event fired when a beat is detected in the audio stream.
+///
+/// The strength classification determines the visual response magnitude,
+/// from subtle pulses to dramatic burst emissions. Sent by `audio_reactive`;
+/// without the `audio` feature it only fires if the host app sends it.
+#[derive(Event, Debug, Clone, Copy)]
 pub struct BeatDetected {
-    /// The strength of the detected beat.
+    /// Classification of the detected beat's intensity
     pub strength: BeatStrength,
 }
 
+/// Sent when queued spawns were discarded because the pool was full.
+///
+/// Fires at most once per frame, after `spawn_particles_from_queue`, with
+/// every request that found the cap reached or no pooled entity left.
+/// Ambient requests refused by the act density target or the interactive
+/// reserve are throttling, not saturation, and spawns held back by the
+/// per-frame spawn limit stay queued; neither is counted. Log it to tune
+/// `ParticlePool::max_active` for an installation.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolExhausted {
+    /// Spawn requests discarded this frame
+    pub dropped: usize,
+}
+
+/// Spawns peas on behalf of code embedding the visualizer.
+///
+/// This is the stable integration point for host apps: send it from any
+/// system and `enqueue_spawn_particles` validates each request before adding
+/// it to the `ParticleSpawnQueue`. Requests then go through the same pool
+/// limits and act density targets as beat and mouse spawns (only
+/// `SpawnSource::Mouse` bypasses the density target).
+///
+/// To follow what was actually spawned, read `ParticleSpawned` and
+/// `ParticleDespawned` after turning them on with
+/// `ParticleLifecycleEvents { enabled: true }`; they are off by default, so
+/// a reader alone receives nothing.
+#[derive(Event, Debug, Clone, Default)]
+pub struct SpawnParticles {
+    /// Particles to spawn, validated before they are queued
+    pub requests: Vec<ParticleSpawnRequest>,
+}
+
+impl SpawnParticles {
+    /// A burst of `count` peas at `center`, flung outward evenly around a ring.
+    #[must_use]
+    pub fn burst(center: Vec2, count: usize, color: Color) -> Self {
+        let requests = (0..count)
+            .map(|i| {
+                let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+                ParticleSpawnRequest {
+                    position: center,
+                    initial_velocity: Vec2::from_angle(angle) * BURST_SPEED,
+                    color,
+                    source: SpawnSource::Beat,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        Self { requests }
+    }
+}
+
+/// Sent when a pooled particle activates, if `ParticleLifecycleEvents` is enabled.
+///
+/// Fires once per activation from `spawn_particles_from_queue`, which can be
+/// thousands per second during a busy act; keep readers cheap.
+///
+/// Lifecycle events are off by default, and adding an
+/// `EventReader<ParticleSpawned>` does not turn them on. Enable them with
+/// `app.insert_resource(ParticleLifecycleEvents { enabled: true })`.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ParticleSpawned {
+    /// The pooled particle entity that activated
+    pub entity: Entity,
+    /// What requested the spawn
+    pub source: SpawnSource,
+    /// World position the particle spawned at
+    pub position: Vec2,
+}
+
+/// Sent when an expired particle returns to the pool, if
+/// `ParticleLifecycleEvents` is enabled.
+///
+/// Fires from `despawn_expired_particles` at the same high rate as
+/// `ParticleSpawned`, and like it is only sent once
+/// `ParticleLifecycleEvents.enabled` is set.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParticleDespawned {
+    /// The particle entity, now back in the pool
+    pub entity: Entity,
+    /// What requested the particle's spawn
+    pub source: SpawnSource,
+}
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Opt-in switch for `ParticleSpawned` and `ParticleDespawned`.
+///
+/// Off by default so the spawn and despawn paths pay nothing unless an
+/// integrator asks for the events; registering a reader does not enable them.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ParticleLifecycleEvents {
+    /// Whether lifecycle events are sent
+    pub enabled: bool,
+}
+
+/// Event writer that only sends while `ParticleLifecycleEvents` is enabled.
+///
+/// Apps without `ParticlePlugin` (no switch or event registered) send nothing.
+#[derive(SystemParam)]
+pub struct LifecycleEventWriter<'w, E: Event> {
+    /// Whether lifecycle events are wanted
+    pub config: Option<Res<'w, ParticleLifecycleEvents>>,
+    /// Queue for the lifecycle event
+    pub events: Option<ResMut<'w, Events<E>>>,
+}
+
+impl<E: Event> LifecycleEventWriter<'_, E> {
+    /// Sends the event built by `event` when lifecycle events are enabled.
+    pub fn send_with(&mut self, event: impl FnOnce() -> E) {
+        let enabled = self.config.as_ref().is_some_and(|config| config.enabled);
+        if let Some(events) = self.events.as_mut().filter(|_| enabled) {
+            events.send(event());
+        }
+    }
+}
+
+/// Active particle entities bucketed by position on a uniform grid.
+///
+/// Rebuilt each frame by `update_spatial_grid` so radius-limited effects
+/// (pointer influence, explosions, swarm separation, density softening) visit
+/// only the cells they overlap instead of every particle.
+#[derive(Resource, Debug, Clone)]
+pub struct SpatialGrid {
+    /// Side length of a grid cell in world units
+    pub cell_size: f32,
+    /// Entities and their positions in each occupied cell
+    pub cells: HashMap<IVec2, Vec<(Entity, Vec2)>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self {
+            cell_size: SPATIAL_GRID_CELL_SIZE,
+            cells: HashMap::default(),
+        }
+    }
+}
+
+impl SpatialGrid {
+    /// Returns the grid cell containing a world position.
+    #[must_use]
+    pub fn cell_of(&self, position: Vec2) -> IVec2 {
+        let size = self.cell_size.max(1.0);
+        (position / size).floor().as_ivec2()
+    }
+
+    /// Empties every cell.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Buckets an entity at a world position.
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        let cell = self.cell_of(position);
+        self.cells.entry(cell).or_default().push((entity, position));
+    }
+
+    /// Returns entities strictly within `radius` of `center`, as of the last rebuild.
+    ///
+    /// Only the cells overlapping the circle's bounding square are visited.
+    pub fn query_radius(&self, center: Vec2, radius: f32) -> impl Iterator<Item = Entity> + '_ {
+        self.neighbors_within(center, radius)
+            .map(|(entity, _)| entity)
+    }
+
+    /// Like `query_radius`, but also yields each entity's position at the last rebuild.
+    pub fn neighbors_within(
+        &self,
+        center: Vec2,
+        radius: f32,
+    ) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let radius = radius.max(0.0);
+        let min = self.cell_of(center - Vec2::splat(radius));
+        let max = self.cell_of(center + Vec2::splat(radius));
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |(_, position)| position.distance(center) < radius)
+            .copied()
+    }
+}
+
+// =============================================================================
+// TEXTURES
+// =============================================================================
+
+/// Soft white disc drawn in place of `pea.png` when that asset fails to load.
+///
+/// Alpha falls from opaque at the center to clear at the edge along a
+/// smoothstep, so tinted sprites read as glowing peas rather than squares.
+#[derive(Debug, Clone, Copy)]
+pub struct ProceduralPeaTexture {
+    /// Width and height in pixels
+    pub size: u32,
+}
+
+impl Default for ProceduralPeaTexture {
+    fn default() -> Self {
+        Self { size: 64 }
+    }
+}
+
+impl ProceduralPeaTexture {
+    /// Renders the radial-gradient RGBA image.
+    #[must_use]
+    pub fn build(&self) -> Image {
+        let size = self.size.max(1);
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255, 255, 255, 0],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+
+        let radius = size as f32 * 0.5;
+        for (index, pixel) in image.data.chunks_exact_mut(4).enumerate() {
+            // Sample at pixel centers
+            let x = (index as u32 % size) as f32 + 0.5;
+            let y = (index as u32 / size) as f32 + 0.5;
+            let distance = Vec2::new(x - radius, y - radius).length() / radius;
+            let t = (1.0 - distance).clamp(0.0, 1.0);
+            pixel[3] = (t * t * (3.0 - 2.0 * t) * 255.0).round() as u8;
+        }
+
+        image
+    }
+}
+
 // =============================================================================
 // STARTUP SYSTEMS
 // =============================================================================
 
 /// Loads the pea texture from assets and stores it as a resource.
-pub fn load_pea_texture(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let texture_handle: Handle<Image> = asset_server.load("pea.png");
+///
+/// Without an asset server (headless runs) the default handle is stored, so
+/// the pool still sets up but its sprites have nothing to draw.
+pub fn load_pea_texture(mut commands: Commands, asset_server: Option<Res<AssetServer>>) {
+    let texture_handle: Handle<Image> = asset_server
+        .map(|asset_server| asset_server.load("pea.png"))
+        .unwrap_or_default();
     commands.insert_resource(PeaTexture {
         handle: texture_handle,
     });
 }
 
+/// Replaces a `pea.png` that failed to load with `ProceduralPeaTexture`.
+///
+/// The fallback is inserted under the failed handle's id, so every sprite,
+/// trail, and instanced draw already holding `PeaTexture` picks it up without
+/// being touched. Stops checking once the texture has loaded or been replaced.
+///
+/// # Stage
+/// Update
+pub fn fallback_missing_pea_texture(
+    pea_texture: Option<Res<PeaTexture>>,
+    asset_server: Option<Res<AssetServer>>,
+    images: Option<ResMut<Assets<Image>>>,
+    mut resolved: Local<bool>,
+) {
+    if *resolved {
+        return;
+    }
+    let (Some(pea_texture), Some(asset_server), Some(mut images)) =
+        (pea_texture, asset_server, images)
+    else {
+        return;
+    };
+
+    match asset_server.load_state(pea_texture.handle.id()) {
+        LoadState::Loaded => *resolved = true,
+        LoadState::Failed(error) => {
+            warn!("Failed to load pea.png ({error}); using a procedural pea texture");
+            images.insert(
+                pea_texture.handle.id(),
+                ProceduralPeaTexture::default().build(),
+            );
+            *resolved = true;
+        }
+        LoadState::NotLoaded | LoadState::Loading => {}
+    }
+}
+
 /// Pre-allocates particle entities for object pooling.
 ///
-/// Creates 15000 particle entities in an inactive, hidden state and adds them
-/// to the `ParticlePool.available_entities` for efficient recycling during gameplay.
-/// This avoids runtime allocations and despawns, ensuring smooth performance.
+/// Creates `ParticlePool.pool_capacity` particle entities (15000 by default,
+/// overridable from `WhirledPeasConfig`) in an inactive, hidden state and adds
+/// them to `ParticlePool.available_entities` for efficient recycling during
+/// gameplay. This avoids runtime allocations and despawns, ensuring smooth
+/// performance.
+///
+/// In `RenderMode::Instanced` the particles get no `Sprite`; the instanced
+/// renderer draws them instead.
 pub fn setup_particle_pool(
     mut commands: Commands,
     mut pool: ResMut<ParticlePool>,
     pea_texture: Res<PeaTexture>,
+    render_mode: Res<RenderMode>,
 ) {
     pool.available_entities.clear();
     pool.active_count = 0;
-    pool.pool_capacity = POOL_CAPACITY;
-    pool.max_active = MAX_ACTIVE;
+    pool.max_active = pool.max_active.min(pool.pool_capacity);
 
     // Pre-allocate entity IDs for the pool
-    let mut entities = Vec::with_capacity(POOL_CAPACITY as usize);
+    let mut entities = Vec::with_capacity(pool.pool_capacity as usize);
 
-    for id in 0..POOL_CAPACITY {
-        let entity = commands
-            .spawn(ParticleBundle::new(id))
-            .insert(Sprite {
+    for id in 0..pool.pool_capacity {
+        let mut entity = commands.spawn(ParticleBundle::new(id));
+        if *render_mode == RenderMode::Sprites {
+            entity.insert(Sprite {
                 image: pea_texture.handle.clone(),
                 custom_size: Some(Vec2::splat(PEA_BASE_SIZE)),
                 ..default()
-            })
-            .id();
-        entities.push(entity);
+            });
+        }
+        entities.push(entity.id());
     }
 
     pool.available_entities = entities;
@@ -119,14 +468,74 @@ pub fn setup_particle_pool(
 // SPAWN SYSTEMS
 // =============================================================================
 
+/// Wall-clock start of this frame's spawn+motion pipeline.
+///
+/// Written by `begin_simulation_timing` and read by `end_simulation_timing`.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SimulationTimer {
+    /// When the pipeline started this frame
+    pub started: Option<Instant>,
+}
+
+/// Returns `request` with a positive lifetime and a finite, on-canvas position.
+///
+/// Non-finite values fall back to the `ParticleSpawnRequest` defaults.
+#[must_use]
+pub fn validate_spawn_request(mut request: ParticleSpawnRequest) -> ParticleSpawnRequest {
+    let defaults = ParticleSpawnRequest::default();
+    if !request.position.is_finite() {
+        request.position = defaults.position;
+    }
+    request.position = request.position.clamp(
+        Vec2::splat(-SPAWN_POSITION_LIMIT),
+        Vec2::splat(SPAWN_POSITION_LIMIT),
+    );
+    if !request.initial_velocity.is_finite() {
+        request.initial_velocity = defaults.initial_velocity;
+    }
+    if !request.lifetime_ms.is_finite() {
+        request.lifetime_ms = defaults.lifetime_ms;
+    }
+    let (min_lifetime_ms, max_lifetime_ms) = SPAWN_LIFETIME_RANGE_MS;
+    request.lifetime_ms = request.lifetime_ms.clamp(min_lifetime_ms, max_lifetime_ms);
+    request
+}
+
+/// Validates `SpawnParticles` requests and adds them to the spawn queue.
+///
+/// Runs before `spawn_particles_from_queue` so they activate the same frame.
+pub fn enqueue_spawn_particles(
+    mut events: EventReader<SpawnParticles>,
+    mut spawn_queue: ResMut<ParticleSpawnQueue>,
+) {
+    for event in events.read() {
+        spawn_queue
+            .pending_spawns
+            .extend(event.requests.iter().cloned().map(validate_spawn_request));
+    }
+}
+
 /// Activates pooled particles from the spawn queue.
 ///
 /// This is a CRITICAL PATH system that processes the `ParticleSpawnQueue` and
 /// activates available particles from the pool. It respects `ParticlePool.max_active`
 /// to prevent performance degradation from too many active particles.
+///
+/// Non-interactive sources stop short of `max_active` by the slice reserved in
+/// `SpawnBudgetConfig`, so mouse spawns queued behind a large beat burst still
+/// activate even when the cap is tiny.
+///
+/// Requests past `SpawnBudgetConfig.frame_spawn_limit` stay queued for the
+/// next frame. Ambient requests refused by the density target or the
+/// interactive reserve are discarded silently; requests left over once the
+/// pool is full are discarded and reported in a single `PoolExhausted` event.
+/// Each activation sends `ParticleSpawned` when `ParticleLifecycleEvents` is
+/// enabled.
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_particles_from_queue(
     mut pool: ResMut<ParticlePool>,
     mut spawn_queue: ResMut<ParticleSpawnQueue>,
+    mut rng: ResMut<ParticleRng>,
     mut query: Query<
         (
             &mut ParticleState,
@@ -136,22 +545,51 @@ pub fn spawn_particles_from_queue(
             &mut ParticleBehavior,
             &mut Spawnable,
             &mut Visibility,
+            Option<&mut TrailRenderer>,
         ),
         With<Particle>,
     >,
     interpolated: Res<InterpolatedActValues>,
+    budget: Res<SpawnBudgetConfig>,
+    fade_profiles: Res<FadeProfiles>,
+    trail_profiles: Res<TrailProfiles>,
+    mut exhausted_events: EventWriter<PoolExhausted>,
+    mut spawned_events: LifecycleEventWriter<ParticleSpawned>,
 ) {
     // Process pending spawn requests
-    let pending = std::mem::take(&mut spawn_queue.pending_spawns);
+    let mut pending = std::mem::take(&mut spawn_queue.pending_spawns).into_iter();
+    let mut dropped = 0;
 
-    for request in pending {
+    // Non-interactive sources leave the reserved slice for mouse painting
+    let active_cap = pool.active_cap();
+    let non_interactive_cap = active_cap.saturating_sub(budget.interactive_reserve(active_cap));
+    let mut spawned_this_frame = 0;
+
+    while let Some(request) = pending.next() {
         // Check if we can spawn more particles
-        if pool.active_count >= pool.max_active {
+        if pool.active_count >= active_cap || pool.available_entities.is_empty() {
+            dropped += 1 + pending.len();
+            break;
+        }
+        // Over the frame limit the rest wait for the next frame
+        if spawned_this_frame >= budget.frame_spawn_limit {
+            spawn_queue.pending_spawns.push(request);
+            spawn_queue.pending_spawns.extend(pending);
             break;
         }
 
-        // Get an available entity from the pool
+        // Ambient spawns respect the (interpolated) act density target and the
+        // interactive reserve; user-driven spawns are only bounded by max_active
+        if request.source != SpawnSource::Mouse
+            && (pool.active_count as f32 >= interpolated.density_target
+                || pool.active_count >= non_interactive_cap)
+        {
+            continue;
+        }
+
+        // Get an available entity from the pool (checked non-empty above)
         let Some(entity) = pool.available_entities.pop() else {
+            dropped += 1 + pending.len();
             break;
         };
 
@@ -164,45 +602,66 @@ pub fn spawn_particles_from_queue(
             mut behavior,
             mut spawnable,
             mut visibility,
+            trail_renderer,
         )) = query.get_mut(entity)
         {
-            // Set particle state to active
+            // Set particle state to active, scaling lifetime for the current act
+            let lifetime_ms = request.lifetime_ms * interpolated.lifetime_multiplier;
             state.active = true;
-            state.lifetime_remaining_ms = request.lifetime_ms;
-            state.lifetime_total_ms = request.lifetime_ms;
+            state.lifetime_remaining_ms = lifetime_ms;
+            state.lifetime_total_ms = lifetime_ms;
 
             // Set position
-            transform.translation = request.position.extend(0.0);
+            transform.translation = request.position.extend(render_layers::PARTICLES);
             transform.scale = Vec3::splat(visual.scale);
 
             // Set visual properties
             visual.base_color = request.color;
             visual.current_color = request.color;
             visual.opacity = 1.0;
+            visual.hue_shift_degrees = 0.0;
+            visual.fade_profile = fade_profiles.for_source(request.source);
+            visual.orient_to_velocity = request.orient_to_velocity;
 
             // Set motion properties
             motion.velocity = request.initial_velocity;
             motion.acceleration = Vec2::ZERO;
-            motion.drag = interpolated.particle_behavior.base_drag();
-            motion.turbulence_seed = fastrand::f32() * 1000.0;
+            motion.drag = interpolated.behavior_coefficients.drag;
+            motion.turbulence_seed = rng.0.f32() * 1000.0;
+            motion.impulse_secs = 0.0;
 
-            // Set behavior based on current act
-            behavior.behavior_type = interpolated.particle_behavior;
+            // Set behavior from the current act blend
+            (behavior.coefficients, behavior.blend_target) = interpolated.particle_blend();
             behavior.behavior_strength = 1.0;
             behavior.target_position = None;
 
-            // Set spawn source
+            // Set spawn source and the trail character that goes with it
             spawnable.spawn_source = request.source;
+            if let Some(mut renderer) = trail_renderer {
+                let profile = trail_profiles.for_source(request.source);
+                renderer.max_segments = profile.max_segments;
+                renderer.fade_duration_ms = profile.fade_duration_ms;
+            }
 
             // Make visible
             *visibility = Visibility::Visible;
 
+            spawned_events.send_with(|| ParticleSpawned {
+                entity,
+                source: request.source,
+                position: request.position,
+            });
             pool.active_count += 1;
+            spawned_this_frame += 1;
         } else {
             // Entity query failed, return it to pool
             pool.available_entities.push(entity);
         }
     }
+
+    if dropped > 0 {
+        exhausted_events.send(PoolExhausted { dropped });
+    }
 }
 
 /// Spawns particles from mouse/touch interaction.
@@ -213,33 +672,68 @@ pub fn spawn_particles_from_queue(
 ///
 /// Spawn rate varies from 8-15 particles/sec normally, up to 120 particles/sec
 /// when holding and moving quickly.
+///
+/// A frame's spawns are spread evenly along the segment from
+/// `MouseState.previous_position` to `position` (see `stroke_spawn_position`),
+/// so fast strokes paint a continuous line instead of beads at each frame's
+/// endpoint.
+///
+/// When `InkBudget` is enabled each spawn spends one unit of ink; a depleted
+/// budget drops the pending accumulator so strokes thin to the refill rate.
+///
+/// Velocity and color jitter are drawn from the current stroke's seed (see
+/// `MouseState::begin_stroke`), so each stroke keeps a coherent texture.
+/// `PaintColorOverride`, when set, replaces the palette color.
+///
+/// Mouse buttons and `TouchState` are optional, so the system runs without
+/// `InputPlugin` or `InteractionPlugin`; missing input never counts as held.
+///
+/// Nothing is painted while the pointer erases, whether through Erase mode
+/// or the `EraserOverride`, so the eraser never draws as it erases, nor while
+/// the Magnet Toy is active, where dragging moves its poles instead.
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_particles_from_mouse(
-    mouse: Res<MouseState>,
-    mode: Res<CurrentInteractionMode>,
+    mut mouse: ResMut<MouseState>,
+    mut ink: ResMut<InkBudget>,
     mut spawn_queue: ResMut<ParticleSpawnQueue>,
     interpolated: Res<InterpolatedActValues>,
     palette: Res<ColorPalette>,
     time: Res<Time>,
-    mouse_button: Res<ButtonInput<MouseButton>>,
-    touch_state: Res<crate::interaction::TouchState>,
+    mouse_button: Option<Res<ButtonInput<MouseButton>>>,
+    touch_state: Option<Res<crate::interaction::TouchState>>,
+    paint_config: Res<PaintConfig>,
+    color_override: Res<PaintColorOverride>,
+    current_mode: Option<Res<CurrentInteractionMode>>,
+    eraser: Option<Res<crate::interaction::EraserOverride>>,
+    magnet_toy: Option<Res<MagnetToy>>,
 ) {
     // Spawn particles when touching/clicking in any mode (fidget app behavior)
-    let _ = mode; // Mode no longer restricts spawning
     if !mouse.is_active {
         return;
     }
 
+    let erasing = current_mode.is_some_and(|mode| mode.mode == InteractionMode::Erase)
+        || eraser.is_some_and(|eraser| eraser.is_active());
+    if erasing || magnet_toy.is_some_and(|toy| toy.active) {
+        return;
+    }
+
+    // A frozen frame spawns nothing and keeps the accumulator where it was
+    let Some(delta_secs) = simulation_delta(&time) else {
+        return;
+    };
+
     // Check if holding mouse button or touch
-    let is_holding = mouse_button.pressed(MouseButton::Left)
-        || touch_state.primary_touch_id.is_some();
+    let is_holding = mouse_button.is_some_and(|buttons| buttons.pressed(MouseButton::Left))
+        || touch_state.is_some_and(|touch| touch.primary_touch_id.is_some());
 
     // Calculate spawn rate based on mouse velocity
     let mouse_speed = mouse.velocity.length();
     let speed_factor = (mouse_speed / 500.0).clamp(0.0, 1.0);
 
     // Base spawn rate
-    let base_spawn_rate =
-        MOUSE_SPAWN_RATE_MIN + (MOUSE_SPAWN_RATE_MAX - MOUSE_SPAWN_RATE_MIN) * speed_factor;
+    let (rate_min, rate_max) = paint_config.spawn_rate_range;
+    let base_spawn_rate = (rate_min + (rate_max - rate_min) * speed_factor).max(f32::EPSILON);
 
     // Apply multiplier when holding and moving
     let spawn_rate = if is_holding && mouse_speed > 10.0 {
@@ -252,36 +746,91 @@ pub fn spawn_particles_from_mouse(
     };
 
     // Accumulate time for spawn timing
-    spawn_queue.spawn_accumulator += time.delta_secs();
+    spawn_queue.spawn_accumulator += delta_secs;
 
     let spawn_interval = 1.0 / spawn_rate;
-    while spawn_queue.spawn_accumulator >= spawn_interval {
+    let spawn_count = (spawn_queue.spawn_accumulator / spawn_interval) as u32;
+    for index in 0..spawn_count {
         spawn_queue.spawn_accumulator -= spawn_interval;
 
+        // Out of ink: discard the backlog rather than bursting on refill
+        if !ink.try_spend(1.0) {
+            spawn_queue.spawn_accumulator = 0.0;
+            break;
+        }
+
+        // Jitter comes from the stroke's seed so one gesture keeps one texture
+        let mut rng = mouse.next_stroke_rng();
+
         // Calculate initial velocity based on mouse velocity with some randomization
-        let base_velocity = mouse.velocity * 0.3;
-        let random_offset = Vec2::new(
-            (fastrand::f32() - 0.5) * 50.0,
-            (fastrand::f32() - 0.5) * 50.0,
+        let initial_velocity = paint_spawn_velocity(
+            mouse.velocity,
+            &paint_config,
+            Vec2::new(rng.f32(), rng.f32()),
         );
-        let initial_velocity = base_velocity + random_offset;
 
-        // Select color from palette with some variation
-        let color = select_spawn_color(&palette, &interpolated, SpawnSource::Mouse);
+        // A user-chosen paint color wins over the palette
+        let color = match color_override.color {
+            Some(color) => color,
+            None => select_spawn_color(&palette, &interpolated, SpawnSource::Mouse, &mut rng),
+        };
 
         // Calculate lifetime with source multiplier
         let lifetime = BASE_LIFETIME_MS * SpawnSource::Mouse.lifetime_multiplier();
 
         spawn_queue.pending_spawns.push(ParticleSpawnRequest {
-            position: mouse.position,
+            position: stroke_spawn_position(
+                mouse.previous_position,
+                mouse.position,
+                index,
+                spawn_count,
+            ),
             initial_velocity,
             color,
             lifetime_ms: lifetime,
             source: SpawnSource::Mouse,
+            stroke_seed: Some(mouse.stroke_seed),
+            orient_to_velocity: paint_config.orient_to_velocity,
         });
     }
 }
 
+/// Returns where the `index`-th of `count` spawns along a pointer segment goes.
+///
+/// Spawns are spaced evenly from just past `from` up to `to`, so the last one
+/// lands on the current pointer position and consecutive frames tile the path
+/// without doubling up on their shared endpoint.
+#[must_use]
+pub fn stroke_spawn_position(from: Vec2, to: Vec2, index: u32, count: u32) -> Vec2 {
+    from.lerp(to, (index + 1) as f32 / count.max(1) as f32)
+}
+
+/// Refills the paint ink budget while it is enabled.
+///
+/// # Ordering
+/// Runs before `spawn_particles_from_mouse`.
+pub fn regenerate_ink(mut ink: ResMut<InkBudget>, time: Res<Time>) {
+    if ink.enabled {
+        ink.regenerate(time.delta_secs());
+    }
+}
+
+/// Calculates the initial velocity of a Paint-spawned particle.
+///
+/// Inherits `velocity_inheritance` of the pointer velocity and adds a random
+/// per-axis offset of width `random_spread`.
+///
+/// # Arguments
+/// * `mouse_velocity` - Current pointer velocity
+/// * `config` - Paint velocity configuration
+/// * `random` - Per-axis random samples in [0.0, 1.0)
+#[must_use]
+pub fn paint_spawn_velocity(mouse_velocity: Vec2, config: &PaintConfig, random: Vec2) -> Vec2 {
+    let base_velocity = mouse_velocity * config.velocity_inheritance;
+    let random_offset = (random - Vec2::splat(0.5)) * config.random_spread;
+    base_velocity + random_offset
+}
+
 /// Condition function for run_if: returns true when interaction mode is Paint.
 pub fn interaction_mode_is_paint(mode: Res<CurrentInteractionMode>) -> bool {
     mode.mode == InteractionMode::Paint
@@ -289,39 +838,36 @@ pub fn interaction_mode_is_paint(mode: Res<CurrentInteractionMode>) -> bool {
 
 /// Spawns particles in response to detected beats.
 ///
-/// Different beat strengths trigger different spawn patterns:
-/// - Soft: 5-10 particles in a gentle scatter
-/// - Medium: 10-20 particles in a ripple pattern
-/// - Strong: 20-40 particles in a radial burst
+/// Different beat strengths trigger different spawn patterns, with counts
+/// from `BeatSpawnConfig` (5-10, 10-20 and 20-40 by default):
+/// - Soft: a gentle scatter
+/// - Medium: a ripple pattern
+/// - Strong: a radial burst
+///
+/// Pattern extents scale with the visible viewport and `BeatSpawnConfig`.
 pub fn spawn_particles_from_beat(
     mut events: EventReader<BeatDetected>,
     mut spawn_queue: ResMut<ParticleSpawnQueue>,
-    _act_state: Res<ActState>,
     interpolated: Res<InterpolatedActValues>,
     palette: Res<ColorPalette>,
     mouse: Res<MouseState>,
+    display_scale: Res<DisplayScale>,
+    beat_spawn_config: Res<BeatSpawnConfig>,
+    mut particle_rng: ResMut<ParticleRng>,
 ) {
+    let extent_scale =
+        beat_spawn_extent_scale(display_scale.world_viewport, beat_spawn_config.radius_scale);
+    let rng = &mut particle_rng.0;
+
     for event in events.read() {
-        let (min_count, max_count, pattern) = match event.strength {
+        let ((min_count, max_count), pattern) = match event.strength {
             BeatStrength::Silence => continue,
-            BeatStrength::Soft => (
-                SOFT_BEAT_SPAWN_RANGE.0,
-                SOFT_BEAT_SPAWN_RANGE.1,
-                SpawnPattern::Scatter,
-            ),
-            BeatStrength::Medium => (
-                MEDIUM_BEAT_SPAWN_RANGE.0,
-                MEDIUM_BEAT_SPAWN_RANGE.1,
-                SpawnPattern::Ripple,
-            ),
-            BeatStrength::Strong => (
-                STRONG_BEAT_SPAWN_RANGE.0,
-                STRONG_BEAT_SPAWN_RANGE.1,
-                SpawnPattern::Burst,
-            ),
+            BeatStrength::Soft => (beat_spawn_config.soft_count, SpawnPattern::Scatter),
+            BeatStrength::Medium => (beat_spawn_config.medium_count, SpawnPattern::Ripple),
+            BeatStrength::Strong => (beat_spawn_config.strong_count, SpawnPattern::Burst),
         };
 
-        let count = fastrand::u32(min_count..=max_count);
+        let count = rng.u32(min_count.min(max_count)..=max_count.max(min_count));
 
         // Use mouse position as spawn center if active, otherwise use screen center
         let center = if mouse.is_active {
@@ -332,39 +878,13 @@ pub fn spawn_particles_from_beat(
 
         // Spawn particles according to pattern
         for i in 0..count {
-            let (position, velocity) = match pattern {
-                SpawnPattern::Scatter => {
-                    let offset = Vec2::new(
-                        (fastrand::f32() - 0.5) * 200.0,
-                        (fastrand::f32() - 0.5) * 200.0,
-                    );
-                    let vel = Vec2::new(
-                        (fastrand::f32() - 0.5) * 100.0,
-                        (fastrand::f32() - 0.5) * 100.0,
-                    );
-                    (center + offset, vel)
-                }
-                SpawnPattern::Ripple => {
-                    let angle = (i as f32 / count as f32) * std::f32::consts::TAU;
-                    let radius = 50.0 + fastrand::f32() * 50.0;
-                    let offset = Vec2::new(angle.cos(), angle.sin()) * radius;
-                    let vel = offset.normalize_or_zero() * (30.0 + fastrand::f32() * 50.0);
-                    (center + offset, vel)
-                }
-                SpawnPattern::Burst => {
-                    let angle = (i as f32 / count as f32) * std::f32::consts::TAU
-                        + (fastrand::f32() - 0.5) * 0.3;
-                    let speed = 100.0 + fastrand::f32() * 150.0;
-                    let vel = Vec2::new(angle.cos(), angle.sin()) * speed;
-                    let offset = vel.normalize_or_zero() * (10.0 + fastrand::f32() * 30.0);
-                    (center + offset, vel)
-                }
-            };
+            let (offset, velocity) = beat_pattern_offset(pattern, i, count, extent_scale, rng);
+            let position = center + offset;
 
-            let color = select_spawn_color(&palette, &interpolated, SpawnSource::Beat);
+            let color = select_spawn_color(&palette, &interpolated, SpawnSource::Beat, rng);
             let lifetime = BASE_LIFETIME_MS
                 * SpawnSource::Beat.lifetime_multiplier()
-                * (0.8 + fastrand::f32() * 0.4);
+                * (0.8 + rng.f32() * 0.4);
 
             spawn_queue.pending_spawns.push(ParticleSpawnRequest {
                 position,
@@ -372,6 +892,8 @@ pub fn spawn_particles_from_beat(
                 color,
                 lifetime_ms: lifetime,
                 source: SpawnSource::Beat,
+                stroke_seed: None,
+                orient_to_velocity: false,
             });
         }
     }
@@ -388,16 +910,64 @@ enum SpawnPattern {
     Burst,
 }
 
+/// Returns the multiplier applied to beat spawn offsets for a viewport.
+///
+/// 1.0 at the 1920x1080 reference; the tighter axis wins so bursts never
+/// spill off a narrow (e.g. portrait) viewport.
+#[must_use]
+pub fn beat_spawn_extent_scale(world_viewport: Vec2, radius_scale: f32) -> f32 {
+    (world_viewport / BEAT_SPAWN_REFERENCE_VIEWPORT)
+        .min_element()
+        .max(0.0)
+        * radius_scale
+}
+
+/// Computes the spawn offset from the beat center and the initial velocity
+/// for particle `index` of `count` in `pattern`.
+///
+/// Offsets are multiplied by `extent_scale`; velocities are left unscaled.
+fn beat_pattern_offset(
+    pattern: SpawnPattern,
+    index: u32,
+    count: u32,
+    extent_scale: f32,
+    rng: &mut fastrand::Rng,
+) -> (Vec2, Vec2) {
+    match pattern {
+        SpawnPattern::Scatter => {
+            let offset = Vec2::new((rng.f32() - 0.5) * 200.0, (rng.f32() - 0.5) * 200.0);
+            let vel = Vec2::new((rng.f32() - 0.5) * 100.0, (rng.f32() - 0.5) * 100.0);
+            (offset * extent_scale, vel)
+        }
+        SpawnPattern::Ripple => {
+            let angle = (index as f32 / count as f32) * std::f32::consts::TAU;
+            let radius = 50.0 + rng.f32() * 50.0;
+            let direction = Vec2::new(angle.cos(), angle.sin());
+            let vel = direction * (30.0 + rng.f32() * 50.0);
+            (direction * radius * extent_scale, vel)
+        }
+        SpawnPattern::Burst => {
+            let angle =
+                (index as f32 / count as f32) * std::f32::consts::TAU + (rng.f32() - 0.5) * 0.3;
+            let speed = 100.0 + rng.f32() * 150.0;
+            let vel = Vec2::new(angle.cos(), angle.sin()) * speed;
+            let offset = vel.normalize_or_zero() * (10.0 + rng.f32() * 30.0);
+            (offset * extent_scale, vel)
+        }
+    }
+}
+
 /// Selects a spawn color from the palette based on source and act state.
 fn select_spawn_color(
     palette: &ColorPalette,
     interpolated: &InterpolatedActValues,
     source: SpawnSource,
+    rng: &mut fastrand::Rng,
 ) -> Color {
     let base_color = match source {
         SpawnSource::Mouse => {
             // Mouse spawns use accent colors
-            let r = fastrand::f32();
+            let r = rng.f32();
             if r < 0.4 {
                 palette.accent_spark
             } else if r < 0.7 {
@@ -408,7 +978,7 @@ fn select_spawn_color(
         }
         SpawnSource::Beat => {
             // Beat spawns use primary colors with saturation
-            let r = fastrand::f32();
+            let r = rng.f32();
             if r < 0.5 {
                 palette.primary_midpoint
             } else if r < 0.8 {
@@ -419,7 +989,7 @@ fn select_spawn_color(
         }
         SpawnSource::Automatic => {
             // Automatic spawns use secondary colors
-            let r = fastrand::f32();
+            let r = rng.f32();
             if r < 0.4 {
                 palette.secondary_cool
             } else if r < 0.7 {
@@ -465,32 +1035,125 @@ fn apply_saturation_multiplier(color: Color, multiplier: f32) -> Color {
 // LIFETIME SYSTEMS
 // =============================================================================
 
+/// Returns how much faster particle lifetimes should drain given the population.
+///
+/// Below the density target lifetimes drain at the normal rate (1.0). Above it
+/// the multiplier grows in proportion to the overshoot, capped at
+/// `DENSITY_EXPIRY_MAX_BOOST`, so a falling target thins the swarm gradually
+/// instead of culling it in a single frame.
+///
+/// # Arguments
+/// * `active_count` - Currently active particles
+/// * `density_target` - Interpolated target particle count for the act
+#[must_use]
+pub fn density_expiry_multiplier(active_count: u32, density_target: f32) -> f32 {
+    let target = density_target.max(1.0);
+    let excess = (active_count as f32 - target) / target;
+
+    if excess <= 0.0 {
+        return 1.0;
+    }
+
+    let t = (excess / DENSITY_EXPIRY_FULL_EXCESS).min(1.0);
+    1.0 + t * (DENSITY_EXPIRY_MAX_BOOST - 1.0)
+}
+
+/// Returns the frame delta in seconds, or `None` when simulation time did not advance.
+///
+/// A paused or zero-delta `Time` must leave the swarm frozen. A zero step is
+/// not a no-op for every system (random forces are resampled, accumulated
+/// acceleration is cleared, speeds are clamped), so motion, lifecycle, and
+/// spawn systems return early on `None` instead.
+#[must_use]
+pub fn simulation_delta(time: &Time) -> Option<f32> {
+    let delta = time.delta_secs();
+    (delta > 0.0 && delta.is_finite()).then_some(delta)
+}
+
 /// Updates particle lifetime for all active particles.
 ///
 /// This is a CRITICAL PATH system that decrements `lifetime_remaining_ms` by
 /// delta time for all active particles. Runs on up to 10k particles per frame.
+///
+/// When the population is over the act's density target, lifetimes drain
+/// faster (see `density_expiry_multiplier`) so the swarm glides toward the
+/// target as it moves across act transitions. Painted particles are neither
+/// counted toward the target nor drained faster, so painting above the
+/// target never shortens the stroke the user just drew.
+///
+/// # Stage
+/// Update (after spawning)
 pub fn update_particle_lifetime(
-    mut query: Query<&mut ParticleState, With<Particle>>,
+    mut query: Query<(&mut ParticleState, Option<&Spawnable>), With<Particle>>,
     time: Res<Time>,
+    interpolated: Res<InterpolatedActValues>,
 ) {
-    let delta_ms = time.delta_secs() * 1000.0;
+    let Some(delta_secs) = simulation_delta(&time) else {
+        return;
+    };
 
-    for mut state in query.iter_mut() {
-        if state.active {
-            state.lifetime_remaining_ms -= delta_ms;
+    let unpainted_count = query
+        .iter()
+        .filter(|(state, spawnable)| state.active && !is_painted(*spawnable))
+        .count() as u32;
+    let delta_ms = delta_secs * 1000.0;
+    let boosted_delta_ms =
+        delta_ms * density_expiry_multiplier(unpainted_count, interpolated.density_target);
+
+    for (mut state, spawnable) in query.iter_mut() {
+        if !state.active {
+            continue;
         }
+        state.lifetime_remaining_ms -= if is_painted(spawnable) {
+            delta_ms
+        } else {
+            boosted_delta_ms
+        };
     }
 }
 
+/// Returns whether a particle was painted by the pointer.
+fn is_painted(spawnable: Option<&Spawnable>) -> bool {
+    spawnable.is_some_and(|spawnable| spawnable.spawn_source == SpawnSource::Mouse)
+}
+
+/// Particles `despawn_expired_particles` may return to the pool, with the
+/// trail state an orphan trail copies.
+type ExpiringParticleQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut ParticleState,
+        &'static mut Visibility,
+        Option<&'static mut Trail>,
+        Option<&'static TrailRenderer>,
+        Option<&'static ParticleVisual>,
+        Option<&'static Spawnable>,
+    ),
+    With<Particle>,
+>;
+
 /// Returns expired particles to the pool.
 ///
 /// Particles with `lifetime_remaining_ms <= 0` are deactivated, hidden, and
 /// returned to `ParticlePool.available_entities` for reuse.
+///
+/// A still-visible trail is copied onto a pooled `OrphanTrail` so it fades
+/// out on its own instead of vanishing with the particle. Without an
+/// `OrphanTrailPool` (no `TrailPlugin`) trails are simply reset.
+///
+/// Each returned particle sends `ParticleDespawned` when
+/// `ParticleLifecycleEvents` is enabled.
 pub fn despawn_expired_particles(
     mut pool: ResMut<ParticlePool>,
-    mut query: Query<(Entity, &mut ParticleState, &mut Visibility), With<Particle>>,
+    mut query: ExpiringParticleQuery,
+    mut orphan_pool: Option<ResMut<OrphanTrailPool>>,
+    mut orphans: Query<(&mut OrphanTrail, &mut Trail), Without<Particle>>,
+    mut despawned_events: LifecycleEventWriter<ParticleDespawned>,
 ) {
-    for (entity, mut state, mut visibility) in query.iter_mut() {
+    for (entity, mut state, mut visibility, trail, renderer, visual, spawnable) in query.iter_mut()
+    {
         if state.active && state.lifetime_remaining_ms <= 0.0 {
             // Deactivate particle
             state.active = false;
@@ -499,128 +1162,402 @@ pub fn despawn_expired_particles(
             // Hide particle
             *visibility = Visibility::Hidden;
 
+            // Detach the trail so it keeps fading after the particle is gone
+            if let Some(mut trail) = trail {
+                if let Some(orphan_pool) = orphan_pool.as_deref_mut() {
+                    if trail_is_visible(&trail) {
+                        let orphan = OrphanTrail {
+                            active: true,
+                            renderer: renderer.copied().unwrap_or_default(),
+                            color: visual.map_or(Color::WHITE, |visual| visual.current_color),
+                        };
+                        detach_trail(&trail, orphan, orphan_pool, &mut orphans);
+                    }
+                }
+                reset_trail(&mut trail);
+            }
+
             // Return to pool
+            despawned_events.send_with(|| ParticleDespawned {
+                entity,
+                source: spawnable.map_or_else(SpawnSource::default, |s| s.spawn_source),
+            });
             pool.available_entities.push(entity);
             pool.active_count = pool.active_count.saturating_sub(1);
         }
     }
 }
 
+/// Copies `trail` and its `orphan` renderer and color onto a free orphan
+/// entity, if the pool has one.
+fn detach_trail(
+    trail: &Trail,
+    orphan: OrphanTrail,
+    pool: &mut OrphanTrailPool,
+    orphans: &mut Query<(&mut OrphanTrail, &mut Trail), Without<Particle>>,
+) {
+    let Some(entity) = pool.acquire() else {
+        return;
+    };
+    match orphans.get_mut(entity) {
+        Ok((mut pooled, mut orphan_trail)) => {
+            *pooled = orphan;
+            *orphan_trail = *trail;
+        }
+        // Stale entity: drop it from the pool rather than handing it out again
+        Err(_) => pool.active_count = pool.active_count.saturating_sub(1),
+    }
+}
+
 // =============================================================================
 // MOTION SYSTEMS
 // =============================================================================
 
-/// Applies act-specific behavior to particle motion.
+/// Computes behavior acceleration as a weighted sum of force kernels.
 ///
-/// This is a CRITICAL PATH system implementing different behaviors per act:
-/// - Drift: Random walk with low speed (Act I)
-/// - Swarm: Move toward center of nearby particles (Act II)
-/// - Orbit: Circular motion around center (Act III)
-/// - Disperse: Move upward and outward (Act IV)
-/// - Float: Very slow drift with minimal forces (Act V)
-pub fn apply_particle_behavior(
-    mut query: Query<
-        (
-            &ParticleBehavior,
-            &ParticleState,
-            &Transform,
-            &mut ParticleMotion,
-        ),
-        With<Particle>,
-    >,
-    time: Res<Time>,
-) {
-    let _dt = time.delta_secs();
+/// Kernels, each scaled by its weight in `coefficients`:
+/// - wander: random walk (Drift, Float)
+/// - cohesion: distance-weighted pull toward the target (Swarm)
+/// - centripetal / tangential: pull and spin around the target (Orbit)
+/// - lift / outward: rise and spread from the center (Disperse, Float)
+///
+/// # Arguments
+/// * `coefficients` - Kernel weights
+/// * `pos` - Particle position
+/// * `target` - Swarm/orbit target (screen center when unset)
+/// * `random` - Per-axis random samples in [0.0, 1.0)
+#[must_use]
+pub fn behavior_acceleration(
+    coefficients: &BehaviorCoefficients,
+    pos: Vec2,
+    target: Vec2,
+    random: Vec2,
+) -> Vec2 {
+    let mut acceleration = (random - Vec2::splat(0.5)) * coefficients.wander;
+
+    let to_target = target - pos;
+    let distance = to_target.length();
+    if distance > 1.0 {
+        // Attraction force diminishes with distance
+        let attraction_strength = (300.0 / (distance + 100.0)) * 50.0;
+        acceleration += to_target / distance * attraction_strength * coefficients.cohesion;
+    }
+    if distance > 10.0 {
+        let inward = to_target / distance;
+        let tangent = Vec2::new(-inward.y, inward.x);
+        acceleration += inward * coefficients.centripetal + tangent * coefficients.tangential;
+    }
 
-    for (behavior, state, transform, mut motion) in query.iter_mut() {
-        if !state.active {
-            continue;
+    acceleration.y += coefficients.lift;
+    if pos.length() > 1.0 {
+        acceleration += pos.normalize() * coefficients.outward;
+    }
+
+    acceleration
+}
+
+/// `behavior_acceleration` for a particle, crossfading its blend target.
+///
+/// During an act transition the particle's own weights and its blend target
+/// each contribute by weight, so at 0.5 both behaviors count equally.
+#[must_use]
+pub fn blended_behavior_acceleration(
+    behavior: &ParticleBehavior,
+    pos: Vec2,
+    target: Vec2,
+    random: Vec2,
+) -> Vec2 {
+    let own = behavior_acceleration(&behavior.coefficients, pos, target, random);
+    match behavior.blend_target {
+        Some((other, weight)) => {
+            let other = behavior_acceleration(&other.coefficients(), pos, target, random);
+            own * (1.0 - weight) + other * weight
         }
+        None => own,
+    }
+}
 
-        let pos = transform.translation.truncate();
-        let strength = behavior.behavior_strength;
-
-        match behavior.behavior_type {
-            ParticleBehaviorType::Drift => {
-                // Random walk: add small random acceleration
-                let random_accel = Vec2::new(
-                    (fastrand::f32() - 0.5) * 30.0,
-                    (fastrand::f32() - 0.5) * 30.0,
-                ) * strength;
-                motion.acceleration = random_accel;
-            }
-            ParticleBehaviorType::Swarm => {
-                // Swarm toward target or screen center
-                let target = behavior.target_position.unwrap_or(Vec2::ZERO);
-                let to_target = target - pos;
-                let distance = to_target.length();
-
-                if distance > 1.0 {
-                    // Attraction force diminishes with distance
-                    let attraction_strength = (300.0 / (distance + 100.0)) * 50.0;
-                    motion.acceleration = to_target.normalize() * attraction_strength * strength;
-                }
-            }
-            ParticleBehaviorType::Orbit => {
-                // Circular motion around target or center
-                let target = behavior.target_position.unwrap_or(Vec2::ZERO);
-                let to_center = target - pos;
-                let distance = to_center.length();
-
-                if distance > 10.0 {
-                    // Centripetal acceleration toward center
-                    let centripetal = to_center.normalize() * 40.0;
-                    // Tangential component for orbital motion
-                    let tangent = Vec2::new(-to_center.y, to_center.x).normalize() * 60.0;
-
-                    motion.acceleration = (centripetal + tangent) * strength;
-                }
-            }
-            ParticleBehaviorType::Disperse => {
-                // Rise upward and spread outward
-                let upward = Vec2::new(0.0, 50.0);
-                let outward = if pos.length() > 1.0 {
-                    pos.normalize() * 20.0
-                } else {
-                    Vec2::ZERO
-                };
-                motion.acceleration = (upward + outward) * strength;
-            }
-            ParticleBehaviorType::Float => {
-                // Very gentle drift with slight upward tendency
-                let gentle_drift = Vec2::new(
-                    (fastrand::f32() - 0.5) * 10.0,
-                    5.0 + (fastrand::f32() - 0.5) * 5.0,
-                );
-                motion.acceleration = gentle_drift * strength;
-            }
+/// Computes the separation acceleration pushing a particle away from close neighbors.
+///
+/// Each neighbor within `radius` contributes a push directly away from it,
+/// weighted by `radius / distance - 1` so the push fades to zero at the edge
+/// and grows as the pair closes in. Coincident neighbors are skipped, as they
+/// give no direction.
+///
+/// # Arguments
+/// * `pos` - Particle position
+/// * `neighbors` - Positions of nearby particles, excluding the particle itself
+/// * `radius` - Distance beyond which neighbors are ignored
+/// * `strength` - Scale of the push
+#[must_use]
+pub fn separation_acceleration(
+    pos: Vec2,
+    neighbors: impl IntoIterator<Item = Vec2>,
+    radius: f32,
+    strength: f32,
+) -> Vec2 {
+    neighbors
+        .into_iter()
+        .filter_map(|neighbor| {
+            let away = pos - neighbor;
+            let distance = away.length();
+            (distance > 0.001 && distance < radius)
+                .then(|| away / distance * (radius / distance - 1.0))
+        })
+        .sum::<Vec2>()
+        * strength
+}
+
+/// Returns the weighted blend of `points` as seen from `pos`.
+///
+/// Each point contributes its position in proportion to
+/// `AttractorPoint::weight_at`. Returns `None` when there are no points or
+/// none has positive strength, so callers fall back to the origin.
+#[must_use]
+pub fn blended_attractor_target<'a>(
+    pos: Vec2,
+    points: impl IntoIterator<Item = &'a AttractorPoint>,
+) -> Option<Vec2> {
+    let (weighted, total) = points
+        .into_iter()
+        .fold((Vec2::ZERO, 0.0), |(sum, total), point| {
+            let weight = point.weight_at(pos);
+            (sum + point.position * weight, total + weight)
+        });
+    (total > f32::EPSILON).then(|| weighted / total)
+}
+
+/// Whether `coefficients` pull toward a target (Swarm and Orbit kernels).
+fn seeks_target(coefficients: &BehaviorCoefficients) -> bool {
+    coefficients.cohesion != 0.0
+        || coefficients.centripetal != 0.0
+        || coefficients.tangential != 0.0
+}
+
+/// Points swarming and orbiting particles at the blended `AttractorPoint`s.
+///
+/// Writes `ParticleBehavior::target_position` for every active particle whose
+/// coefficients pull toward a target; `None` (the origin) when no attractor
+/// point exists. Other particles keep their target.
+///
+/// # Ordering
+/// After `spawn_particles_from_queue` (which clears targets), before
+/// `apply_particle_behavior`.
+pub fn assign_attractor_targets(
+    points: Query<&AttractorPoint>,
+    mut particles: Query<(&ParticleState, &Transform, &mut ParticleBehavior), With<Particle>>,
+) {
+    for (state, transform, mut behavior) in particles.iter_mut() {
+        if !state.active || !seeks_target(&behavior.blended_coefficients()) {
+            continue;
+        }
+        let target = blended_attractor_target(transform.translation.truncate(), &points);
+        if behavior.target_position != target {
+            behavior.target_position = target;
         }
     }
 }
 
-/// Applies turbulence using noise for organic particle movement.
+/// Applies act-specific behavior to particle motion.
 ///
-/// Uses a simplified noise function based on the particle's turbulence_seed
-/// and current time. Turbulence strength varies by act:
-/// - Higher in Acts III (Crescendo) and IV (Release)
-/// - Lower in Act V (Transcendence)
-pub fn apply_turbulence(
-    mut query: Query<(&mut ParticleMotion, &ParticleState, &Transform), With<Particle>>,
-    act_state: Res<ActState>,
+/// This is a CRITICAL PATH system. Each act's behavior is a point in
+/// `BehaviorCoefficients` space, blended across transitions by act management:
+/// - Drift: Random walk with low speed (Act I)
+/// - Swarm: Move toward center of nearby particles (Act II)
+/// - Orbit: Circular motion around center (Act III)
+/// - Disperse: Move upward and outward (Act IV)
+/// - Float: Very slow drift with minimal forces (Act V)
+/// - Flow: Carried along the `flow_velocity` curl-noise current (opt-in)
+///
+/// Flow steers velocity directly rather than adding acceleration, by a
+/// fraction `coefficients.flow * FLOW_STEER_RATE * dt` per frame; drag and
+/// the speed clamp in `apply_velocity_changes` still bound the result.
+///
+/// Behavior acceleration is scaled by the act's
+/// `PhysicsProfile::acceleration_scale`.
+///
+/// Under Swarm and Orbit, `separation_acceleration` from at most
+/// `BehaviorTuning.max_separation_neighbors` nearby particles (found through
+/// last frame's `SpatialGrid`) keeps the peas from collapsing into a blob.
+pub fn apply_particle_behavior(
+    mut query: Query<
+        (
+            Entity,
+            &ParticleBehavior,
+            &ParticleState,
+            &Transform,
+            &mut ParticleMotion,
+        ),
+        With<Particle>,
+    >,
     time: Res<Time>,
+    mut rng: ResMut<ParticleRng>,
+    interpolated: Res<InterpolatedActValues>,
+    tuning: Res<BehaviorTuning>,
+    grid: Res<SpatialGrid>,
 ) {
+    let Some(dt) = simulation_delta(&time) else {
+        return;
+    };
     let elapsed = time.elapsed_secs();
+    let acceleration_scale = interpolated.physics.acceleration_scale;
+
+    // Separation fades with Swarm/Orbit's share of a behavior crossfade
+    let separation_weight = if tuning.separation_strength > 0.0 {
+        interpolated.behavior_weight(&[ParticleBehaviorType::Swarm, ParticleBehaviorType::Orbit])
+    } else {
+        0.0
+    };
+
+    for (entity, behavior, state, transform, mut motion) in query.iter_mut() {
+        if !state.active {
+            continue;
+        }
+
+        let pos = transform.translation.truncate();
+        let target = behavior.target_position.unwrap_or(Vec2::ZERO);
+        let random = Vec2::new(rng.0.f32(), rng.0.f32());
+
+        motion.acceleration = blended_behavior_acceleration(behavior, pos, target, random)
+            * behavior.behavior_strength
+            * acceleration_scale;
+
+        let flow = behavior.blended_coefficients().flow;
+        if flow > 0.0 {
+            let steer = (flow * FLOW_STEER_RATE * dt).min(1.0);
+            let current = flow_velocity(pos, elapsed) * behavior.behavior_strength;
+            motion.velocity = motion.velocity.lerp(current, steer);
+        }
+
+        if separation_weight > 0.0 {
+            let neighbors = grid
+                .neighbors_within(pos, tuning.separation_radius)
+                .filter(|(neighbor, _)| *neighbor != entity)
+                .take(tuning.max_separation_neighbors)
+                .map(|(_, neighbor_pos)| neighbor_pos);
+            motion.acceleration += separation_acceleration(
+                pos,
+                neighbors,
+                tuning.separation_radius,
+                tuning.separation_strength * separation_weight,
+            );
+        }
+    }
+}
+
+/// Samples the turbulence noise field at a position.
+///
+/// The curl of 3D simplex noise (see `curl_noise`), sampled at the scaled
+/// position with time as the third axis, so turbulence swirls instead of
+/// bunching particles up. The particle's seed offsets the time axis so
+/// neighbors decorrelate. Shared by `apply_turbulence` and the trail shimmer
+/// so both see the same field.
+///
+/// # Arguments
+/// * `pos` - World position to sample
+/// * `elapsed` - Elapsed time in seconds
+/// * `seed` - The particle's `turbulence_seed`
+#[must_use]
+pub fn sample_turbulence_field(pos: Vec2, elapsed: f32, seed: f32) -> Vec2 {
+    let point = (pos * TURBULENCE_SPATIAL_SCALE).extend(elapsed * TURBULENCE_TIME_SCALE + seed);
+    curl_noise(point) * TURBULENCE_CURL_GAIN
+}
+
+/// Returns the Flow current's velocity at a position.
+///
+/// The curl of the turbulence noise (no per-particle seed, so every particle
+/// follows the same streamlines), scaled to world units by `FLOW_SPEED`.
+/// Divergence-free, so Flow neither clumps nor thins the swarm.
+///
+/// # Arguments
+/// * `pos` - World position to sample
+/// * `elapsed` - Elapsed time in seconds
+#[must_use]
+pub fn flow_velocity(pos: Vec2, elapsed: f32) -> Vec2 {
+    let point = (pos * TURBULENCE_SPATIAL_SCALE).extend(elapsed * TURBULENCE_TIME_SCALE);
+    curl_noise(point) * FLOW_SPEED
+}
 
-    // Turbulence strength varies by act
-    let act_turbulence_multiplier = match act_state.current_act {
+/// Returns the turbulence multiplier of `act` while its intensity rests on the baseline.
+///
+/// - Higher in Acts III (Crescendo) and IV (Release)
+/// - Lower in Act V (Transcendence)
+#[must_use]
+pub fn act_turbulence(act: Act) -> f32 {
+    match act {
         Act::Emergence => 0.5,
         Act::Accumulation => 0.6,
         Act::Crescendo => 1.0,
         Act::Release => 0.8,
         Act::Transcendence => 0.3,
+    }
+}
+
+/// Returns the turbulence strength applied for the given act, intensity, and quietude.
+///
+/// At rest the act's `act_turbulence` applies; audio and interaction lift it
+/// toward full strength as far as they lift `Intensity` above the act baseline.
+#[must_use]
+pub fn turbulence_strength(act: Act, intensity: &Intensity, quietude: &Quietude) -> f32 {
+    let rest = act_turbulence(act);
+    let gain = rest + (1.0 - rest) * intensity.lift(act);
+    BASE_TURBULENCE_STRENGTH * gain * quietude.scale(quietude.turbulence_floor)
+}
+
+/// Recomputes the global `Intensity` from act, audio energy, and interaction.
+///
+/// Audio energy is `AudioAnalysis.amplitude_peak`; interaction is the pointer
+/// speed relative to `FULL_INTERACTION_SPEED` while the pointer is active. An
+/// `external_override` wins over the blend. Registered here rather than by
+/// `AudioReactivePlugin`, so the value still follows the act and the pointer
+/// with audio off or compiled out.
+///
+/// # System Ordering
+/// - Runs after: `process_audio_input` (when audio is enabled)
+/// - Runs before: `apply_turbulence`, `apply_audio_to_spawn_rate`, `apply_pulse_effect`
+pub fn update_intensity(
+    act_state: Res<ActState>,
+    audio_analysis: Res<AudioAnalysis>,
+    mouse_state: Res<MouseState>,
+    mut intensity: ResMut<Intensity>,
+) {
+    let interaction = if mouse_state.is_active {
+        mouse_state.velocity.length() / FULL_INTERACTION_SPEED
+    } else {
+        0.0
+    };
+
+    let value = match intensity.external_override {
+        Some(value) => value.clamp(0.0, 1.0),
+        None => intensity.blend(
+            act_state.current_act,
+            audio_analysis.amplitude_peak,
+            interaction,
+        ),
     };
+    if intensity.value != value {
+        intensity.value = value;
+    }
+}
 
-    let turbulence_strength = BASE_TURBULENCE_STRENGTH * act_turbulence_multiplier;
+/// Applies turbulence using noise for organic particle movement.
+///
+/// Samples `sample_turbulence_field` with the particle's turbulence_seed
+/// and current time. Turbulence strength follows the act and the global
+/// `Intensity` (see `turbulence_strength`) and settles toward
+/// `Quietude.turbulence_floor` in sustained silence.
+pub fn apply_turbulence(
+    mut query: Query<(&mut ParticleMotion, &ParticleState, &Transform), With<Particle>>,
+    act_state: Res<ActState>,
+    intensity: Res<Intensity>,
+    quietude: Res<Quietude>,
+    time: Res<Time>,
+) {
+    let Some(dt) = simulation_delta(&time) else {
+        return;
+    };
+    let elapsed = time.elapsed_secs();
+    let turbulence_strength = turbulence_strength(act_state.current_act, &intensity, &quietude);
 
     for (mut motion, state, transform) in query.iter_mut() {
         if !state.active {
@@ -628,34 +1565,158 @@ pub fn apply_turbulence(
         }
 
         let pos = transform.translation.truncate();
+        let field = sample_turbulence_field(pos, elapsed, motion.turbulence_seed);
+        let turbulence = field * turbulence_strength;
+        motion.velocity += turbulence * dt;
+    }
+}
+
+/// Returns the acceleration an attractor applies to a particle at `particle_pos`.
+///
+/// Points toward the attractor for positive strength and away for negative
+/// strength, with quadratic falloff to zero at the attractor's radius.
+#[must_use]
+pub fn attractor_acceleration(
+    particle_pos: Vec2,
+    attractor_pos: Vec2,
+    attractor: &Attractor,
+) -> Vec2 {
+    let to_attractor = attractor_pos - particle_pos;
+    let distance = to_attractor.length();
+    if distance < 1.0 {
+        return Vec2::ZERO;
+    }
+
+    let falloff = crate::interaction::quadratic_falloff(distance, attractor.radius);
+    to_attractor / distance * attractor.strength * falloff
+}
+
+/// Run condition: true while the narrative act forces should apply.
+pub fn act_forces_enabled(magnet_toy: Res<MagnetToy>) -> bool {
+    !magnet_toy.active
+}
+
+/// Run condition: true while the Magnet Toy mode is active.
+pub fn magnet_toy_active(magnet_toy: Res<MagnetToy>) -> bool {
+    magnet_toy.active
+}
+
+/// Adds the pull or push of every `Attractor` entity to particle acceleration.
+///
+/// Weighted by each particle's `Attractable::attraction_weight` when present.
+///
+/// # Ordering
+/// Runs after `apply_turbulence`, before `integrate_particle_motion`.
+pub fn apply_attractor_forces(
+    attractors: Query<(&Transform, &Attractor), Without<Particle>>,
+    mut particles: Query<
+        (
+            &Transform,
+            &ParticleState,
+            &mut ParticleMotion,
+            Option<&Attractable>,
+        ),
+        With<Particle>,
+    >,
+    time: Res<Time>,
+) {
+    if attractors.is_empty() || simulation_delta(&time).is_none() {
+        return;
+    }
+
+    for (transform, state, mut motion, attractable) in particles.iter_mut() {
+        if !state.active {
+            continue;
+        }
+
+        let pos = transform.translation.truncate();
+        let weight = attractable.map_or(1.0, |a| a.attraction_weight);
+        for (attractor_transform, attractor) in attractors.iter() {
+            let attractor_pos = attractor_transform.translation.truncate();
+            motion.acceleration += attractor_acceleration(pos, attractor_pos, attractor) * weight;
+        }
+    }
+}
+
+/// Damps particle velocity while the Magnet Toy is active for a filing-like settle.
+pub fn apply_magnet_toy_damping(
+    mut query: Query<(&mut ParticleMotion, &ParticleState), With<Particle>>,
+    magnet_toy: Res<MagnetToy>,
+    time: Res<Time>,
+) {
+    let Some(dt) = simulation_delta(&time) else {
+        return;
+    };
+    let retention = magnet_toy.filing_drag.clamp(0.0, 1.0).powf(dt * 60.0);
+
+    for (mut motion, state) in query.iter_mut() {
+        if state.active {
+            motion.velocity *= retention;
+        }
+    }
+}
 
-        // Simple noise approximation using sine waves with different frequencies
-        let t = elapsed * TURBULENCE_TIME_SCALE + motion.turbulence_seed;
-        let noise_x = (t * 1.3 + pos.x * 0.01).sin() * 0.5
-            + (t * 2.7 + pos.y * 0.02).sin() * 0.3
-            + (t * 0.7 + pos.x * 0.03 + pos.y * 0.02).sin() * 0.2;
-        let noise_y = (t * 1.7 + pos.y * 0.01).sin() * 0.5
-            + (t * 2.3 + pos.x * 0.02).sin() * 0.3
-            + (t * 0.9 + pos.y * 0.03 + pos.x * 0.02).sin() * 0.2;
+/// Minimum seconds between warnings about particles with non-finite motion.
+const NON_FINITE_WARNING_INTERVAL_SECS: f32 = 5.0;
 
-        let turbulence = Vec2::new(noise_x, noise_y) * turbulence_strength;
-        motion.velocity += turbulence * time.delta_secs();
+/// Throttles the warning logged when particles are recycled for non-finite motion.
+#[derive(Default)]
+pub struct NonFiniteMotionWarning {
+    last_warned_secs: Option<f32>,
+    pending: u32,
+}
+
+impl NonFiniteMotionWarning {
+    /// Records `count` recycled particles, logging at most once per interval.
+    fn report(&mut self, system: &str, count: u32, now_secs: f32) {
+        self.pending += count;
+        if self.pending == 0
+            || self
+                .last_warned_secs
+                .is_some_and(|last| now_secs - last < NON_FINITE_WARNING_INTERVAL_SECS)
+        {
+            return;
+        }
+        warn!(
+            "{system}: recycled {} particle(s) with non-finite velocity or position",
+            self.pending
+        );
+        self.last_warned_secs = Some(now_secs);
+        self.pending = 0;
     }
 }
 
+/// Zeroes a particle's motion and expires it so `despawn_expired_particles`
+/// returns it to the pool on its next run.
+///
+/// Without this a NaN position hides the particle forever while it still
+/// counts as active, leaking its pool slot.
+fn discard_non_finite_particle(motion: &mut ParticleMotion, state: &mut ParticleState) {
+    motion.velocity = Vec2::ZERO;
+    motion.acceleration = Vec2::ZERO;
+    state.lifetime_remaining_ms = 0.0;
+}
+
 /// Integrates particle motion: applies velocity and acceleration to position.
 ///
 /// This is a CRITICAL PATH system that runs on all active particles:
 /// - Updates position: position += velocity * dt
 /// - Updates velocity: velocity += acceleration * dt
 /// - Applies drag: velocity *= drag
+///
+/// Particles whose velocity or position becomes non-finite are moved back to
+/// the origin and recycled (see `discard_non_finite_particle`).
 pub fn integrate_particle_motion(
-    mut query: Query<(&ParticleMotion, &ParticleState, &mut Transform), With<Particle>>,
+    mut query: Query<(&mut ParticleMotion, &mut ParticleState, &mut Transform), With<Particle>>,
     time: Res<Time>,
+    mut warning: Local<NonFiniteMotionWarning>,
 ) {
-    let dt = time.delta_secs();
+    let Some(dt) = simulation_delta(&time) else {
+        return;
+    };
 
-    for (motion, state, mut transform) in query.iter_mut() {
+    let mut discarded = 0;
+    for (mut motion, mut state, mut transform) in query.iter_mut() {
         if !state.active {
             continue;
         }
@@ -663,19 +1724,41 @@ pub fn integrate_particle_motion(
         // Apply velocity to position
         transform.translation.x += motion.velocity.x * dt;
         transform.translation.y += motion.velocity.y * dt;
+
+        if !motion.velocity.is_finite() || !transform.translation.is_finite() {
+            transform.translation.x = 0.0;
+            transform.translation.y = 0.0;
+            discard_non_finite_particle(&mut motion, &mut state);
+            discarded += 1;
+        }
     }
+    warning.report("integrate_particle_motion", discarded, time.elapsed_secs());
 }
 
 /// Post-integration system to apply acceleration and drag to velocity.
 ///
 /// Separated from integrate_particle_motion for clearer system ordering.
+/// Drag strength and the speed cap come from the act's `PhysicsProfile` in
+/// `InterpolatedActValues`. Particles whose velocity becomes non-finite are
+/// recycled.
+///
+/// A particle with `ParticleMotion.impulse_secs` left (set by explosions and
+/// hyperspace) is capped at `IMPULSE_MAX_SPEED` instead, easing back to the
+/// act's cap over `IMPULSE_CAP_EXEMPT_SECS`, so impulses can streak even in
+/// slow acts and are squeezed back down rather than snapped.
 pub fn apply_velocity_changes(
-    mut query: Query<(&mut ParticleMotion, &ParticleState), With<Particle>>,
+    mut query: Query<(&mut ParticleMotion, &mut ParticleState), With<Particle>>,
     time: Res<Time>,
+    interpolated: Res<InterpolatedActValues>,
+    mut warning: Local<NonFiniteMotionWarning>,
 ) {
-    let dt = time.delta_secs();
+    let Some(dt) = simulation_delta(&time) else {
+        return;
+    };
 
-    for (mut motion, state) in query.iter_mut() {
+    let physics = interpolated.physics;
+    let mut discarded = 0;
+    for (mut motion, mut state) in query.iter_mut() {
         if !state.active {
             continue;
         }
@@ -686,10 +1769,23 @@ pub fn apply_velocity_changes(
 
         // Apply drag (exponential decay) - copy drag to avoid borrow issues
         let drag = motion.drag;
-        motion.velocity *= drag.powf(dt * 60.0);
+        motion.velocity *= physics.drag_retention(drag, dt);
+
+        // NaN inputs would slip past the speed clamp below
+        if !motion.velocity.is_finite() {
+            discard_non_finite_particle(&mut motion, &mut state);
+            discarded += 1;
+            continue;
+        }
 
-        // Clamp velocity to prevent runaway speeds
-        let max_speed = 500.0;
+        // Clamp velocity to the act's speed cap, lifted while an impulse lasts
+        let max_speed = if motion.impulse_secs > 0.0 {
+            let lift = (motion.impulse_secs / IMPULSE_CAP_EXEMPT_SECS).min(1.0);
+            motion.impulse_secs = (motion.impulse_secs - dt).max(0.0);
+            physics.max_speed.max(IMPULSE_MAX_SPEED * lift)
+        } else {
+            physics.max_speed
+        };
         if motion.velocity.length() > max_speed {
             motion.velocity = motion.velocity.normalize() * max_speed;
         }
@@ -697,52 +1793,310 @@ pub fn apply_velocity_changes(
         // Reset acceleration for next frame
         motion.acceleration = Vec2::ZERO;
     }
+    warning.report("apply_velocity_changes", discarded, time.elapsed_secs());
 }
 
 // =============================================================================
 // VISUAL SYSTEMS
 // =============================================================================
 
-/// Syncs particle visual state to sprite components for rendering.
+/// Returns the opacity factor for a particle with `count` particles (itself
+/// included) within `DensityOpacityConfig.radius`.
 ///
-/// Copies ParticleVisual properties (color, opacity, scale) to the Sprite
-/// component so the rendering system displays the correct appearance.
-/// Applies pulse opacity modifier for breathing effect without blurry scaling.
-pub fn sync_sprite_visuals(
-    mut query: Query<
-        (&ParticleVisual, &ParticleState, &PulseResponder, &mut Sprite, &mut Transform),
-        With<Particle>,
-    >,
+/// Full opacity up to `crowd_threshold`, then reduced by `strength` per extra
+/// particle, never below `min_factor`.
+#[must_use]
+pub fn density_opacity_factor(count: u32, config: &DensityOpacityConfig) -> f32 {
+    let excess = count.saturating_sub(config.crowd_threshold) as f32;
+    (1.0 / (1.0 + config.strength * excess)).clamp(config.min_factor, 1.0)
+}
+
+/// Returns the neighbor count past which `density_opacity_factor` no longer
+/// changes, so density lookups can stop counting there.
+fn density_saturation_count(config: &DensityOpacityConfig) -> usize {
+    if config.strength <= 0.0 {
+        return config.crowd_threshold as usize;
+    }
+    if config.min_factor <= 0.0 {
+        return usize::MAX;
+    }
+    let excess = ((1.0 / config.min_factor - 1.0) / config.strength).ceil();
+    config.crowd_threshold as usize + excess as usize
+}
+
+/// Returns `size` (world units) snapped to whole physical pixels.
+///
+/// The world size is unchanged by the scale factor; snapping only keeps the
+/// nearest-filtered pea texture crisp on fractional and high-DPI displays.
+/// The pixel grid follows the camera zoom, so a zoomed-out view snaps to
+/// coarser world steps.
+#[must_use]
+pub fn scale_adjusted_base_size(size: f32, display: &DisplayScale) -> f32 {
+    let pixels_per_unit = display.screen_pixels_per_unit();
+    if !pixels_per_unit.is_finite() || pixels_per_unit <= 0.0 {
+        return size;
+    }
+    (size * pixels_per_unit).round().max(1.0) / pixels_per_unit
+}
+
+/// Rebuilds `SpatialGrid` from active particle positions.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs after `integrate_particle_motion` and `despawn_expired_particles`, so
+/// the grid holds this frame's positions and only live particles.
+pub fn update_spatial_grid(
+    query: Query<(Entity, &Transform, &ParticleState), With<Particle>>,
+    mut grid: ResMut<SpatialGrid>,
 ) {
-    for (visual, state, pulse_responder, mut sprite, mut transform) in query.iter_mut() {
-        if !state.active {
-            continue;
+    grid.clear();
+
+    for (entity, transform, state) in query.iter() {
+        if state.active {
+            grid.insert(entity, transform.translation.truncate());
         }
+    }
+}
 
-        // Calculate opacity based on lifetime remaining
-        let lifetime_factor = if state.lifetime_total_ms > 0.0 {
-            (state.lifetime_remaining_ms / state.lifetime_total_ms).clamp(0.0, 1.0)
-        } else {
-            1.0
-        };
+/// Returns the displayed color (opacity in alpha) and size of an active particle.
+///
+/// Combines the lifetime fade-out, the pulse opacity and scale modifiers, and
+/// density softening. Density counts neighbors in this frame's `SpatialGrid`.
+/// Shared by `sync_sprite_visuals` and `build_particle_instances`, so both
+/// render modes draw the same peas.
+///
+/// # Arguments
+/// * `visual` - The particle's color, opacity, scale, and fade profile
+/// * `state` - Lifetime, for the fade in the last 20%
+/// * `pulse_responder` - Breathing opacity and scale modifiers
+/// * `position` - World position, for the density lookup
+/// * `density` - Density softening config and this frame's spatial grid
+/// * `display_scale` - Screen-density adjustment for the base size
+#[must_use]
+pub fn particle_appearance(
+    visual: &ParticleVisual,
+    state: &ParticleState,
+    pulse_responder: &PulseResponder,
+    position: Vec2,
+    density: (&DensityOpacityConfig, &SpatialGrid),
+    display_scale: &DisplayScale,
+) -> (Color, f32) {
+    // Calculate opacity based on lifetime remaining
+    let lifetime_factor = if state.lifetime_total_ms > 0.0 {
+        (state.lifetime_remaining_ms / state.lifetime_total_ms).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
 
-        // Fade out in the last 20% of lifetime
-        let fade_factor = if lifetime_factor < 0.2 {
-            lifetime_factor / 0.2
-        } else {
-            1.0
-        };
+    // Fade out over the last 20% of lifetime, shaped per spawn source
+    let fade_factor = visual.fade_profile.fade_factor(lifetime_factor);
+
+    // Apply pulse opacity modifier for breathing effect
+    let mut final_opacity = visual.opacity * fade_factor * pulse_responder.current_opacity_modifier;
+
+    // Soften crowded clusters so they read as volume rather than a solid blob
+    let (density_config, grid) = density;
+    if density_config.enabled {
+        let count = grid
+            .query_radius(position, density_config.radius)
+            .take(density_saturation_count(density_config))
+            .count();
+        final_opacity *= density_opacity_factor(count as u32, density_config);
+    }
+
+    let color = visual.current_color.to_srgba();
+    let size = scale_adjusted_base_size(
+        PEA_BASE_SIZE * visual.scale * pulse_responder.current_scale_modifier,
+        display_scale,
+    );
+    (
+        Color::srgba(color.red, color.green, color.blue, final_opacity),
+        size,
+    )
+}
+
+/// Returns how many times longer than wide a pea moving at `speed` is drawn.
+///
+/// Round (1.0) up to `STREAK_MIN_SPEED`, then one extra pea length for every
+/// `STREAK_SPEED_PER_STRETCH` beyond it, clamped to `max_stretch` and to
+/// `MAX_STREAK_STRETCH`.
+#[must_use]
+pub fn streak_stretch(speed: f32, max_stretch: f32) -> f32 {
+    let stretch = 1.0 + (speed - STREAK_MIN_SPEED).max(0.0) / STREAK_SPEED_PER_STRETCH;
+    stretch.min(max_stretch.clamp(1.0, MAX_STREAK_STRETCH))
+}
+
+/// Returns the quad size of a pea of edge `size` stretched by `stretch`:
+/// length along the velocity, then width across it.
+///
+/// The width narrows by `1 / sqrt(stretch)` so a long streak reads as a thin
+/// trail of light rather than a growing blob.
+#[must_use]
+pub fn streak_size(size: f32, stretch: f32) -> Vec2 {
+    Vec2::new(size * stretch, size / stretch.sqrt())
+}
+
+/// Returns the streak stretch and heading (radians about z) of a streaking
+/// particle, or `None` when it is drawn round.
+#[must_use]
+pub fn streak_shape(streak: Option<(&MotionStreak, &ParticleMotion)>) -> Option<(f32, f32)> {
+    let (streak, motion) = streak?;
+    let stretch = streak_stretch(motion.velocity.length(), streak.max_stretch);
+    (stretch > 1.0).then(|| (stretch, motion.velocity.to_angle()))
+}
+
+/// Particles `update_motion_streaks` starts or stops streaking.
+type StreakQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static ParticleMotion,
+        &'static ParticleState,
+        Option<&'static ParticleVisual>,
+        Has<MotionStreak>,
+        &'static mut Transform,
+    ),
+    With<Particle>,
+>;
+
+/// Adds `MotionStreak` to particles faster than `STREAK_MIN_SPEED` and
+/// removes it once they slow below `STREAK_RELEASE_FRACTION` of it.
+///
+/// Only explosion and hyperspace impulses lift a pea past the act caps (see
+/// `apply_velocity_changes`). A removed streak resets the pea's rotation,
+/// unless `orient_particles_to_velocity` is turning it.
+///
+/// # Ordering
+/// Runs after `apply_velocity_changes`, so the PostUpdate sync sees this
+/// frame's velocities with the streaks already applied.
+pub fn update_motion_streaks(mut commands: Commands, mut query: StreakQuery) {
+    let release_speed = STREAK_MIN_SPEED * STREAK_RELEASE_FRACTION;
+
+    for (entity, motion, state, visual, streaking, mut transform) in query.iter_mut() {
+        let speed = motion.velocity.length();
+        if streaking && (!state.active || speed < release_speed) {
+            commands.entity(entity).remove::<MotionStreak>();
+            if !visual.is_some_and(|visual| visual.orient_to_velocity) {
+                transform.rotation = Quat::IDENTITY;
+            }
+        } else if !streaking && state.active && speed > STREAK_MIN_SPEED {
+            commands.entity(entity).insert(MotionStreak::default());
+        }
+    }
+}
+
+/// Returns the rotation about z (radians) that turns a pea at `current`
+/// toward facing `velocity` with its local +y, smoothed over `dt` seconds.
+///
+/// Below `ORIENT_MIN_SPEED` the pea keeps `current`. Turns take the short way
+/// round, so a heading crossing +/-pi never spins the long way.
+#[must_use]
+pub fn oriented_heading(current: f32, velocity: Vec2, dt: f32) -> f32 {
+    if velocity.length() < ORIENT_MIN_SPEED {
+        return current;
+    }
+    let target = velocity.to_angle() - std::f32::consts::FRAC_PI_2;
+    let delta = (target - current + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+        - std::f32::consts::PI;
+    current + delta * (1.0 - (-ORIENT_TURN_RATE * dt).exp())
+}
+
+/// Unstreaked particles `orient_particles_to_velocity` may turn.
+type OrientQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static ParticleVisual,
+        &'static ParticleState,
+        &'static ParticleMotion,
+        &'static mut Transform,
+    ),
+    (With<Particle>, Without<MotionStreak>),
+>;
+
+/// Turns peas with `ParticleVisual.orient_to_velocity` so their "up" faces
+/// their velocity (see `oriented_heading`).
+///
+/// Only the rotation is written: pulse scaling stays in `Sprite::custom_size`,
+/// so transform scale remains 1.0. Streaking peas are skipped, since the
+/// render sync already turns them along their heading.
+///
+/// # Ordering
+/// Runs after `apply_velocity_changes`, alongside `update_motion_streaks`.
+pub fn orient_particles_to_velocity(time: Res<Time>, mut query: OrientQuery) {
+    let dt = time.delta_secs();
+
+    for (visual, state, motion, mut transform) in query.iter_mut() {
+        if !state.active || !visual.orient_to_velocity {
+            continue;
+        }
+        let current = transform.rotation.to_euler(EulerRot::ZYX).0;
+        transform.rotation = Quat::from_rotation_z(oriented_heading(current, motion.velocity, dt));
+    }
+}
 
-        // Apply pulse opacity modifier for breathing effect
-        let final_opacity = visual.opacity * fade_factor * pulse_responder.current_opacity_modifier;
+/// Particles and the sprites `sync_sprite_visuals` draws them with.
+type SpriteSyncQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static ParticleVisual,
+        &'static ParticleState,
+        &'static PulseResponder,
+        Option<(&'static MotionStreak, &'static ParticleMotion)>,
+        &'static mut Sprite,
+        &'static mut Transform,
+    ),
+    With<Particle>,
+>;
 
-        // Apply color with opacity
-        let color = visual.current_color.to_srgba();
-        sprite.color = Color::srgba(color.red, color.green, color.blue, final_opacity);
+/// Syncs particle visual state to sprite components for rendering.
+///
+/// Copies the `particle_appearance` color and size to the Sprite component
+/// so the rendering system displays the correct appearance. Pulse scaling
+/// goes through `custom_size` rather than the transform to avoid blurry
+/// texture filtering. Peas with a `MotionStreak` are stretched along their
+/// velocity (see `streak_size`) and turned to face it; once the streak no
+/// longer stretches, the rotation resets unless `orient_to_velocity` is set.
+///
+/// Only registered in `RenderMode::Sprites`.
+pub fn sync_sprite_visuals(
+    mut query: SpriteSyncQuery,
+    density_config: Res<DensityOpacityConfig>,
+    grid: Res<SpatialGrid>,
+    display_scale: Res<DisplayScale>,
+) {
+    for (visual, state, pulse_responder, streak, mut sprite, mut transform) in query.iter_mut() {
+        if !state.active {
+            continue;
+        }
 
-        // Apply scale to the pea sprite via custom_size (no pulse scaling to avoid blur)
-        let scaled_size = PEA_BASE_SIZE * visual.scale;
-        sprite.custom_size = Some(Vec2::splat(scaled_size));
+        let (color, size) = particle_appearance(
+            visual,
+            state,
+            pulse_responder,
+            transform.translation.truncate(),
+            (&density_config, &grid),
+            &display_scale,
+        );
+        sprite.color = color;
+        sprite.custom_size = Some(match streak_shape(streak) {
+            Some((stretch, heading)) => {
+                transform.rotation = Quat::from_rotation_z(heading);
+                streak_size(size, stretch)
+            }
+            None => {
+                // Drop the streak heading; `orient_particles_to_velocity`
+                // owns the rotation of oriented peas
+                if !visual.orient_to_velocity && transform.rotation != Quat::IDENTITY {
+                    transform.rotation = Quat::IDENTITY;
+                }
+                Vec2::splat(size)
+            }
+        });
 
         // Keep transform scale at 1.0 to avoid blurry texture filtering
         transform.scale = Vec3::ONE;
@@ -750,35 +2104,128 @@ pub fn sync_sprite_visuals(
 }
 
 // =============================================================================
-// PLUGIN
+// SIMULATION BUDGET SYSTEMS
 // =============================================================================
 
-/// Plugin bundling all particle-related systems.
+/// Marks the start of the spawn+motion pipeline for this frame.
 ///
-/// Registers the following systems:
-/// - Startup: setup_particle_pool
-/// - Update: spawn_particles_from_queue, spawn_particles_from_mouse,
-///           spawn_particles_from_beat, update_particle_lifetime,
-///           despawn_expired_particles, apply_particle_behavior,
-///           apply_turbulence, integrate_particle_motion, sync_sprite_visuals
-pub struct ParticlePlugin;
+/// # Ordering
+/// Runs before `regenerate_ink`, the first spawn system.
+pub fn begin_simulation_timing(mut timer: ResMut<SimulationTimer>) {
+    timer.started = Some(Instant::now());
+}
+
+/// Records the spawn+motion pipeline's wall time in `PerformanceMetrics`.
+///
+/// Systems scheduled in parallel with the pipeline are included, so this is
+/// an upper bound on the simulation's own cost.
+///
+/// # Ordering
+/// Runs after `apply_velocity_changes` and `despawn_expired_particles`.
+pub fn end_simulation_timing(
+    mut timer: ResMut<SimulationTimer>,
+    mut metrics: ResMut<PerformanceMetrics>,
+) {
+    if let Some(started) = timer.started.take() {
+        metrics.particle_update_time_ms = started.elapsed().as_secs_f32() * 1000.0;
+    }
+}
+
+/// Adjusts the simulation-budget particle ceiling and the per-frame spawn
+/// limit to hold the measured simulation time near `SimFrameBudget.budget_ms`.
+///
+/// Publishes to `ParticlePool.ceilings`; the pool's cap is the lowest ceiling.
+///
+/// # Ordering
+/// Runs after `end_simulation_timing`, only while the controller is enabled
+/// and time follows the wall clock (see `paced_by_real_time`).
+pub fn regulate_simulation_budget(
+    time: Res<Time>,
+    metrics: Res<PerformanceMetrics>,
+    mut controller: ResMut<SimFrameBudget>,
+    mut pool: ResMut<ParticlePool>,
+    mut spawn_budget: ResMut<SpawnBudgetConfig>,
+) {
+    let current = pool.ceilings.simulation_budget.unwrap_or(pool.max_active);
+    let ceiling = controller.step(metrics.particle_update_time_ms, time.delta_secs(), current);
+    pool.ceilings.simulation_budget = Some(ceiling);
+    spawn_budget.frame_spawn_limit = controller.spawn_limit(pool.active_cap());
+}
+
+/// Condition function for run_if: returns true when the sim budget controller is on.
+pub fn simulation_budget_enabled(controller: Res<SimFrameBudget>) -> bool {
+    controller.enabled
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin bundling all particle-related systems.
+///
+/// Registers the following systems:
+/// - Startup: setup_particle_pool
+/// - Update (until `pea.png` resolves): fallback_missing_pea_texture
+/// - Update: begin_simulation_timing, regenerate_ink, enqueue_spawn_particles,
+///   spawn_particles_from_queue, spawn_particles_from_mouse, spawn_particles_from_beat,
+///   update_particle_lifetime, despawn_expired_particles, assign_attractor_targets,
+///   apply_particle_behavior, apply_turbulence, apply_attractor_forces,
+///   apply_magnet_toy_damping, integrate_particle_motion, update_motion_streaks,
+///   orient_particles_to_velocity, end_simulation_timing, regulate_simulation_budget
+/// - PostUpdate: sync_sprite_visuals, or `ParticleInstancingPlugin` in
+///   `RenderMode::Instanced`
+///
+/// `BlendMode::Additive` selects `RenderMode::Instanced`, since only the
+/// instanced renderer can switch blend state. The switch is logged, and the
+/// pool keeps its sprite-mode sizing rather than growing to
+/// `INSTANCED_MAX_ACTIVE`.
+pub struct ParticlePlugin;
 
 impl Plugin for ParticlePlugin {
     fn build(&self, app: &mut App) {
+        let mut render_mode = *app
+            .init_resource::<RenderMode>()
+            .world()
+            .resource::<RenderMode>();
+        let blend_mode = *app
+            .init_resource::<BlendMode>()
+            .world()
+            .resource::<BlendMode>();
+        // Sprites cannot switch blend state, so additive peas are drawn instanced
+        let forced_instanced =
+            render_mode == RenderMode::Sprites && blend_mode == BlendMode::Additive;
+        if forced_instanced {
+            warn!("BlendMode::Additive switches RenderMode::Sprites to RenderMode::Instanced");
+            render_mode = RenderMode::Instanced;
+            app.insert_resource(render_mode);
+        }
+
         app.add_event::<BeatDetected>()
+            .add_event::<PoolExhausted>()
+            .add_event::<SpawnParticles>()
+            .add_event::<ParticleSpawned>()
+            .add_event::<ParticleDespawned>()
+            .init_resource::<ParticleLifecycleEvents>()
+            .init_resource::<SpatialGrid>()
+            .init_resource::<SimulationTimer>()
             // Startup systems: load texture first, then setup pool
             .add_systems(Startup, (load_pea_texture, setup_particle_pool).chain())
+            .add_systems(Update, fallback_missing_pea_texture)
             // Update systems with proper ordering (only in Fidget state)
             .add_systems(
                 Update,
                 (
                     // Spawn systems - run before motion
+                    begin_simulation_timing,
+                    regenerate_ink,
                     spawn_particles_from_mouse, // Works in all acts for fidget app behavior
-                    spawn_particles_from_beat,
+                    // Audio-driven spawning stops while paused
+                    spawn_particles_from_beat.run_if(experience_running),
+                    enqueue_spawn_particles,
                     spawn_particles_from_queue,
                 )
                     .chain()
-                    .run_if(in_state(AppState::Fidget)),
+                    .run_if(in_fidget_state),
             )
             .add_systems(
                 Update,
@@ -789,25 +2236,80 @@ impl Plugin for ParticlePlugin {
                 )
                     .chain()
                     .after(spawn_particles_from_queue)
-                    .run_if(in_state(AppState::Fidget)),
+                    .run_if(in_fidget_state)
+                    .run_if(experience_running),
             )
             .add_systems(
                 Update,
                 (
                     // Motion systems - CRITICAL PATH
-                    apply_particle_behavior,
-                    apply_turbulence,
+                    update_intensity,
+                    assign_attractor_targets,
+                    // Act forces are suspended while the Magnet Toy is active
+                    apply_particle_behavior.run_if(act_forces_enabled),
+                    apply_turbulence.run_if(act_forces_enabled),
+                    apply_attractor_forces,
+                    apply_magnet_toy_damping.run_if(magnet_toy_active),
                     integrate_particle_motion,
                     apply_velocity_changes,
                 )
                     .chain()
                     .after(spawn_particles_from_queue)
-                    .run_if(in_state(AppState::Fidget)),
+                    .run_if(in_fidget_state)
+                    .run_if(experience_running),
+            )
+            .add_systems(
+                Update,
+                (update_motion_streaks, orient_particles_to_velocity)
+                    .after(apply_velocity_changes)
+                    .after(despawn_expired_particles)
+                    .run_if(in_fidget_state)
+                    .run_if(experience_running),
             )
             .add_systems(
-                PostUpdate,
-                sync_sprite_visuals.run_if(in_state(AppState::Fidget)),
+                Update,
+                update_spatial_grid
+                    .after(integrate_particle_motion)
+                    .after(despawn_expired_particles)
+                    .run_if(in_fidget_state),
+            )
+            .add_systems(
+                Update,
+                (
+                    end_simulation_timing,
+                    regulate_simulation_budget
+                        .run_if(simulation_budget_enabled)
+                        .run_if(paced_by_real_time),
+                )
+                    .chain()
+                    .after(apply_velocity_changes)
+                    .after(despawn_expired_particles)
+                    .run_if(in_fidget_state),
             );
+
+        match render_mode {
+            RenderMode::Sprites => {
+                app.add_systems(PostUpdate, sync_sprite_visuals.run_if(in_fidget_state));
+            }
+            RenderMode::Instanced if forced_instanced => {
+                // Only the renderer was switched, so keep the sprite pool sizing
+                let pool = app.world_mut().remove_resource::<ParticlePool>();
+                let ceiling = app
+                    .world()
+                    .get_resource::<SimFrameBudget>()
+                    .map(|budget| budget.ceiling_active);
+                app.add_plugins(ParticleInstancingPlugin);
+                app.insert_resource(pool.unwrap_or_default());
+                if let Some(ceiling) = ceiling {
+                    app.world_mut()
+                        .resource_mut::<SimFrameBudget>()
+                        .ceiling_active = ceiling;
+                }
+            }
+            RenderMode::Instanced => {
+                app.add_plugins(ParticleInstancingPlugin);
+            }
+        }
     }
 }
 
@@ -820,28 +2322,1627 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_spawn_pattern_constants() {
-        assert!(SOFT_BEAT_SPAWN_RANGE.0 < SOFT_BEAT_SPAWN_RANGE.1);
-        assert!(MEDIUM_BEAT_SPAWN_RANGE.0 < MEDIUM_BEAT_SPAWN_RANGE.1);
-        assert!(STRONG_BEAT_SPAWN_RANGE.0 < STRONG_BEAT_SPAWN_RANGE.1);
+    fn test_spatial_grid_query_matches_brute_force() {
+        let mut rng = fastrand::Rng::with_seed(7);
+        for cell_size in [25.0, 100.0, 333.0] {
+            let mut grid = SpatialGrid {
+                cell_size,
+                ..Default::default()
+            };
+            let points: Vec<(Entity, Vec2)> = (0..2000)
+                .map(|i| {
+                    let x = rng.f32() * 2000.0 - 1000.0;
+                    let y = rng.f32() * 1200.0 - 600.0;
+                    (Entity::from_raw(i), Vec2::new(x, y))
+                })
+                .collect();
+            for (entity, position) in &points {
+                grid.insert(*entity, *position);
+            }
+
+            for _ in 0..50 {
+                let center = Vec2::new(rng.f32() * 2400.0 - 1200.0, rng.f32() * 1600.0 - 800.0);
+                let radius = rng.f32() * 450.0;
+
+                let mut from_grid: Vec<Entity> = grid.query_radius(center, radius).collect();
+                let mut brute_force: Vec<Entity> = points
+                    .iter()
+                    .filter(|(_, position)| position.distance(center) < radius)
+                    .map(|(entity, _)| *entity)
+                    .collect();
+                from_grid.sort();
+                brute_force.sort();
+                assert_eq!(from_grid, brute_force, "cell {cell_size}, radius {radius}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_additive_blend_selects_instanced_renderer() {
+        let mut app = App::new();
+        app.insert_resource(BlendMode::Additive)
+            .add_plugins(ParticlePlugin);
+
+        assert_eq!(*app.world().resource::<RenderMode>(), RenderMode::Instanced);
+        assert!(app
+            .world()
+            .contains_resource::<crate::instancing::ParticleInstances>());
+    }
+
+    #[test]
+    fn test_additive_blend_keeps_sprite_pool_sizing() {
+        let mut app = App::new();
+        app.insert_resource(BlendMode::Additive)
+            .insert_resource(ParticlePool {
+                pool_capacity: 3000,
+                max_active: 2000,
+                ..Default::default()
+            })
+            .insert_resource(SimFrameBudget {
+                ceiling_active: 2000,
+                ..Default::default()
+            })
+            .add_plugins(ParticlePlugin);
+
+        let pool = app.world().resource::<ParticlePool>();
+        assert_eq!(pool.pool_capacity, 3000);
+        assert_eq!(pool.max_active, 2000);
+        assert_eq!(
+            app.world().resource::<SimFrameBudget>().ceiling_active,
+            2000
+        );
+    }
+
+    #[test]
+    fn test_spawn_pattern_defaults() {
+        let config = BeatSpawnConfig::default();
+        assert!(config.soft_count.0 < config.soft_count.1);
+        assert!(config.medium_count.0 < config.medium_count.1);
+        assert!(config.strong_count.0 < config.strong_count.1);
 
         // Medium should spawn more than soft
-        assert!(MEDIUM_BEAT_SPAWN_RANGE.0 >= SOFT_BEAT_SPAWN_RANGE.0);
+        assert!(config.medium_count.0 >= config.soft_count.0);
         // Strong should spawn more than medium
-        assert!(STRONG_BEAT_SPAWN_RANGE.0 >= MEDIUM_BEAT_SPAWN_RANGE.0);
+        assert!(config.strong_count.0 >= config.medium_count.0);
     }
 
     #[test]
-    fn test_pool_constants() {
-        assert!(MAX_ACTIVE <= POOL_CAPACITY);
-        assert!(POOL_CAPACITY > 0);
-        assert!(MAX_ACTIVE > 0);
+    fn test_pool_defaults() {
+        let pool = ParticlePool::default();
+        assert!(pool.max_active <= pool.pool_capacity);
+        assert!(pool.pool_capacity > 0);
+        assert!(pool.max_active > 0);
     }
 
     #[test]
     fn test_mouse_spawn_rate_range() {
-        assert!(MOUSE_SPAWN_RATE_MIN > 0.0);
-        assert!(MOUSE_SPAWN_RATE_MAX > MOUSE_SPAWN_RATE_MIN);
+        let (min, max) = PaintConfig::default().spawn_rate_range;
+        assert!(min > 0.0);
+        assert!(max > min);
+    }
+
+    #[test]
+    fn test_paint_velocity_inheritance() {
+        let mouse_velocity = Vec2::new(400.0, -200.0);
+        let sticky = PaintConfig {
+            velocity_inheritance: 0.1,
+            ..Default::default()
+        };
+        let flingy = PaintConfig {
+            velocity_inheritance: 0.9,
+            ..Default::default()
+        };
+
+        // Sample the same random offsets for both configs
+        for _ in 0..20 {
+            let random = Vec2::new(fastrand::f32(), fastrand::f32());
+            let sticky_velocity = paint_spawn_velocity(mouse_velocity, &sticky, random);
+            let flingy_velocity = paint_spawn_velocity(mouse_velocity, &flingy, random);
+
+            assert!(
+                flingy_velocity.distance(mouse_velocity) < sticky_velocity.distance(mouse_velocity)
+            );
+        }
+
+        // Defaults match the original 0.3 inheritance and +/-25 spread
+        let default_velocity =
+            paint_spawn_velocity(mouse_velocity, &PaintConfig::default(), Vec2::splat(0.5));
+        assert_eq!(default_velocity, mouse_velocity * 0.3);
+    }
+
+    #[test]
+    fn test_density_expiry_multiplier() {
+        // At or below target, lifetimes drain normally
+        assert_eq!(density_expiry_multiplier(100, 200.0), 1.0);
+        assert_eq!(density_expiry_multiplier(200, 200.0), 1.0);
+
+        // Proportional to the overshoot
+        let slight = density_expiry_multiplier(220, 200.0);
+        let heavy = density_expiry_multiplier(260, 200.0);
+        assert!(slight > 1.0 && heavy > slight);
+
+        // Capped for extreme overshoot
+        assert_eq!(
+            density_expiry_multiplier(10_000, 100.0),
+            DENSITY_EXPIRY_MAX_BOOST
+        );
+    }
+
+    #[test]
+    fn test_behavior_acceleration_is_weighted_kernel_sum() {
+        let swarm = ParticleBehaviorType::Swarm.coefficients();
+        let orbit = ParticleBehaviorType::Orbit.coefficients();
+        let pos = Vec2::new(200.0, -80.0);
+        // Centered random samples cancel the wander kernel
+        let accel =
+            |c: &BehaviorCoefficients| behavior_acceleration(c, pos, Vec2::ZERO, Vec2::splat(0.5));
+
+        let halfway = accel(&swarm.lerp(&orbit, 0.5));
+        let expected = (accel(&swarm) + accel(&orbit)) * 0.5;
+        assert!((halfway - expected).length() < 1e-3);
+
+        // Pure orbit spins: the tangential part is perpendicular to the pull
+        let inward = (-pos).normalize();
+        assert!(accel(&orbit).perp_dot(inward).abs() > 1.0);
+        assert_eq!(accel(&BehaviorCoefficients::ZERO), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_painting_depletes_ink_and_idle_refills_it() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<ColorPalette>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<crate::interaction::TouchState>()
+            .init_resource::<PaintConfig>()
+            .init_resource::<PaintColorOverride>()
+            .init_resource::<ParticleSpawnQueue>()
+            .insert_resource(InkBudget {
+                enabled: true,
+                current: 20.0,
+                max: 20.0,
+                regen_per_second: 5.0,
+            })
+            .insert_resource(MouseState {
+                position: Vec2::ZERO,
+                velocity: Vec2::new(800.0, 0.0),
+                is_active: true,
+                ..Default::default()
+            })
+            .add_systems(Update, (regenerate_ink, spawn_particles_from_mouse).chain());
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+
+        let step = |app: &mut App, millis: u64| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            app.update();
+        };
+
+        // Two seconds of fast held painting wants far more than 20 particles
+        for _ in 0..20 {
+            step(&mut app, 100);
+        }
+        let spawned = app
+            .world()
+            .resource::<ParticleSpawnQueue>()
+            .pending_spawns
+            .len();
+        let ink = app.world().resource::<InkBudget>().current;
+        assert!(ink < 1.0, "painting should drain the ink, {ink} left");
+        // Initial ink plus what regenerated while painting
+        assert!(spawned <= 20 + 10, "spawned {spawned} with a 20 ink budget");
+
+        // Stop painting; ink refills toward max and no further
+        app.world_mut().resource_mut::<MouseState>().is_active = false;
+        step(&mut app, 2000);
+        let refilled = app.world().resource::<InkBudget>().fraction();
+        assert!(refilled > 0.4 && refilled < 0.6);
+        step(&mut app, 10_000);
+        assert_eq!(app.world().resource::<InkBudget>().fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_spawns_share_stroke_seed_until_next_press() {
+        use bevy::input::touch::Touches;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<ColorPalette>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Touches>()
+            .init_resource::<crate::interaction::TouchState>()
+            .init_resource::<InkBudget>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<PaintColorOverride>()
+            .init_resource::<ParticleRng>()
+            .insert_resource(PaintConfig {
+                stroke_seed: Some(42),
+                ..Default::default()
+            })
+            .insert_resource(MouseState {
+                velocity: Vec2::new(800.0, 0.0),
+                is_active: true,
+                ..Default::default()
+            })
+            .add_systems(
+                Update,
+                (
+                    crate::interaction::begin_paint_strokes,
+                    spawn_particles_from_mouse,
+                )
+                    .chain(),
+            );
+
+        // Paints one frame and returns the stroke seeds of what it spawned
+        let paint_frame = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+            app.world_mut()
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .clear();
+            let mut queue = app.world_mut().resource_mut::<ParticleSpawnQueue>();
+            let seeds: Vec<_> = queue
+                .pending_spawns
+                .drain(..)
+                .map(|r| r.stroke_seed)
+                .collect();
+            seeds
+        };
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        let mut first_stroke = paint_frame(&mut app);
+        first_stroke.extend(paint_frame(&mut app));
+        assert!(first_stroke.len() >= 2);
+        let seed = first_stroke[0].expect("mouse spawns carry their stroke seed");
+        assert!(first_stroke.iter().all(|s| *s == Some(seed)));
+
+        // Lift and press again: a new stroke with a new seed
+        let mut input = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        input.release(MouseButton::Left);
+        input.clear();
+        input.press(MouseButton::Left);
+        let second_stroke = paint_frame(&mut app);
+        assert!(!second_stroke.is_empty());
+        assert!(second_stroke
+            .iter()
+            .all(|s| s.is_some() && *s != Some(seed)));
+        assert_eq!(app.world().resource::<MouseState>().stroke_id, 2);
+    }
+
+    #[test]
+    fn test_paint_color_override_colors_mouse_spawns() {
+        use std::time::Duration;
+
+        let chosen = Color::srgb(0.1, 0.9, 0.4);
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<ColorPalette>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<crate::interaction::TouchState>()
+            .init_resource::<PaintConfig>()
+            .init_resource::<InkBudget>()
+            .init_resource::<ParticleSpawnQueue>()
+            .insert_resource(PaintColorOverride {
+                color: Some(chosen),
+                ..Default::default()
+            })
+            .insert_resource(MouseState {
+                velocity: Vec2::new(800.0, 0.0),
+                is_active: true,
+                ..Default::default()
+            })
+            .add_systems(Update, spawn_particles_from_mouse);
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+
+        let paint_frame = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+            let mut queue = app.world_mut().resource_mut::<ParticleSpawnQueue>();
+            let colors: Vec<Color> = queue.pending_spawns.drain(..).map(|r| r.color).collect();
+            colors
+        };
+
+        let overridden = paint_frame(&mut app);
+        assert!(!overridden.is_empty());
+        assert!(overridden.iter().all(|color| *color == chosen));
+
+        // Clearing the override returns to the palette
+        app.world_mut().resource_mut::<PaintColorOverride>().color = None;
+        let palette_colors = paint_frame(&mut app);
+        assert!(!palette_colors.is_empty());
+        assert!(palette_colors.iter().all(|color| *color != chosen));
+
+        // Dragging a Magnet Toy pole paints nothing
+        app.insert_resource(MagnetToy {
+            active: true,
+            ..Default::default()
+        });
+        assert!(paint_frame(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_fast_stroke_spreads_spawns_along_path() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<ColorPalette>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<crate::interaction::TouchState>()
+            .init_resource::<PaintConfig>()
+            .init_resource::<PaintColorOverride>()
+            .init_resource::<InkBudget>()
+            .init_resource::<ParticleSpawnQueue>()
+            .insert_resource(MouseState {
+                previous_position: Vec2::new(-300.0, 0.0),
+                position: Vec2::new(300.0, 0.0),
+                velocity: Vec2::new(6000.0, 0.0),
+                is_active: true,
+                ..Default::default()
+            })
+            .add_systems(Update, spawn_particles_from_mouse);
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+
+        // One long frame: a 600-unit jump with many spawns due
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+
+        let queue = app.world().resource::<ParticleSpawnQueue>();
+        let xs: Vec<f32> = queue.pending_spawns.iter().map(|r| r.position.x).collect();
+        assert!(xs.len() >= 4, "spawned {}", xs.len());
+
+        // Evenly spaced from just past the start to the current position
+        let spacing = 600.0 / xs.len() as f32;
+        for (index, x) in xs.iter().enumerate() {
+            let expected = -300.0 + spacing * (index + 1) as f32;
+            assert!((x - expected).abs() < 1e-3, "spawn {index} at {x}");
+        }
+        assert!(xs.iter().filter(|x| **x < 0.0).count() >= xs.len() / 2 - 1);
+    }
+
+    #[test]
+    fn test_beat_burst_leaves_room_for_mouse_spawns() {
+        const MAX_ACTIVE: u32 = 8;
+
+        let mut app = App::new();
+        app.init_resource::<InterpolatedActValues>()
+            .init_resource::<SpawnBudgetConfig>()
+            .init_resource::<FadeProfiles>()
+            .init_resource::<TrailProfiles>()
+            .add_event::<PoolExhausted>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ParticleRng>()
+            .insert_resource(ParticlePool {
+                max_active: MAX_ACTIVE,
+                ..Default::default()
+            })
+            .add_systems(Update, spawn_particles_from_queue);
+
+        let entities: Vec<Entity> = (0..64)
+            .map(|id| app.world_mut().spawn(ParticleBundle::new(id)).id())
+            .collect();
+        app.world_mut()
+            .resource_mut::<ParticlePool>()
+            .available_entities = entities;
+
+        // A huge beat burst queued ahead of a few mouse spawns
+        let request = |source| ParticleSpawnRequest {
+            source,
+            ..Default::default()
+        };
+        let mut pending: Vec<_> = (0..40).map(|_| request(SpawnSource::Beat)).collect();
+        pending.extend((0..4).map(|_| request(SpawnSource::Mouse)));
+        app.world_mut()
+            .resource_mut::<ParticleSpawnQueue>()
+            .pending_spawns = pending;
+
+        app.update();
+
+        let mut query = app.world_mut().query::<(&ParticleState, &Spawnable)>();
+        let mut active_from = |source| {
+            query
+                .iter(app.world())
+                .filter(|(state, spawnable)| state.active && spawnable.spawn_source == source)
+                .count()
+        };
+        let beat = active_from(SpawnSource::Beat);
+        let mouse = active_from(SpawnSource::Mouse);
+
+        assert!(beat > 0 && beat < MAX_ACTIVE as usize);
+        assert!(
+            mouse > 0,
+            "mouse spawns must not be starved by the beat burst"
+        );
+        assert!(beat + mouse <= MAX_ACTIVE as usize);
+    }
+
+    #[test]
+    fn test_procedural_pea_texture_is_soft_disc() {
+        let image = ProceduralPeaTexture { size: 32 }.build();
+        assert_eq!(image.width(), 32);
+        assert_eq!(image.height(), 32);
+        assert_eq!(image.data.len(), 32 * 32 * 4);
+
+        let alpha = |x: usize, y: usize| image.data[(y * 32 + x) * 4 + 3];
+        assert!(alpha(16, 16) > 240, "center should be nearly opaque");
+        assert!(alpha(16, 16) > alpha(24, 16));
+        assert!(alpha(24, 16) > alpha(30, 16));
+        assert_eq!(alpha(0, 0), 0, "corners lie outside the disc");
+        assert!(alpha(0, 16) < 4, "edge should be nearly clear");
+    }
+
+    #[test]
+    fn test_streak_stretch_grows_with_speed_and_clamps() {
+        assert_eq!(streak_stretch(0.0, 4.0), 1.0);
+        assert_eq!(streak_stretch(STREAK_MIN_SPEED, 4.0), 1.0);
+        let one_length_faster = STREAK_MIN_SPEED + STREAK_SPEED_PER_STRETCH;
+        assert!((streak_stretch(one_length_faster, 4.0) - 2.0).abs() < 1e-5);
+
+        // Monotonic up to the component's ceiling, then flat
+        let mut previous = 1.0;
+        for speed in (0..40).map(|i| STREAK_MIN_SPEED + i as f32 * 100.0) {
+            let stretch = streak_stretch(speed, 4.0);
+            assert!(stretch >= previous);
+            previous = stretch;
+        }
+        assert_eq!(streak_stretch(1.0e6, 4.0), 4.0);
+
+        // Absurd requests are held to the hard ceiling, and never shrink a pea
+        assert_eq!(streak_stretch(1.0e6, 100.0), MAX_STREAK_STRETCH);
+        assert_eq!(streak_stretch(1.0e6, 0.5), 1.0);
+
+        let size = streak_size(40.0, 4.0);
+        assert_eq!(size, Vec2::new(160.0, 20.0));
+    }
+
+    #[test]
+    fn test_motion_streak_toggles_with_hysteresis() {
+        let mut app = App::new();
+        app.add_systems(Update, update_motion_streaks);
+        let pea = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    ..default()
+                },
+                ParticleMotion {
+                    velocity: Vec2::new(STREAK_MIN_SPEED * 1.5, 0.0),
+                    ..default()
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        let set_speed_and_update = |app: &mut App, speed: f32| {
+            app.world_mut()
+                .get_mut::<ParticleMotion>(pea)
+                .unwrap()
+                .velocity = Vec2::Y * speed;
+            app.update();
+            app.world().get::<MotionStreak>(pea).is_some()
+        };
+        assert!(set_speed_and_update(&mut app, STREAK_MIN_SPEED * 1.5));
+        // Just under the threshold keeps the streak until it slows further
+        assert!(set_speed_and_update(&mut app, STREAK_MIN_SPEED * 0.9));
+        assert!(!set_speed_and_update(&mut app, STREAK_MIN_SPEED * 0.5));
+        assert!(!set_speed_and_update(&mut app, STREAK_MIN_SPEED * 0.9));
+    }
+
+    #[test]
+    fn test_impulse_outruns_act_cap_and_streaks() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InterpolatedActValues>()
+            .add_systems(
+                Update,
+                (apply_velocity_changes, update_motion_streaks).chain(),
+            );
+        let mut spawn = |impulse_secs: f32| {
+            app.world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active: true,
+                        ..default()
+                    },
+                    ParticleMotion {
+                        velocity: Vec2::new(1500.0, 0.0),
+                        impulse_secs,
+                        ..default()
+                    },
+                    Transform::from_rotation(Quat::from_rotation_z(1.0)),
+                ))
+                .id()
+        };
+        let kicked = spawn(IMPULSE_CAP_EXEMPT_SECS);
+        let drifting = spawn(0.0);
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(16));
+            app.update();
+        };
+        step(&mut app);
+
+        // The impulse keeps its speed past the streak threshold; the other is capped
+        let max_speed = app
+            .world()
+            .resource::<InterpolatedActValues>()
+            .physics
+            .max_speed;
+        assert!(max_speed < STREAK_MIN_SPEED);
+        assert!(app.world().get::<MotionStreak>(kicked).is_some());
+        assert!(app.world().get::<MotionStreak>(drifting).is_none());
+        let speed = app
+            .world()
+            .get::<ParticleMotion>(drifting)
+            .unwrap()
+            .velocity
+            .length();
+        assert!((speed - max_speed).abs() < 1e-3);
+
+        // Once the impulse wears off the cap returns and the streak is released
+        for _ in 0..60 {
+            step(&mut app);
+        }
+        let motion = app.world().get::<ParticleMotion>(kicked).unwrap();
+        assert_eq!(motion.impulse_secs, 0.0);
+        assert!(motion.velocity.length() <= max_speed + 1e-3);
+        assert!(app.world().get::<MotionStreak>(kicked).is_none());
+        assert_eq!(
+            app.world().get::<Transform>(kicked).unwrap().rotation,
+            Quat::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_oriented_heading_turns_short_way_and_holds_when_slow() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        // Moving along +x, "up" (+y) turns a quarter turn clockwise
+        let settled = oriented_heading(0.0, Vec2::new(100.0, 0.0), 10.0);
+        assert!((settled + FRAC_PI_2).abs() < 1e-4);
+
+        // A short step only turns part of the way
+        let step = oriented_heading(0.0, Vec2::new(100.0, 0.0), 1.0 / 60.0);
+        assert!(step < 0.0 && step > -FRAC_PI_2);
+
+        // Near-stationary peas hold their last heading
+        assert_eq!(oriented_heading(1.2, Vec2::new(1.0, -3.0), 1.0), 1.2);
+
+        // Facing just past +pi, a target just past -pi is a small turn, not a full spin
+        let target_velocity = Vec2::from_angle(-PI + 0.1 + FRAC_PI_2) * 100.0;
+        let turned = oriented_heading(PI - 0.1, target_velocity, 1.0 / 60.0);
+        assert!((turned - (PI - 0.1)).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_orientation_is_opt_in_and_keeps_scale() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, orient_particles_to_velocity);
+        let mut spawn = |orient_to_velocity: bool| {
+            app.world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active: true,
+                        ..default()
+                    },
+                    ParticleVisual {
+                        orient_to_velocity,
+                        ..default()
+                    },
+                    ParticleMotion {
+                        velocity: Vec2::new(0.0, -200.0),
+                        ..default()
+                    },
+                    Transform::default(),
+                ))
+                .id()
+        };
+        let oriented = spawn(true);
+        let fixed = spawn(false);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs(5));
+        app.update();
+
+        let transform = app.world().get::<Transform>(oriented).unwrap();
+        let heading = transform.rotation.to_euler(EulerRot::ZYX).0;
+        assert!((heading.abs() - std::f32::consts::PI).abs() < 1e-3);
+        assert_eq!(transform.scale, Vec3::ONE);
+        assert_eq!(
+            app.world().get::<Transform>(fixed).unwrap().rotation,
+            Quat::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_ended_streak_resets_rotation_unless_oriented() {
+        let mut app = App::new();
+        app.init_resource::<DensityOpacityConfig>()
+            .init_resource::<SpatialGrid>()
+            .init_resource::<DisplayScale>()
+            .add_systems(Update, sync_sprite_visuals);
+        let turned = Quat::from_rotation_z(1.0);
+        let mut spawn = |orient_to_velocity: bool| {
+            app.world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active: true,
+                        ..default()
+                    },
+                    ParticleVisual {
+                        orient_to_velocity,
+                        ..default()
+                    },
+                    PulseResponder::default(),
+                    // Still attached through the hysteresis band, but too slow to stretch
+                    MotionStreak::default(),
+                    ParticleMotion {
+                        velocity: Vec2::new(STREAK_MIN_SPEED * 0.9, 0.0),
+                        ..default()
+                    },
+                    Sprite::default(),
+                    Transform::from_rotation(turned),
+                ))
+                .id()
+        };
+        let plain = spawn(false);
+        let oriented = spawn(true);
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Transform>(plain).unwrap().rotation,
+            Quat::IDENTITY
+        );
+        assert_eq!(
+            app.world().get::<Transform>(oriented).unwrap().rotation,
+            turned
+        );
+    }
+
+    #[test]
+    fn test_overfilled_queue_reports_dropped_spawns_once() {
+        const MAX_ACTIVE: u32 = 5;
+        const REQUESTS: usize = 12;
+
+        let mut app = App::new();
+        app.init_resource::<InterpolatedActValues>()
+            .init_resource::<SpawnBudgetConfig>()
+            .init_resource::<FadeProfiles>()
+            .init_resource::<TrailProfiles>()
+            .add_event::<PoolExhausted>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ParticleRng>()
+            .insert_resource(ParticlePool {
+                max_active: MAX_ACTIVE,
+                ..Default::default()
+            })
+            .add_systems(Update, spawn_particles_from_queue);
+
+        let entities: Vec<Entity> = (0..20)
+            .map(|id| app.world_mut().spawn(ParticleBundle::new(id)).id())
+            .collect();
+        app.world_mut()
+            .resource_mut::<ParticlePool>()
+            .available_entities = entities;
+        app.world_mut()
+            .resource_mut::<ParticleSpawnQueue>()
+            .pending_spawns = (0..REQUESTS)
+            .map(|_| ParticleSpawnRequest {
+                source: SpawnSource::Mouse,
+                ..Default::default()
+            })
+            .collect();
+
+        app.update();
+
+        let events: Vec<PoolExhausted> = app
+            .world()
+            .resource::<Events<PoolExhausted>>()
+            .iter_current_update_events()
+            .copied()
+            .collect();
+        assert_eq!(
+            events,
+            vec![PoolExhausted {
+                dropped: REQUESTS - MAX_ACTIVE as usize,
+            }]
+        );
+        assert_eq!(app.world().resource::<ParticlePool>().utilization(), 1.0);
+
+        // A frame with nothing dropped stays quiet
+        app.update();
+        let events = app.world().resource::<Events<PoolExhausted>>();
+        assert_eq!(events.iter_current_update_events().count(), 0);
+    }
+
+    #[test]
+    fn test_spawn_request_sets_orientation_on_reused_peas() {
+        let mut app = App::new();
+        app.init_resource::<InterpolatedActValues>()
+            .init_resource::<SpawnBudgetConfig>()
+            .init_resource::<FadeProfiles>()
+            .init_resource::<TrailProfiles>()
+            .add_event::<PoolExhausted>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<ParticlePool>()
+            .add_systems(Update, spawn_particles_from_queue);
+
+        // A pooled pea still oriented from its previous life
+        let mut reused = ParticleBundle::new(0);
+        reused.visual.orient_to_velocity = true;
+        let reused = app.world_mut().spawn(reused).id();
+        let fresh = app.world_mut().spawn(ParticleBundle::new(1)).id();
+        app.world_mut()
+            .resource_mut::<ParticlePool>()
+            .available_entities = vec![fresh, reused];
+        app.world_mut()
+            .resource_mut::<ParticleSpawnQueue>()
+            .pending_spawns = vec![
+            ParticleSpawnRequest {
+                source: SpawnSource::Mouse,
+                ..Default::default()
+            },
+            ParticleSpawnRequest {
+                source: SpawnSource::Mouse,
+                orient_to_velocity: true,
+                ..Default::default()
+            },
+        ];
+
+        app.update();
+
+        let oriented = |entity| {
+            app.world()
+                .get::<ParticleVisual>(entity)
+                .unwrap()
+                .orient_to_velocity
+        };
+        assert!(!oriented(reused));
+        assert!(oriented(fresh));
+    }
+
+    #[test]
+    fn test_spawn_limit_defers_and_density_refusals_are_not_counted() {
+        let mut app = App::new();
+        app.insert_resource(InterpolatedActValues {
+            density_target: 2.0,
+            ..Default::default()
+        })
+        .insert_resource(SpawnBudgetConfig {
+            frame_spawn_limit: 3,
+            ..Default::default()
+        })
+        .init_resource::<FadeProfiles>()
+        .init_resource::<TrailProfiles>()
+        .add_event::<PoolExhausted>()
+        .init_resource::<ParticleSpawnQueue>()
+        .init_resource::<ParticleRng>()
+        .init_resource::<ParticlePool>()
+        .add_systems(Update, spawn_particles_from_queue);
+
+        let entities: Vec<Entity> = (0..20)
+            .map(|id| app.world_mut().spawn(ParticleBundle::new(id)).id())
+            .collect();
+        app.world_mut()
+            .resource_mut::<ParticlePool>()
+            .available_entities = entities;
+        let request = |source| ParticleSpawnRequest {
+            source,
+            ..Default::default()
+        };
+        // Two beats reach the density target, the third is refused, then
+        // one mouse spawn hits the frame limit and four more wait
+        let mut pending: Vec<_> = (0..3).map(|_| request(SpawnSource::Beat)).collect();
+        pending.extend((0..5).map(|_| request(SpawnSource::Mouse)));
+        app.world_mut()
+            .resource_mut::<ParticleSpawnQueue>()
+            .pending_spawns = pending;
+
+        app.update();
+
+        assert_eq!(app.world().resource::<ParticlePool>().active_count, 3);
+        let queue = &app.world().resource::<ParticleSpawnQueue>().pending_spawns;
+        assert_eq!(queue.len(), 4);
+        assert!(queue
+            .iter()
+            .all(|request| request.source == SpawnSource::Mouse));
+        // Neither the density refusal nor the deferral means the pool is full
+        let events = app.world().resource::<Events<PoolExhausted>>();
+        assert_eq!(events.iter_current_update_events().count(), 0);
+
+        // The deferred spawns activate on the following frames
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<ParticlePool>().active_count, 7);
+        assert!(app
+            .world()
+            .resource::<ParticleSpawnQueue>()
+            .pending_spawns
+            .is_empty());
+    }
+
+    /// Spawns one pea from a `ParticleRng` seeded with `seed` and returns
+    /// its turbulence field at a few fixed points and times.
+    fn seeded_turbulence_samples(seed: u64) -> Vec<Vec2> {
+        let mut app = App::new();
+        app.init_resource::<InterpolatedActValues>()
+            .init_resource::<SpawnBudgetConfig>()
+            .init_resource::<FadeProfiles>()
+            .init_resource::<TrailProfiles>()
+            .add_event::<PoolExhausted>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ParticlePool>()
+            .insert_resource(ParticleRng::with_seed(seed))
+            .add_systems(Update, spawn_particles_from_queue);
+        let particle = app.world_mut().spawn(ParticleBundle::new(0)).id();
+        app.world_mut()
+            .resource_mut::<ParticlePool>()
+            .available_entities = vec![particle];
+        app.world_mut()
+            .resource_mut::<ParticleSpawnQueue>()
+            .pending_spawns
+            .push(ParticleSpawnRequest::default());
+        app.update();
+
+        let turbulence_seed = app
+            .world()
+            .get::<ParticleMotion>(particle)
+            .unwrap()
+            .turbulence_seed;
+        [
+            (Vec2::ZERO, 0.0),
+            (Vec2::new(120.0, -45.0), 3.5),
+            (Vec2::new(-600.0, 310.0), 42.0),
+            (Vec2::new(900.0, 480.0), 120.0),
+        ]
+        .into_iter()
+        .map(|(pos, elapsed)| sample_turbulence_field(pos, elapsed, turbulence_seed))
+        .collect()
+    }
+
+    #[test]
+    fn test_seed_fixes_turbulence_field() {
+        use crate::resources::RngSeed;
+
+        // The same seed replays the same field
+        let seed = RngSeed::default().0;
+        let field = seeded_turbulence_samples(seed);
+        assert_eq!(field, seeded_turbulence_samples(seed));
+
+        // Neighbouring seeds give unrelated fields, not shifted copies
+        let other = seeded_turbulence_samples(seed + 1);
+        let matching = field
+            .iter()
+            .zip(&other)
+            .filter(|(a, b)| a.abs_diff_eq(**b, 1e-3))
+            .count();
+        assert_eq!(matching, 0, "{field:?} vs {other:?}");
+    }
+
+    #[test]
+    fn test_overlapping_swarm_particles_separate() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(InterpolatedActValues {
+                particle_behavior: ParticleBehaviorType::Swarm,
+                ..Default::default()
+            })
+            .init_resource::<ParticleRng>()
+            .init_resource::<BehaviorTuning>()
+            .init_resource::<SpatialGrid>()
+            .add_systems(
+                Update,
+                (update_spatial_grid, apply_particle_behavior).chain(),
+            );
+
+        // No act forces, so any acceleration comes from separation alone
+        let mut spawn_at = |x: f32| {
+            let mut bundle = ParticleBundle::new(0);
+            bundle.state.active = true;
+            bundle.behavior.coefficients = BehaviorCoefficients::ZERO;
+            bundle.transform = Transform::from_xyz(x, 0.0, 0.0);
+            app.world_mut().spawn(bundle).id()
+        };
+        let left = spawn_at(-3.0);
+        let right = spawn_at(3.0);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        app.update();
+
+        let acceleration = |entity| {
+            app.world()
+                .get::<ParticleMotion>(entity)
+                .unwrap()
+                .acceleration
+        };
+        let (left_accel, right_accel) = (acceleration(left), acceleration(right));
+        assert!(left_accel.x < 0.0 && right_accel.x > 0.0);
+        assert!((left_accel + right_accel).length() < 1e-3);
+
+        // Outside Swarm and Orbit there is no separation
+        app.world_mut()
+            .resource_mut::<InterpolatedActValues>()
+            .particle_behavior = ParticleBehaviorType::Drift;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        app.update();
+        let acceleration = |entity| {
+            app.world()
+                .get::<ParticleMotion>(entity)
+                .unwrap()
+                .acceleration
+        };
+        assert_eq!(acceleration(left), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_separation_caps_and_ignores_distant_neighbors() {
+        let pos = Vec2::ZERO;
+        assert_eq!(
+            separation_acceleration(pos, [Vec2::new(50.0, 0.0)], 40.0, 1.0),
+            Vec2::ZERO
+        );
+        assert_eq!(separation_acceleration(pos, [pos], 40.0, 1.0), Vec2::ZERO);
+
+        // Closer neighbors push harder
+        let near = separation_acceleration(pos, [Vec2::new(5.0, 0.0)], 40.0, 1.0);
+        let far = separation_acceleration(pos, [Vec2::new(30.0, 0.0)], 40.0, 1.0);
+        assert!(near.x < far.x && far.x < 0.0);
+    }
+
+    #[test]
+    fn test_turbulence_field_decorrelates_at_a_distance() {
+        fn correlation(a: &[f32], b: &[f32]) -> f32 {
+            let mean = |v: &[f32]| v.iter().sum::<f32>() / v.len() as f32;
+            let (mean_a, mean_b) = (mean(a), mean(b));
+            let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+            for (x, y) in a.iter().zip(b) {
+                cov += (x - mean_a) * (y - mean_b);
+                var_a += (x - mean_a) * (x - mean_a);
+                var_b += (y - mean_b) * (y - mean_b);
+            }
+            cov / (var_a * var_b).sqrt()
+        }
+
+        // Two distant points over time
+        let times: Vec<f32> = (0..800).map(|i| i as f32 * 0.25).collect();
+        let series = |pos: Vec2| -> Vec<f32> {
+            times
+                .iter()
+                .map(|&t| sample_turbulence_field(pos, t, 0.0).x)
+                .collect()
+        };
+        let over_time = correlation(&series(Vec2::ZERO), &series(Vec2::new(4000.0, -2500.0)));
+        assert!(over_time.abs() < 0.3, "correlation {over_time}");
+
+        // A line and the same line shifted by one period of the old summed sines
+        let line = |shift: f32| -> Vec<f32> {
+            (0..800)
+                .map(|i| Vec2::new(i as f32 * 7.0 + shift, 0.0))
+                .map(|pos| sample_turbulence_field(pos, 3.0, 0.0).x)
+                .collect()
+        };
+        let banding = correlation(&line(0.0), &line(std::f32::consts::TAU / 0.01));
+        assert!(banding.abs() < 0.3, "correlation {banding}");
+    }
+
+    #[test]
+    fn test_turbulence_field_is_divergence_free() {
+        // Central differences of each component; a curl field has zero divergence
+        let h = 1.0;
+        for (pos, elapsed, seed) in [
+            (Vec2::new(10.0, 20.0), 0.0, 0.0),
+            (Vec2::new(-340.0, 95.0), 7.5, 312.0),
+            (Vec2::new(820.0, -610.0), 41.0, 987.0),
+        ] {
+            let field = |offset: Vec2| sample_turbulence_field(pos + offset, elapsed, seed);
+            let d_dx = (field(Vec2::X * h).x - field(-Vec2::X * h).x) / (2.0 * h);
+            let d_dy = (field(Vec2::Y * h).y - field(-Vec2::Y * h).y) / (2.0 * h);
+            let scale = field(Vec2::ZERO).length().max(1e-3) * TURBULENCE_SPATIAL_SCALE;
+            assert!(
+                (d_dx + d_dy).abs() < scale * 0.05,
+                "divergence {}",
+                d_dx + d_dy
+            );
+        }
+    }
+
+    #[test]
+    fn test_flow_steers_onto_current_within_speed_cap() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<BehaviorTuning>()
+            .init_resource::<SpatialGrid>()
+            .add_systems(
+                Update,
+                (apply_particle_behavior, apply_velocity_changes).chain(),
+            );
+
+        let flow = ParticleBehaviorType::Flow;
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 10_000.0,
+                    lifetime_total_ms: 10_000.0,
+                },
+                ParticleMotion {
+                    velocity: Vec2::new(2000.0, 0.0),
+                    drag: flow.base_drag(),
+                    ..Default::default()
+                },
+                ParticleBehavior {
+                    coefficients: flow.coefficients(),
+                    ..Default::default()
+                },
+                Transform::from_xyz(120.0, -40.0, 0.0),
+            ))
+            .id();
+
+        for _ in 0..120 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(16));
+            app.update();
+            let velocity = app
+                .world()
+                .get::<ParticleMotion>(particle)
+                .unwrap()
+                .velocity;
+            assert!(velocity.is_finite() && velocity.length() <= 500.0 + 1e-3);
+        }
+
+        // Drag keeps it below the current's speed, but it follows the current
+        let elapsed = app.world().resource::<Time>().elapsed_secs();
+        let current = flow_velocity(Vec2::new(120.0, -40.0), elapsed);
+        let velocity = app
+            .world()
+            .get::<ParticleMotion>(particle)
+            .unwrap()
+            .velocity;
+        assert!(
+            velocity.dot(current) > 0.0,
+            "velocity {velocity}, current {current}"
+        );
+        assert!(velocity.length() < current.length() * 1.5);
+    }
+
+    #[test]
+    fn test_zero_delta_leaves_particle_state_unchanged() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ActState>()
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<ParticlePool>()
+            .init_resource::<Intensity>()
+            .init_resource::<Quietude>()
+            .init_resource::<MagnetToy>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<BehaviorTuning>()
+            .init_resource::<SpatialGrid>()
+            .add_systems(
+                Update,
+                (
+                    update_particle_lifetime,
+                    apply_particle_behavior,
+                    apply_turbulence,
+                    apply_attractor_forces,
+                    apply_magnet_toy_damping,
+                    integrate_particle_motion,
+                    apply_velocity_changes,
+                )
+                    .chain(),
+            );
+        app.world_mut()
+            .spawn((Transform::from_xyz(50.0, 0.0, 0.0), Attractor::default()));
+        // Over the speed cap and carrying acceleration, so any zero step would show
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 1000.0,
+                    lifetime_total_ms: 1000.0,
+                },
+                ParticleMotion {
+                    velocity: Vec2::new(900.0, 0.0),
+                    acceleration: Vec2::new(0.0, 40.0),
+                    ..Default::default()
+                },
+                ParticleBehavior::default(),
+                Transform::from_xyz(-20.0, 10.0, 0.0),
+            ))
+            .id();
+
+        let snapshot = |app: &App| {
+            let entity = app.world().entity(particle);
+            let motion = *entity.get::<ParticleMotion>().unwrap();
+            (
+                entity.get::<Transform>().unwrap().translation,
+                motion.velocity,
+                motion.acceleration,
+                entity.get::<ParticleState>().unwrap().lifetime_remaining_ms,
+            )
+        };
+        let before = snapshot(&app);
+
+        // Paused time: several zero-delta frames
+        for _ in 0..5 {
+            app.update();
+        }
+        let frozen = snapshot(&app);
+        assert_eq!(frozen, before);
+        assert!(frozen.1.is_finite() && frozen.0.is_finite());
+
+        // Resuming advances the simulation again
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        app.update();
+        assert_ne!(snapshot(&app), before);
+    }
+
+    #[test]
+    fn test_lifecycle_events_match_activations() {
+        const ACTIVATIONS: usize = 7;
+
+        let mut app = App::new();
+        app.init_resource::<InterpolatedActValues>()
+            .init_resource::<SpawnBudgetConfig>()
+            .init_resource::<FadeProfiles>()
+            .init_resource::<TrailProfiles>()
+            .add_event::<PoolExhausted>()
+            .add_event::<ParticleSpawned>()
+            .add_event::<ParticleDespawned>()
+            .init_resource::<ParticleLifecycleEvents>()
+            .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<ParticlePool>()
+            .add_systems(
+                Update,
+                (spawn_particles_from_queue, despawn_expired_particles).chain(),
+            );
+
+        let entities: Vec<Entity> = (0..20)
+            .map(|id| app.world_mut().spawn(ParticleBundle::new(id)).id())
+            .collect();
+        app.world_mut()
+            .resource_mut::<ParticlePool>()
+            .available_entities = entities;
+        let queue_spawns = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<ParticleSpawnQueue>()
+                .pending_spawns = (0..ACTIVATIONS)
+                .map(|i| ParticleSpawnRequest {
+                    position: Vec2::new(i as f32, 0.0),
+                    source: SpawnSource::Mouse,
+                    ..Default::default()
+                })
+                .collect();
+        };
+
+        // Disabled by default: nothing is sent
+        queue_spawns(&mut app);
+        app.update();
+        assert!(app.world().resource::<Events<ParticleSpawned>>().is_empty());
+
+        app.world_mut()
+            .resource_mut::<ParticleLifecycleEvents>()
+            .enabled = true;
+        queue_spawns(&mut app);
+        app.update();
+        let spawned: Vec<ParticleSpawned> = app
+            .world()
+            .resource::<Events<ParticleSpawned>>()
+            .iter_current_update_events()
+            .copied()
+            .collect();
+        assert_eq!(spawned.len(), ACTIVATIONS);
+        assert!(spawned
+            .iter()
+            .all(|event| event.source == SpawnSource::Mouse));
+
+        // Expire everything: one despawn event per active particle
+        let mut states = app.world_mut().query::<&mut ParticleState>();
+        for mut state in states.iter_mut(app.world_mut()) {
+            state.lifetime_remaining_ms = 0.0;
+        }
+        app.update();
+        let despawned = app.world().resource::<Events<ParticleDespawned>>();
+        assert_eq!(
+            despawned.iter_current_update_events().count(),
+            ACTIVATIONS * 2
+        );
+        assert!(despawned
+            .iter_current_update_events()
+            .any(|event| event.entity == spawned[0].entity));
+    }
+
+    #[test]
+    fn test_spawn_particles_burst_enqueues_every_request() {
+        let mut app = App::new();
+        app.add_event::<SpawnParticles>()
+            .init_resource::<ParticleSpawnQueue>()
+            .add_systems(Update, enqueue_spawn_particles);
+
+        let center = Vec2::new(40.0, -25.0);
+        app.world_mut()
+            .send_event(SpawnParticles::burst(center, 12, Color::BLACK));
+        app.update();
+
+        let queue = app.world().resource::<ParticleSpawnQueue>();
+        assert_eq!(queue.pending_spawns.len(), 12);
+        assert!(queue
+            .pending_spawns
+            .iter()
+            .all(|request| request.position == center));
+        let net: Vec2 = queue
+            .pending_spawns
+            .iter()
+            .map(|r| r.initial_velocity)
+            .sum();
+        assert!(net.length() < 1e-3, "burst should spread evenly, net {net}");
+    }
+
+    #[test]
+    fn test_validate_spawn_request_clamps_lifetime_and_position() {
+        let request = validate_spawn_request(ParticleSpawnRequest {
+            position: Vec2::new(1.0e9, -1.0e9),
+            lifetime_ms: -50.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            request.position,
+            Vec2::new(SPAWN_POSITION_LIMIT, -SPAWN_POSITION_LIMIT)
+        );
+        assert!(request.lifetime_ms > 0.0);
+
+        let request = validate_spawn_request(ParticleSpawnRequest {
+            position: Vec2::new(f32::INFINITY, 10.0),
+            lifetime_ms: f32::NAN,
+            ..Default::default()
+        });
+        assert_eq!(request.position, Vec2::ZERO);
+        assert_eq!(
+            request.lifetime_ms,
+            ParticleSpawnRequest::default().lifetime_ms
+        );
+    }
+
+    #[test]
+    fn test_nan_velocity_particle_is_recycled() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(ParticlePool {
+                active_count: 1,
+                ..Default::default()
+            })
+            .init_resource::<InterpolatedActValues>()
+            .init_resource::<OrphanTrailPool>()
+            .add_systems(
+                Update,
+                (
+                    integrate_particle_motion,
+                    apply_velocity_changes,
+                    update_particle_lifetime,
+                    despawn_expired_particles,
+                )
+                    .chain(),
+            );
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 5000.0,
+                    lifetime_total_ms: 5000.0,
+                },
+                ParticleMotion {
+                    velocity: Vec2::new(f32::NAN, 10.0),
+                    ..Default::default()
+                },
+                Transform::from_xyz(20.0, 20.0, 0.0),
+                Visibility::Visible,
+            ))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        app.update();
+
+        let entity = app.world().entity(particle);
+        assert!(!entity.get::<ParticleState>().unwrap().active);
+        assert_eq!(entity.get::<ParticleMotion>().unwrap().velocity, Vec2::ZERO);
+        assert!(entity.get::<Transform>().unwrap().translation.is_finite());
+        assert_eq!(*entity.get::<Visibility>().unwrap(), Visibility::Hidden);
+
+        let pool = app.world().resource::<ParticlePool>();
+        assert_eq!(pool.active_count, 0);
+        assert!(pool.available_entities.contains(&particle));
+    }
+
+    #[test]
+    fn test_density_target_step_down_is_gradual() {
+        use std::time::Duration;
+
+        const START_COUNT: u32 = 1000;
+        const STEP_MS: u64 = 100;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InterpolatedActValues>()
+            .insert_resource(ParticlePool {
+                active_count: START_COUNT,
+                ..Default::default()
+            })
+            .init_resource::<OrphanTrailPool>()
+            .add_systems(
+                Update,
+                (update_particle_lifetime, despawn_expired_particles).chain(),
+            );
+
+        // Lifetimes spread evenly across 1-11 seconds
+        for i in 0..START_COUNT {
+            let lifetime_ms = 1000.0 + i as f32 * 10.0;
+            app.world_mut().spawn((
+                Particle { id: i },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: lifetime_ms,
+                    lifetime_total_ms: lifetime_ms,
+                },
+                Visibility::Visible,
+            ));
+        }
+
+        // Density target falls from 1000 to 200 over two seconds, then holds
+        let mut previous = START_COUNT;
+        for step in 0..40 {
+            let target = (1000.0 - step as f32 * 40.0).max(200.0);
+            app.world_mut()
+                .resource_mut::<InterpolatedActValues>()
+                .density_target = target;
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(STEP_MS));
+            app.update();
+
+            let active = app.world().resource::<ParticlePool>().active_count;
+            assert!(active <= previous, "population must never grow");
+
+            // Worst case: one step drains STEP_MS * max boost of lifetime,
+            // i.e. 40 particles at 10ms spacing. A cliff would be hundreds.
+            let max_drop = (STEP_MS as f32 * DENSITY_EXPIRY_MAX_BOOST / 10.0) as u32 + 1;
+            assert!(
+                previous - active <= max_drop,
+                "dropped {} particles in one step",
+                previous - active
+            );
+            previous = active;
+        }
+
+        assert!(previous < START_COUNT);
+    }
+
+    #[test]
+    fn test_resting_turbulence_keeps_act_table_and_rises_with_intensity() {
+        let quietude = Quietude::default();
+        for (act, multiplier) in [
+            (Act::Emergence, 0.5),
+            (Act::Accumulation, 0.6),
+            (Act::Crescendo, 1.0),
+            (Act::Release, 0.8),
+            (Act::Transcendence, 0.3),
+        ] {
+            let resting = turbulence_strength(act, &Intensity::for_act(act), &quietude);
+            assert!((resting - BASE_TURBULENCE_STRENGTH * multiplier).abs() < 1e-4);
+        }
+
+        let peak = Intensity {
+            value: 1.0,
+            ..Default::default()
+        };
+        let lifted = turbulence_strength(Act::Emergence, &peak, &quietude);
+        assert!((lifted - BASE_TURBULENCE_STRENGTH).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_intensity_follows_act_without_audio_plugin() {
+        let mut app = App::new();
+        app.insert_resource(ActState {
+            current_act: Act::Crescendo,
+            ..Default::default()
+        })
+        .init_resource::<AudioAnalysis>()
+        .init_resource::<MouseState>()
+        .init_resource::<Intensity>()
+        .add_systems(Update, update_intensity);
+        app.update();
+
+        assert_eq!(app.world().resource::<Intensity>().value, 1.0);
+    }
+
+    #[test]
+    fn test_painted_particles_skip_density_drain() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(InterpolatedActValues {
+                density_target: 10.0,
+                ..Default::default()
+            })
+            .add_systems(Update, update_particle_lifetime);
+
+        let mut spawn = |source: SpawnSource| {
+            app.world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active: true,
+                        lifetime_remaining_ms: 5000.0,
+                        lifetime_total_ms: 5000.0,
+                    },
+                    Spawnable {
+                        spawn_source: source,
+                    },
+                ))
+                .id()
+        };
+        // A stroke far above the target, plus one automatic particle
+        let painted: Vec<Entity> = (0..100).map(|_| spawn(SpawnSource::Mouse)).collect();
+        let automatic = spawn(SpawnSource::Automatic);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+
+        let remaining = |entity: Entity| {
+            app.world()
+                .get::<ParticleState>(entity)
+                .unwrap()
+                .lifetime_remaining_ms
+        };
+        assert_eq!(remaining(painted[0]), 4900.0);
+        // The stroke does not count toward the target either
+        assert_eq!(remaining(automatic), 4900.0);
+    }
+
+    #[test]
+    fn test_crowded_particle_renders_with_lower_alpha() {
+        let mut app = App::new();
+        app.insert_resource(DensityOpacityConfig {
+            enabled: true,
+            ..Default::default()
+        })
+        .init_resource::<SpatialGrid>()
+        .init_resource::<DisplayScale>()
+        .add_systems(Update, (update_spatial_grid, sync_sprite_visuals).chain());
+
+        let spawn = |app: &mut App, id: u32, position: Vec2| {
+            app.world_mut()
+                .spawn((
+                    Particle { id },
+                    ParticleState {
+                        active: true,
+                        lifetime_remaining_ms: 5000.0,
+                        lifetime_total_ms: 5000.0,
+                    },
+                    ParticleVisual::default(),
+                    PulseResponder::default(),
+                    Sprite::default(),
+                    Transform::from_translation(position.extend(0.0)),
+                ))
+                .id()
+        };
+
+        let crowded = spawn(&mut app, 0, Vec2::new(5.0, 5.0));
+        for id in 1..12 {
+            spawn(&mut app, id, Vec2::new(5.0 + id as f32, 5.0));
+        }
+        let isolated = spawn(&mut app, 100, Vec2::new(500.0, 500.0));
+
+        app.update();
+
+        let crowded_alpha = app.world().get::<Sprite>(crowded).unwrap().color.alpha();
+        let isolated_alpha = app.world().get::<Sprite>(isolated).unwrap().color.alpha();
+        assert!(crowded_alpha < isolated_alpha);
+
+        // Clamped so clusters never vanish entirely
+        let config = DensityOpacityConfig::default();
+        assert_eq!(density_opacity_factor(10_000, &config), config.min_factor);
+        assert_eq!(density_opacity_factor(config.crowd_threshold, &config), 1.0);
+    }
+
+    #[test]
+    fn test_scale_adjusted_base_size() {
+        // A 1x laptop and a 2x retina display with the same logical window
+        // show the same world size
+        let laptop = DisplayScale::from_window(1.0, Vec2::new(1920.0, 1080.0), 1080.0);
+        let retina = DisplayScale::from_window(2.0, Vec2::new(1920.0, 1080.0), 1080.0);
+        assert_eq!(
+            scale_adjusted_base_size(PEA_BASE_SIZE, &laptop),
+            PEA_BASE_SIZE
+        );
+        assert_eq!(
+            scale_adjusted_base_size(PEA_BASE_SIZE, &retina),
+            PEA_BASE_SIZE
+        );
+        assert_eq!(retina.physical_pixels_per_unit, 2.0);
+
+        // Fractional scaling snaps to whole physical pixels and stays close
+        let fractional = DisplayScale::from_window(1.25, Vec2::new(1440.0, 900.0), 1080.0);
+        let size = scale_adjusted_base_size(PEA_BASE_SIZE * 0.37, &fractional);
+        let physical = size * fractional.physical_pixels_per_unit;
+        assert!((physical - physical.round()).abs() < 1e-3);
+        assert!((size - PEA_BASE_SIZE * 0.37).abs() <= 1.0 / fractional.physical_pixels_per_unit);
+
+        // Zoomed out 2x, a world unit covers half the pixels, so the step doubles
+        let zoomed_out = DisplayScale {
+            camera_scale: 2.0,
+            ..fractional.clone()
+        };
+        let size = scale_adjusted_base_size(PEA_BASE_SIZE * 0.37, &zoomed_out);
+        let physical = size * fractional.physical_pixels_per_unit / 2.0;
+        assert!((physical - physical.round()).abs() < 1e-3);
+
+        // Degenerate scales fall back to the unsnapped size
+        let broken = DisplayScale {
+            scale_factor: 0.0,
+            physical_pixels_per_unit: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(scale_adjusted_base_size(12.5, &broken), 12.5);
     }
 
     #[test]
@@ -862,6 +3963,124 @@ mod tests {
         assert!((gray_srgba.red - expected_gray).abs() < 0.01);
     }
 
+    #[test]
+    fn test_burst_offsets_scale_with_viewport() {
+        let reference = beat_spawn_extent_scale(Vec2::new(1920.0, 1080.0), 1.0);
+        let small = beat_spawn_extent_scale(Vec2::new(960.0, 540.0), 1.0);
+        let portrait = beat_spawn_extent_scale(Vec2::new(607.5, 1080.0), 1.0);
+        let configured = beat_spawn_extent_scale(Vec2::new(1920.0, 1080.0), 1.5);
+        assert_eq!(reference, 1.0);
+        assert_eq!(small, 0.5);
+        assert!((portrait - 607.5 / 1920.0).abs() < 1e-6);
+        assert_eq!(configured, 1.5);
+
+        // Same random draws at two scales: offsets scale, velocities don't
+        let sample = |pattern, index, scale| {
+            beat_pattern_offset(pattern, index, 8, scale, &mut fastrand::Rng::with_seed(7))
+        };
+        for pattern in [
+            SpawnPattern::Scatter,
+            SpawnPattern::Ripple,
+            SpawnPattern::Burst,
+        ] {
+            for index in 0..8 {
+                let (base_offset, base_vel) = sample(pattern, index, reference);
+                let (small_offset, small_vel) = sample(pattern, index, small);
+                assert!((small_offset - base_offset * 0.5).length() < 1e-4);
+                assert_eq!(small_vel, base_vel);
+            }
+        }
+    }
+
+    #[test]
+    fn test_opposite_poles_exert_opposite_forces() {
+        let particle = Vec2::new(0.0, 0.0);
+        let north = Attractor {
+            strength: 2500.0,
+            radius: 600.0,
+        };
+        let south = Attractor {
+            strength: -2500.0,
+            ..north
+        };
+
+        let pole_pos = Vec2::new(200.0, 0.0);
+        let pull = attractor_acceleration(particle, pole_pos, &north);
+        let push = attractor_acceleration(particle, pole_pos, &south);
+        assert!(pull.x > 0.0, "positive pole pulls toward itself");
+        assert!(push.x < 0.0, "negative pole pushes away");
+        assert_eq!(pull, -push);
+
+        // No force beyond the radius or at the pole's center
+        assert_eq!(
+            attractor_acceleration(particle, Vec2::new(700.0, 0.0), &north),
+            Vec2::ZERO
+        );
+        assert_eq!(
+            attractor_acceleration(pole_pos, pole_pos, &north),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn test_behavior_crossfade_weights_both_behaviors_equally_at_halfway() {
+        let (pos, target, random) = (Vec2::new(120.0, -80.0), Vec2::ZERO, Vec2::new(0.3, 0.8));
+        let accel = |behavior_type: ParticleBehaviorType| {
+            behavior_acceleration(&behavior_type.coefficients(), pos, target, random)
+        };
+        let swarm = accel(ParticleBehaviorType::Swarm);
+        let orbit = accel(ParticleBehaviorType::Orbit);
+
+        let behavior = ParticleBehavior {
+            coefficients: ParticleBehaviorType::Swarm.coefficients(),
+            blend_target: Some((ParticleBehaviorType::Orbit, 0.5)),
+            ..Default::default()
+        };
+        let blended = blended_behavior_acceleration(&behavior, pos, target, random);
+        assert!((blended - (swarm + orbit) * 0.5).length() < 1e-3);
+
+        // No blend target leaves the particle's own behavior untouched
+        let plain = ParticleBehavior {
+            blend_target: None,
+            ..behavior
+        };
+        assert_eq!(
+            blended_behavior_acceleration(&plain, pos, target, random),
+            swarm
+        );
+    }
+
+    #[test]
+    fn test_blended_attractor_target() {
+        assert_eq!(blended_attractor_target(Vec2::ZERO, &[]), None);
+
+        // A single point is the target wherever the particle is
+        let point = AttractorPoint::at(Vec2::new(300.0, -120.0));
+        for pos in [Vec2::ZERO, Vec2::new(-900.0, 400.0), point.position] {
+            let target = blended_attractor_target(pos, &[point]).unwrap();
+            assert!(target.distance(point.position) < 1e-3);
+        }
+
+        // Equal points equidistant from the particle blend to their midpoint
+        let left = AttractorPoint::at(Vec2::new(-200.0, 0.0));
+        let right = AttractorPoint::at(Vec2::new(200.0, 0.0));
+        let midpoint = blended_attractor_target(Vec2::new(0.0, 50.0), &[left, right]).unwrap();
+        assert!(midpoint.distance(Vec2::ZERO) < 1e-3);
+
+        // The nearer point dominates
+        let near_left = blended_attractor_target(Vec2::new(-150.0, 0.0), &[left, right]).unwrap();
+        assert!(near_left.x < 0.0);
+
+        // Zero-strength points are ignored
+        let dormant = AttractorPoint {
+            strength: 0.0,
+            ..right
+        };
+        let target = blended_attractor_target(Vec2::ZERO, &[left, dormant]).unwrap();
+        assert!(target.distance(left.position) < 1e-3);
+        assert_eq!(blended_attractor_target(Vec2::ZERO, &[dormant]), None);
+    }
+
     #[test]
     fn test_beat_strength_spawning() {
         // Silence should not spawn
@@ -1,11 +1,16 @@
 //! Module: post_process
-//! Purpose: Post-processing visual effects including bloom, chromatic aberration, vignette, and film grain
+//! Purpose: Post-processing visual effects including bloom, focus blur, chromatic aberration, vignette, and film grain
 //! Dependencies: resources, bevy::prelude, bevy::core_pipeline::bloom
 
-use bevy::core_pipeline::bloom::Bloom;
+use bevy::core_pipeline::bloom::{Bloom, BloomCompositeMode, BloomPrefilter};
 use bevy::prelude::*;
+use bevy::render::extract_resource::ExtractResource;
 
-use crate::resources::PostProcessSettings;
+use crate::components::WhirledCamera;
+use crate::render_layers;
+use crate::resources::{MouseState, PostProcessSettings, Quietude};
+use crate::screen_pass::ScreenPassPlugin;
+use crate::types::BloomComposite;
 
 // =============================================================================
 // POST-PROCESSING RESOURCES
@@ -16,9 +21,9 @@ use crate::resources::PostProcessSettings;
 /// Chromatic aberration simulates lens imperfection by separating color channels,
 /// creating a subtle RGB fringing effect. Intensity increases during emotional peaks.
 ///
-/// Note: This resource stores configuration for a custom shader effect.
-/// Full implementation requires a custom render pipeline or bevy_post_process crate.
-#[derive(Resource, Debug, Clone)]
+/// Extracted to the render world each frame it changes and applied by the
+/// chromatic aberration pass in `screen_pass`.
+#[derive(Resource, Debug, Clone, ExtractResource)]
 pub struct ChromaticAberrationSettings {
     /// Strength of the color channel separation (0.0 = none, 1.0 = maximum)
     pub strength: f32,
@@ -41,14 +46,16 @@ impl Default for ChromaticAberrationSettings {
 /// Intensity is stronger in early acts and dissolves by Act V for a feeling
 /// of openness and transcendence.
 ///
-/// Note: This resource stores configuration for a custom shader effect.
-/// Full implementation requires a custom render pipeline or bevy_post_process crate.
-#[derive(Resource, Debug, Clone)]
+/// Extracted to the render world each frame it changes and applied by the
+/// vignette pass in `screen_pass`.
+#[derive(Resource, Debug, Clone, ExtractResource)]
 pub struct VignetteSettings {
     /// Darkness intensity at the edges (0.0 = none, 1.0 = fully darkened)
     pub intensity: f32,
     /// Smoothness of the vignette falloff (higher = softer edge)
     pub smoothness: f32,
+    /// Color the edges fade toward (black for a classic darkening)
+    pub vignette_color: Color,
     /// Whether the effect is enabled
     pub enabled: bool,
 }
@@ -58,6 +65,7 @@ impl Default for VignetteSettings {
         Self {
             intensity: 0.3,
             smoothness: 0.5,
+            vignette_color: Color::BLACK,
             enabled: true,
         }
     }
@@ -68,9 +76,9 @@ impl Default for VignetteSettings {
 /// Film grain adds organic noise texture to the visuals, creating a
 /// cinematic quality and preventing banding in gradients.
 ///
-/// Note: This resource stores configuration for a custom shader effect.
-/// Full implementation requires a custom render pipeline or bevy_post_process crate.
-#[derive(Resource, Debug, Clone)]
+/// Extracted to the render world each frame it changes and applied by the
+/// film grain pass in `screen_pass`.
+#[derive(Resource, Debug, Clone, ExtractResource)]
 pub struct FilmGrainSettings {
     /// Amount of grain noise (0.0 = none, 1.0 = heavy grain)
     pub amount: f32,
@@ -87,6 +95,68 @@ impl Default for FilmGrainSettings {
     }
 }
 
+/// Settings for the depth-of-field style focus blur.
+///
+/// Everything within `sharp_radius` of the interaction center stays crisp;
+/// beyond it the blur ramps up over `falloff` to `strength`, so peas far from
+/// the cursor soften as if out of a shallow focal plane. Distances are in
+/// screen-height units, so the in-focus region stays round on any aspect.
+///
+/// Extracted to the render world each frame it changes and applied by the
+/// focus blur pass in `screen_pass`.
+#[derive(Resource, Debug, Clone, PartialEq, ExtractResource)]
+pub struct FocusBlurSettings {
+    /// Blur radius at full defocus, in UV units (0.0 = none)
+    pub strength: f32,
+    /// Interaction center in screen UV (0,0 top-left, 1,1 bottom-right)
+    pub focus: Vec2,
+    /// Distance from the focus that stays fully sharp
+    pub sharp_radius: f32,
+    /// Distance beyond `sharp_radius` over which the blur reaches full strength
+    pub falloff: f32,
+    /// Viewport width over height
+    pub aspect: f32,
+    /// Whether the effect is enabled
+    pub enabled: bool,
+}
+
+impl Default for FocusBlurSettings {
+    fn default() -> Self {
+        Self {
+            strength: 0.0,
+            focus: Vec2::splat(0.5),
+            sharp_radius: 0.12,
+            falloff: 0.45,
+            aspect: 1.0,
+            enabled: true,
+        }
+    }
+}
+
+/// Master output dimmer for fading the whole experience to black.
+///
+/// `1.0` is full output, `0.0` is black. The value is a target: the applied
+/// level eases toward it so hosts can set it directly for smooth show
+/// transitions. Independent of the act timeline and of `GentleFade`, which
+/// exits the app.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct MasterDimmer(pub f32);
+
+impl Default for MasterDimmer {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Full-screen black overlay that applies the master dimmer as a final multiply.
+///
+/// Tracks the currently applied dimmer level, which eases toward `MasterDimmer`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MasterDimmerOverlay {
+    /// Dimmer level currently applied to the output (0.0 to 1.0)
+    pub applied_level: f32,
+}
+
 // =============================================================================
 // CONSTANTS
 // =============================================================================
@@ -97,21 +167,27 @@ const _DEFAULT_BLOOM_INTENSITY: f32 = 0.3;
 /// Maximum bloom intensity during peak moments.
 const MAX_BLOOM_INTENSITY: f32 = 1.0;
 
-/// Default bloom low frequency boost (softness).
-const DEFAULT_BLOOM_LF_BOOST: f32 = 0.7;
-
 /// Default bloom low frequency boost curvature.
 const DEFAULT_BLOOM_LF_BOOST_CURVATURE: f32 = 0.95;
 
 /// Default bloom high pass frequency (controls brightness threshold behavior).
 const DEFAULT_BLOOM_HIGH_PASS_FREQUENCY: f32 = 1.0;
 
-/// Maximum chromatic aberration strength.
-const MAX_CHROMATIC_ABERRATION: f32 = 0.015;
+/// Maximum chromatic aberration strength (`screen_pass` maps it to its widest pixel offset).
+pub(crate) const MAX_CHROMATIC_ABERRATION: f32 = 0.015;
+
+/// Focus blur radius in UV units at `PostProcessSettings.focus_blur_strength` 1.0.
+const MAX_FOCUS_BLUR_RADIUS: f32 = 0.012;
 
 /// Maximum vignette intensity.
 const MAX_VIGNETTE_INTENSITY: f32 = 0.6;
 
+/// Film grain amount restored by the toggle when no earlier amount is known.
+const DEFAULT_FILM_GRAIN_AMOUNT: f32 = 0.02;
+
+/// Rate at which the applied dimmer level approaches `MasterDimmer` (per second).
+const MASTER_DIMMER_RATE: f32 = 4.0;
+
 // =============================================================================
 // SYSTEMS
 // =============================================================================
@@ -120,6 +196,9 @@ const MAX_VIGNETTE_INTENSITY: f32 = 0.6;
 ///
 /// This system:
 /// - Reads `PostProcessSettings.bloom_threshold`, `bloom_intensity`, and `bloom_radius`
+/// - Applies the per-act bloom character: composite mode, low-frequency boost,
+///   and prefilter
+/// - Dims intensity toward `Quietude.bloom_floor` in sustained silence
 /// - Updates the `Bloom` component on the camera entity
 /// - Configures bloom for the ethereal glow characteristic of Chromatic Elegy
 ///
@@ -133,10 +212,11 @@ const MAX_VIGNETTE_INTENSITY: f32 = 0.6;
 /// Bevy 0.13+ has built-in bloom support via the `Bloom` component.
 pub fn update_bloom(
     post_process_settings: Res<PostProcessSettings>,
-    mut camera_query: Query<&mut Bloom, With<Camera>>,
+    quietude: Res<Quietude>,
+    mut camera_query: Query<&mut Bloom, With<WhirledCamera>>,
 ) {
-    // Only update if settings changed
-    if !post_process_settings.is_changed() {
+    // Only update if settings or quietude changed
+    if !post_process_settings.is_changed() && !quietude.is_changed() {
         return;
     }
 
@@ -151,23 +231,30 @@ pub fn update_bloom(
         .bloom_intensity
         .clamp(0.0, MAX_BLOOM_INTENSITY);
 
-    // Update bloom settings
-    bloom.intensity = intensity;
+    // Update bloom settings, dimmed in sustained quiet
+    bloom.intensity = intensity * quietude.scale(quietude.bloom_floor);
 
-    // Map bloom_radius to Bevy's low_frequency_boost for soft, ethereal glow
+    // Scale the act's low_frequency_boost by bloom_radius for soft, ethereal glow
     // Higher radius = more diffuse bloom
     let radius_factor = (post_process_settings.bloom_radius / 16.0).clamp(0.0, 1.0);
-    bloom.low_frequency_boost = DEFAULT_BLOOM_LF_BOOST * (1.0 + radius_factor * 0.5);
+    bloom.low_frequency_boost =
+        post_process_settings.bloom_low_frequency_boost * (1.0 + radius_factor * 0.5);
     bloom.low_frequency_boost_curvature = DEFAULT_BLOOM_LF_BOOST_CURVATURE;
 
+    bloom.composite_mode = bloom_composite_mode(post_process_settings.bloom_composite);
+    bloom.prefilter = bloom_prefilter(&post_process_settings);
+
     // High pass frequency affects bloom threshold behavior
     // Lower threshold = more pixels bloom
     let threshold_factor = 1.0 - post_process_settings.bloom_threshold.clamp(0.0, 1.0);
     bloom.high_pass_frequency = DEFAULT_BLOOM_HIGH_PASS_FREQUENCY * (1.0 + threshold_factor);
 
     debug!(
-        "Bloom updated: intensity={:.3}, low_freq_boost={:.3}, high_pass={:.3}",
-        bloom.intensity, bloom.low_frequency_boost, bloom.high_pass_frequency
+        "Bloom updated: intensity={:.3}, low_freq_boost={:.3}, high_pass={:.3}, composite={:?}",
+        bloom.intensity,
+        bloom.low_frequency_boost,
+        bloom.high_pass_frequency,
+        post_process_settings.bloom_composite
     );
 }
 
@@ -175,8 +262,9 @@ pub fn update_bloom(
 ///
 /// This system:
 /// - Reads `PostProcessSettings.chromatic_aberration_strength`
-/// - Updates `ChromaticAberrationSettings` resource
-/// - Prepares configuration for custom post-process shader
+/// - Updates `ChromaticAberrationSettings` resource, which the chromatic
+///   aberration render pass reads
+/// - Disables the pass while `PostProcessSettings.reduced_effects` is set
 ///
 /// # Stage
 /// PostUpdate
@@ -185,8 +273,8 @@ pub fn update_bloom(
 /// Runs before `apply_post_process_chain`.
 ///
 /// # Note
-/// This is a placeholder for custom shader integration. The actual chromatic
-/// aberration effect requires a custom render pipeline or third-party crate.
+/// The clamped strength is the channel offset at the screen corners, so
+/// `MAX_CHROMATIC_ABERRATION` bounds the widest fringe on screen.
 pub fn update_chromatic_aberration(
     post_process_settings: Res<PostProcessSettings>,
     mut chromatic_settings: ResMut<ChromaticAberrationSettings>,
@@ -202,7 +290,7 @@ pub fn update_chromatic_aberration(
         .clamp(0.0, MAX_CHROMATIC_ABERRATION);
 
     chromatic_settings.strength = strength;
-    chromatic_settings.enabled = strength > 0.0001;
+    chromatic_settings.enabled = strength > 0.0001 && !post_process_settings.reduced_effects;
 
     debug!(
         "Chromatic aberration updated: strength={:.4}, enabled={}",
@@ -210,12 +298,57 @@ pub fn update_chromatic_aberration(
     );
 }
 
+/// Updates focus blur settings from PostProcessSettings and the cursor.
+///
+/// This system:
+/// - Scales `PostProcessSettings.focus_blur_strength` to a blur radius
+/// - Projects `MouseState.position` through the camera into screen UV, so the
+///   focus follows pans and zooms
+/// - Drops the blur to zero while `PostProcessSettings.reduced_effects` is set
+///
+/// `FocusBlurSettings.enabled` is the host's switch and is never written
+/// here. The last cursor position is kept while the mouse is away, so the
+/// focal point does not jump when the cursor leaves the window.
+///
+/// # Stage
+/// PostUpdate
+///
+/// # Ordering
+/// Runs before `apply_post_process_chain`.
+pub fn update_focus_blur(
+    post_process_settings: Res<PostProcessSettings>,
+    mouse_state: Res<MouseState>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<WhirledCamera>>,
+    mut focus_blur_settings: ResMut<FocusBlurSettings>,
+) {
+    let strength = if post_process_settings.reduced_effects {
+        0.0
+    } else {
+        post_process_settings.focus_blur_strength.clamp(0.0, 1.0)
+    };
+    let mut updated = FocusBlurSettings {
+        strength: strength * MAX_FOCUS_BLUR_RADIUS,
+        ..focus_blur_settings.clone()
+    };
+
+    if let Ok((camera, camera_transform)) = camera_query.get_single() {
+        if let Some(ndc) = camera.world_to_ndc(camera_transform, mouse_state.position.extend(0.0)) {
+            updated.focus = Vec2::new(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5);
+        }
+        if let Some(size) = camera.logical_viewport_size() {
+            updated.aspect = size.x / size.y.max(1.0);
+        }
+    }
+
+    // Only touch the resource (and re-upload the uniform) when something moved
+    focus_blur_settings.set_if_neq(updated);
+}
+
 /// Updates vignette settings based on PostProcessSettings.
 ///
 /// This system:
-/// - Reads `PostProcessSettings.vignette_intensity`
-/// - Updates `VignetteSettings` resource
-/// - Prepares configuration for custom post-process shader
+/// - Reads `PostProcessSettings.vignette_intensity` and `vignette_color`
+/// - Updates `VignetteSettings` resource, which the vignette render pass reads
 ///
 /// # Stage
 /// PostUpdate
@@ -228,8 +361,6 @@ pub fn update_chromatic_aberration(
 /// - Acts I-III: Stronger vignette (0.3-0.4) for intimate focus
 /// - Act IV: Gradually reducing (0.2-0.1)
 /// - Act V: Minimal or none for expansive feeling
-///
-/// This is a placeholder for custom shader integration.
 pub fn update_vignette(
     post_process_settings: Res<PostProcessSettings>,
     mut vignette_settings: ResMut<VignetteSettings>,
@@ -245,6 +376,7 @@ pub fn update_vignette(
         .clamp(0.0, MAX_VIGNETTE_INTENSITY);
 
     vignette_settings.intensity = intensity;
+    vignette_settings.vignette_color = post_process_settings.vignette_color;
     vignette_settings.enabled = intensity > 0.01;
 
     // Smoothness inversely related to intensity for natural feel
@@ -261,8 +393,8 @@ pub fn update_vignette(
 ///
 /// This system:
 /// - Reads `PostProcessSettings.film_grain_amount`
-/// - Updates `FilmGrainSettings` resource
-/// - Prepares configuration for custom post-process shader
+/// - Updates `FilmGrainSettings` resource, which the film grain render pass reads
+/// - Disables the pass while `PostProcessSettings.reduced_effects` is set
 ///
 /// # Stage
 /// PostUpdate
@@ -287,7 +419,7 @@ pub fn update_film_grain(
     let amount = post_process_settings.film_grain_amount.clamp(0.0, 0.1);
 
     film_grain_settings.amount = amount;
-    film_grain_settings.enabled = amount > 0.001;
+    film_grain_settings.enabled = amount > 0.001 && !post_process_settings.reduced_effects;
 
     debug!(
         "Film grain updated: amount={:.3}, enabled={}",
@@ -295,6 +427,34 @@ pub fn update_film_grain(
     );
 }
 
+/// Toggles film grain on and off with the G key.
+///
+/// Turning grain off zeroes `PostProcessSettings.film_grain_amount`, which
+/// `update_film_grain` turns into a disabled pass; turning it back on restores
+/// the amount in use before it was switched off.
+///
+/// # Stage
+/// Update
+pub fn toggle_film_grain(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut post_process_settings: ResMut<PostProcessSettings>,
+    mut stashed_amount: Local<Option<f32>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+
+    if post_process_settings.film_grain_amount > 0.0 {
+        *stashed_amount = Some(post_process_settings.film_grain_amount);
+        post_process_settings.film_grain_amount = 0.0;
+    } else {
+        post_process_settings.film_grain_amount =
+            stashed_amount.take().unwrap_or(DEFAULT_FILM_GRAIN_AMOUNT);
+    }
+    let enabled = post_process_settings.film_grain_amount > 0.0;
+    info!("Film grain {}", if enabled { "on" } else { "off" });
+}
+
 /// Orchestrates the post-processing chain and logs current settings.
 ///
 /// This system:
@@ -348,10 +508,72 @@ pub fn apply_post_process_chain(
     );
 }
 
+/// Eases the dimmer overlay toward `MasterDimmer` and applies it.
+///
+/// The overlay is black with alpha `1 - level`, so standard alpha blending
+/// multiplies everything beneath it (including bloom input) by `level`.
+///
+/// # Stage
+/// PostUpdate
+pub fn update_master_dimmer(
+    master_dimmer: Res<MasterDimmer>,
+    mut overlay_query: Query<(&mut MasterDimmerOverlay, &mut Sprite, &mut Visibility)>,
+    time: Res<Time>,
+) {
+    let target = master_dimmer.0.clamp(0.0, 1.0);
+    let blend = 1.0 - (-MASTER_DIMMER_RATE * time.delta_secs()).exp();
+
+    for (mut overlay, mut sprite, mut visibility) in overlay_query.iter_mut() {
+        let mut level = overlay.applied_level + (target - overlay.applied_level) * blend;
+        if (level - target).abs() < 0.001 {
+            level = target;
+        }
+        overlay.applied_level = level;
+
+        let alpha = master_dimmer_overlay_alpha(level);
+        sprite.color = Color::srgba(0.0, 0.0, 0.0, alpha);
+        *visibility = if alpha > 0.0 {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
 // =============================================================================
 // STARTUP SYSTEMS
 // =============================================================================
 
+/// Spawns the full-screen overlay used by the master dimmer.
+///
+/// # Stage
+/// Startup
+pub fn setup_master_dimmer(mut commands: Commands, master_dimmer: Res<MasterDimmer>) {
+    let level = master_dimmer.0.clamp(0.0, 1.0);
+    let alpha = master_dimmer_overlay_alpha(level);
+
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.0, 0.0, 0.0, alpha),
+            custom_size: Some(Vec2::new(
+                crate::visual::VIEWPORT_WIDTH * 4.0,
+                crate::visual::VIEWPORT_HEIGHT * 4.0,
+            )),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, render_layers::OVERLAY),
+        if alpha > 0.0 {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        },
+        MasterDimmerOverlay {
+            applied_level: level,
+        },
+        Name::new("MasterDimmerOverlay"),
+    ));
+}
+
 /// Sets up the Bloom component on the camera for post-processing.
 ///
 /// This system adds the `Bloom` component to the main camera if it doesn't
@@ -362,7 +584,7 @@ pub fn apply_post_process_chain(
 pub fn setup_bloom(
     mut commands: Commands,
     post_process_settings: Res<PostProcessSettings>,
-    camera_query: Query<Entity, (With<Camera2d>, Without<Bloom>)>,
+    camera_query: Query<Entity, (With<WhirledCamera>, Without<Bloom>)>,
 ) {
     let Ok(camera_entity) = camera_query.get_single() else {
         // Camera may already have bloom or doesn't exist yet
@@ -371,12 +593,14 @@ pub fn setup_bloom(
 
     // Create initial bloom settings
     let bloom = Bloom {
-        intensity: post_process_settings.bloom_intensity.clamp(0.0, MAX_BLOOM_INTENSITY),
-        low_frequency_boost: DEFAULT_BLOOM_LF_BOOST,
+        intensity: post_process_settings
+            .bloom_intensity
+            .clamp(0.0, MAX_BLOOM_INTENSITY),
+        low_frequency_boost: post_process_settings.bloom_low_frequency_boost,
         low_frequency_boost_curvature: DEFAULT_BLOOM_LF_BOOST_CURVATURE,
         high_pass_frequency: DEFAULT_BLOOM_HIGH_PASS_FREQUENCY,
-        // Use default compositing mode for natural-looking bloom
-        composite_mode: bevy::core_pipeline::bloom::BloomCompositeMode::EnergyConserving,
+        prefilter: bloom_prefilter(&post_process_settings),
+        composite_mode: bloom_composite_mode(post_process_settings.bloom_composite),
         ..default()
     };
 
@@ -384,7 +608,7 @@ pub fn setup_bloom(
 
     info!(
         "Bloom component added to camera: intensity={:.2}, low_freq_boost={:.2}",
-        post_process_settings.bloom_intensity, DEFAULT_BLOOM_LF_BOOST
+        post_process_settings.bloom_intensity, post_process_settings.bloom_low_frequency_boost
     );
 }
 
@@ -392,6 +616,24 @@ pub fn setup_bloom(
 // HELPER FUNCTIONS
 // =============================================================================
 
+/// Maps the configured composite mode to Bevy's `BloomCompositeMode`.
+#[must_use]
+pub fn bloom_composite_mode(composite: BloomComposite) -> BloomCompositeMode {
+    match composite {
+        BloomComposite::EnergyConserving => BloomCompositeMode::EnergyConserving,
+        BloomComposite::Additive => BloomCompositeMode::Additive,
+    }
+}
+
+/// Builds Bevy's `BloomPrefilter` from the configured threshold and softness.
+#[must_use]
+pub fn bloom_prefilter(settings: &PostProcessSettings) -> BloomPrefilter {
+    BloomPrefilter {
+        threshold: settings.bloom_prefilter_threshold.max(0.0),
+        threshold_softness: settings.bloom_prefilter_softness.clamp(0.0, 1.0),
+    }
+}
+
 /// Calculates recommended post-process settings for a given act progression.
 ///
 /// This helper function provides act-specific post-processing values:
@@ -473,6 +715,16 @@ pub fn lerp_post_process_values(
     )
 }
 
+/// Returns the black overlay alpha that scales output brightness by `level`.
+///
+/// Alpha blending black over a pixel gives `pixel * (1 - alpha)`, so an
+/// alpha of `1 - level` is a final multiply by `level`.
+#[inline]
+#[must_use]
+pub fn master_dimmer_overlay_alpha(level: f32) -> f32 {
+    1.0 - level.clamp(0.0, 1.0)
+}
+
 // =============================================================================
 // SYSTEM SETS
 // =============================================================================
@@ -493,28 +745,28 @@ pub struct PostProcessStartupSet;
 ///
 /// This plugin handles:
 /// - Bloom effect via Bevy's built-in `Bloom` component
-/// - Chromatic aberration configuration (placeholder for custom shader)
-/// - Vignette effect configuration (placeholder for custom shader)
-/// - Film grain configuration (placeholder for custom shader)
+/// - Focus blur, chromatic aberration, vignette, and film grain via
+///   full-screen render passes (see `ScreenPassPlugin`)
+/// - The `G` key toggling film grain
 ///
 /// # Systems
 ///
 /// ## Startup
 /// - `setup_bloom`: Adds Bloom component to camera
+/// - `setup_master_dimmer`: Spawns the master dimmer overlay
+///
+/// ## Update
+/// - `toggle_film_grain`: Flips `PostProcessSettings.film_grain_amount` with G
 ///
 /// ## PostUpdate (ordered)
 /// - `update_bloom`: Updates Bevy's BloomSettings
 /// - `update_chromatic_aberration`: Updates ChromaticAberrationSettings
+/// - `update_focus_blur`: Updates FocusBlurSettings from the act and cursor
 /// - `update_vignette`: Updates VignetteSettings
 /// - `update_film_grain`: Updates FilmGrainSettings
+/// - `update_master_dimmer`: Eases the master dimmer overlay toward `MasterDimmer`
 /// - `apply_post_process_chain`: Orchestrates and logs post-processing state
 ///
-/// # Note
-/// Full post-processing (chromatic aberration, vignette, film grain) requires
-/// custom render pipelines. This module sets up the configuration resources;
-/// actual shader integration should be done via Bevy's render graph or a
-/// third-party crate like `bevy_post_process`.
-///
 /// # Example
 /// ```ignore
 /// use bevy::prelude::*;
@@ -533,8 +785,15 @@ impl Plugin for PostProcessPlugin {
     fn build(&self, app: &mut App) {
         // Register post-processing resources
         app.init_resource::<ChromaticAberrationSettings>()
+            .init_resource::<FocusBlurSettings>()
             .init_resource::<VignetteSettings>()
-            .init_resource::<FilmGrainSettings>();
+            .init_resource::<FilmGrainSettings>()
+            .init_resource::<MasterDimmer>();
+
+        app.add_plugins(ScreenPassPlugin);
+
+        app.add_systems(Startup, setup_master_dimmer)
+            .add_systems(Update, toggle_film_grain);
 
         // Add startup systems
         // setup_bloom runs after the camera is created (in PostStartup to ensure camera exists)
@@ -548,8 +807,10 @@ impl Plugin for PostProcessPlugin {
             (
                 update_bloom,
                 update_chromatic_aberration,
+                update_focus_blur,
                 update_vignette,
                 update_film_grain,
+                update_master_dimmer,
             )
                 .in_set(PostProcessUpdateSet),
         )
@@ -569,6 +830,7 @@ impl Plugin for PostProcessPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::screen_pass::VignetteUniform;
 
     #[test]
     fn test_chromatic_aberration_settings_default() {
@@ -585,6 +847,108 @@ mod tests {
         assert!(settings.enabled);
     }
 
+    #[test]
+    fn test_vignette_color_reaches_uniforms() {
+        let amber = Color::srgb(0.35, 0.2, 0.05);
+
+        let mut app = App::new();
+        app.insert_resource(PostProcessSettings {
+            vignette_intensity: 0.4,
+            vignette_color: amber,
+            ..Default::default()
+        })
+        .init_resource::<VignetteSettings>()
+        .add_systems(Update, update_vignette);
+        app.update();
+
+        let vignette = app.world().resource::<VignetteSettings>().clone();
+        assert_eq!(vignette.vignette_color, amber);
+
+        let uniform = VignetteUniform::from_settings(&vignette);
+        let linear = amber.to_linear();
+        assert_eq!(
+            uniform.color,
+            Vec3::new(linear.red, linear.green, linear.blue)
+        );
+        assert_eq!(uniform.intensity, 0.4);
+
+        // Default stays black
+        let default_uniform = VignetteUniform::from_settings(&VignetteSettings::default());
+        assert_eq!(default_uniform.color, Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_film_grain_toggle_restores_amount() {
+        let mut app = App::new();
+        app.insert_resource(PostProcessSettings {
+            film_grain_amount: 0.05,
+            ..Default::default()
+        })
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<FilmGrainSettings>()
+        .add_systems(Update, (toggle_film_grain, update_film_grain).chain());
+
+        fn press_g(app: &mut App) {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            keyboard.release(KeyCode::KeyG);
+            keyboard.press(KeyCode::KeyG);
+            app.update();
+        }
+
+        press_g(&mut app);
+        assert_eq!(
+            app.world()
+                .resource::<PostProcessSettings>()
+                .film_grain_amount,
+            0.0
+        );
+        assert!(!app.world().resource::<FilmGrainSettings>().enabled);
+
+        press_g(&mut app);
+        assert_eq!(
+            app.world()
+                .resource::<PostProcessSettings>()
+                .film_grain_amount,
+            0.05
+        );
+        assert!(app.world().resource::<FilmGrainSettings>().enabled);
+    }
+
+    #[test]
+    fn test_focus_blur_tracks_act_strength_and_reduced_effects() {
+        use crate::screen_pass::focus_blur_pass_active;
+
+        let mut app = App::new();
+        app.init_resource::<PostProcessSettings>()
+            .init_resource::<MouseState>()
+            .init_resource::<FocusBlurSettings>()
+            .add_systems(Update, update_focus_blur);
+        app.update();
+        assert!(!focus_blur_pass_active(
+            app.world().resource::<FocusBlurSettings>()
+        ));
+
+        app.world_mut()
+            .resource_mut::<PostProcessSettings>()
+            .focus_blur_strength = 0.6;
+        app.update();
+        let settings = app.world().resource::<FocusBlurSettings>();
+        assert!(focus_blur_pass_active(settings));
+        assert!((settings.strength - 0.6 * MAX_FOCUS_BLUR_RADIUS).abs() < 1e-6);
+
+        app.world_mut()
+            .resource_mut::<PostProcessSettings>()
+            .reduced_effects = true;
+        app.update();
+        let settings = app.world().resource::<FocusBlurSettings>();
+        assert!(!focus_blur_pass_active(settings));
+        assert!(
+            settings.enabled,
+            "reduced effects leave the host's switch alone"
+        );
+    }
+
     #[test]
     fn test_film_grain_settings_default() {
         let settings = FilmGrainSettings::default();
@@ -666,9 +1030,59 @@ mod tests {
 
     #[test]
     fn test_bloom_constants() {
-        assert_eq!(DEFAULT_BLOOM_INTENSITY, 0.3);
+        assert_eq!(_DEFAULT_BLOOM_INTENSITY, 0.3);
         assert_eq!(MAX_BLOOM_INTENSITY, 1.0);
-        assert!(DEFAULT_BLOOM_LF_BOOST > 0.0);
+        assert!(PostProcessSettings::default().bloom_low_frequency_boost > 0.0);
+    }
+
+    #[cfg(feature = "acts")]
+    #[test]
+    fn test_per_act_bloom_character_reaches_bloom_component() {
+        use crate::act_management::{update_post_process_for_act, ActScene};
+        use crate::resources::{ActState, ColorInterpolation};
+        use crate::types::Act;
+
+        let bloom_for = |act: Act| {
+            let mut app = App::new();
+            app.insert_resource(ActState {
+                current_act: act,
+                ..Default::default()
+            })
+            .init_resource::<ActScene>()
+            .init_resource::<PostProcessSettings>()
+            .init_resource::<ColorInterpolation>()
+            .init_resource::<Quietude>()
+            .add_systems(Update, (update_post_process_for_act, update_bloom).chain());
+            let camera = app
+                .world_mut()
+                .spawn((WhirledCamera, Bloom::default()))
+                .id();
+            app.update();
+            app.world().get::<Bloom>(camera).unwrap().clone()
+        };
+
+        let crescendo = bloom_for(Act::Crescendo);
+        let transcendence = bloom_for(Act::Transcendence);
+
+        // Crescendo: punchy additive bloom on bright peas only
+        assert_eq!(crescendo.composite_mode, BloomCompositeMode::Additive);
+        assert!(crescendo.prefilter.threshold > 0.0);
+
+        // Transcendence: soft, diffuse, energy-conserving glow
+        assert_eq!(
+            transcendence.composite_mode,
+            BloomCompositeMode::EnergyConserving
+        );
+        assert_eq!(transcendence.prefilter.threshold, 0.0);
+        assert!(transcendence.low_frequency_boost > crescendo.low_frequency_boost);
+
+        // Unconfigured settings keep the original energy-conserving character
+        let defaults = PostProcessSettings::default();
+        assert_eq!(
+            bloom_composite_mode(defaults.bloom_composite),
+            BloomCompositeMode::EnergyConserving
+        );
+        assert_eq!(bloom_prefilter(&defaults).threshold, 0.0);
     }
 
     #[test]
@@ -679,6 +1093,71 @@ mod tests {
         assert!(MAX_VIGNETTE_INTENSITY <= 1.0);
     }
 
+    #[test]
+    fn test_master_dimmer_default_is_full_output() {
+        let dimmer = MasterDimmer::default();
+        assert_eq!(dimmer.0, 1.0);
+        assert_eq!(master_dimmer_overlay_alpha(dimmer.0), 0.0);
+    }
+
+    #[test]
+    fn test_master_dimmer_half_halves_brightness() {
+        let alpha = master_dimmer_overlay_alpha(0.5);
+
+        // Black composited over a pixel at this alpha scales it by (1 - alpha)
+        let brightness = 0.8;
+        let output = brightness * (1.0 - alpha);
+        assert!((output - brightness * 0.5).abs() < 0.001);
+
+        // Zero is full black; out-of-range values are clamped
+        assert_eq!(master_dimmer_overlay_alpha(0.0), 1.0);
+        assert_eq!(master_dimmer_overlay_alpha(1.5), 0.0);
+    }
+
+    #[test]
+    fn test_master_dimmer_overlay_eases_to_target() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<MasterDimmer>()
+            .add_systems(Startup, setup_master_dimmer)
+            .add_systems(PostUpdate, update_master_dimmer);
+
+        let step = |app: &mut App, frames: u32| {
+            for _ in 0..frames {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(50));
+                app.update();
+            }
+            let mut query = app
+                .world_mut()
+                .query::<(&MasterDimmerOverlay, &Sprite, &Visibility, &Transform)>();
+            let (overlay, sprite, visibility, transform) = query.single(app.world());
+            assert_eq!(transform.translation.z, render_layers::OVERLAY);
+            (overlay.applied_level, sprite.color.alpha(), *visibility)
+        };
+
+        // Full output draws nothing over the scene
+        assert_eq!(step(&mut app, 1), (1.0, 0.0, Visibility::Hidden));
+
+        // Halving the dimmer eases the overlay in rather than snapping
+        app.world_mut().resource_mut::<MasterDimmer>().0 = 0.5;
+        let (level, alpha, _) = step(&mut app, 1);
+        assert!(level > 0.5 && level < 1.0, "level {level} should ease");
+        assert!(alpha > 0.0 && alpha < 0.5);
+
+        let (level, alpha, visibility) = step(&mut app, 40);
+        assert_eq!(level, 0.5);
+        assert!((alpha - 0.5).abs() < 1e-4);
+        assert_eq!(visibility, Visibility::Visible);
+
+        // Back to full output hides the overlay again
+        app.world_mut().resource_mut::<MasterDimmer>().0 = 1.0;
+        assert_eq!(step(&mut app, 40), (1.0, 0.0, Visibility::Hidden));
+    }
+
     #[test]
     fn test_vignette_progression_decreases() {
         // Vignette should decrease from Act I to Act V
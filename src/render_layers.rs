@@ -0,0 +1,138 @@
+//! Module: render_layers
+//! Purpose: Central z-depth bands for every sprite the experience spawns
+//! Dependencies: none (tests drive heatmap, interaction, and trail spawners)
+//!
+//! All 2D content shares one camera, so draw order is decided purely by
+//! `Transform.translation.z`. Every spawning path takes its depth from here
+//! so new effects slot into a known band instead of picking ad-hoc values.
+//!
+//! Back to front:
+//!
+//! | Band                 | z      | Contents                               |
+//! |----------------------|--------|----------------------------------------|
+//! | `BACKGROUND`         | -100   | Gradient background sprites            |
+//! | `BACKGROUND_GLOW`    | -99    | Interaction heatmap glow               |
+//! | `TRAILS`             | -2     | Trail ribbons                          |
+//! | `BREADCRUMBS`        | -1     | Breadcrumb trail markers               |
+//! | `PARTICLES`          | 0      | Pea particles                          |
+//! | `FOREGROUND_EFFECTS` | 10     | Magnet poles                           |
+//! | `OVERLAY`            | 500    | Full-screen overlays (master dimmer)   |
+
+// =============================================================================
+// Z-DEPTH BANDS
+// =============================================================================
+
+/// Gradient background, behind everything.
+pub const BACKGROUND: f32 = -100.0;
+
+/// Faint glows painted onto the background.
+pub const BACKGROUND_GLOW: f32 = -99.0;
+
+/// Trail ribbons, behind the particles that leave them.
+pub const TRAILS: f32 = -2.0;
+
+/// Breadcrumb markers, just behind the particles.
+pub const BREADCRUMBS: f32 = -1.0;
+
+/// Pea particles.
+pub const PARTICLES: f32 = 0.0;
+
+/// Foreground effects such as magnet poles.
+pub const FOREGROUND_EFFECTS: f32 = 10.0;
+
+/// Full-screen overlays, in front of all scene content.
+pub const OVERLAY: f32 = 500.0;
+
+/// All bands from back to front.
+pub const LAYER_ORDER: [f32; 7] = [
+    BACKGROUND,
+    BACKGROUND_GLOW,
+    TRAILS,
+    BREADCRUMBS,
+    PARTICLES,
+    FOREGROUND_EFFECTS,
+    OVERLAY,
+];
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{
+        BreadcrumbMarker, MagnetPole, OrphanTrail, Trail, TrailRibbon, TrailSegment,
+    };
+    use crate::heatmap::{
+        render_interaction_heatmap, HeatmapGlow, InteractionHeatmap, InteractionHeatmapConfig,
+    };
+    use crate::interaction::sync_magnet_poles;
+    use crate::resources::MagnetToy;
+    use crate::trail::{
+        render_trails, setup_breadcrumb_pool, BreadcrumbPool, TrailMetrics, TrailRibbonMaterial,
+    };
+    use bevy::prelude::*;
+
+    /// Returns the draw depth of the single entity carrying `C`.
+    fn depth_of<C: Component>(app: &mut App) -> f32 {
+        let mut query = app.world_mut().query_filtered::<&Transform, With<C>>();
+        query.iter(app.world()).next().unwrap().translation.z
+    }
+
+    #[test]
+    fn test_spawned_content_draws_back_to_front() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<TrailMetrics>()
+            .init_resource::<BreadcrumbPool>()
+            .init_resource::<InteractionHeatmap>()
+            .insert_resource(InteractionHeatmapConfig {
+                render_glow: true,
+                ..Default::default()
+            })
+            .insert_resource(MagnetToy {
+                active: true,
+                ..Default::default()
+            })
+            .insert_resource(TrailRibbonMaterial(Handle::default()))
+            .add_systems(Startup, setup_breadcrumb_pool)
+            .add_systems(
+                Update,
+                (render_interaction_heatmap, render_trails, sync_magnet_poles),
+            );
+
+        let mut trail = Trail::default();
+        for i in 0..3 {
+            trail.push_segment(TrailSegment {
+                position: Vec2::new(i as f32 * 10.0, 0.0),
+                opacity: 1.0,
+                ..default()
+            });
+        }
+        app.world_mut().spawn((
+            OrphanTrail {
+                active: true,
+                ..default()
+            },
+            trail,
+        ));
+        app.update();
+
+        // Each spawning path lands in its band, so sprites sort in this order
+        let drawn = [
+            depth_of::<HeatmapGlow>(&mut app),
+            depth_of::<TrailRibbon>(&mut app),
+            depth_of::<BreadcrumbMarker>(&mut app),
+            depth_of::<MagnetPole>(&mut app),
+        ];
+        assert_eq!(
+            drawn,
+            [BACKGROUND_GLOW, TRAILS, BREADCRUMBS, FOREGROUND_EFFECTS]
+        );
+        assert!(drawn.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(LAYER_ORDER.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -2,12 +2,27 @@
 //! Purpose: Global ECS resources for Chromatic Elegy application state
 //! Dependencies: types, bevy::prelude
 
+use std::collections::VecDeque;
+
+use bevy::color::{Hsla, Hue};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
+use crate::trail::{TRAIL_FADE_DURATION_MS, TRAIL_SEGMENTS};
 use crate::types::{
-    Act, BeatStrength, FalloffType, InteractionMode, ParticleBehaviorType, SpawnSource,
+    ease_in_out_cubic, Act, BeatStrength, BehaviorCoefficients, BloomComposite, ColorLerpSpace,
+    ExperienceLength, FadeProfile, FalloffType, GradientStyle, InteractionMode, PaletteScheme,
+    ParticleBehaviorType, SpawnSource, ACT_BOUNDARIES_SECONDS,
 };
 
+/// Golden angle in degrees, used to spread generated accent hues.
+const GOLDEN_ANGLE_DEGREES: f32 = 137.507_77;
+
+/// Wraps a hue in degrees into [0, 360).
+fn wrap_hue(hue: f32) -> f32 {
+    hue.rem_euclid(360.0)
+}
+
 // =============================================================================
 // APPLICATION STATE RESOURCES
 // =============================================================================
@@ -28,6 +43,10 @@ pub struct ActState {
     pub is_transitioning: bool,
     /// Progress of act transition (0.0 - 1.0)
     pub transition_progress: f32,
+    /// Number of full passes through the experience completed so far
+    pub completed_passes: u32,
+    /// Act the current transition started from, if known
+    pub transition_from: Option<Act>,
 }
 
 impl Default for ActState {
@@ -38,10 +57,40 @@ impl Default for ActState {
             total_elapsed_seconds: 0.0,
             is_transitioning: false,
             transition_progress: 0.0,
+            completed_passes: 0,
+            transition_from: None,
         }
     }
 }
 
+impl ActState {
+    /// Returns the act the current transition blends from.
+    ///
+    /// Falls back to the preceding act when the source was not recorded.
+    #[must_use]
+    pub fn transition_source(&self) -> Act {
+        self.transition_from
+            .unwrap_or_else(|| self.current_act.previous().unwrap_or(self.current_act))
+    }
+}
+
+/// Whether the whole experience is frozen.
+///
+/// While paused the timeline, particle motion and lifetimes, pea rotation
+/// and streaks, trails, pointer forces, and audio-driven spawning stop; the
+/// camera and rendering stay live so the frame is held. Flipped by
+/// `TogglePause` (the P key).
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExperiencePaused(pub bool);
+
+/// Longest frame delta the timeline accepts on the first frame after a resume.
+pub const MAX_RESUME_DELTA_SECS: f32 = 1.0 / 30.0;
+
+/// Condition function for run_if: returns true while the experience is not paused.
+pub fn experience_running(paused: Res<ExperiencePaused>) -> bool {
+    !paused.0
+}
+
 /// Defines timing boundaries for each act in seconds.
 ///
 /// Act I (Emergence): 0-3 minutes
@@ -49,12 +98,17 @@ impl Default for ActState {
 /// Act III (Crescendo): 7-10 minutes
 /// Act IV (Release): 10-13 minutes
 /// Act V (Transcendence): 13-15 minutes
+///
+/// Those are the `ExperienceLength::Full` boundaries; `set_length` rescales
+/// them proportionally for the other presets.
 #[derive(Resource, Debug, Clone)]
 pub struct ActTimings {
     /// Boundary timestamps in seconds: [start, act2, act3, act4, act5, end]
     pub act_boundaries_seconds: [f32; 6],
     /// Duration of smooth transitions between acts in milliseconds
     pub transition_duration_ms: f32,
+    /// Length preset the boundaries were last scaled to
+    pub length: ExperienceLength,
 }
 
 impl Default for ActTimings {
@@ -63,10 +117,68 @@ impl Default for ActTimings {
             // [0, 180, 420, 600, 780, 900] = [0, 3min, 7min, 10min, 13min, 15min]
             act_boundaries_seconds: [0.0, 180.0, 420.0, 600.0, 780.0, 900.0],
             transition_duration_ms: 2000.0,
+            length: ExperienceLength::Full,
         }
     }
 }
 
+impl ActTimings {
+    /// Returns timings scaled to `length`.
+    #[must_use]
+    pub fn for_length(length: ExperienceLength) -> Self {
+        let mut timings = Self::default();
+        timings.set_length(length);
+        timings
+    }
+
+    /// Returns timings for a pass of `total_seconds`, rescaling every act
+    /// boundary proportionally (e.g. `scaled(120.0)` for a 2-minute demo).
+    ///
+    /// The total is validated like `ExperienceLength::Custom`.
+    #[must_use]
+    pub fn scaled(total_seconds: f32) -> Self {
+        Self::for_length(ExperienceLength::Custom(total_seconds))
+    }
+
+    /// Rescales every act boundary to `length`, keeping the full arc's proportions.
+    ///
+    /// Transitions keep their duration but never exceed a quarter of the
+    /// shortest act.
+    pub fn set_length(&mut self, length: ExperienceLength) {
+        let full = ACT_BOUNDARIES_SECONDS[5];
+        let total = length.total_seconds();
+        // Divide first so the final boundary lands exactly on `total`
+        self.act_boundaries_seconds =
+            ACT_BOUNDARIES_SECONDS.map(|boundary| boundary / full * total);
+        self.length = length;
+
+        let shortest_act_ms = self
+            .act_boundaries_seconds
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]) * 1000.0)
+            .fold(f32::INFINITY, f32::min);
+        self.transition_duration_ms = self.transition_duration_ms.min(shortest_act_ms * 0.25);
+    }
+
+    /// Returns the total length of one pass in seconds.
+    #[must_use]
+    pub fn total_seconds(&self) -> f32 {
+        self.act_boundaries_seconds[5]
+    }
+
+    /// Returns the act at `elapsed` seconds into the pass.
+    #[must_use]
+    pub fn act_at(&self, elapsed: f32) -> Act {
+        Act::from_elapsed_seconds_with(&self.act_boundaries_seconds, elapsed)
+    }
+
+    /// Returns overall progress (0.0 to 1.0) through one pass.
+    #[must_use]
+    pub fn progress(&self, elapsed: f32) -> f32 {
+        (elapsed / self.total_seconds().max(f32::EPSILON)).clamp(0.0, 1.0)
+    }
+}
+
 /// Interpolated values derived from current act state for smooth transitions.
 ///
 /// These values are recalculated each frame during act transitions to provide
@@ -77,14 +189,22 @@ pub struct InterpolatedActValues {
     pub background_color_start: Color,
     /// Ending color of current background gradient
     pub background_color_end: Color,
-    /// Current particle behavior mode
+    /// Current particle behavior mode (nearest act during transitions)
     pub particle_behavior: ParticleBehaviorType,
+    /// Other act's behavior still mixed in during a transition and its weight
+    pub behavior_blend_target: Option<(ParticleBehaviorType, f32)>,
+    /// Force-kernel weights blended across the current transition
+    pub behavior_coefficients: BehaviorCoefficients,
     /// Current interaction mode
     pub interaction_mode: InteractionMode,
     /// Saturation multiplier for particle colors
     pub saturation_multiplier: f32,
     /// Target particle density for current act
     pub density_target: f32,
+    /// Multiplier applied to spawn lifetimes for the current act
+    pub lifetime_multiplier: f32,
+    /// Speed cap and force scaling blended across the current transition
+    pub physics: PhysicsProfile,
 }
 
 impl Default for InterpolatedActValues {
@@ -94,10 +214,209 @@ impl Default for InterpolatedActValues {
             background_color_start: Color::srgb(0.102, 0.102, 0.180),
             background_color_end: Color::srgb(0.051, 0.051, 0.102),
             particle_behavior: ParticleBehaviorType::Drift,
+            behavior_blend_target: None,
+            behavior_coefficients: ParticleBehaviorType::Drift.coefficients(),
             interaction_mode: InteractionMode::Paint,
             saturation_multiplier: 1.0,
-            density_target: 0.3,
+            density_target: 200.0,
+            lifetime_multiplier: 1.0,
+            physics: PhysicsProfile::default(),
+        }
+    }
+}
+
+impl InterpolatedActValues {
+    /// Kernel weights and blend target to hand each particle.
+    ///
+    /// While a transition crossfades two behaviors, particles carry the
+    /// nearest act's weights plus the other behavior as their blend target.
+    #[must_use]
+    pub fn particle_blend(&self) -> (BehaviorCoefficients, Option<(ParticleBehaviorType, f32)>) {
+        match self.behavior_blend_target {
+            Some(target) => (self.particle_behavior.coefficients(), Some(target)),
+            None => (self.behavior_coefficients, None),
+        }
+    }
+
+    /// How much of the current blend is one of `behaviors` (0.0 to 1.0).
+    #[must_use]
+    pub fn behavior_weight(&self, behaviors: &[ParticleBehaviorType]) -> f32 {
+        let share = |behavior| {
+            if behaviors.contains(&behavior) {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        match self.behavior_blend_target {
+            Some((other, weight)) => {
+                share(self.particle_behavior) * (1.0 - weight) + share(other) * weight
+            }
+            None => share(self.particle_behavior),
+        }
+    }
+}
+
+/// Global calm/intensity meta-parameter shared by every intensity-driven system.
+///
+/// `value` runs from 0.0 (calm) to 1.0 (peak) and is recomputed each frame by
+/// `update_intensity` from three inputs:
+///
+/// ```text
+/// lift  = clamp(audio_weight * audio_energy + interaction_weight * interaction, 0, 1)
+/// value = act_baseline + (1 - act_baseline) * lift
+/// ```
+///
+/// The act sets the floor and audio energy and interaction lift it toward 1.0,
+/// so a silent, untouched scene sits exactly on the act's baseline. Setting
+/// `external_override` replaces the blend; `OscInputPlugin` sets it from
+/// `/intensity` messages while OSC is live.
+///
+/// Computed by `ParticlePlugin`, so it works without the `audio` feature.
+/// Read by audio generation, turbulence, bloom pumping, trails, and the spawn rate.
+#[derive(Resource, Debug, Clone)]
+pub struct Intensity {
+    /// Current intensity (0.0 = calm, 1.0 = peak)
+    pub value: f32,
+    /// Value pushed by an external controller, used instead of the blend
+    pub external_override: Option<f32>,
+    /// How strongly audio energy lifts intensity above the act baseline
+    pub audio_weight: f32,
+    /// How strongly pointer activity lifts intensity above the act baseline
+    pub interaction_weight: f32,
+}
+
+impl Default for Intensity {
+    fn default() -> Self {
+        Self {
+            value: Self::act_baseline(Act::Emergence),
+            external_override: None,
+            audio_weight: 0.3,
+            interaction_weight: 0.2,
+        }
+    }
+}
+
+impl Intensity {
+    /// Returns an intensity resting on `act`'s baseline.
+    #[must_use]
+    pub fn for_act(act: Act) -> Self {
+        Self {
+            value: Self::act_baseline(act),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the baseline intensity of an act.
+    ///
+    /// - Emergence: Very low (0.3) - sparse, quiet
+    /// - Accumulation: Medium (0.6) - building
+    /// - Crescendo: Maximum (1.0) - peak intensity
+    /// - Release: Medium-high (0.7) - diminishing
+    /// - Transcendence: Low (0.4) - peaceful dissolution
+    #[must_use]
+    pub fn act_baseline(act: Act) -> f32 {
+        match act {
+            Act::Emergence => 0.3,
+            Act::Accumulation => 0.6,
+            Act::Crescendo => 1.0,
+            Act::Release => 0.7,
+            Act::Transcendence => 0.4,
+        }
+    }
+
+    /// Blends act, audio energy, and interaction (each 0.0 to 1.0) into an intensity.
+    ///
+    /// Ignores `external_override`; see the type docs for the formula.
+    #[must_use]
+    pub fn blend(&self, act: Act, audio_energy: f32, interaction: f32) -> f32 {
+        let baseline = Self::act_baseline(act);
+        let lift = (self.audio_weight * audio_energy.clamp(0.0, 1.0)
+            + self.interaction_weight * interaction.clamp(0.0, 1.0))
+        .clamp(0.0, 1.0);
+        baseline + (1.0 - baseline) * lift
+    }
+
+    /// Returns how far `value` sits above `act`'s baseline toward 1.0 (0.0 to 1.0).
+    ///
+    /// Always 0.0 in an act whose baseline is already the peak.
+    #[must_use]
+    pub fn lift(&self, act: Act) -> f32 {
+        let baseline = Self::act_baseline(act);
+        if baseline >= 1.0 {
+            return 0.0;
+        }
+        ((self.value - baseline) / (1.0 - baseline)).clamp(0.0, 1.0)
+    }
+
+    /// Maps the current intensity linearly between a calm and a peak value.
+    #[must_use]
+    pub fn gain(&self, calm: f32, peak: f32) -> f32 {
+        calm + (peak - calm) * self.value.clamp(0.0, 1.0)
+    }
+}
+
+/// How far sustained audio quiet has calmed the field.
+///
+/// `value` runs from 0.0 (normal) to 1.0 (fully calmed). It rises over
+/// `settle_secs` while `AudioAnalysis.amplitude_peak` stays below `threshold`
+/// and falls back over `recover_secs` once sound returns, so a brief gap
+/// between notes barely registers. Turbulence, spawn rate, and bloom are
+/// each scaled from 1.0 down to their floor as `value` rises.
+#[derive(Resource, Debug, Clone)]
+pub struct Quietude {
+    /// Current quietude (0.0 = normal, 1.0 = fully calmed)
+    pub value: f32,
+    /// Whether quiet passages calm the field
+    pub enabled: bool,
+    /// Peak amplitude below which audio counts as quiet
+    pub threshold: f32,
+    /// Seconds of sustained quiet to reach full quietude
+    pub settle_secs: f32,
+    /// Seconds for full quietude to fade once sound returns
+    pub recover_secs: f32,
+    /// Turbulence multiplier at full quietude
+    pub turbulence_floor: f32,
+    /// Spawn rate multiplier at full quietude
+    pub spawn_floor: f32,
+    /// Bloom intensity multiplier at full quietude
+    pub bloom_floor: f32,
+}
+
+impl Default for Quietude {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            enabled: true,
+            threshold: 0.05,
+            settle_secs: 4.0,
+            recover_secs: 0.5,
+            turbulence_floor: 0.25,
+            spawn_floor: 0.4,
+            bloom_floor: 0.6,
+        }
+    }
+}
+
+impl Quietude {
+    /// Returns the quietude after `delta_secs` more of the given peak amplitude.
+    #[must_use]
+    pub fn advance(&self, amplitude_peak: f32, delta_secs: f32) -> f32 {
+        if !self.enabled {
+            return 0.0;
         }
+        let step = if amplitude_peak < self.threshold {
+            delta_secs / self.settle_secs.max(f32::EPSILON)
+        } else {
+            -delta_secs / self.recover_secs.max(f32::EPSILON)
+        };
+        (self.value + step).clamp(0.0, 1.0)
+    }
+
+    /// Scales linearly from 1.0 down to `floor` as quietude rises.
+    #[must_use]
+    pub fn scale(&self, floor: f32) -> f32 {
+        1.0 - (1.0 - floor) * self.value.clamp(0.0, 1.0)
     }
 }
 
@@ -109,7 +428,7 @@ impl Default for InterpolatedActValues {
 ///
 /// Colors progress from dark, muted tones in early acts to bright, luminous
 /// hues in later acts, representing an emotional journey through grief to transcendence.
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, PartialEq)]
 pub struct ColorPalette {
     /// Deep navy blue - initial emergence from void (#1a1a2e)
     pub primary_initial: Color,
@@ -152,10 +471,131 @@ impl Default for ColorPalette {
     }
 }
 
+impl ColorPalette {
+    /// Names accepted by `preset`, in the order the C key cycles through them.
+    pub const PRESET_NAMES: [&'static str; 4] = ["Default", "Aurora", "Ember", "Monochrome"];
+
+    /// Returns the built-in palette called `name` (case-insensitive).
+    ///
+    /// - Default: the original grief-to-transcendence arc
+    /// - Aurora: night blue into drifting greens and violet
+    /// - Ember: charcoal into fire, glowing orange and gold
+    /// - Monochrome: charcoal to white, no hue at all
+    #[must_use]
+    pub fn preset(name: &str) -> Option<Self> {
+        let hex = match name.to_ascii_lowercase().as_str() {
+            "default" => return Some(Self::default()),
+            "aurora" => [
+                "#0b1a2e", "#1fbf8f", "#e6fff7", "#1c2b3a", "#7ad9c2", "#d6f5ee", "#5ef2a9",
+                "#7b5cff", "#c9f7ff",
+            ],
+            "ember" => [
+                "#1c0f0a", "#d2481a", "#fff1dc", "#2e211c", "#f29e4c", "#f7d9b9", "#ff7a2e",
+                "#8c1c13", "#ffd36b",
+            ],
+            "monochrome" => [
+                "#141414", "#7a7a7a", "#f5f5f5", "#2a2a2a", "#a6a6a6", "#dcdcdc", "#ffffff",
+                "#505050", "#e8e8e8",
+            ],
+            _ => return None,
+        };
+        Some(Self::from_hex(hex))
+    }
+
+    /// Builds a palette from nine hex strings, parsed with `hex_to_color`.
+    ///
+    /// Order matches the fields: primary initial, midpoint, final; secondary
+    /// cool, warm, ethereal; accent spark, deep, hope. Malformed entries log a
+    /// warning and fall back to white.
+    #[must_use]
+    pub fn from_hex(hex: [&str; 9]) -> Self {
+        let [initial, midpoint, last, cool, warm, ethereal, spark, deep, hope] =
+            hex.map(crate::visual::hex_to_color);
+        Self {
+            primary_initial: initial,
+            primary_midpoint: midpoint,
+            primary_final: last,
+            secondary_cool: cool,
+            secondary_warm: warm,
+            secondary_ethereal: ethereal,
+            accent_spark: spark,
+            accent_deep: deep,
+            accent_hope: hope,
+        }
+    }
+
+    /// Returns every color in field order (see `from_hex`).
+    #[must_use]
+    pub fn colors(&self) -> [Color; 9] {
+        [
+            self.primary_initial,
+            self.primary_midpoint,
+            self.primary_final,
+            self.secondary_cool,
+            self.secondary_warm,
+            self.secondary_ethereal,
+            self.accent_spark,
+            self.accent_deep,
+            self.accent_hope,
+        ]
+    }
+
+    /// Procedurally generates a palette from a seed and hue scheme.
+    ///
+    /// Keeps the default palette's structure - a dark initial primary, a
+    /// saturated midpoint, and a luminous final tone - so contrast across the
+    /// arc is preserved while the hues change per seed.
+    ///
+    /// # Arguments
+    /// * `seed` - Same seed always yields the same palette
+    /// * `scheme` - Hue relationship between the primary colors
+    #[must_use]
+    pub fn generate(seed: u64, scheme: PaletteScheme) -> Self {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let base_hue = rng.f32() * 360.0;
+        let [h0, h1, h2] = scheme
+            .hue_offsets()
+            .map(|offset| wrap_hue(base_hue + offset));
+        let accent = |k: f32| wrap_hue(base_hue + k * GOLDEN_ANGLE_DEGREES);
+
+        Self {
+            primary_initial: Color::hsl(h0, 0.35, 0.14),
+            primary_midpoint: Color::hsl(h1, 0.75, 0.45),
+            primary_final: Color::hsl(h2, 0.6, 0.96),
+
+            secondary_cool: Color::hsl(h0, 0.1, 0.2),
+            secondary_warm: Color::hsl(h1, 0.5, 0.65),
+            secondary_ethereal: Color::hsl(h2, 0.45, 0.86),
+
+            accent_spark: Color::hsl(accent(1.0), 1.0, 0.7),
+            accent_deep: Color::hsl(accent(2.0), 0.7, 0.63),
+            accent_hope: Color::hsl(accent(3.0), 0.95, 0.82),
+        }
+    }
+
+    /// Returns the palette color that represents an act in host UIs.
+    ///
+    /// - Emergence: `accent_deep` - violet light out of the void
+    /// - Accumulation: `secondary_warm` - gathering warmth
+    /// - Crescendo: `primary_midpoint` - crimson peak
+    /// - Release: `accent_spark` - coral scattering
+    /// - Transcendence: `primary_final` - luminous cream
+    #[must_use]
+    pub fn theme_color(&self, act: Act) -> Color {
+        match act {
+            Act::Emergence => self.accent_deep,
+            Act::Accumulation => self.secondary_warm,
+            Act::Crescendo => self.primary_midpoint,
+            Act::Release => self.accent_spark,
+            Act::Transcendence => self.primary_final,
+        }
+    }
+}
+
 /// Pre-computed background gradient pairs for each act.
 ///
 /// Each act has a distinct gradient representing its emotional character.
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, PartialEq)]
 pub struct BackgroundGradients {
     /// Gradient color pairs for each of the five acts: [[start, end]; 5]
     pub act_gradients: [[Color; 2]; 5],
@@ -195,6 +635,32 @@ impl Default for BackgroundGradients {
     }
 }
 
+impl BackgroundGradients {
+    /// Generates act gradients matching `ColorPalette::generate` for the same seed.
+    ///
+    /// Six stops run from a near-black void to a luminous final tone, drifting
+    /// through the scheme's hues; each act blends between consecutive stops.
+    #[must_use]
+    pub fn generate(seed: u64, scheme: PaletteScheme) -> Self {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let base_hue = rng.f32() * 360.0;
+        let [h0, h1, h2] = scheme
+            .hue_offsets()
+            .map(|offset| wrap_hue(base_hue + offset));
+
+        let stop_hues = [h0, h0, h1, h1, h2, h2];
+        let stop_saturation = [0.3, 0.3, 0.3, 0.5, 0.4, 0.45];
+        let stop_lightness = [0.07, 0.13, 0.18, 0.28, 0.42, 0.88];
+        let stops: [Color; 6] = std::array::from_fn(|i| {
+            Color::hsl(stop_hues[i], stop_saturation[i], stop_lightness[i])
+        });
+
+        Self {
+            act_gradients: std::array::from_fn(|act| [stops[act], stops[act + 1]]),
+        }
+    }
+}
+
 /// Current background state with animated pulse effect.
 ///
 /// Updated each frame based on act interpolation and audio input.
@@ -204,6 +670,8 @@ pub struct CurrentBackground {
     pub gradient_start: Color,
     /// Current gradient end color
     pub gradient_end: Color,
+    /// Direction of the gradient, switched at the transition midpoint
+    pub style: GradientStyle,
     /// Intensity of audio-driven pulse effect (0.0 - 1.0)
     pub pulse_intensity: f32,
 }
@@ -213,11 +681,99 @@ impl Default for CurrentBackground {
         Self {
             gradient_start: Color::srgb(0.051, 0.051, 0.090),
             gradient_end: Color::srgb(0.102, 0.102, 0.180),
+            style: GradientStyle::Vertical,
             pulse_intensity: 0.0,
         }
     }
 }
 
+/// Chooses between the fixed default palette and a seeded generated one.
+///
+/// Applied at startup by `apply_palette_config`, which replaces
+/// `ColorPalette` and the act scene's background gradients when `generated`
+/// is set.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PaletteConfig {
+    /// Whether to generate the palette from `seed` instead of using the default
+    pub generated: bool,
+    /// Seed for palette generation; each seed gives a unique but coherent look
+    pub seed: u64,
+    /// Hue relationship used for generation
+    pub scheme: PaletteScheme,
+}
+
+/// Window scale information for DPI-aware rendering.
+///
+/// World units come from the camera's fixed 1080-unit vertical projection, so
+/// particle sizes and interaction radii cover the same fraction of the window
+/// on a 1x laptop and a 2x retina display. This resource records how many
+/// physical pixels back each world unit so sprite sizes can snap to whole
+/// physical pixels, and the visible world area for viewport-relative layout.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DisplayScale {
+    /// Window scale factor (physical pixels per logical pixel)
+    pub scale_factor: f32,
+    /// Physical pixels covered by one world unit at the camera's base zoom
+    pub physical_pixels_per_unit: f32,
+    /// Visible world area (width follows the window aspect ratio)
+    pub world_viewport: Vec2,
+    /// Orthographic projection scale of the crate's camera (2.0 shows twice the world)
+    pub camera_scale: f32,
+}
+
+impl DisplayScale {
+    /// Builds the display scale for a window of the given logical size.
+    ///
+    /// # Arguments
+    /// * `scale_factor` - The window's scale factor
+    /// * `logical_size` - Window size in logical pixels
+    /// * `viewport_height` - World units spanned vertically by the camera
+    #[must_use]
+    pub fn from_window(scale_factor: f32, logical_size: Vec2, viewport_height: f32) -> Self {
+        if viewport_height <= 0.0 || logical_size.y <= 0.0 {
+            return Self {
+                scale_factor,
+                physical_pixels_per_unit: scale_factor,
+                ..Default::default()
+            };
+        }
+
+        let aspect = logical_size.x / logical_size.y;
+        Self {
+            scale_factor,
+            physical_pixels_per_unit: scale_factor * logical_size.y / viewport_height,
+            world_viewport: Vec2::new(viewport_height * aspect, viewport_height),
+            camera_scale: 1.0,
+        }
+    }
+
+    /// Physical pixels covered by one world unit at the current camera zoom.
+    #[must_use]
+    pub fn screen_pixels_per_unit(&self) -> f32 {
+        self.physical_pixels_per_unit / self.camera_scale.max(f32::EPSILON)
+    }
+}
+
+impl Default for DisplayScale {
+    fn default() -> Self {
+        Self {
+            scale_factor: 1.0,
+            physical_pixels_per_unit: 1.0,
+            world_viewport: Vec2::new(1920.0, 1080.0),
+            camera_scale: 1.0,
+        }
+    }
+}
+
+/// Configures the color space used by the crate's color interpolation.
+///
+/// Affects act background transitions and particle color blends.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ColorInterpolation {
+    /// Space that colors are converted into before blending
+    pub space: ColorLerpSpace,
+}
+
 // =============================================================================
 // AUDIO RESOURCES
 // =============================================================================
@@ -225,7 +781,8 @@ impl Default for CurrentBackground {
 /// Real-time audio analysis data from FFT processing.
 ///
 /// Updated each frame with current audio levels across frequency bands.
-/// Beat detection uses amplitude history to identify rhythmic events.
+/// Beat detection runs spectral-flux onset detection over a short history of
+/// band energies (see `detect_onset`).
 #[derive(Resource, Debug, Clone)]
 pub struct AudioAnalysis {
     /// Low frequency amplitude (bass presence)
@@ -248,6 +805,14 @@ pub struct AudioAnalysis {
     pub beat_detected: bool,
     /// Strength classification of detected beat
     pub beat_strength: BeatStrength,
+    /// Recent per-band energies `[bass, mid, high, shimmer]`, oldest first
+    pub band_energy_history: VecDeque<[f32; 4]>,
+    /// Recent spectral flux values, oldest first, for the adaptive threshold
+    pub flux_history: VecDeque<f32>,
+    /// Spectral flux of the latest frame
+    pub onset_flux: f32,
+    /// Seconds since the last detected onset
+    pub seconds_since_onset: f32,
 }
 
 impl Default for AudioAnalysis {
@@ -263,7 +828,75 @@ impl Default for AudioAnalysis {
             frequency_shimmer: 0.0,
             beat_detected: false,
             beat_strength: BeatStrength::Silence,
+            band_energy_history: VecDeque::new(),
+            flux_history: VecDeque::new(),
+            onset_flux: 0.0,
+            seconds_since_onset: f32::INFINITY,
+        }
+    }
+}
+
+impl AudioAnalysis {
+    /// Returns the current band energies as `[bass, mid, high, shimmer]`.
+    #[must_use]
+    pub fn band_energies(&self) -> [f32; 4] {
+        [
+            self.frequency_bass,
+            self.frequency_mid,
+            self.frequency_high,
+            self.frequency_shimmer,
+        ]
+    }
+
+    /// Feeds this frame's band energies to the spectral-flux onset detector.
+    ///
+    /// Flux is the summed positive change in band energy since the previous
+    /// frame. An onset fires when it exceeds the moving mean plus
+    /// `onset_threshold_std` moving standard deviations of recent flux (and
+    /// at least `onset_min_flux`), no sooner than `onset_min_interval_secs`
+    /// after the previous onset.
+    ///
+    /// Returns the flux of a detected onset.
+    pub fn detect_onset(&mut self, mapping: &AudioVisualMapping, dt: f32) -> Option<f32> {
+        let history_len = mapping.onset_history_frames.max(2);
+        let energies = self.band_energies();
+
+        let flux = self.band_energy_history.back().map_or(0.0, |previous| {
+            energies
+                .iter()
+                .zip(previous)
+                .map(|(current, previous)| (current - previous).max(0.0))
+                .sum()
+        });
+        self.band_energy_history.push_back(energies);
+        while self.band_energy_history.len() > history_len {
+            self.band_energy_history.pop_front();
         }
+
+        // Threshold from the history before this frame, so a spike cannot raise its own bar
+        let count = self.flux_history.len().max(1) as f32;
+        let mean = self.flux_history.iter().sum::<f32>() / count;
+        let variance = self
+            .flux_history
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f32>()
+            / count;
+        let threshold =
+            (mean + mapping.onset_threshold_std * variance.sqrt()).max(mapping.onset_min_flux);
+
+        self.flux_history.push_back(flux);
+        while self.flux_history.len() > history_len {
+            self.flux_history.pop_front();
+        }
+        self.onset_flux = flux;
+        self.seconds_since_onset += dt;
+
+        if flux <= threshold || self.seconds_since_onset < mapping.onset_min_interval_secs {
+            return None;
+        }
+        self.seconds_since_onset = 0.0;
+        Some(flux)
     }
 }
 
@@ -284,6 +917,16 @@ pub struct AudioVisualMapping {
     pub frequency_to_spawn_rate_range: (f32, f32),
     /// Frequency maps to hue shift: (min_shift, max_shift) degrees
     pub frequency_to_hue_shift_range: (f32, f32),
+    /// Onset sensitivity: standard deviations above the moving mean flux that fire a beat
+    pub onset_threshold_std: f32,
+    /// Smallest spectral flux that can fire a beat, however quiet the history
+    pub onset_min_flux: f32,
+    /// Minimum seconds between onsets, suppressing double triggers
+    pub onset_min_interval_secs: f32,
+    /// Frames of energy and flux history behind the adaptive threshold
+    pub onset_history_frames: usize,
+    /// Multiplier from onset flux to the value classified into a `BeatStrength`
+    pub onset_strength_gain: f32,
 }
 
 impl Default for AudioVisualMapping {
@@ -295,6 +938,11 @@ impl Default for AudioVisualMapping {
             amplitude_to_bloom_range: (0.0, 0.8),
             frequency_to_spawn_rate_range: (4.0, 40.0),
             frequency_to_hue_shift_range: (-5.0, 5.0),
+            onset_threshold_std: 1.5,
+            onset_min_flux: 0.02,
+            onset_min_interval_secs: 0.12,
+            onset_history_frames: 43, // ~0.7 s at 60 fps
+            onset_strength_gain: 4.0,
         }
     }
 }
@@ -303,10 +951,23 @@ impl Default for AudioVisualMapping {
 ///
 /// Volume is proportional to active particles - more interaction means
 /// louder ambient audio, allowing the app to blend with existing device audio.
+///
+/// With a multi-track playlist, `audio_entity` is the incoming track and
+/// `fading_entity` the outgoing one while they cross-fade. Every entity taken
+/// out of the state by its methods is handed back to the caller to despawn,
+/// so none is ever left playing untracked.
 #[derive(Resource, Debug, Clone)]
 pub struct AmbientAudioState {
     /// Entity holding the audio player component
     pub audio_entity: Option<Entity>,
+    /// Outgoing track's audio player while a cross-fade is in progress
+    pub fading_entity: Option<Entity>,
+    /// Index of the playing track in the playlist
+    pub current_track: usize,
+    /// Seconds the current track has been playing
+    pub track_elapsed_seconds: f32,
+    /// Cross-fade progress from 0.0 (just switched) to 1.0 (outgoing track silent)
+    pub crossfade_progress: f32,
     /// Current volume level (0.0 to 1.0)
     pub current_volume: f32,
     /// Target volume based on particle count
@@ -323,6 +984,10 @@ impl Default for AmbientAudioState {
     fn default() -> Self {
         Self {
             audio_entity: None,
+            fading_entity: None,
+            current_track: 0,
+            track_elapsed_seconds: 0.0,
+            crossfade_progress: 1.0,
             current_volume: 0.0,
             target_volume: 0.0,
             max_volume: 0.7, // 70% max volume to blend nicely with other audio
@@ -332,6 +997,63 @@ impl Default for AmbientAudioState {
     }
 }
 
+impl AmbientAudioState {
+    /// Makes `incoming` (playing `track`) the current player and starts
+    /// fading out the previous one.
+    ///
+    /// Returns an outgoing player from an earlier switch that had not
+    /// finished fading; the caller must despawn it.
+    #[must_use]
+    pub fn begin_crossfade(&mut self, incoming: Entity, track: usize) -> Option<Entity> {
+        let stale = self.fading_entity.take();
+        self.fading_entity = self.audio_entity.replace(incoming);
+        self.current_track = track;
+        self.track_elapsed_seconds = 0.0;
+        self.crossfade_progress = if self.fading_entity.is_some() {
+            0.0
+        } else {
+            1.0
+        };
+        stale
+    }
+
+    /// Advances an in-progress cross-fade lasting `duration_secs`.
+    ///
+    /// Returns the outgoing player once it is silent; the caller must despawn it.
+    #[must_use]
+    pub fn advance_crossfade(&mut self, dt: f32, duration_secs: f32) -> Option<Entity> {
+        self.fading_entity?;
+        self.crossfade_progress =
+            (self.crossfade_progress + dt / duration_secs.max(f32::EPSILON)).min(1.0);
+        if self.crossfade_progress < 1.0 {
+            return None;
+        }
+        self.fading_entity.take()
+    }
+
+    /// Volumes for the incoming and outgoing players at `current_volume`.
+    #[must_use]
+    pub fn crossfade_volumes(&self) -> (f32, f32) {
+        let progress = self.crossfade_progress.clamp(0.0, 1.0);
+        (
+            self.current_volume * progress,
+            self.current_volume * (1.0 - progress),
+        )
+    }
+
+    /// Stops playback, returning every player the caller must despawn.
+    #[must_use]
+    pub fn release_players(&mut self) -> Vec<Entity> {
+        self.track_elapsed_seconds = 0.0;
+        self.crossfade_progress = 1.0;
+        self.audio_entity
+            .take()
+            .into_iter()
+            .chain(self.fading_entity.take())
+            .collect()
+    }
+}
+
 // =============================================================================
 // INTERACTION RESOURCES
 // =============================================================================
@@ -344,32 +1066,82 @@ impl Default for AmbientAudioState {
 pub struct MouseState {
     /// Current mouse position in world coordinates
     pub position: Vec2,
+    /// Position at the start of this frame; spawns are spread from here to `position`
+    pub previous_position: Vec2,
     /// Mouse velocity (change per frame)
     pub velocity: Vec2,
     /// Whether mouse is within the window and active
     pub is_active: bool,
     /// Accumulated interaction time for radius growth
     pub accumulated_interaction: f32,
+    /// Identifier of the current paint stroke, bumped on every press
+    pub stroke_id: u64,
+    /// Jitter seed shared by every spawn in the current stroke
+    pub stroke_seed: u64,
+    /// Spawns drawn from the current stroke so far
+    pub stroke_spawns: u64,
 }
 
 impl Default for MouseState {
     fn default() -> Self {
         Self {
             position: Vec2::ZERO,
+            previous_position: Vec2::ZERO,
             velocity: Vec2::ZERO,
             is_active: false,
             accumulated_interaction: 0.0,
+            stroke_id: 0,
+            stroke_seed: 0,
+            stroke_spawns: 0,
         }
     }
 }
 
+impl MouseState {
+    /// Starts a new paint stroke.
+    ///
+    /// With a `base_seed` the stroke seed is derived from it and the stroke id,
+    /// so a session replays the same stroke textures; without one every press
+    /// draws a fresh seed from `rng` (normally `ParticleRng`).
+    ///
+    /// The new stroke starts where the pointer is, rather than joining a line
+    /// back to where the last one ended.
+    pub fn begin_stroke(&mut self, base_seed: Option<u64>, rng: &mut fastrand::Rng) {
+        self.previous_position = self.position;
+        self.stroke_id = self.stroke_id.wrapping_add(1);
+        self.stroke_seed = match base_seed {
+            Some(base) => mix_seed(base, self.stroke_id),
+            None => rng.u64(..),
+        };
+        self.stroke_spawns = 0;
+    }
+
+    /// Returns the random source for the next spawn of the current stroke.
+    ///
+    /// Each spawn gets its own stream, derived from the stroke seed and the
+    /// spawn's index within the stroke.
+    pub fn next_stroke_rng(&mut self) -> fastrand::Rng {
+        let rng = fastrand::Rng::with_seed(mix_seed(self.stroke_seed, self.stroke_spawns));
+        self.stroke_spawns = self.stroke_spawns.wrapping_add(1);
+        rng
+    }
+}
+
+/// Mixes `salt` into `seed` with a SplitMix64 finalizer.
+fn mix_seed(seed: u64, salt: u64) -> u64 {
+    let mut z = seed ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 /// Configuration for mouse interaction radius and falloff.
 ///
 /// Radius grows from base to max based on accumulated interaction time,
 /// creating a "warming up" feel as the user engages more.
 #[derive(Resource, Debug, Clone)]
 pub struct InteractionConfig {
-    /// Starting interaction radius in pixels
+    /// Starting interaction radius in world units (DPI-independent)
     pub base_radius: f32,
     /// Maximum interaction radius after sustained engagement
     pub max_radius: f32,
@@ -377,6 +1149,10 @@ pub struct InteractionConfig {
     pub current_radius: f32,
     /// Type of distance falloff for influence calculation
     pub falloff_type: FalloffType,
+    /// Peak velocity impulse of a click explosion (world units/second)
+    pub explosion_force: f32,
+    /// Reach of a click explosion in world units
+    pub explosion_radius: f32,
 }
 
 impl Default for InteractionConfig {
@@ -386,12 +1162,370 @@ impl Default for InteractionConfig {
             max_radius: 200.0,
             current_radius: 80.0,
             falloff_type: FalloffType::Quadratic,
+            explosion_force: 800.0,
+            explosion_radius: 400.0,
+        }
+    }
+}
+
+/// Interaction-radius growth for one act.
+///
+/// The radii are factors of `InteractionConfig::base_radius` and `max_radius`,
+/// so the global tuning still sets the overall scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RadiusGrowth {
+    /// Multiplier on `InteractionConfig::base_radius` for the starting radius
+    pub base_scale: f32,
+    /// Multiplier on `InteractionConfig::max_radius` for the fully grown radius
+    pub max_scale: f32,
+    /// Seconds of full-speed interaction to grow from base to max
+    pub growth_seconds: f32,
+}
+
+impl Default for RadiusGrowth {
+    fn default() -> Self {
+        Self {
+            base_scale: 1.0,
+            max_scale: 1.0,
+            growth_seconds: 60.0,
+        }
+    }
+}
+
+impl RadiusGrowth {
+    /// Blends two growth settings (`t` = 0.0 is `self`).
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            base_scale: self.base_scale.lerp(other.base_scale, t),
+            max_scale: self.max_scale.lerp(other.max_scale, t),
+            growth_seconds: self.growth_seconds.lerp(other.growth_seconds, t),
+        }
+    }
+
+    /// Radius this act aims for after `accumulated` seconds of interaction.
+    ///
+    /// Grows linearly from the scaled base to the scaled max over
+    /// `growth_seconds`, then holds.
+    #[must_use]
+    pub fn target_radius(&self, config: &InteractionConfig, accumulated: f32) -> f32 {
+        let base = config.base_radius * self.base_scale;
+        let max = (config.max_radius * self.max_scale).max(base);
+        let progress = accumulated / self.growth_seconds.max(f32::EPSILON);
+        (base + progress * (max - base)).clamp(base, max)
+    }
+}
+
+/// Per-act interaction-radius growth, indexed by `Act::index`.
+///
+/// Accumulation grows quickly to a wide reach so gathering feels generous;
+/// Transcendence stays small and slow so touch is gentle. The values blend
+/// through act transitions with the same easing as other act values.
+#[derive(Resource, Debug, Clone)]
+pub struct RadiusGrowthProfile {
+    /// Growth settings for each act
+    pub acts: [RadiusGrowth; 5],
+}
+
+impl Default for RadiusGrowthProfile {
+    fn default() -> Self {
+        let growth = |base_scale, max_scale, growth_seconds| RadiusGrowth {
+            base_scale,
+            max_scale,
+            growth_seconds,
+        };
+        Self {
+            acts: [
+                growth(1.0, 1.0, 60.0),
+                growth(1.0, 1.5, 25.0),
+                growth(1.1, 1.25, 40.0),
+                growth(1.0, 1.0, 60.0),
+                growth(0.75, 0.7, 120.0),
+            ],
+        }
+    }
+}
+
+impl RadiusGrowthProfile {
+    /// Growth settings for `act`.
+    #[must_use]
+    pub fn for_act(&self, act: Act) -> RadiusGrowth {
+        self.acts[act.index()]
+    }
+
+    /// Growth settings for the current act, eased across a transition.
+    #[must_use]
+    pub fn current(&self, act_state: &ActState) -> RadiusGrowth {
+        let current = self.for_act(act_state.current_act);
+        if !act_state.is_transitioning {
+            return current;
+        }
+        let t = ease_in_out_cubic(act_state.transition_progress);
+        self.for_act(act_state.transition_source())
+            .lerp(&current, t)
+    }
+}
+
+/// Motion limits and force scaling for one act.
+///
+/// `drag_scale` multiplies the damping strength of each particle's drag
+/// (`ParticleBehaviorType::base_drag` blended by act): per 60 Hz frame the
+/// velocity keeps `drag.powf(drag_scale)`, so 2.0 damps twice as hard and
+/// 0.0 disables drag.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PhysicsProfile {
+    /// Speed cap applied after acceleration and drag (world units/second)
+    pub max_speed: f32,
+    /// Multiplier on the damping strength of particle drag
+    pub drag_scale: f32,
+    /// Multiplier on behavior acceleration
+    pub acceleration_scale: f32,
+}
+
+impl Default for PhysicsProfile {
+    fn default() -> Self {
+        Self {
+            max_speed: 500.0,
+            drag_scale: 1.0,
+            acceleration_scale: 1.0,
+        }
+    }
+}
+
+impl PhysicsProfile {
+    /// Blends two profiles (`t` = 0.0 is `self`).
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            max_speed: self.max_speed.lerp(other.max_speed, t),
+            drag_scale: self.drag_scale.lerp(other.drag_scale, t),
+            acceleration_scale: self.acceleration_scale.lerp(other.acceleration_scale, t),
+        }
+    }
+
+    /// Fraction of velocity kept after `dt` seconds of `drag`.
+    #[must_use]
+    pub fn drag_retention(&self, drag: f32, dt: f32) -> f32 {
+        drag.powf(dt * 60.0 * self.drag_scale)
+    }
+}
+
+/// Configuration for how Paint strokes hand velocity to new particles.
+///
+/// Low inheritance gives a "sticky" feel where peas stay where they are
+/// painted; high inheritance gives a "flingy" feel where they follow the stroke.
+#[derive(Resource, Debug, Clone)]
+pub struct PaintConfig {
+    /// Fraction of the pointer velocity inherited by spawned particles
+    pub velocity_inheritance: f32,
+    /// Width of the random velocity offset added per axis (world units/second)
+    pub random_spread: f32,
+    /// Base seed for per-stroke jitter; `None` draws a fresh seed on every press
+    pub stroke_seed: Option<u64>,
+    /// Particles per second painted by a slow and a fast pointer, before the held-button boost
+    pub spawn_rate_range: (f32, f32),
+    /// Turn painted peas to face their direction of travel
+    pub orient_to_velocity: bool,
+}
+
+impl Default for PaintConfig {
+    fn default() -> Self {
+        Self {
+            velocity_inheritance: 0.3,
+            random_spread: 50.0,
+            stroke_seed: None,
+            spawn_rate_range: (8.0, 15.0),
+            orient_to_velocity: false,
+        }
+    }
+}
+
+/// User-chosen color for painted particles, replacing the palette.
+///
+/// While `color` is set, `SpawnSource::Mouse` spawns use it as-is; beat and
+/// automatic spawns keep the palette. Clearing it returns painting to the
+/// palette. `handle_paint_color_keys` steps the hue with `[` / `]` and clears
+/// with `\`; external controllers can write `color` directly.
+#[derive(Resource, Debug, Clone)]
+pub struct PaintColorOverride {
+    /// Color for painted particles, or `None` to use the palette
+    pub color: Option<Color>,
+    /// Hue change per selector step, in degrees
+    pub hue_step_degrees: f32,
+}
+
+impl Default for PaintColorOverride {
+    fn default() -> Self {
+        Self {
+            color: None,
+            hue_step_degrees: 30.0,
+        }
+    }
+}
+
+impl PaintColorOverride {
+    /// Rotates the selected hue by `steps` selector steps.
+    ///
+    /// Starts from `fallback` when no color is selected yet.
+    pub fn step_hue(&mut self, steps: f32, fallback: Color) {
+        let current = Hsla::from(self.color.unwrap_or(fallback));
+        self.color = Some(current.rotate_hue(steps * self.hue_step_degrees).into());
+    }
+}
+
+/// Finite paint "ink" that Paint spawns consume and that refills over time.
+///
+/// Opt-in: while `enabled` is false painting is unlimited. When enabled each
+/// pointer-spawned particle costs one unit; once depleted, strokes thin out
+/// to the regeneration rate. `fraction()` drives a UI meter.
+#[derive(Resource, Debug, Clone)]
+pub struct InkBudget {
+    /// Whether painting consumes ink
+    pub enabled: bool,
+    /// Ink currently available, in particles
+    pub current: f32,
+    /// Ink capacity, in particles
+    pub max: f32,
+    /// Ink regained per second
+    pub regen_per_second: f32,
+}
+
+impl Default for InkBudget {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            current: 300.0,
+            max: 300.0,
+            regen_per_second: 40.0,
+        }
+    }
+}
+
+impl InkBudget {
+    /// Returns the fill level for a meter (0.0 to 1.0).
+    #[must_use]
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+
+    /// Spends `amount` of ink if available. Always succeeds while disabled.
+    pub fn try_spend(&mut self, amount: f32) -> bool {
+        if !self.enabled {
+            return true;
+        }
+        if self.current < amount {
+            return false;
+        }
+        self.current -= amount;
+        true
+    }
+
+    /// Refills ink toward `max` over `delta_secs`.
+    pub fn regenerate(&mut self, delta_secs: f32) {
+        self.current = (self.current + self.regen_per_second * delta_secs).min(self.max);
+    }
+}
+
+/// Configuration for beat-triggered spawn layouts.
+///
+/// Scatter, ripple, and burst extents are sized relative to the visible
+/// viewport (see `DisplayScale::world_viewport`) and then multiplied by
+/// `radius_scale`, so bursts stay proportionate across resolutions. Each
+/// beat strength spawns a random count from its inclusive range.
+#[derive(Resource, Debug, Clone)]
+pub struct BeatSpawnConfig {
+    /// Multiplier applied to all beat spawn offsets
+    pub radius_scale: f32,
+    /// Particles spawned by a soft beat (scatter)
+    pub soft_count: (u32, u32),
+    /// Particles spawned by a medium beat (ripple)
+    pub medium_count: (u32, u32),
+    /// Particles spawned by a strong beat (burst)
+    pub strong_count: (u32, u32),
+}
+
+impl Default for BeatSpawnConfig {
+    fn default() -> Self {
+        Self {
+            radius_scale: 1.0,
+            soft_count: (5, 10),
+            medium_count: (10, 20),
+            strong_count: (20, 40),
+        }
+    }
+}
+
+/// Magnet Toy: a standalone mode with two opposite-polarity attractors.
+///
+/// Toggled with M. While active the act behaviors and turbulence are
+/// suspended and particles are heavily damped so they settle into lines
+/// between the poles like iron filings.
+#[derive(Resource, Debug, Clone)]
+pub struct MagnetToy {
+    /// Whether the toy mode is running
+    pub active: bool,
+    /// Force magnitude of each pole; pole 0 attracts, pole 1 repels
+    pub pole_strength: f32,
+    /// Reach of each pole in world units
+    pub pole_radius: f32,
+    /// Velocity retained per 1/60 s while active (lower = stickier filings)
+    pub filing_drag: f32,
+    /// Arrow-key movement speed for the second pole (world units/second)
+    pub keyboard_pole_speed: f32,
+}
+
+impl Default for MagnetToy {
+    fn default() -> Self {
+        Self {
+            active: false,
+            pole_strength: 2500.0,
+            pole_radius: 600.0,
+            filing_drag: 0.9,
+            keyboard_pole_speed: 600.0,
+        }
+    }
+}
+
+/// Configuration for density-aware particle opacity.
+///
+/// Where many particles overlap, each one's rendered alpha is lowered so
+/// dense clusters read as soft volume rather than a blown-out solid mass.
+#[derive(Resource, Debug, Clone)]
+pub struct DensityOpacityConfig {
+    /// Whether density-aware opacity is applied
+    pub enabled: bool,
+    /// Radius around each particle within which neighbors count toward its
+    /// density, in world units
+    pub radius: f32,
+    /// Particles per cell that render at full opacity
+    pub crowd_threshold: u32,
+    /// Opacity reduction per particle above the threshold
+    pub strength: f32,
+    /// Lowest opacity factor applied to a crowded particle
+    pub min_factor: f32,
+}
+
+impl Default for DensityOpacityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            radius: 20.0,
+            crowd_threshold: 3,
+            strength: 0.1,
+            min_factor: 0.35,
         }
     }
 }
 
 /// Current interaction mode based on act state.
 ///
+/// Rebuilt every frame from `BaseInteractionMode` by the pointer overrides
+/// (double-tap cycling, MIDI, the eraser), so write the base to change the
+/// mode for good.
+///
 /// Each act offers a different way for the user to interact with particles:
 /// - Paint (Act I): Create particles
 /// - Attract (Act II): Draw particles toward cursor
@@ -402,16 +1536,45 @@ impl Default for InteractionConfig {
 pub struct CurrentInteractionMode {
     /// Active interaction mode
     pub mode: InteractionMode,
+    /// Other mode still mixed into pointer forces during an act transition
+    /// and its weight (0.0 to 1.0); `mode` carries the remaining weight
+    pub blend_target: Option<(InteractionMode, f32)>,
 }
 
 impl Default for CurrentInteractionMode {
     fn default() -> Self {
         Self {
             mode: InteractionMode::Paint,
+            blend_target: None,
         }
     }
 }
 
+impl CurrentInteractionMode {
+    /// Switches to `mode` outright, dropping any crossfade.
+    pub fn set(&mut self, mode: InteractionMode) {
+        self.mode = mode;
+        self.blend_target = None;
+    }
+
+    /// The modes whose forces apply this frame, each with its weight.
+    pub fn weighted_modes(&self) -> impl Iterator<Item = (InteractionMode, f32)> {
+        let other_weight = self.blend_target.map_or(0.0, |(_, weight)| weight);
+        std::iter::once((self.mode, 1.0 - other_weight))
+            .chain(self.blend_target)
+            .filter(|(_, weight)| *weight > 0.0)
+    }
+}
+
+/// Interaction mode before any pointer override is applied.
+///
+/// Written by `interpolate_act_values`; without the `acts` feature it keeps
+/// whatever the host set (Paint by default). `apply_interaction_mode_cycle`
+/// copies it into `CurrentInteractionMode` each frame, so an override that
+/// ends falls back to this mode instead of latching.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BaseInteractionMode(pub CurrentInteractionMode);
+
 // =============================================================================
 // PARTICLE POOL RESOURCES
 // =============================================================================
@@ -428,8 +1591,24 @@ pub struct ParticlePool {
     pub active_count: u32,
     /// Total pool capacity (pre-allocated entities)
     pub pool_capacity: u32,
-    /// Maximum particles that can be active simultaneously
+    /// Configured maximum of simultaneously active particles
     pub max_active: u32,
+    /// Lower caps published by the load controllers
+    pub ceilings: ActiveCeilings,
+}
+
+/// Particle caps published by the controllers that shed load.
+///
+/// Each controller writes only its own field; `ParticlePool::active_cap`
+/// takes the minimum, so no controller undoes another's reduction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActiveCeilings {
+    /// Set by `AdaptiveQuality` from the measured frame rate
+    pub adaptive_quality: Option<u32>,
+    /// Set by the kiosk watchdog after repeated frame stalls
+    pub frame_stall: Option<u32>,
+    /// Set by `SimFrameBudget` from the measured simulation time
+    pub simulation_budget: Option<u32>,
 }
 
 impl Default for ParticlePool {
@@ -439,6 +1618,103 @@ impl Default for ParticlePool {
             active_count: 0,
             pool_capacity: 15000,
             max_active: 10000,
+            ceilings: ActiveCeilings::default(),
+        }
+    }
+}
+
+impl ParticlePool {
+    /// Returns the cap spawning honours: `max_active` lowered by any
+    /// controller ceiling.
+    #[must_use]
+    pub fn active_cap(&self) -> u32 {
+        let ActiveCeilings {
+            adaptive_quality,
+            frame_stall,
+            simulation_budget,
+        } = self.ceilings;
+        [adaptive_quality, frame_stall, simulation_budget]
+            .into_iter()
+            .flatten()
+            .fold(self.max_active, u32::min)
+    }
+
+    /// Returns the share of `active_cap` in use (0.0 = idle, 1.0 = saturated).
+    ///
+    /// A pool with a cap of 0 cannot spawn anything and reports 1.0.
+    #[must_use]
+    pub fn utilization(&self) -> f32 {
+        let cap = self.active_cap();
+        if cap == 0 {
+            return 1.0;
+        }
+        self.active_count as f32 / cap as f32
+    }
+}
+
+/// Seed for reproducible simulation runs.
+///
+/// Set by `WhirledPeasPlugin::with_seed` and read once when
+/// `WhirledPeasHeadlessPlugin` is built, to seed `ParticleRng`.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RngSeed(pub u64);
+
+/// Random source for particle spawning and behavior.
+///
+/// Shared by beat and paint spawning (stroke seeds, colors, jitter), act
+/// behaviors, and turbulence seeds. Defaults to an entropy-seeded generator,
+/// so interactive runs differ; seed it with `ParticleRng::with_seed` (or
+/// `WhirledPeasPlugin::with_seed`) for reproducible runs. Independent streams
+/// can be split off with `fork`.
+///
+/// A seed replays identically within one version; changes to how spawning
+/// draws from the stream may change what a seed produces between versions.
+#[derive(Resource, Debug, Clone)]
+pub struct ParticleRng(pub fastrand::Rng);
+
+impl Default for ParticleRng {
+    fn default() -> Self {
+        Self(fastrand::Rng::new())
+    }
+}
+
+impl ParticleRng {
+    /// Returns a generator that always produces the same stream for `seed`.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self(fastrand::Rng::with_seed(seed))
+    }
+
+    /// Splits off an independent generator, advancing this one.
+    ///
+    /// Forks taken in the same order from the same seed are identical.
+    #[must_use]
+    pub fn fork(&mut self) -> fastrand::Rng {
+        self.0.fork()
+    }
+}
+
+/// Tuning for act behavior forces that live outside `BehaviorCoefficients`.
+///
+/// Separation keeps swarming and orbiting peas spaced apart, boids-style:
+/// neighbors closer than `separation_radius` push each other away, harder the
+/// closer they are. Only applied under `Swarm` and `Orbit`.
+#[derive(Resource, Debug, Clone)]
+pub struct BehaviorTuning {
+    /// Repulsion scale between close neighbors (0.0 disables separation)
+    pub separation_strength: f32,
+    /// Neighbors closer than this (world units) repel each other
+    pub separation_radius: f32,
+    /// Most neighbors summed per particle, bounding the cost inside dense clumps
+    pub max_separation_neighbors: usize,
+}
+
+impl Default for BehaviorTuning {
+    fn default() -> Self {
+        Self {
+            separation_strength: 40.0,
+            separation_radius: 40.0,
+            max_separation_neighbors: 8,
         }
     }
 }
@@ -467,6 +1743,130 @@ impl Default for ParticleSpawnQueue {
     }
 }
 
+/// Fair-share scheduling for the spawn queue.
+///
+/// Non-interactive sources (beats, automatic) may only fill the pool up to
+/// `max_active` minus a reserved slice, so a large beat burst can never take
+/// the last free slots and starve mouse painting on small caps.
+#[derive(Resource, Debug, Clone)]
+pub struct SpawnBudgetConfig {
+    /// Fraction of `max_active` reserved for interactive spawns (0.0 to 1.0)
+    pub interactive_reserve_fraction: f32,
+    /// Most particles activated in a single frame (set by `SimFrameBudget`)
+    pub frame_spawn_limit: u32,
+}
+
+impl Default for SpawnBudgetConfig {
+    fn default() -> Self {
+        Self {
+            interactive_reserve_fraction: 0.2,
+            frame_spawn_limit: u32::MAX,
+        }
+    }
+}
+
+impl SpawnBudgetConfig {
+    /// Returns the number of slots reserved for interactive spawns.
+    ///
+    /// Rounds up so any non-zero fraction keeps at least one slot free.
+    #[must_use]
+    pub fn interactive_reserve(&self, max_active: u32) -> u32 {
+        let fraction = self.interactive_reserve_fraction.clamp(0.0, 1.0);
+        ((max_active as f32 * fraction).ceil() as u32).min(max_active)
+    }
+}
+
+/// Fade-out shape for each spawn source.
+///
+/// Copied onto `ParticleVisual::fade_profile` when a particle spawns. Beat
+/// particles are short-lived accents, so by default they fade harder than
+/// painted and ambient ones.
+#[derive(Resource, Debug, Clone)]
+pub struct FadeProfiles {
+    /// Fade for particles painted with the mouse or touch
+    pub mouse: FadeProfile,
+    /// Fade for particles spawned on audio beats
+    pub beat: FadeProfile,
+    /// Fade for ambient particles
+    pub automatic: FadeProfile,
+}
+
+impl Default for FadeProfiles {
+    fn default() -> Self {
+        Self {
+            mouse: FadeProfile::Linear,
+            beat: FadeProfile::EaseOut,
+            automatic: FadeProfile::Linear,
+        }
+    }
+}
+
+impl FadeProfiles {
+    /// Returns the fade profile for particles from `source`.
+    #[must_use]
+    pub fn for_source(&self, source: SpawnSource) -> FadeProfile {
+        match source {
+            SpawnSource::Mouse => self.mouse,
+            SpawnSource::Beat => self.beat,
+            SpawnSource::Automatic => self.automatic,
+        }
+    }
+}
+
+/// Trail length and fade for particles from one spawn source.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrailProfile {
+    /// Number of segments the trail keeps (up to `MAX_TRAIL_SEGMENTS`)
+    pub max_segments: usize,
+    /// Time for a segment to fade out, in milliseconds
+    pub fade_duration_ms: f32,
+}
+
+/// Trail character for each spawn source.
+///
+/// Copied onto `TrailRenderer` when a particle spawns. Painted particles
+/// leave long, lingering trails; beat bursts leave short, quick flicks.
+#[derive(Resource, Debug, Clone)]
+pub struct TrailProfiles {
+    /// Trails of particles painted with the mouse or touch
+    pub mouse: TrailProfile,
+    /// Trails of particles spawned on audio beats
+    pub beat: TrailProfile,
+    /// Trails of ambient particles
+    pub automatic: TrailProfile,
+}
+
+impl Default for TrailProfiles {
+    fn default() -> Self {
+        Self {
+            mouse: TrailProfile {
+                max_segments: 18,
+                fade_duration_ms: 2200.0,
+            },
+            beat: TrailProfile {
+                max_segments: 8,
+                fade_duration_ms: 900.0,
+            },
+            automatic: TrailProfile {
+                max_segments: TRAIL_SEGMENTS,
+                fade_duration_ms: TRAIL_FADE_DURATION_MS,
+            },
+        }
+    }
+}
+
+impl TrailProfiles {
+    /// Returns the trail profile for particles from `source`.
+    #[must_use]
+    pub fn for_source(&self, source: SpawnSource) -> TrailProfile {
+        match source {
+            SpawnSource::Mouse => self.mouse,
+            SpawnSource::Beat => self.beat,
+            SpawnSource::Automatic => self.automatic,
+        }
+    }
+}
+
 /// A single request to spawn a particle with specified properties.
 #[derive(Debug, Clone)]
 pub struct ParticleSpawnRequest {
@@ -480,6 +1880,10 @@ pub struct ParticleSpawnRequest {
     pub lifetime_ms: f32,
     /// Source of the spawn request
     pub source: SpawnSource,
+    /// Seed of the paint stroke this spawn belongs to, if any
+    pub stroke_seed: Option<u64>,
+    /// Turn the pea to face its direction of travel (see `ParticleVisual`)
+    pub orient_to_velocity: bool,
 }
 
 impl Default for ParticleSpawnRequest {
@@ -490,6 +1894,8 @@ impl Default for ParticleSpawnRequest {
             color: Color::WHITE,
             lifetime_ms: 5000.0,
             source: SpawnSource::Automatic,
+            stroke_seed: None,
+            orient_to_velocity: false,
         }
     }
 }
@@ -511,12 +1917,27 @@ pub struct PostProcessSettings {
     pub bloom_intensity: f32,
     /// Bloom blur radius in pixels
     pub bloom_radius: f32,
+    /// How bloom is composited (energy-conserving or additive)
+    pub bloom_composite: BloomComposite,
+    /// Base low-frequency boost (higher = more diffuse glow), scaled up by `bloom_radius`
+    pub bloom_low_frequency_boost: f32,
+    /// Prefilter luminance threshold (0.0 = every pixel blooms)
+    pub bloom_prefilter_threshold: f32,
+    /// Prefilter threshold softness (0.0 = hard cutoff, 1.0 = fully soft)
+    pub bloom_prefilter_softness: f32,
     /// Chromatic aberration strength (color channel separation)
     pub chromatic_aberration_strength: f32,
     /// Vignette darkness at edges
     pub vignette_intensity: f32,
+    /// Color the vignette fades the edges toward
+    pub vignette_color: Color,
     /// Film grain noise amount
     pub film_grain_amount: f32,
+    /// Blur strength away from the interaction center (0.0 = everything in focus)
+    pub focus_blur_strength: f32,
+    /// Skip film grain, chromatic aberration, and focus blur; set by `AdaptiveQuality`
+    /// under severe load
+    pub reduced_effects: bool,
 }
 
 impl Default for PostProcessSettings {
@@ -525,9 +1946,16 @@ impl Default for PostProcessSettings {
             bloom_threshold: 0.7,
             bloom_intensity: 0.3,
             bloom_radius: 8.0,
+            bloom_composite: BloomComposite::EnergyConserving,
+            bloom_low_frequency_boost: 0.7,
+            bloom_prefilter_threshold: 0.0,
+            bloom_prefilter_softness: 0.0,
             chromatic_aberration_strength: 0.0,
             vignette_intensity: 0.3,
+            vignette_color: Color::BLACK,
             film_grain_amount: 0.02,
+            focus_blur_strength: 0.0,
+            reduced_effects: false,
         }
     }
 }
@@ -563,10 +1991,19 @@ impl Default for MotionTiming {
     }
 }
 
+/// Frame rate below which a frame counts toward `PerformanceMetrics::is_struggling`.
+pub const STRUGGLING_FPS: f32 = 45.0;
+
+/// Consecutive slow frames before `PerformanceMetrics::is_struggling` reports true.
+pub const STRUGGLING_FRAME_STREAK: u32 = 30;
+
 /// Runtime performance metrics for monitoring and adaptive quality.
 ///
 /// Tracks frame times and system-specific timings to enable
-/// automatic quality adjustment if performance degrades.
+/// automatic quality adjustment if performance degrades. `current_fps` and
+/// `frame_time_ms` are filled from Bevy's frame-time diagnostics by the
+/// metrics module; `particle_update_time_ms` is measured around the
+/// spawn+motion pipeline.
 #[derive(Resource, Debug, Clone)]
 pub struct PerformanceMetrics {
     /// Current frames per second
@@ -577,6 +2014,8 @@ pub struct PerformanceMetrics {
     pub particle_update_time_ms: f32,
     /// Time spent rendering in milliseconds
     pub render_time_ms: f32,
+    /// Consecutive recorded frames below `STRUGGLING_FPS`
+    pub slow_frame_streak: u32,
 }
 
 impl Default for PerformanceMetrics {
@@ -586,7 +2025,245 @@ impl Default for PerformanceMetrics {
             frame_time_ms: 16.67,
             particle_update_time_ms: 0.0,
             render_time_ms: 0.0,
+            slow_frame_streak: 0,
+        }
+    }
+}
+
+impl PerformanceMetrics {
+    /// Stores one frame's measurements and updates the slow-frame streak.
+    pub fn record_frame(&mut self, fps: f32, frame_time_ms: f32) {
+        self.current_fps = fps;
+        self.frame_time_ms = frame_time_ms;
+        if fps < STRUGGLING_FPS {
+            self.slow_frame_streak = self.slow_frame_streak.saturating_add(1);
+        } else {
+            self.slow_frame_streak = 0;
+        }
+    }
+
+    /// Returns true once the frame rate has stayed below `STRUGGLING_FPS`
+    /// for `STRUGGLING_FRAME_STREAK` consecutive frames.
+    #[must_use]
+    pub fn is_struggling(&self) -> bool {
+        self.slow_frame_streak >= STRUGGLING_FRAME_STREAK
+    }
+}
+
+/// Closed-loop controller holding simulation cost near a frame-time budget.
+///
+/// Each frame the measured spawn+motion time (`PerformanceMetrics.particle_update_time_ms`)
+/// is smoothed and compared to `budget_ms`. The normalized error
+/// `(smoothed - budget) / budget` drives a PID step that lowers
+/// `ParticlePool.ceilings.simulation_budget` when over budget and raises it
+/// when under:
+///
+/// - `kp`: fraction of the cap removed per unit of error, every frame
+/// - `ki`: integral gain; removes steady-state offset (integral clamped to ±1)
+/// - `kd`: derivative gain; damps overshoot when the cost changes quickly
+/// - `smoothing`: weight of the newest sample in the moving average
+///
+/// The ceiling never leaves `[min_active, ceiling_active]`, and
+/// `ParticlePool::active_cap` applies it as the minimum of `max_active` and
+/// every published ceiling. The per-frame spawn limit follows that cap via
+/// `spawn_limit_fraction`. Opt-in; off by default.
+#[derive(Resource, Debug, Clone)]
+pub struct SimFrameBudget {
+    /// Whether the controller adjusts the particle cap
+    pub enabled: bool,
+    /// Target spawn+motion time per frame in milliseconds
+    pub budget_ms: f32,
+    /// Proportional gain
+    pub kp: f32,
+    /// Integral gain (per second of accumulated error)
+    pub ki: f32,
+    /// Derivative gain (seconds)
+    pub kd: f32,
+    /// Exponential moving average weight of the newest measurement (0.0 to 1.0)
+    pub smoothing: f32,
+    /// Lowest cap the controller will set
+    pub min_active: u32,
+    /// Highest cap the controller will set
+    pub ceiling_active: u32,
+    /// Per-frame spawn limit as a fraction of the cap
+    pub spawn_limit_fraction: f32,
+    /// Smoothed measured time in milliseconds
+    pub smoothed_ms: f32,
+    /// Accumulated normalized error
+    pub integral: f32,
+    /// Normalized error from the previous step
+    pub previous_error: f32,
+}
+
+impl Default for SimFrameBudget {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            budget_ms: 8.0,
+            kp: 0.05,
+            ki: 0.02,
+            kd: 0.005,
+            smoothing: 0.2,
+            min_active: 500,
+            ceiling_active: 10000,
+            spawn_limit_fraction: 0.05,
+            smoothed_ms: 0.0,
+            integral: 0.0,
+            previous_error: 0.0,
+        }
+    }
+}
+
+impl SimFrameBudget {
+    /// Advances the controller and returns the new particle cap.
+    ///
+    /// # Arguments
+    /// * `measured_ms` - Spawn+motion time measured this frame
+    /// * `delta_secs` - Frame delta in seconds
+    /// * `max_active` - Current particle cap
+    #[must_use]
+    pub fn step(&mut self, measured_ms: f32, delta_secs: f32, max_active: u32) -> u32 {
+        let alpha = self.smoothing.clamp(0.0, 1.0);
+        self.smoothed_ms += (measured_ms - self.smoothed_ms) * alpha;
+
+        let error = (self.smoothed_ms - self.budget_ms) / self.budget_ms.max(0.1);
+        self.integral = (self.integral + error * delta_secs).clamp(-1.0, 1.0);
+        let derivative = if delta_secs > 0.0 {
+            (error - self.previous_error) / delta_secs
+        } else {
+            0.0
+        };
+        self.previous_error = error;
+
+        let adjustment = self.kp * error + self.ki * self.integral + self.kd * derivative;
+        let scaled = max_active.max(1) as f32 * (1.0 - adjustment.clamp(-0.5, 0.5));
+        let floor = self.min_active.min(self.ceiling_active) as f32;
+        scaled.clamp(floor, self.ceiling_active as f32).round() as u32
+    }
+
+    /// Returns the per-frame spawn limit for a cap.
+    #[must_use]
+    pub fn spawn_limit(&self, max_active: u32) -> u32 {
+        ((max_active as f32 * self.spawn_limit_fraction).ceil() as u32).max(1)
+    }
+}
+
+/// Frame-rate driven quality controller that sheds particles under load.
+///
+/// When `PerformanceMetrics.current_fps` stays below `fps_floor` for
+/// `sustain_seconds`, `ParticlePool.ceilings.adaptive_quality` ramps down by
+/// `reduce_per_second` (never below `min_active`). Once the frame rate stays
+/// at or above `recovery_fps` for `sustain_seconds`, the ceiling ramps back
+/// up by `restore_per_second` toward `ceiling_active`. Between the two
+/// thresholds the ceiling holds, so it does not oscillate around the floor.
+/// `ParticlePool::active_cap` takes the minimum of `max_active` and every
+/// published ceiling, so `max_active` itself stays the configured value.
+///
+/// Under severe load (below `severe_fps` for `sustain_seconds`) film grain
+/// and chromatic aberration are also switched off via
+/// `PostProcessSettings.reduced_effects`, until the frame rate recovers.
+///
+/// Runs independently of `SimFrameBudget`; enable only one of the two.
+#[derive(Resource, Debug, Clone)]
+pub struct AdaptiveQuality {
+    /// Whether the controller adjusts the particle cap
+    pub enabled: bool,
+    /// Frame rate below which particles are shed
+    pub fps_floor: f32,
+    /// Frame rate at or above which particles are restored (hysteresis band top)
+    pub recovery_fps: f32,
+    /// Frame rate below which film grain and chromatic aberration are disabled
+    pub severe_fps: f32,
+    /// Whether severe load may disable film grain and chromatic aberration
+    pub reduce_effects_when_severe: bool,
+    /// How long a frame rate must persist before the controller reacts
+    pub sustain_seconds: f32,
+    /// Particles removed from the cap per second while struggling
+    pub reduce_per_second: f32,
+    /// Particles returned to the cap per second while recovered
+    pub restore_per_second: f32,
+    /// Lowest cap the controller will set
+    pub min_active: u32,
+    /// Cap restored to; `None` captures `max_active` on the first step
+    pub ceiling_active: Option<u32>,
+    /// Seconds the frame rate has stayed below `fps_floor`
+    pub below_floor_seconds: f32,
+    /// Seconds the frame rate has stayed below `severe_fps`
+    pub severe_seconds: f32,
+    /// Seconds the frame rate has stayed at or above `recovery_fps`
+    pub recovered_seconds: f32,
+    /// Fractional particles carried between steps so slow ramps still move
+    pub pending_change: f32,
+    /// Whether film grain and chromatic aberration are currently shed
+    pub effects_reduced: bool,
+}
+
+impl Default for AdaptiveQuality {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            fps_floor: 45.0,
+            recovery_fps: 55.0,
+            severe_fps: 30.0,
+            reduce_effects_when_severe: true,
+            sustain_seconds: 1.0,
+            reduce_per_second: 500.0,
+            restore_per_second: 250.0,
+            min_active: 1000,
+            ceiling_active: None,
+            below_floor_seconds: 0.0,
+            severe_seconds: 0.0,
+            recovered_seconds: 0.0,
+            pending_change: 0.0,
+            effects_reduced: false,
+        }
+    }
+}
+
+impl AdaptiveQuality {
+    /// Advances the controller and returns the new particle cap.
+    ///
+    /// # Arguments
+    /// * `fps` - Frame rate measured this frame
+    /// * `delta_secs` - Frame delta in seconds
+    /// * `max_active` - Current particle cap
+    #[must_use]
+    pub fn step(&mut self, fps: f32, delta_secs: f32, max_active: u32) -> u32 {
+        let ceiling = *self.ceiling_active.get_or_insert(max_active);
+        let floor = self.min_active.min(ceiling);
+
+        let sustain = |seconds: f32, holds: bool| if holds { seconds + delta_secs } else { 0.0 };
+        self.below_floor_seconds = sustain(self.below_floor_seconds, fps < self.fps_floor);
+        self.severe_seconds = sustain(self.severe_seconds, fps < self.severe_fps);
+        self.recovered_seconds = sustain(self.recovered_seconds, fps >= self.recovery_fps);
+        let struggling = self.below_floor_seconds >= self.sustain_seconds;
+        let recovered = self.recovered_seconds >= self.sustain_seconds;
+
+        if self.severe_seconds >= self.sustain_seconds && self.reduce_effects_when_severe {
+            self.effects_reduced = true;
+        } else if recovered {
+            self.effects_reduced = false;
+        }
+
+        // Inside the hysteresis band the cap holds
+        let rate = if struggling {
+            -self.reduce_per_second
+        } else if recovered {
+            self.restore_per_second
+        } else {
+            self.pending_change = 0.0;
+            return max_active;
+        };
+
+        self.pending_change += rate * delta_secs;
+        let whole = self.pending_change.trunc();
+        self.pending_change -= whole;
+
+        let target = (max_active as f32 + whole).clamp(floor as f32, ceiling as f32);
+        if target <= floor as f32 || target >= ceiling as f32 {
+            self.pending_change = 0.0;
         }
+        target as u32
     }
 }
 
@@ -623,23 +2300,32 @@ pub struct ResourcesPlugin;
 
 impl Plugin for ResourcesPlugin {
     fn build(&self, app: &mut App) {
-        // Load font directly using world access
+        // Load font directly using world access; headless apps have no asset server or UI
         {
             let world = app.world_mut();
-            let asset_server = world.resource::<AssetServer>();
-            let handle = asset_server.load("fonts/FiraSans-Bold.ttf");
-            world.insert_resource(UiFont { handle });
+            if let Some(asset_server) = world.get_resource::<AssetServer>() {
+                let handle = asset_server.load("fonts/FiraSans-Bold.ttf");
+                world.insert_resource(UiFont { handle });
+            }
         }
 
         app
             // Application state
             .init_resource::<ActState>()
+            .init_resource::<ExperiencePaused>()
             .init_resource::<ActTimings>()
             .init_resource::<InterpolatedActValues>()
+            .init_resource::<Intensity>()
+            .init_resource::<Quietude>()
             // Colors
             .init_resource::<ColorPalette>()
             .init_resource::<BackgroundGradients>()
             .init_resource::<CurrentBackground>()
+            .init_resource::<ColorInterpolation>()
+            .init_resource::<PaletteConfig>()
+            .init_resource::<DisplayScale>()
+            .init_resource::<BeatSpawnConfig>()
+            .init_resource::<MagnetToy>()
             // Audio
             .init_resource::<AudioAnalysis>()
             .init_resource::<AudioVisualMapping>()
@@ -647,15 +2333,28 @@ impl Plugin for ResourcesPlugin {
             // Interaction
             .init_resource::<MouseState>()
             .init_resource::<InteractionConfig>()
+            .init_resource::<RadiusGrowthProfile>()
+            .init_resource::<PaintConfig>()
+            .init_resource::<PaintColorOverride>()
+            .init_resource::<InkBudget>()
+            .init_resource::<DensityOpacityConfig>()
             .init_resource::<CurrentInteractionMode>()
+            .init_resource::<BaseInteractionMode>()
             // Particle pool
             .init_resource::<ParticlePool>()
             .init_resource::<ParticleSpawnQueue>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<BehaviorTuning>()
+            .init_resource::<SpawnBudgetConfig>()
+            .init_resource::<FadeProfiles>()
+            .init_resource::<TrailProfiles>()
             // Post-processing
             .init_resource::<PostProcessSettings>()
             // Timing
             .init_resource::<MotionTiming>()
-            .init_resource::<PerformanceMetrics>();
+            .init_resource::<PerformanceMetrics>()
+            .init_resource::<SimFrameBudget>()
+            .init_resource::<AdaptiveQuality>();
     }
 }
 
@@ -663,6 +2362,60 @@ impl Plugin for ResourcesPlugin {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_theme_color_per_act() {
+        let palette = ColorPalette::default();
+        assert_eq!(palette.theme_color(Act::Emergence), palette.accent_deep);
+        assert_eq!(
+            palette.theme_color(Act::Accumulation),
+            palette.secondary_warm
+        );
+        assert_eq!(
+            palette.theme_color(Act::Crescendo),
+            palette.primary_midpoint
+        );
+        assert_eq!(palette.theme_color(Act::Release), palette.accent_spark);
+        assert_eq!(
+            palette.theme_color(Act::Transcendence),
+            palette.primary_final
+        );
+
+        // Every act gets its own color
+        let colors: Vec<Color> = Act::all()
+            .iter()
+            .map(|act| palette.theme_color(*act))
+            .collect();
+        for (i, a) in colors.iter().enumerate() {
+            assert!(colors[i + 1..].iter().all(|b| b != a));
+        }
+    }
+
+    #[test]
+    fn test_is_struggling_needs_consecutive_slow_frames() {
+        let mut metrics = PerformanceMetrics::default();
+        assert!(!metrics.is_struggling());
+
+        for _ in 0..STRUGGLING_FRAME_STREAK - 1 {
+            metrics.record_frame(30.0, 33.3);
+        }
+        assert!(!metrics.is_struggling());
+        metrics.record_frame(30.0, 33.3);
+        assert!(metrics.is_struggling());
+        assert_eq!(metrics.current_fps, 30.0);
+        assert_eq!(metrics.frame_time_ms, 33.3);
+
+        // One healthy frame resets the streak
+        metrics.record_frame(60.0, 16.7);
+        assert!(!metrics.is_struggling());
+        assert_eq!(metrics.slow_frame_streak, 0);
+
+        // Exactly at the threshold is not slow
+        for _ in 0..STRUGGLING_FRAME_STREAK {
+            metrics.record_frame(STRUGGLING_FPS, 22.2);
+        }
+        assert!(!metrics.is_struggling());
+    }
+
     #[test]
     fn test_act_state_default() {
         let state = ActState::default();
@@ -672,6 +2425,49 @@ mod tests {
         assert!(!state.is_transitioning);
     }
 
+    #[test]
+    fn test_interactive_reserve_rounds_up() {
+        let config = SpawnBudgetConfig {
+            interactive_reserve_fraction: 0.2,
+            ..Default::default()
+        };
+        assert_eq!(config.interactive_reserve(10_000), 2000);
+        assert_eq!(config.interactive_reserve(3), 1);
+        assert_eq!(config.interactive_reserve(0), 0);
+
+        let none = SpawnBudgetConfig {
+            interactive_reserve_fraction: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(none.interactive_reserve(8), 0);
+    }
+
+    #[test]
+    fn test_sim_budget_overrun_reduces_cap() {
+        let mut controller = SimFrameBudget {
+            enabled: true,
+            budget_ms: 8.0,
+            ..Default::default()
+        };
+
+        // Sustained 16ms of simulation against an 8ms budget
+        let mut cap = 10_000;
+        for _ in 0..60 {
+            let next = controller.step(16.0, 1.0 / 60.0, cap);
+            assert!(next <= cap);
+            cap = next;
+        }
+        assert!(cap < 8000, "cap should shrink under overrun, got {cap}");
+        assert!(cap >= controller.min_active);
+
+        // Well under budget, the cap recovers but never passes the ceiling
+        for _ in 0..600 {
+            cap = controller.step(2.0, 1.0 / 60.0, cap);
+        }
+        assert_eq!(cap, controller.ceiling_active);
+        assert_eq!(controller.spawn_limit(cap), 500);
+    }
+
     #[test]
     fn test_act_timings_boundaries() {
         let timings = ActTimings::default();
@@ -683,6 +2479,78 @@ mod tests {
         assert_eq!(timings.act_boundaries_seconds[5], 900.0);
     }
 
+    #[test]
+    fn test_short_length_keeps_act_proportions() {
+        let full = ActTimings::default();
+        let short = ActTimings::for_length(ExperienceLength::Short);
+        assert_eq!(short.length, ExperienceLength::Short);
+
+        let durations = |timings: &ActTimings| {
+            let b = timings.act_boundaries_seconds;
+            [
+                b[1] - b[0],
+                b[2] - b[1],
+                b[3] - b[2],
+                b[4] - b[3],
+                b[5] - b[4],
+            ]
+        };
+        let short_durations = durations(&short);
+        assert!((short_durations.iter().sum::<f32>() - 300.0).abs() < 1e-3);
+        for (short_act, full_act) in short_durations.iter().zip(durations(&full)) {
+            assert!((short_act * 3.0 - full_act).abs() < 1e-3);
+        }
+
+        // Act lookup and progress follow the rescaled boundaries
+        assert_eq!(short.act_at(70.0), Act::Accumulation);
+        assert_eq!(short.act_at(299.0), Act::Transcendence);
+        assert_eq!(short.progress(150.0), 0.5);
+
+        // Custom lengths are validated; Full restores the defaults
+        assert_eq!(ExperienceLength::Custom(5.0).total_seconds(), 60.0);
+        assert_eq!(ExperienceLength::Custom(f32::NAN).total_seconds(), 900.0);
+        let mut restored = short;
+        restored.set_length(ExperienceLength::Full);
+        assert_eq!(restored.act_boundaries_seconds, full.act_boundaries_seconds);
+    }
+
+    #[test]
+    fn test_scaled_timings_map_acts_proportionally() {
+        let demo = ActTimings::scaled(120.0);
+        assert_eq!(demo.total_seconds(), 120.0);
+        assert_eq!(demo.length, ExperienceLength::Custom(120.0));
+
+        // 120 / 900 of each full boundary: [0, 24, 56, 80, 104, 120]
+        let expected = [0.0, 24.0, 56.0, 80.0, 104.0, 120.0];
+        for (boundary, want) in demo.act_boundaries_seconds.iter().zip(expected) {
+            assert!((boundary - want).abs() < 1e-3, "{boundary} != {want}");
+        }
+
+        assert_eq!(demo.act_at(12.0), Act::Emergence);
+        assert_eq!(demo.act_at(30.0), Act::Accumulation);
+        assert_eq!(demo.act_at(60.0), Act::Crescendo);
+        assert_eq!(demo.act_at(90.0), Act::Release);
+        assert_eq!(demo.act_at(110.0), Act::Transcendence);
+        assert_eq!(demo.progress(60.0), 0.5);
+    }
+
+    #[test]
+    fn test_paint_color_hue_steps_wrap_around() {
+        let fallback = Color::hsl(200.0, 0.8, 0.5);
+        let mut color_override = PaintColorOverride::default();
+
+        color_override.step_hue(1.0, fallback);
+        let first = Hsla::from(color_override.color.unwrap());
+        assert!((first.hue - 230.0).abs() < 0.5);
+
+        // A full turn of steps lands back on the same hue
+        for _ in 0..12 {
+            color_override.step_hue(1.0, fallback);
+        }
+        let wrapped = Hsla::from(color_override.color.unwrap());
+        assert!((wrapped.hue - first.hue).abs() < 0.5);
+    }
+
     #[test]
     fn test_color_palette_valid_colors() {
         let palette = ColorPalette::default();
@@ -698,6 +2566,67 @@ mod tests {
         check_color(palette.primary_final);
     }
 
+    #[test]
+    fn test_generated_palette_is_seed_deterministic() {
+        for scheme in [
+            PaletteScheme::Analogous,
+            PaletteScheme::Complementary,
+            PaletteScheme::Triadic,
+        ] {
+            assert_eq!(
+                ColorPalette::generate(42, scheme),
+                ColorPalette::generate(42, scheme)
+            );
+            assert_ne!(
+                ColorPalette::generate(42, scheme),
+                ColorPalette::generate(43, scheme)
+            );
+            assert_eq!(
+                BackgroundGradients::generate(7, scheme),
+                BackgroundGradients::generate(7, scheme)
+            );
+            assert_ne!(
+                BackgroundGradients::generate(7, scheme),
+                BackgroundGradients::generate(8, scheme)
+            );
+        }
+
+        // Generated palettes keep the dark-to-light arc
+        let palette = ColorPalette::generate(99, PaletteScheme::Triadic);
+        let lightness = |color: Color| Hsla::from(color).lightness;
+        assert!(lightness(palette.primary_initial) < lightness(palette.primary_midpoint));
+        assert!(lightness(palette.primary_midpoint) < lightness(palette.primary_final));
+    }
+
+    #[test]
+    fn test_palette_presets_are_valid_srgb() {
+        for name in ColorPalette::PRESET_NAMES {
+            let palette = ColorPalette::preset(name).unwrap();
+            for color in palette.colors() {
+                let rgba = color.to_srgba();
+                for component in [rgba.red, rgba.green, rgba.blue, rgba.alpha] {
+                    assert!((0.0..=1.0).contains(&component), "{name}: {rgba:?}");
+                }
+            }
+            // Every preset keeps the dark-to-light arc
+            let lightness = |color: Color| Hsla::from(color).lightness;
+            assert!(lightness(palette.primary_initial) < lightness(palette.primary_final));
+            assert_eq!(ColorPalette::preset(&name.to_uppercase()), Some(palette));
+        }
+
+        assert_eq!(
+            ColorPalette::preset("Default"),
+            Some(ColorPalette::default())
+        );
+        assert_eq!(ColorPalette::preset("Plaid"), None);
+        let coral = ColorPalette::from_hex(["#ff6b6b"; 9]);
+        assert!(coral
+            .colors()
+            .iter()
+            .all(|color| *color == coral.accent_spark));
+        assert!((coral.accent_spark.to_srgba().red - 1.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_particle_pool_capacity() {
         let pool = ParticlePool::default();
@@ -706,6 +2635,27 @@ mod tests {
         assert!(pool.max_active <= pool.pool_capacity);
     }
 
+    #[test]
+    fn test_active_cap_is_lowest_ceiling() {
+        let mut pool = ParticlePool::default();
+        assert_eq!(pool.active_cap(), pool.max_active);
+
+        pool.ceilings.adaptive_quality = Some(8000);
+        pool.ceilings.simulation_budget = Some(6000);
+        assert_eq!(pool.active_cap(), 6000);
+
+        // A controller restoring its own ceiling cannot lift another's
+        pool.ceilings.adaptive_quality = Some(10_000);
+        assert_eq!(pool.active_cap(), 6000);
+
+        // Ceilings above the configured cap never raise it
+        pool.ceilings = ActiveCeilings {
+            frame_stall: Some(20_000),
+            ..Default::default()
+        };
+        assert_eq!(pool.active_cap(), pool.max_active);
+    }
+
     #[test]
     fn test_audio_visual_mapping_ranges() {
         let mapping = AudioVisualMapping::default();
@@ -721,4 +2671,85 @@ mod tests {
         assert!(config.base_radius <= config.max_radius);
         assert!(config.current_radius >= config.base_radius);
     }
+
+    #[test]
+    fn test_radius_growth_varies_by_act() {
+        let config = InteractionConfig::default();
+        let profile = RadiusGrowthProfile::default();
+        let gather = profile.for_act(Act::Accumulation);
+        let gentle = profile.for_act(Act::Transcendence);
+
+        for accumulated in [5.0, 15.0, 30.0, 120.0] {
+            assert!(
+                gather.target_radius(&config, accumulated)
+                    > gentle.target_radius(&config, accumulated),
+                "{accumulated}s"
+            );
+        }
+        // Accumulation is fully grown well before Transcendence
+        let gather_max = gather.target_radius(&config, f32::MAX);
+        assert_eq!(gather.target_radius(&config, 30.0), gather_max);
+        let gentle_max = gentle.target_radius(&config, f32::MAX);
+        assert!(gentle.target_radius(&config, 30.0) < gentle_max);
+        assert!(gather_max > config.max_radius);
+    }
+
+    #[test]
+    fn test_radius_growth_blends_through_transition() {
+        let profile = RadiusGrowthProfile::default();
+        let mut act_state = ActState {
+            current_act: Act::Accumulation,
+            ..Default::default()
+        };
+        assert_eq!(
+            profile.current(&act_state),
+            profile.for_act(Act::Accumulation)
+        );
+
+        act_state.is_transitioning = true;
+        act_state.transition_from = Some(Act::Emergence);
+        act_state.transition_progress = 0.0;
+        assert_eq!(profile.current(&act_state), profile.for_act(Act::Emergence));
+
+        act_state.transition_progress = 0.5;
+        let halfway = profile.current(&act_state);
+        let from = profile.for_act(Act::Emergence);
+        let to = profile.for_act(Act::Accumulation);
+        assert!(halfway.growth_seconds < from.growth_seconds);
+        assert!(halfway.growth_seconds > to.growth_seconds);
+    }
+
+    #[test]
+    fn test_drag_scale_composes_with_base_drag() {
+        let dt = 1.0 / 60.0;
+        let behaviors = [
+            ParticleBehaviorType::Drift,
+            ParticleBehaviorType::Swarm,
+            ParticleBehaviorType::Orbit,
+            ParticleBehaviorType::Disperse,
+            ParticleBehaviorType::Float,
+            ParticleBehaviorType::Flow,
+        ];
+        for behavior in behaviors {
+            let drag = behavior.base_drag();
+            let unscaled = PhysicsProfile::default().drag_retention(drag, dt);
+            assert!((unscaled - drag).abs() < 1e-6, "{behavior:?}");
+
+            let doubled = PhysicsProfile {
+                drag_scale: 2.0,
+                ..Default::default()
+            };
+            assert!((doubled.drag_retention(drag, dt) - drag * drag).abs() < 1e-6);
+
+            // Two half-length steps keep the same velocity as one full step
+            let half = doubled.drag_retention(drag, dt / 2.0);
+            assert!((half * half - doubled.drag_retention(drag, dt)).abs() < 1e-6);
+
+            let no_drag = PhysicsProfile {
+                drag_scale: 0.0,
+                ..Default::default()
+            };
+            assert_eq!(no_drag.drag_retention(drag, dt), 1.0);
+        }
+    }
 }
@@ -0,0 +1,991 @@
+//! Module: screen_pass
+//! Purpose: Full-screen post-process render passes driven by the post_process settings
+//! Dependencies: post_process, bevy::render, bevy::core_pipeline
+
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+use bevy::core_pipeline::fullscreen_vertex_shader::fullscreen_shader_vertex_state;
+use bevy::ecs::query::QueryItem;
+use bevy::image::BevyDefault;
+use bevy::prelude::*;
+use bevy::render::extract_resource::ExtractResourcePlugin;
+use bevy::render::globals::{GlobalsBuffer, GlobalsUniform};
+use bevy::render::render_graph::{
+    NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
+};
+use bevy::render::render_resource::binding_types::{sampler, texture_2d, uniform_buffer};
+use bevy::render::render_resource::{
+    BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, BindingResource,
+    CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState, MultisampleState,
+    Operations, PipelineCache, PrimitiveState, RenderPassColorAttachment, RenderPassDescriptor,
+    RenderPipelineDescriptor, Sampler, SamplerBindingType, SamplerDescriptor, ShaderStages,
+    ShaderType, TextureFormat, TextureSampleType, UniformBuffer,
+};
+use bevy::render::renderer::{RenderContext, RenderDevice, RenderQueue};
+use bevy::render::view::ViewTarget;
+use bevy::render::{Render, RenderApp, RenderSet};
+
+use crate::post_process::{
+    ChromaticAberrationSettings, FilmGrainSettings, FocusBlurSettings, VignetteSettings,
+    MAX_CHROMATIC_ABERRATION,
+};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Asset path of the vignette fragment shader.
+const VIGNETTE_SHADER_PATH: &str = "shaders/vignette.wgsl";
+
+/// Asset path of the chromatic aberration fragment shader.
+const CHROMATIC_ABERRATION_SHADER_PATH: &str = "shaders/chromatic_aberration.wgsl";
+
+/// Asset path of the film grain fragment shader.
+const FILM_GRAIN_SHADER_PATH: &str = "shaders/film_grain.wgsl";
+
+/// Asset path of the focus blur fragment shader.
+const FOCUS_BLUR_SHADER_PATH: &str = "shaders/focus_blur.wgsl";
+
+/// Intensity below which the vignette pass is skipped entirely.
+const MIN_VIGNETTE_INTENSITY: f32 = 0.001;
+
+/// Strength below which the chromatic aberration pass is skipped entirely.
+const MIN_CHROMATIC_ABERRATION_STRENGTH: f32 = 0.0001;
+
+/// Channel offset at the screen corners, in physical pixels, at `MAX_CHROMATIC_ABERRATION`.
+const MAX_CHROMATIC_ABERRATION_PIXELS: f32 = 12.0;
+
+/// Amount below which the film grain pass is skipped entirely.
+const MIN_FILM_GRAIN_AMOUNT: f32 = 0.001;
+
+/// Blur radius (UV units) below which the focus blur pass is skipped entirely.
+const MIN_FOCUS_BLUR_STRENGTH: f32 = 0.0002;
+
+// =============================================================================
+// UNIFORMS
+// =============================================================================
+
+/// Uniform block for `assets/shaders/vignette.wgsl`.
+///
+/// Field order and types mirror `VignetteUniform` in the shader. The color is
+/// leading so the two scalars pack into the `vec3`'s trailing slot and the
+/// next 16-byte row.
+#[derive(ShaderType, Debug, Clone, Copy, PartialEq, Default)]
+pub struct VignetteUniform {
+    /// Linear RGB the edges fade toward
+    pub color: Vec3,
+    /// Darkness at the screen corners (0.0 to 1.0)
+    pub intensity: f32,
+    /// Edge falloff (higher = softer)
+    pub smoothness: f32,
+}
+
+impl VignetteUniform {
+    /// Packs `VignetteSettings` into the shader uniform layout.
+    ///
+    /// A disabled vignette packs a zero intensity so a stale buffer is inert.
+    #[must_use]
+    pub fn from_settings(settings: &VignetteSettings) -> Self {
+        let edge = settings.vignette_color.to_linear();
+        Self {
+            color: Vec3::new(edge.red, edge.green, edge.blue),
+            intensity: if settings.enabled {
+                settings.intensity
+            } else {
+                0.0
+            },
+            smoothness: settings.smoothness,
+        }
+    }
+}
+
+/// Uniform block for `assets/shaders/chromatic_aberration.wgsl`.
+///
+/// `strength` is the red/blue channel offset at the screen corners in
+/// physical pixels; the shader divides by the screen size, so the fringe is
+/// equally wide at any resolution. `MAX_CHROMATIC_ABERRATION` maps to
+/// `MAX_CHROMATIC_ABERRATION_PIXELS`. Padded to one 16-byte row.
+#[derive(ShaderType, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ChromaticAberrationUniform {
+    /// Channel offset at the corners in physical pixels
+    pub strength: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_a: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_b: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_c: f32,
+}
+
+impl ChromaticAberrationUniform {
+    /// Packs `ChromaticAberrationSettings` into the shader uniform layout.
+    #[must_use]
+    pub fn from_settings(settings: &ChromaticAberrationSettings) -> Self {
+        Self {
+            strength: if settings.enabled {
+                settings.strength / MAX_CHROMATIC_ABERRATION * MAX_CHROMATIC_ABERRATION_PIXELS
+            } else {
+                0.0
+            },
+            ..default()
+        }
+    }
+}
+
+/// Uniform block for `assets/shaders/film_grain.wgsl`.
+///
+/// `amount` is the peak noise added to every channel of the linear scene
+/// color, ahead of tonemapping. Padded to one 16-byte row.
+#[derive(ShaderType, Debug, Clone, Copy, PartialEq, Default)]
+pub struct FilmGrainUniform {
+    /// Peak grain offset per pixel
+    pub amount: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_a: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_b: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_c: f32,
+}
+
+impl FilmGrainUniform {
+    /// Packs `FilmGrainSettings` into the shader uniform layout.
+    #[must_use]
+    pub fn from_settings(settings: &FilmGrainSettings) -> Self {
+        Self {
+            amount: if settings.enabled {
+                settings.amount
+            } else {
+                0.0
+            },
+            ..default()
+        }
+    }
+}
+
+/// Uniform block for `assets/shaders/focus_blur.wgsl`.
+///
+/// `focus` is the cursor in screen UV; distances are measured in
+/// screen-height units, with `aspect` stretching x so the sharp region is
+/// round. Padded to two 16-byte rows.
+#[derive(ShaderType, Debug, Clone, Copy, PartialEq, Default)]
+pub struct FocusBlurUniform {
+    /// Interaction center in screen UV
+    pub focus: Vec2,
+    /// Blur radius at full defocus in UV units
+    pub strength: f32,
+    /// Distance from the focus that stays fully sharp
+    pub sharp_radius: f32,
+    /// Distance over which the blur ramps up to `strength`
+    pub falloff: f32,
+    /// Viewport width over height
+    pub aspect: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_a: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_b: f32,
+}
+
+impl FocusBlurUniform {
+    /// Packs `FocusBlurSettings` into the shader uniform layout.
+    ///
+    /// A disabled blur packs a zero strength so a stale buffer is inert.
+    #[must_use]
+    pub fn from_settings(settings: &FocusBlurSettings) -> Self {
+        Self {
+            focus: settings.focus,
+            strength: if settings.enabled {
+                settings.strength
+            } else {
+                0.0
+            },
+            sharp_radius: settings.sharp_radius,
+            falloff: settings.falloff.max(0.001),
+            aspect: settings.aspect.max(0.001),
+            ..default()
+        }
+    }
+}
+
+/// Returns whether the vignette pass should draw this frame.
+#[inline]
+#[must_use]
+pub fn vignette_pass_active(settings: &VignetteSettings) -> bool {
+    settings.enabled && settings.intensity > MIN_VIGNETTE_INTENSITY
+}
+
+/// Returns whether the chromatic aberration pass should draw this frame.
+///
+/// Acts without aberration skip the full-screen pass instead of drawing a
+/// zero offset.
+#[inline]
+#[must_use]
+pub fn chromatic_aberration_pass_active(settings: &ChromaticAberrationSettings) -> bool {
+    settings.enabled && settings.strength >= MIN_CHROMATIC_ABERRATION_STRENGTH
+}
+
+/// Returns whether the film grain pass should draw this frame.
+#[inline]
+#[must_use]
+pub fn film_grain_pass_active(settings: &FilmGrainSettings) -> bool {
+    settings.enabled && settings.amount >= MIN_FILM_GRAIN_AMOUNT
+}
+
+/// Returns whether the focus blur pass should draw this frame.
+///
+/// Acts without focus blur skip the full-screen pass and its 16 taps per
+/// pixel instead of drawing a zero radius.
+#[inline]
+#[must_use]
+pub fn focus_blur_pass_active(settings: &FocusBlurSettings) -> bool {
+    settings.enabled && settings.strength >= MIN_FOCUS_BLUR_STRENGTH
+}
+
+// =============================================================================
+// RENDER WORLD RESOURCES
+// =============================================================================
+
+/// GPU buffer holding the current `VignetteUniform`.
+#[derive(Resource, Default)]
+pub struct VignetteUniformBuffer(pub UniformBuffer<VignetteUniform>);
+
+/// GPU buffer holding the current `ChromaticAberrationUniform`.
+#[derive(Resource, Default)]
+pub struct ChromaticAberrationUniformBuffer(pub UniformBuffer<ChromaticAberrationUniform>);
+
+/// GPU buffer holding the current `FilmGrainUniform`.
+#[derive(Resource, Default)]
+pub struct FilmGrainUniformBuffer(pub UniformBuffer<FilmGrainUniform>);
+
+/// GPU buffer holding the current `FocusBlurUniform`.
+#[derive(Resource, Default)]
+pub struct FocusBlurUniformBuffer(pub UniformBuffer<FocusBlurUniform>);
+
+/// Bind group layout, sampler, and cached pipelines for one full-screen pass.
+///
+/// Every pass binds the screen texture, a sampler, its own uniform, and
+/// Bevy's `Globals` (for shaders that animate with `globals.time`). One
+/// pipeline is queued per view target format, since the camera may or may
+/// not be HDR.
+pub struct ScreenPassPipeline {
+    label: &'static str,
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    sdr_pipeline: CachedRenderPipelineId,
+    hdr_pipeline: CachedRenderPipelineId,
+}
+
+impl ScreenPassPipeline {
+    /// Builds the layout for uniform `U` and queues both pipelines for `shader_path`.
+    fn new<U: ShaderType>(world: &World, label: &'static str, shader_path: &'static str) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let layout = render_device.create_bind_group_layout(
+            label,
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                    uniform_buffer::<U>(false),
+                    uniform_buffer::<GlobalsUniform>(false),
+                ),
+            ),
+        );
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+        let shader = world.resource::<AssetServer>().load(shader_path);
+
+        let descriptor = |format: TextureFormat| RenderPipelineDescriptor {
+            label: Some(label.into()),
+            layout: vec![layout.clone()],
+            push_constant_ranges: Vec::new(),
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: shader.clone(),
+                shader_defs: Vec::new(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            zero_initialize_workgroup_memory: false,
+        };
+
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let sdr_pipeline =
+            pipeline_cache.queue_render_pipeline(descriptor(TextureFormat::bevy_default()));
+        let hdr_pipeline =
+            pipeline_cache.queue_render_pipeline(descriptor(ViewTarget::TEXTURE_FORMAT_HDR));
+
+        Self {
+            label,
+            layout,
+            sampler,
+            sdr_pipeline,
+            hdr_pipeline,
+        }
+    }
+
+    /// Draws the pass over `view_target`, reading its current output and
+    /// writing through the post-process ping-pong.
+    ///
+    /// Does nothing while the shader is still compiling or before the
+    /// uniform has been uploaded.
+    fn draw(
+        &self,
+        render_context: &mut RenderContext,
+        view_target: &ViewTarget,
+        world: &World,
+        uniform_binding: Option<BindingResource>,
+    ) {
+        let pipeline_id = if view_target.is_hdr() {
+            self.hdr_pipeline
+        } else {
+            self.sdr_pipeline
+        };
+        let Some(pipeline) = world
+            .resource::<PipelineCache>()
+            .get_render_pipeline(pipeline_id)
+        else {
+            return;
+        };
+        let Some(uniform_binding) = uniform_binding else {
+            return;
+        };
+        let Some(globals_binding) = world.resource::<GlobalsBuffer>().buffer.binding() else {
+            return;
+        };
+
+        let post_process = view_target.post_process_write();
+        let bind_group = render_context.render_device().create_bind_group(
+            self.label,
+            &self.layout,
+            &BindGroupEntries::sequential((
+                post_process.source,
+                &self.sampler,
+                uniform_binding,
+                globals_binding,
+            )),
+        );
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some(self.label),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+/// Pipelines for every full-screen post-process pass.
+#[derive(Resource)]
+pub struct ScreenPassPipelines {
+    /// Radial RGB channel separation
+    pub chromatic_aberration: ScreenPassPipeline,
+    /// Animated luminance noise
+    pub film_grain: ScreenPassPipeline,
+    /// Defocus away from the interaction center
+    pub focus_blur: ScreenPassPipeline,
+    /// Edge darkening
+    pub vignette: ScreenPassPipeline,
+}
+
+impl FromWorld for ScreenPassPipelines {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            chromatic_aberration: ScreenPassPipeline::new::<ChromaticAberrationUniform>(
+                world,
+                "chromatic_aberration_pass",
+                CHROMATIC_ABERRATION_SHADER_PATH,
+            ),
+            film_grain: ScreenPassPipeline::new::<FilmGrainUniform>(
+                world,
+                "film_grain_pass",
+                FILM_GRAIN_SHADER_PATH,
+            ),
+            focus_blur: ScreenPassPipeline::new::<FocusBlurUniform>(
+                world,
+                "focus_blur_pass",
+                FOCUS_BLUR_SHADER_PATH,
+            ),
+            vignette: ScreenPassPipeline::new::<VignetteUniform>(
+                world,
+                "vignette_pass",
+                VIGNETTE_SHADER_PATH,
+            ),
+        }
+    }
+}
+
+// =============================================================================
+// RENDER GRAPH
+// =============================================================================
+
+/// Render graph label for the chromatic aberration pass.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct ChromaticAberrationLabel;
+
+/// Render graph label for the film grain pass.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct FilmGrainLabel;
+
+/// Render graph label for the focus blur pass.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct FocusBlurLabel;
+
+/// Render graph label for the vignette pass.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct VignetteLabel;
+
+/// Full-screen pass that splits red and blue outward from the center.
+///
+/// Skipped entirely while `chromatic_aberration_pass_active` is false.
+#[derive(Default)]
+pub struct ChromaticAberrationNode;
+
+impl ViewNode for ChromaticAberrationNode {
+    type ViewQuery = &'static ViewTarget;
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        view_target: QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let Some(settings) = world.get_resource::<ChromaticAberrationSettings>() else {
+            return Ok(());
+        };
+        if !chromatic_aberration_pass_active(settings) {
+            return Ok(());
+        }
+
+        let binding = world
+            .resource::<ChromaticAberrationUniformBuffer>()
+            .0
+            .binding();
+        world
+            .resource::<ScreenPassPipelines>()
+            .chromatic_aberration
+            .draw(render_context, view_target, world, binding);
+
+        Ok(())
+    }
+}
+
+/// Full-screen pass that overlays per-pixel luminance noise, re-seeded each frame.
+///
+/// Skipped entirely while `film_grain_pass_active` is false.
+#[derive(Default)]
+pub struct FilmGrainNode;
+
+impl ViewNode for FilmGrainNode {
+    type ViewQuery = &'static ViewTarget;
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        view_target: QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let Some(settings) = world.get_resource::<FilmGrainSettings>() else {
+            return Ok(());
+        };
+        if !film_grain_pass_active(settings) {
+            return Ok(());
+        }
+
+        let binding = world.resource::<FilmGrainUniformBuffer>().0.binding();
+        world.resource::<ScreenPassPipelines>().film_grain.draw(
+            render_context,
+            view_target,
+            world,
+            binding,
+        );
+
+        Ok(())
+    }
+}
+
+/// Full-screen pass that blurs the view more the farther it is from the cursor.
+///
+/// Skipped entirely while `focus_blur_pass_active` is false.
+#[derive(Default)]
+pub struct FocusBlurNode;
+
+impl ViewNode for FocusBlurNode {
+    type ViewQuery = &'static ViewTarget;
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        view_target: QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let Some(settings) = world.get_resource::<FocusBlurSettings>() else {
+            return Ok(());
+        };
+        if !focus_blur_pass_active(settings) {
+            return Ok(());
+        }
+
+        let binding = world.resource::<FocusBlurUniformBuffer>().0.binding();
+        world.resource::<ScreenPassPipelines>().focus_blur.draw(
+            render_context,
+            view_target,
+            world,
+            binding,
+        );
+
+        Ok(())
+    }
+}
+
+/// Full-screen pass that darkens the view toward its edges.
+///
+/// Reads the main 2D pass output (after bloom) and writes the vignetted
+/// image back through the view target's post-process ping-pong.
+#[derive(Default)]
+pub struct VignetteNode;
+
+impl ViewNode for VignetteNode {
+    type ViewQuery = &'static ViewTarget;
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        view_target: QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let Some(settings) = world.get_resource::<VignetteSettings>() else {
+            return Ok(());
+        };
+        if !vignette_pass_active(settings) {
+            return Ok(());
+        }
+
+        let binding = world.resource::<VignetteUniformBuffer>().0.binding();
+        world.resource::<ScreenPassPipelines>().vignette.draw(
+            render_context,
+            view_target,
+            world,
+            binding,
+        );
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Uploads the extracted `ChromaticAberrationSettings` into its uniform buffer.
+///
+/// # Stage
+/// Render (`RenderSet::PrepareResources`)
+///
+/// # Ordering
+/// Runs after `ChromaticAberrationSettings` is extracted; the buffer is
+/// rewritten only when the settings changed.
+pub fn prepare_chromatic_aberration_uniform(
+    settings: Res<ChromaticAberrationSettings>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut buffer: ResMut<ChromaticAberrationUniformBuffer>,
+) {
+    if !settings.is_changed() && buffer.0.buffer().is_some() {
+        return;
+    }
+
+    buffer
+        .0
+        .set(ChromaticAberrationUniform::from_settings(&settings));
+    buffer.0.write_buffer(&render_device, &render_queue);
+}
+
+/// Uploads the extracted `FilmGrainSettings` into its uniform buffer.
+///
+/// The per-frame seed comes from `globals.time`, so the buffer is rewritten
+/// only when the settings changed.
+///
+/// # Stage
+/// Render (`RenderSet::PrepareResources`)
+pub fn prepare_film_grain_uniform(
+    settings: Res<FilmGrainSettings>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut buffer: ResMut<FilmGrainUniformBuffer>,
+) {
+    if !settings.is_changed() && buffer.0.buffer().is_some() {
+        return;
+    }
+
+    buffer.0.set(FilmGrainUniform::from_settings(&settings));
+    buffer.0.write_buffer(&render_device, &render_queue);
+}
+
+/// Uploads the extracted `FocusBlurSettings` into its uniform buffer.
+///
+/// # Stage
+/// Render (`RenderSet::PrepareResources`)
+///
+/// # Ordering
+/// Runs after `FocusBlurSettings` is extracted; the buffer is rewritten only
+/// when the settings (including the cursor position) changed.
+pub fn prepare_focus_blur_uniform(
+    settings: Res<FocusBlurSettings>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut buffer: ResMut<FocusBlurUniformBuffer>,
+) {
+    if !settings.is_changed() && buffer.0.buffer().is_some() {
+        return;
+    }
+
+    buffer.0.set(FocusBlurUniform::from_settings(&settings));
+    buffer.0.write_buffer(&render_device, &render_queue);
+}
+
+/// Uploads the extracted `VignetteSettings` into the vignette uniform buffer.
+///
+/// # Stage
+/// Render (`RenderSet::PrepareResources`)
+///
+/// # Ordering
+/// Runs after `VignetteSettings` is extracted; the buffer is rewritten only
+/// when the settings changed.
+pub fn prepare_vignette_uniform(
+    settings: Res<VignetteSettings>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut buffer: ResMut<VignetteUniformBuffer>,
+) {
+    if !settings.is_changed() && buffer.0.buffer().is_some() {
+        return;
+    }
+
+    buffer.0.set(VignetteUniform::from_settings(&settings));
+    buffer.0.write_buffer(&render_device, &render_queue);
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that wires the full-screen post-process passes into the 2D render graph.
+///
+/// # Render Graph (Core2d)
+/// `EndMainPass` -> `FocusBlurLabel` -> `Bloom`
+///
+/// `Bloom` -> `ChromaticAberrationLabel` -> `VignetteLabel` -> `FilmGrainLabel`
+/// -> `Tonemapping`
+///
+/// Focus blur runs on the finished particle field before bloom, so
+/// out-of-focus peas glow as soft discs instead of sharp points with a blurred
+/// halo, and the bloom itself is never blurred twice. The other passes run
+/// after the main 2D pass and bloom, so glow near the edges is fringed and
+/// darkened too, and before tonemapping so they work in scene-linear color.
+/// Aberration comes first, as it does in a real lens; grain comes last so it
+/// sits on top of everything, as it would on film.
+///
+/// # Systems
+///
+/// ## Render
+/// - `prepare_chromatic_aberration_uniform`: Uploads `ChromaticAberrationSettings`
+/// - `prepare_film_grain_uniform`: Uploads `FilmGrainSettings`
+/// - `prepare_focus_blur_uniform`: Uploads `FocusBlurSettings`
+/// - `prepare_vignette_uniform`: Uploads `VignetteSettings` to the GPU
+///
+/// Without a `RenderApp` (headless tests) the plugin only registers extraction.
+pub struct ScreenPassPlugin;
+
+impl Plugin for ScreenPassPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ExtractResourcePlugin::<ChromaticAberrationSettings>::default(),
+            ExtractResourcePlugin::<FilmGrainSettings>::default(),
+            ExtractResourcePlugin::<FocusBlurSettings>::default(),
+            ExtractResourcePlugin::<VignetteSettings>::default(),
+        ));
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .init_resource::<ChromaticAberrationUniformBuffer>()
+            .init_resource::<FilmGrainUniformBuffer>()
+            .init_resource::<FocusBlurUniformBuffer>()
+            .init_resource::<VignetteUniformBuffer>()
+            .add_systems(
+                Render,
+                (
+                    prepare_chromatic_aberration_uniform
+                        .run_if(resource_exists::<ChromaticAberrationSettings>),
+                    prepare_film_grain_uniform.run_if(resource_exists::<FilmGrainSettings>),
+                    prepare_focus_blur_uniform.run_if(resource_exists::<FocusBlurSettings>),
+                    prepare_vignette_uniform.run_if(resource_exists::<VignetteSettings>),
+                )
+                    .in_set(RenderSet::PrepareResources),
+            )
+            .add_render_graph_node::<ViewNodeRunner<ChromaticAberrationNode>>(
+                Core2d,
+                ChromaticAberrationLabel,
+            )
+            .add_render_graph_node::<ViewNodeRunner<VignetteNode>>(Core2d, VignetteLabel)
+            .add_render_graph_node::<ViewNodeRunner<FilmGrainNode>>(Core2d, FilmGrainLabel)
+            .add_render_graph_node::<ViewNodeRunner<FocusBlurNode>>(Core2d, FocusBlurLabel)
+            .add_render_graph_edges(Core2d, (Node2d::EndMainPass, FocusBlurLabel, Node2d::Bloom))
+            .add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Bloom,
+                    ChromaticAberrationLabel,
+                    VignetteLabel,
+                    FilmGrainLabel,
+                    Node2d::Tonemapping,
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<ScreenPassPipelines>();
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::render_resource::encase;
+
+    #[cfg(feature = "acts")]
+    use crate::post_process::{update_chromatic_aberration, update_focus_blur};
+    use crate::post_process::{update_film_grain, update_vignette};
+    #[cfg(feature = "acts")]
+    use crate::resources::MouseState;
+    use crate::resources::PostProcessSettings;
+
+    fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+        f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_vignette_uniform_matches_wgsl_layout() {
+        // WGSL: vec3<f32> at 0 (align 16), f32 at 12, f32 at 16, struct
+        // size rounded up to its 16-byte alignment.
+        assert_eq!(VignetteUniform::min_size().get(), 32);
+
+        let uniform = VignetteUniform {
+            color: Vec3::new(0.1, 0.2, 0.3),
+            intensity: 0.45,
+            smoothness: 0.7,
+        };
+        let mut buffer = encase::UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&uniform).unwrap();
+        let bytes = buffer.into_inner();
+
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(read_f32(&bytes, 0), 0.1);
+        assert_eq!(read_f32(&bytes, 4), 0.2);
+        assert_eq!(read_f32(&bytes, 8), 0.3);
+        assert_eq!(read_f32(&bytes, 12), 0.45);
+        assert_eq!(read_f32(&bytes, 16), 0.7);
+    }
+
+    #[test]
+    fn test_disabled_vignette_skips_pass() {
+        let mut settings = VignetteSettings::default();
+        assert!(vignette_pass_active(&settings));
+
+        settings.enabled = false;
+        assert!(!vignette_pass_active(&settings));
+        assert_eq!(VignetteUniform::from_settings(&settings).intensity, 0.0);
+
+        settings.enabled = true;
+        settings.intensity = 0.0;
+        assert!(!vignette_pass_active(&settings));
+    }
+
+    #[test]
+    fn test_uniform_intensity_follows_acts_down() {
+        let mut app = App::new();
+        app.init_resource::<PostProcessSettings>()
+            .init_resource::<VignetteSettings>()
+            .add_systems(Update, update_vignette);
+
+        let mut previous = f32::INFINITY;
+        for act_vignette in [0.5, 0.4, 0.35, 0.2, 0.05] {
+            app.world_mut()
+                .resource_mut::<PostProcessSettings>()
+                .vignette_intensity = act_vignette;
+            app.update();
+
+            let uniform =
+                VignetteUniform::from_settings(app.world().resource::<VignetteSettings>());
+            assert!(uniform.intensity < previous);
+            assert!((uniform.intensity - act_vignette).abs() < 1e-6);
+            previous = uniform.intensity;
+        }
+    }
+
+    #[test]
+    fn test_chromatic_aberration_uniform_is_one_row() {
+        assert_eq!(ChromaticAberrationUniform::min_size().get(), 16);
+    }
+
+    #[test]
+    fn test_zero_aberration_skips_pass() {
+        let mut settings = ChromaticAberrationSettings::default();
+        assert!(!chromatic_aberration_pass_active(&settings));
+
+        settings.strength = 0.008;
+        assert!(chromatic_aberration_pass_active(&settings));
+
+        settings.enabled = false;
+        assert!(!chromatic_aberration_pass_active(&settings));
+        assert_eq!(
+            ChromaticAberrationUniform::from_settings(&settings).strength,
+            0.0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "acts")]
+    fn test_crescendo_has_peak_fringing() {
+        use crate::act_management::ActScene;
+        use crate::types::Act;
+
+        let mut app = App::new();
+        app.init_resource::<PostProcessSettings>()
+            .init_resource::<ChromaticAberrationSettings>()
+            .add_systems(Update, update_chromatic_aberration);
+
+        let mut strengths = Vec::new();
+        for act_strength in ActScene::default().chromatic_aberration {
+            app.world_mut()
+                .resource_mut::<PostProcessSettings>()
+                .chromatic_aberration_strength = act_strength;
+            app.update();
+
+            let settings = app.world().resource::<ChromaticAberrationSettings>();
+            let active = chromatic_aberration_pass_active(settings);
+            assert_eq!(active, act_strength > 0.0);
+            strengths.push(ChromaticAberrationUniform::from_settings(settings).strength);
+        }
+
+        let peak = strengths.iter().cloned().fold(0.0, f32::max);
+        assert_eq!(strengths[Act::Crescendo.index()], peak);
+
+        // The peak fringe is a visible offset in pixels, never more than the cap
+        assert!((1.0..=MAX_CHROMATIC_ABERRATION_PIXELS).contains(&peak));
+    }
+
+    #[test]
+    fn test_film_grain_pass_follows_amount() {
+        let mut app = App::new();
+        app.init_resource::<PostProcessSettings>()
+            .init_resource::<FilmGrainSettings>()
+            .add_systems(Update, update_film_grain);
+        app.update();
+
+        let settings = app.world().resource::<FilmGrainSettings>();
+        assert!(film_grain_pass_active(settings));
+        assert_eq!(FilmGrainUniform::from_settings(settings).amount, 0.02);
+        assert_eq!(FilmGrainUniform::min_size().get(), 16);
+
+        app.world_mut()
+            .resource_mut::<PostProcessSettings>()
+            .film_grain_amount = 0.0;
+        app.update();
+
+        let settings = app.world().resource::<FilmGrainSettings>();
+        assert!(!film_grain_pass_active(settings));
+        assert_eq!(FilmGrainUniform::from_settings(settings).amount, 0.0);
+    }
+
+    #[test]
+    fn test_focus_blur_uniform_matches_wgsl_layout() {
+        // WGSL: vec2<f32> at 0, then six f32s, rounded up to 32 bytes
+        assert_eq!(FocusBlurUniform::min_size().get(), 32);
+
+        let settings = FocusBlurSettings {
+            strength: 0.01,
+            focus: Vec2::new(0.25, 0.75),
+            aspect: 16.0 / 9.0,
+            ..default()
+        };
+        let mut buffer = encase::UniformBuffer::new(Vec::<u8>::new());
+        buffer
+            .write(&FocusBlurUniform::from_settings(&settings))
+            .unwrap();
+        let bytes = buffer.into_inner();
+
+        assert_eq!(read_f32(&bytes, 0), 0.25);
+        assert_eq!(read_f32(&bytes, 4), 0.75);
+        assert_eq!(read_f32(&bytes, 8), 0.01);
+        assert_eq!(read_f32(&bytes, 20), 16.0 / 9.0);
+    }
+
+    #[test]
+    #[cfg(feature = "acts")]
+    fn test_focus_blur_only_draws_in_crescendo() {
+        use crate::act_management::ActScene;
+
+        let mut app = App::new();
+        app.init_resource::<PostProcessSettings>()
+            .init_resource::<MouseState>()
+            .init_resource::<FocusBlurSettings>()
+            .add_systems(Update, update_focus_blur);
+
+        let focus_blur = ActScene::default().focus_blur;
+        for (index, act_strength) in focus_blur.iter().enumerate() {
+            app.world_mut()
+                .resource_mut::<PostProcessSettings>()
+                .focus_blur_strength = *act_strength;
+            app.update();
+
+            let settings = app.world().resource::<FocusBlurSettings>();
+            let is_crescendo = index == crate::types::Act::Crescendo.index();
+            assert_eq!(
+                focus_blur_pass_active(settings),
+                is_crescendo,
+                "act {index}"
+            );
+        }
+
+        // A host that switches the pass off keeps it off
+        app.world_mut().resource_mut::<FocusBlurSettings>().enabled = false;
+        app.world_mut()
+            .resource_mut::<PostProcessSettings>()
+            .focus_blur_strength = focus_blur[crate::types::Act::Crescendo.index()];
+        app.update();
+        let settings = app.world().resource::<FocusBlurSettings>();
+        assert!(!settings.enabled);
+        assert!(!focus_blur_pass_active(settings));
+        assert_eq!(FocusBlurUniform::from_settings(settings).strength, 0.0);
+    }
+}
@@ -0,0 +1,294 @@
+//! Module: screenshot
+//! Purpose: Key-triggered, optionally supersampled PNG stills with post-processing
+//! Dependencies: components, interaction, bevy::render::view::screenshot, bevy::tasks
+//!
+//! Desktop only; the module is compiled out on Android.
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::core_pipeline::bloom::Bloom;
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::image::BevyDefault;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::{
+    Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+};
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::tasks::IoTaskPool;
+use bevy::window::PrimaryWindow;
+
+use crate::components::WhirledCamera;
+use crate::interaction::CaptureScreenshot;
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Largest supersample factor accepted from `ScreenshotConfig`.
+pub const MAX_SUPERSAMPLE: u32 = 4;
+
+/// Largest capture edge in pixels; larger supersampled targets are scaled down
+/// to fit, as most GPUs cap 2D textures at 8192.
+const MAX_CAPTURE_DIMENSION: u32 = 8192;
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Where screenshots are saved and at what resolution.
+#[derive(Resource, Debug, Clone)]
+pub struct ScreenshotConfig {
+    /// Directory PNGs are written to; created on first capture
+    pub output_dir: PathBuf,
+    /// Render scale relative to the window (1 captures the window itself)
+    pub supersample: u32,
+}
+
+impl Default for ScreenshotConfig {
+    fn default() -> Self {
+        Self {
+            output_dir: PathBuf::from("screenshots"),
+            supersample: 1,
+        }
+    }
+}
+
+// =============================================================================
+// COMPONENTS
+// =============================================================================
+
+/// Temporary camera rendering a supersampled capture; despawned once the
+/// capture has been read back.
+#[derive(Component, Debug)]
+pub struct SupersampleCamera;
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Returns the timestamped file name for a capture taken at `unix_millis`.
+#[must_use]
+pub fn screenshot_file_name(unix_millis: u128) -> String {
+    format!("whirled_peas_{unix_millis}.png")
+}
+
+/// Returns the capture size for a window of `window_size` pixels.
+///
+/// The factor is clamped to 1..=`MAX_SUPERSAMPLE`, then reduced until both
+/// edges fit within `MAX_CAPTURE_DIMENSION`.
+#[must_use]
+pub fn supersampled_size(window_size: UVec2, supersample: u32) -> UVec2 {
+    let largest_edge = window_size.max_element().max(1);
+    let fitting = (MAX_CAPTURE_DIMENSION / largest_edge).max(1);
+    window_size * supersample.clamp(1, MAX_SUPERSAMPLE).min(fitting)
+}
+
+/// Encodes `image` as an RGB PNG at `path`, creating its directory.
+pub(crate) fn write_png(image: Image, path: &Path) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|error| error.to_string())?;
+    }
+    let dynamic = image
+        .try_into_dynamic()
+        .map_err(|error| error.to_string())?;
+    dynamic
+        .to_rgb8()
+        .save(path)
+        .map_err(|error| error.to_string())
+}
+
+/// Observer that hands a captured frame to the IO task pool, so PNG encoding
+/// and the disk write never block a frame. Despawns `capture_camera` when set.
+fn save_capture(
+    path: PathBuf,
+    capture_camera: Option<Entity>,
+) -> impl FnMut(Trigger<ScreenshotCaptured>, Commands) {
+    move |trigger, mut commands| {
+        if let Some(camera) = capture_camera {
+            commands.entity(camera).despawn();
+        }
+
+        let image = trigger.event().0.clone();
+        let path = path.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                match write_png(image, &path) {
+                    Ok(()) => info!("Saved screenshot to {}", path.display()),
+                    Err(error) => warn!("Failed to save screenshot {}: {error}", path.display()),
+                }
+            })
+            .detach();
+    }
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// The main camera whose view `capture_screenshots` copies for supersampling.
+type SourceCameraQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Camera,
+        &'static OrthographicProjection,
+        &'static Transform,
+        Option<&'static Bloom>,
+        Option<&'static Tonemapping>,
+    ),
+    With<WhirledCamera>,
+>;
+
+/// Captures the frame on `CaptureScreenshot`.
+///
+/// At `supersample` 1 the primary window is captured as shown. Above that, a
+/// temporary camera copies the main camera (projection, bloom, tonemapping)
+/// and renders into an enlarged image target for one capture; the full-screen
+/// post-process passes run for every 2D view, so they apply to it too. UI is
+/// not part of supersampled stills.
+///
+/// The GPU readback is asynchronous and the PNG is written off-thread, so a
+/// capture does not stall the simulation.
+///
+/// # Stage
+/// Update
+pub fn capture_screenshots(
+    mut commands: Commands,
+    mut requests: EventReader<CaptureScreenshot>,
+    mut images: ResMut<Assets<Image>>,
+    config: Res<ScreenshotConfig>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: SourceCameraQuery,
+) {
+    // Several presses in one frame still make one still
+    if requests.read().count() == 0 {
+        return;
+    }
+
+    let unix_millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis());
+    let path = config.output_dir.join(screenshot_file_name(unix_millis));
+
+    let window_size = windows
+        .get_single()
+        .map_or(UVec2::ZERO, Window::physical_size);
+    let capture_size = supersampled_size(window_size, config.supersample);
+    let supersampling = capture_size != window_size;
+
+    let Ok((camera, projection, transform, bloom, tonemapping)) = cameras.get_single() else {
+        return;
+    };
+    if !supersampling {
+        commands
+            .spawn(Screenshot::primary_window())
+            .observe(save_capture(path, None));
+        return;
+    }
+
+    let size = Extent3d {
+        width: capture_size.x,
+        height: capture_size.y,
+        depth_or_array_layers: 1,
+    };
+    let mut target = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some("supersampled_screenshot"),
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::bevy_default(),
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_SRC
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    target.resize(size);
+    let target = images.add(target);
+
+    let mut capture_camera = commands.spawn((
+        Camera2d,
+        Camera {
+            target: RenderTarget::Image(target.clone()),
+            // Render before the window camera; the order only breaks ties
+            order: camera.order - 1,
+            ..camera.clone()
+        },
+        projection.clone(),
+        *transform,
+        SupersampleCamera,
+    ));
+    if let Some(bloom) = bloom {
+        capture_camera.insert(bloom.clone());
+    }
+    if let Some(tonemapping) = tonemapping {
+        capture_camera.insert(*tonemapping);
+    }
+    let capture_camera = capture_camera.id();
+
+    commands
+        .spawn(Screenshot::image(target))
+        .observe(save_capture(path, Some(capture_camera)));
+    info!(
+        "Capturing {}x{} screenshot ({}x supersampled)",
+        capture_size.x,
+        capture_size.y,
+        capture_size.x / window_size.x.max(1)
+    );
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Plugin that saves a PNG on `CaptureScreenshot` (F12).
+///
+/// # Systems
+/// - `capture_screenshots` (Update): Captures the window or a supersampled target
+pub struct ScreenshotPlugin;
+
+impl Plugin for ScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenshotConfig>()
+            .add_event::<CaptureScreenshot>()
+            .add_systems(Update, capture_screenshots);
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supersampled_size_clamps_factor_and_dimension() {
+        let window = UVec2::new(1920, 1080);
+        assert_eq!(supersampled_size(window, 1), window);
+        assert_eq!(supersampled_size(window, 0), window);
+        assert_eq!(supersampled_size(window, 2), UVec2::new(3840, 2160));
+        assert_eq!(supersampled_size(window, 99), UVec2::new(7680, 4320));
+
+        // A 4K window can only double before hitting the texture limit
+        assert_eq!(
+            supersampled_size(UVec2::new(3840, 2160), 4),
+            UVec2::new(7680, 4320)
+        );
+        assert_eq!(supersampled_size(UVec2::ZERO, 2), UVec2::ZERO);
+    }
+
+    #[test]
+    fn test_screenshot_names_are_timestamped_pngs() {
+        let earlier = screenshot_file_name(1_700_000_000_000);
+        let later = screenshot_file_name(1_700_000_000_001);
+        assert!(earlier.ends_with(".png"));
+        assert_ne!(earlier, later);
+    }
+}
@@ -0,0 +1,285 @@
+//! Module: snapshot
+//! Purpose: CPU-rasterized particle snapshots for thumbnails and headless use
+//! Dependencies: components, instancing, visual, bevy::prelude
+//!
+//! Renders the active particle set as soft blurred points into a Bevy
+//! `Image` without the GPU render pipeline or post-process chain. Quality is
+//! aimed at gallery thumbnails, not a faithful reproduction of the live frame.
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::components::{Particle, ParticleState};
+use crate::instancing::ParticleInstances;
+use crate::visual::{INITIAL_CLEAR_COLOR, VIEWPORT_HEIGHT, VIEWPORT_WIDTH};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Smallest splat radius in output pixels, so distant thumbnails keep every pea.
+const MIN_SPLAT_RADIUS_PX: f32 = 1.0;
+
+/// Splat falloff is cut off at this many standard deviations.
+const SPLAT_CUTOFF_SIGMAS: f32 = 3.0;
+
+// =============================================================================
+// TYPES
+// =============================================================================
+
+/// A single particle as seen by the snapshot compositor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapshotPoint {
+    /// World position (origin at screen center, y up)
+    pub position: Vec2,
+    /// Particle color including opacity
+    pub color: Color,
+    /// Particle diameter in world units
+    pub size: f32,
+}
+
+/// Output settings for `render_snapshot`.
+#[derive(Debug, Clone)]
+pub struct SnapshotConfig {
+    /// Output width in pixels
+    pub width: u32,
+    /// Output height in pixels
+    pub height: u32,
+    /// World-space area captured, centered on the origin
+    pub world_size: Vec2,
+    /// Color the image is cleared to before compositing
+    pub background: Color,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            width: 320,
+            height: 180,
+            world_size: Vec2::new(VIEWPORT_WIDTH, VIEWPORT_HEIGHT),
+            background: INITIAL_CLEAR_COLOR,
+        }
+    }
+}
+
+// =============================================================================
+// SNAPSHOT FUNCTIONS
+// =============================================================================
+
+/// Collects the active particles in `world` as snapshot points.
+///
+/// Reads position from `Transform` and color/size from `Sprite`, so the
+/// snapshot matches what `sync_sprite_visuals` last wrote. In
+/// `RenderMode::Instanced` the particles have no sprites, so the points come
+/// from `ParticleInstances` instead.
+pub fn collect_snapshot_points(world: &mut World) -> Vec<SnapshotPoint> {
+    if let Some(instances) = world.get_resource::<ParticleInstances>() {
+        return instances
+            .instances
+            .iter()
+            .map(|instance| SnapshotPoint {
+                position: instance.position.truncate(),
+                color: instance.color(),
+                size: instance.size,
+            })
+            .collect();
+    }
+
+    let mut query = world.query_filtered::<(&ParticleState, &Transform, &Sprite), With<Particle>>();
+
+    query
+        .iter(world)
+        .filter(|(state, _, _)| state.active)
+        .map(|(_, transform, sprite)| SnapshotPoint {
+            position: transform.translation.truncate(),
+            color: sprite.color,
+            size: sprite.custom_size.map_or(1.0, |size| size.max_element()),
+        })
+        .collect()
+}
+
+/// Rasterizes `points` into an sRGB RGBA8 image.
+///
+/// Each point is splatted as a Gaussian blob sized to its diameter and
+/// alpha-composited over the background in linear space, in slice order.
+#[must_use]
+pub fn render_snapshot(points: &[SnapshotPoint], config: &SnapshotConfig) -> Image {
+    let width = config.width.max(1);
+    let height = config.height.max(1);
+    let pixels_per_unit = Vec2::new(
+        width as f32 / config.world_size.x.max(f32::EPSILON),
+        height as f32 / config.world_size.y.max(f32::EPSILON),
+    );
+
+    let background = config.background.to_linear();
+    let background = Vec3::new(background.red, background.green, background.blue);
+    let mut buffer = vec![background; (width * height) as usize];
+
+    for point in points {
+        let color = point.color.to_linear();
+        if color.alpha <= 0.0 {
+            continue;
+        }
+        let rgb = Vec3::new(color.red, color.green, color.blue);
+
+        // World (centered, y up) to pixel (top-left origin, y down)
+        let center = Vec2::new(
+            (point.position.x + config.world_size.x * 0.5) * pixels_per_unit.x,
+            (config.world_size.y * 0.5 - point.position.y) * pixels_per_unit.y,
+        );
+        let radius = (point.size * 0.5 * pixels_per_unit.min_element()).max(MIN_SPLAT_RADIUS_PX);
+        let sigma = radius * 0.5;
+        let reach = sigma * SPLAT_CUTOFF_SIGMAS;
+
+        let min_x = (center.x - reach).floor().max(0.0) as u32;
+        let max_x = ((center.x + reach).ceil() as i64).clamp(0, width as i64) as u32;
+        let min_y = (center.y - reach).floor().max(0.0) as u32;
+        let max_y = ((center.y + reach).ceil() as i64).clamp(0, height as i64) as u32;
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let offset = Vec2::new(x as f32 + 0.5, y as f32 + 0.5) - center;
+                let distance_sq = offset.length_squared();
+                if distance_sq > reach * reach {
+                    continue;
+                }
+                let weight = (-distance_sq / (2.0 * sigma * sigma)).exp();
+                let alpha = (color.alpha * weight).clamp(0.0, 1.0);
+                let pixel = &mut buffer[(y * width + x) as usize];
+                *pixel = pixel.lerp(rgb, alpha);
+            }
+        }
+    }
+
+    let mut image = Image::new_fill(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+
+    for (pixel, bytes) in buffer.iter().zip(image.data.chunks_exact_mut(4)) {
+        let srgb = Srgba::from(LinearRgba::rgb(pixel.x, pixel.y, pixel.z));
+        for (byte, channel) in bytes.iter_mut().zip([srgb.red, srgb.green, srgb.blue]) {
+            *byte = (channel.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+        bytes[3] = 255;
+    }
+
+    image
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(image: &Image, x: u32, y: u32) -> [u8; 4] {
+        let width = image.texture_descriptor.size.width;
+        let index = ((y * width + x) * 4) as usize;
+        image.data[index..index + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_snapshot_places_points_at_expected_pixels() {
+        let config = SnapshotConfig {
+            width: 64,
+            height: 36,
+            world_size: Vec2::new(1920.0, 1080.0),
+            background: Color::BLACK,
+        };
+        let points = [
+            SnapshotPoint {
+                position: Vec2::ZERO,
+                color: Color::srgb(1.0, 0.0, 0.0),
+                size: 120.0,
+            },
+            // Top-left quadrant: world (-480, 270) maps to pixel (16, 9)
+            SnapshotPoint {
+                position: Vec2::new(-480.0, 270.0),
+                color: Color::srgb(0.0, 0.0, 1.0),
+                size: 120.0,
+            },
+        ];
+
+        let image = render_snapshot(&points, &config);
+        assert_eq!(image.texture_descriptor.size.width, 64);
+        assert_eq!(image.texture_descriptor.size.height, 36);
+
+        let center = pixel(&image, 32, 18);
+        assert!(center[0] > 200, "center should be red, got {center:?}");
+        assert!(center[2] < 20);
+
+        let top_left = pixel(&image, 16, 9);
+        assert!(
+            top_left[2] > 200,
+            "top-left point should be blue, got {top_left:?}"
+        );
+        assert!(top_left[0] < 20);
+
+        // Far from every point the background is untouched
+        assert_eq!(pixel(&image, 60, 33), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_snapshot_ignores_transparent_and_offscreen_points() {
+        let config = SnapshotConfig {
+            width: 16,
+            height: 16,
+            world_size: Vec2::splat(160.0),
+            background: Color::BLACK,
+        };
+        let points = [
+            SnapshotPoint {
+                position: Vec2::ZERO,
+                color: Color::srgba(1.0, 1.0, 1.0, 0.0),
+                size: 40.0,
+            },
+            SnapshotPoint {
+                position: Vec2::new(5000.0, -5000.0),
+                color: Color::WHITE,
+                size: 40.0,
+            },
+        ];
+
+        let image = render_snapshot(&points, &config);
+        assert!(image.data.chunks_exact(4).all(|p| p == [0, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_collect_snapshot_points_skips_inactive() {
+        let mut world = World::new();
+        let spawn = |world: &mut World, active: bool, x: f32| {
+            world.spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active,
+                    lifetime_remaining_ms: 1000.0,
+                    lifetime_total_ms: 1000.0,
+                },
+                Transform::from_xyz(x, 0.0, 0.0),
+                Sprite {
+                    color: Color::WHITE,
+                    custom_size: Some(Vec2::splat(30.0)),
+                    ..default()
+                },
+            ));
+        };
+        spawn(&mut world, true, 10.0);
+        spawn(&mut world, false, 20.0);
+
+        let points = collect_snapshot_points(&mut world);
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].position, Vec2::new(10.0, 0.0));
+        assert_eq!(points[0].size, 30.0);
+    }
+}
@@ -2,20 +2,33 @@
 //! Purpose: Trail rendering system with exponential opacity decay for particle visualization
 //! Dependencies: components, resources, particle
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-
-use crate::components::{Particle, ParticleState, ParticleVisual, Trail, TrailRenderer, TrailSegment};
-use crate::intro::AppState;
-use crate::resources::MotionTiming;
+use bevy::render::mesh::PrimitiveTopology;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::view::NoFrustumCulling;
+
+use crate::components::{
+    BreadcrumbMarker, OrphanTrail, Particle, ParticleMotion, ParticleState, ParticleVisual,
+    Spawnable, Trail, TrailRenderer, TrailRibbon, TrailRibbonLink, TrailSegment,
+};
+use crate::particle::sample_turbulence_field;
+use crate::render_layers;
+use crate::resources::{experience_running, Intensity, PeaTexture};
+use crate::types::{in_fidget_state, TrailStyle};
 
 // =============================================================================
 // CONSTANTS
 // =============================================================================
 
-/// Number of segments in each trail's circular buffer.
+/// Default number of segments in each trail's circular buffer.
+///
+/// Per-particle lengths come from `TrailRenderer::max_segments`.
 pub const TRAIL_SEGMENTS: usize = 12;
 
-/// Duration for trail to fully fade out in milliseconds.
+/// Default duration for a trail to fully fade out in milliseconds.
+///
+/// Per-particle durations come from `TrailRenderer::fade_duration_ms`.
 pub const TRAIL_FADE_DURATION_MS: f32 = 1500.0;
 
 /// Base width of trails at the head (newest segment).
@@ -25,10 +38,176 @@ pub const TRAIL_BASE_WIDTH: f32 = 4.0;
 /// Width at segment n = base_width * taper_factor^n
 pub const TRAIL_TAPER_FACTOR: f32 = 0.7;
 
+/// Number of pre-allocated breadcrumb marker sprites.
+const BREADCRUMB_POOL_CAPACITY: usize = 2000;
+
+/// Number of pre-allocated orphan trail entities.
+pub const ORPHAN_TRAIL_POOL_CAPACITY: usize = 256;
+
+/// Segments at or below this opacity count as invisible.
+const VISIBLE_SEGMENT_OPACITY: f32 = 0.01;
+
+/// Consecutive ribbon points closer than this (world units) would form a
+/// degenerate quad, so the later one is skipped.
+const MIN_RIBBON_SEGMENT_SPACING: f32 = 0.5;
+
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Aggregate trail statistics for tuning fade duration and taper.
+///
+/// Recomputed each frame by `render_trails` from `get_trail_length` and
+/// `get_oldest_segment_age`.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TrailMetrics {
+    /// Number of active particles with trail rendering enabled
+    pub active_trails: u32,
+    /// Total visible segments across all trails
+    pub total_visible_segments: u32,
+    /// Average visible trail length in world units
+    pub average_length: f32,
+    /// Longest visible trail length in world units
+    pub max_length: f32,
+    /// Age of the oldest visible segment across all trails, in milliseconds
+    pub oldest_segment_age_ms: f32,
+    /// Number of detached trails still fading after their particle expired
+    pub orphan_trails: u32,
+}
+
+/// Configuration for `TrailStyle::Breadcrumbs` markers.
+#[derive(Resource, Debug, Clone)]
+pub struct BreadcrumbConfig {
+    /// Time between dropped markers per particle, in milliseconds
+    pub drop_interval_ms: f32,
+    /// How long each marker takes to fade out, in milliseconds
+    pub marker_lifetime_ms: f32,
+    /// Marker sprite size in world units
+    pub marker_size: f32,
+    /// Opacity of a freshly dropped marker (markers are faint by design)
+    pub marker_opacity: f32,
+}
+
+impl Default for BreadcrumbConfig {
+    fn default() -> Self {
+        Self {
+            drop_interval_ms: 120.0,
+            marker_lifetime_ms: 900.0,
+            marker_size: 10.0,
+            marker_opacity: 0.35,
+        }
+    }
+}
+
+/// Configuration for turbulence-driven trail shimmer.
+///
+/// Each recorded segment samples the particle's turbulence field; the width
+/// and opacity of the segment are nudged by that value so ribbons shimmer
+/// where the air is rough and stay clean where it is calm.
+#[derive(Resource, Debug, Clone)]
+pub struct TrailShimmerConfig {
+    /// Whether turbulence modulates trail segments
+    pub enabled: bool,
+    /// Maximum fractional width change at full turbulence
+    pub width_amount: f32,
+    /// Maximum fractional opacity change at full turbulence
+    pub opacity_amount: f32,
+}
+
+impl Default for TrailShimmerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            width_amount: 0.2,
+            opacity_amount: 0.1,
+        }
+    }
+}
+
+/// Lightweight sprite pool backing breadcrumb markers.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BreadcrumbPool {
+    /// Marker entities available for reuse
+    pub available_entities: Vec<Entity>,
+    /// Count of currently visible markers
+    pub active_count: u32,
+}
+
+/// Shared material for trail ribbon meshes; color comes from vertex colors.
+#[derive(Resource, Debug, Clone)]
+pub struct TrailRibbonMaterial(pub Handle<ColorMaterial>);
+
+/// Pool of entities that carry trails detached from expired particles.
+///
+/// When the pool is exhausted, expiring particles' trails vanish with them.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct OrphanTrailPool {
+    /// Orphan entities available for reuse
+    pub available_entities: Vec<Entity>,
+    /// Count of orphans currently fading
+    pub active_count: u32,
+}
+
+impl OrphanTrailPool {
+    /// Takes an orphan entity from the pool, if one is free.
+    pub fn acquire(&mut self) -> Option<Entity> {
+        let entity = self.available_entities.pop()?;
+        self.active_count += 1;
+        Some(entity)
+    }
+
+    /// Returns an orphan entity to the pool.
+    pub fn release(&mut self, entity: Entity) {
+        self.available_entities.push(entity);
+        self.active_count = self.active_count.saturating_sub(1);
+    }
+}
+
 // =============================================================================
 // HELPER FUNCTIONS
 // =============================================================================
 
+/// Returns true if any segment of the trail is still visible.
+#[must_use]
+pub fn trail_is_visible(trail: &Trail) -> bool {
+    trail
+        .iter_segments()
+        .any(|s| s.opacity > VISIBLE_SEGMENT_OPACITY)
+}
+
+/// Returns whether a breadcrumb should be dropped between two segment timestamps.
+///
+/// A marker is dropped each time the trail's head crosses a multiple of
+/// `interval_ms`, so the drop rate is independent of frame rate.
+///
+/// # Arguments
+/// * `previous_ms` - Timestamp of the previous trail segment
+/// * `current_ms` - Timestamp of the newest trail segment
+/// * `interval_ms` - Time between drops
+#[inline]
+#[must_use]
+pub fn breadcrumb_due(previous_ms: f32, current_ms: f32, interval_ms: f32) -> bool {
+    if interval_ms <= 0.0 {
+        return true;
+    }
+    (current_ms / interval_ms).floor() > (previous_ms / interval_ms).floor()
+}
+
+/// Converts a local turbulence sample into a signed shimmer value.
+///
+/// Magnitude follows the turbulence strength (capped at 1.0); the sign
+/// follows its horizontal direction so consecutive segments swell and thin.
+#[inline]
+#[must_use]
+pub fn trail_turbulence_modulation(turbulence: Vec2) -> f32 {
+    let magnitude = turbulence.length().min(1.0);
+    if turbulence.x < 0.0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
 /// Calculates the width of a trail segment based on its position in the trail.
 ///
 /// The trail tapers from the head (newest segment) to the tail (oldest segment).
@@ -46,6 +225,111 @@ pub fn calculate_trail_width(segment_index: usize, base_width: f32, taper_factor
     base_width * taper_factor.powi(segment_index as i32)
 }
 
+/// Vertex data for one trail ribbon, drawn as a triangle strip.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RibbonVertices {
+    /// Strip vertices, alternating left and right edges, oldest to newest
+    pub positions: Vec<[f32; 3]>,
+    /// Linear RGBA per vertex, alpha scaled by segment opacity
+    pub colors: Vec<[f32; 4]>,
+}
+
+impl RibbonVertices {
+    /// Returns true if there is at least one quad to draw.
+    #[must_use]
+    pub fn is_drawable(&self) -> bool {
+        self.positions.len() >= 4
+    }
+}
+
+/// Builds triangle-strip ribbon geometry from a trail, oldest to newest.
+///
+/// Walks the circular buffer from `head_index` so wraparound is handled,
+/// tapers width with `calculate_trail_width` (index 0 = newest), and scales
+/// `color`'s alpha by each segment's opacity. Invisible segments split the
+/// ribbon into runs joined by degenerate triangles, so a trail that fades
+/// back in does not bridge the gap. Points closer than
+/// `MIN_RIBBON_SEGMENT_SPACING` to their predecessor are skipped.
+#[must_use]
+pub fn build_trail_ribbon(trail: &Trail, renderer: &TrailRenderer, color: Color) -> RibbonVertices {
+    let mut runs: Vec<Vec<(Vec2, f32, f32)>> = vec![Vec::new()];
+    for (index, segment) in trail.iter_segments().enumerate() {
+        if segment.opacity <= VISIBLE_SEGMENT_OPACITY {
+            runs.push(Vec::new());
+            continue;
+        }
+        let width = calculate_trail_width(index, renderer.base_width, renderer.taper_factor);
+        if let Some(run) = runs.last_mut() {
+            run.push((segment.position, width, segment.opacity));
+        }
+    }
+
+    let base = color.to_linear();
+    let mut vertices = RibbonVertices::default();
+    // Runs were collected newest-first; emit them oldest-first
+    for mut run in runs.into_iter().rev() {
+        run.reverse();
+        run.dedup_by(|next, kept| next.0.distance(kept.0) < MIN_RIBBON_SEGMENT_SPACING);
+        if run.len() < 2 {
+            continue;
+        }
+
+        let stitch = vertices.positions.len();
+        for (i, &(position, width, opacity)) in run.iter().enumerate() {
+            let previous = run[i.saturating_sub(1)].0;
+            let next = run[(i + 1).min(run.len() - 1)].0;
+            let offset = (next - previous).normalize_or_zero().perp() * (width * 0.5);
+
+            let rgba = [base.red, base.green, base.blue, base.alpha * opacity];
+            vertices
+                .positions
+                .push((position + offset).extend(0.0).to_array());
+            vertices
+                .positions
+                .push((position - offset).extend(0.0).to_array());
+            vertices.colors.extend([rgba, rgba]);
+        }
+
+        // Join to the previous run with two degenerate triangles
+        if stitch > 0 {
+            let joined = [vertices.positions[stitch - 1], vertices.positions[stitch]];
+            let joined_colors = [vertices.colors[stitch - 1], vertices.colors[stitch]];
+            vertices.positions.splice(stitch..stitch, joined);
+            vertices.colors.splice(stitch..stitch, joined_colors);
+        }
+    }
+    vertices
+}
+
+/// Writes ribbon vertices into a triangle-strip mesh.
+fn write_ribbon_mesh(mesh: &mut Mesh, vertices: RibbonVertices) {
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices.positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, vertices.colors);
+}
+
+/// Calculates how strongly a particle at the given speed contributes to its trail.
+///
+/// Returns 0.0 at or below `min_speed`, 1.0 at `min_speed + fade_range` and above,
+/// with a smoothstep ramp in between so trails fade in and out as speed crosses
+/// the threshold. Keeps slow ambient drift from cluttering the screen.
+///
+/// # Arguments
+/// * `speed` - Current particle speed in world units per second
+/// * `min_speed` - Speed below which no visible trail is recorded
+/// * `fade_range` - Speed range above `min_speed` over which the trail fades in
+///
+/// # Returns
+/// Trail contribution factor between 0.0 and 1.0.
+#[inline]
+#[must_use]
+pub fn trail_speed_factor(speed: f32, min_speed: f32, fade_range: f32) -> f32 {
+    if fade_range <= 0.0 {
+        return if speed > min_speed { 1.0 } else { 0.0 };
+    }
+    let t = ((speed - min_speed) / fade_range).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
 /// Calculates the decay rate for exponential opacity decay.
 ///
 /// Given a target fade duration, computes the decay rate constant `k` such that
@@ -69,6 +353,23 @@ fn calculate_decay_rate(duration_ms: f32) -> f32 {
     }
 }
 
+/// Fades every segment of `trail` by one step of `dt_ms`.
+///
+/// Opacity reaches ~1% after `fade_duration_ms`; segments that drop below
+/// 0.001 are snapped to zero.
+pub fn decay_trail_segments(trail: &mut Trail, fade_duration_ms: f32, dt_ms: f32) {
+    let decay_factor = (-calculate_decay_rate(fade_duration_ms) * dt_ms).exp();
+    for segment in trail.active_segments_mut() {
+        // Apply exponential decay
+        segment.opacity *= decay_factor;
+
+        // Clamp to zero when effectively invisible (avoids denormals)
+        if segment.opacity < 0.001 {
+            segment.opacity = 0.0;
+        }
+    }
+}
+
 // =============================================================================
 // UPDATE SYSTEMS
 // =============================================================================
@@ -78,21 +379,43 @@ fn calculate_decay_rate(duration_ms: f32) -> f32 {
 /// This system runs after `integrate_particle_motion` to capture the new positions.
 /// For each active particle with a Trail component:
 /// 1. Gets the current world position from Transform
-/// 2. Creates a new TrailSegment with the position and full opacity
-/// 3. Pushes the segment to the Trail circular buffer (advancing head_index)
-/// 4. Sets the timestamp for age tracking
+/// 2. Scales opacity and width by `trail_speed_factor`, so slow particles
+///    record invisible segments and only energetic peas streak
+/// 3. Samples the turbulence field at the particle and, if shimmer is
+///    enabled, modulates width and opacity by it (see `TrailShimmerConfig`)
+/// 4. Pushes the segment to the Trail circular buffer (advancing head_index)
+/// 5. Sets the timestamp for age tracking
+///
+/// A trail whose capacity differs from `TrailRenderer::max_segments` (the
+/// particle was respawned from another source) is resized and cleared first.
+///
+/// Slow particles still push (zero-opacity) segments rather than skipping,
+/// so a trail that fades back in does not bridge a stale gap.
 ///
 /// # System Ordering
 /// - Stage: Update
 /// - After: integrate_particle_motion
 /// - Before: decay_trail_opacity
 pub fn update_trails(
-    mut query: Query<(&Transform, &ParticleState, &TrailRenderer, &mut Trail), With<Particle>>,
+    mut query: Query<
+        (
+            &Transform,
+            &ParticleState,
+            &ParticleMotion,
+            &TrailRenderer,
+            &mut Trail,
+        ),
+        With<Particle>,
+    >,
     time: Res<Time>,
+    intensity: Res<Intensity>,
+    shimmer: Res<TrailShimmerConfig>,
 ) {
-    let current_time_ms = time.elapsed_secs() * 1000.0;
+    let elapsed = time.elapsed_secs();
+    let current_time_ms = elapsed * 1000.0;
+    let act_turbulence = intensity.value;
 
-    for (transform, state, renderer, mut trail) in query.iter_mut() {
+    for (transform, state, motion, renderer, mut trail) in query.iter_mut() {
         // Skip inactive particles
         if !state.active {
             continue;
@@ -103,18 +426,40 @@ pub fn update_trails(
             continue;
         }
 
+        if trail.capacity != renderer.max_segments {
+            trail.set_capacity(renderer.max_segments);
+        }
+
         // Extract 2D position from transform
         let position = transform.translation.truncate();
 
+        // Fade the trail's contribution in/out around the speed threshold
+        let speed_factor = trail_speed_factor(
+            motion.velocity.length(),
+            renderer.min_speed,
+            renderer.speed_fade_range,
+        );
+
         // Calculate width for the head segment (index 0 = newest)
-        let width = calculate_trail_width(0, renderer.base_width, renderer.taper_factor);
+        let width =
+            calculate_trail_width(0, renderer.base_width, renderer.taper_factor) * speed_factor;
+
+        // Local turbulence from the same field that moves the particle
+        let turbulence_modulation = if shimmer.enabled {
+            let field = sample_turbulence_field(position, elapsed, motion.turbulence_seed);
+            trail_turbulence_modulation(field * act_turbulence)
+        } else {
+            0.0
+        };
 
         // Create new trail segment at current position
         let segment = TrailSegment {
             position,
-            opacity: 1.0,
-            width,
+            opacity: (speed_factor * (1.0 + shimmer.opacity_amount * turbulence_modulation))
+                .clamp(0.0, 1.0),
+            width: width * (1.0 + shimmer.width_amount * turbulence_modulation),
             timestamp_ms: current_time_ms,
+            turbulence_modulation,
         };
 
         // Push to circular buffer (this advances head_index)
@@ -125,46 +470,156 @@ pub fn update_trails(
 /// Applies exponential opacity decay to all trail segments.
 ///
 /// Uses the formula: opacity *= exp(-decay_rate * dt)
-/// where decay_rate is calculated to fade trails over TRAIL_FADE_DURATION_MS.
+/// where decay_rate fades each particle's trail over its
+/// `TrailRenderer::fade_duration_ms`, so sources can fade at different rates.
 ///
-/// The decay rate can optionally be derived from MotionTiming for synchronization
-/// with the overall experience timing, but defaults to TRAIL_FADE_DURATION_MS.
+/// Orphan trails detached from expired particles keep the fade duration
+/// they were detached with.
 ///
 /// # System Ordering
 /// - Stage: Update
 /// - After: update_trails
 /// - Before: render_trails
 pub fn decay_trail_opacity(
-    mut query: Query<&mut Trail, With<Particle>>,
+    mut trails: Query<(&mut Trail, &TrailRenderer), With<Particle>>,
+    mut orphans: Query<(&mut Trail, &OrphanTrail), Without<Particle>>,
     time: Res<Time>,
-    motion_timing: Option<Res<MotionTiming>>,
 ) {
     let dt_ms = time.delta_secs() * 1000.0;
 
-    // Calculate decay rate based on fade duration
-    // If MotionTiming is available, we could sync to its timing, but we use
-    // the constant TRAIL_FADE_DURATION_MS for consistent trail behavior
-    let fade_duration = if let Some(ref timing) = motion_timing {
-        // Use slow_transition_duration_ms as a reference but cap at our max
-        timing.slow_transition_duration_ms.min(TRAIL_FADE_DURATION_MS)
-    } else {
-        TRAIL_FADE_DURATION_MS
-    };
+    for (mut trail, renderer) in trails.iter_mut() {
+        decay_trail_segments(&mut trail, renderer.fade_duration_ms, dt_ms);
+    }
+    for (mut trail, orphan) in orphans.iter_mut() {
+        decay_trail_segments(&mut trail, orphan.renderer.fade_duration_ms, dt_ms);
+    }
+}
 
-    let decay_rate = calculate_decay_rate(fade_duration);
-    let decay_factor = (-decay_rate * dt_ms).exp();
+/// Returns orphan trails to their pool once they have fully faded.
+///
+/// # System Ordering
+/// - Stage: Update
+/// - After: decay_trail_opacity
+pub fn release_faded_orphan_trails(
+    mut orphans: Query<(Entity, &mut OrphanTrail, &mut Trail), Without<Particle>>,
+    mut pool: ResMut<OrphanTrailPool>,
+) {
+    for (entity, mut orphan, mut trail) in orphans.iter_mut() {
+        if orphan.active && !trail_is_visible(&trail) {
+            orphan.active = false;
+            reset_trail(&mut trail);
+            pool.release(entity);
+        }
+    }
+}
+
+/// Drops breadcrumb markers along trails that use `TrailStyle::Breadcrumbs`.
+///
+/// Reads the two newest `TrailSegment`s of each trail and, whenever the head
+/// crosses a `BreadcrumbConfig.drop_interval_ms` boundary, activates a pooled
+/// marker at the head position in the particle's current color. Segments that
+/// were recorded invisible (slow particles) drop nothing.
+///
+/// # System Ordering
+/// - Stage: Update
+/// - After: update_trails
+pub fn emit_breadcrumbs(
+    trails: Query<(&Trail, &TrailRenderer, &ParticleVisual, &ParticleState), With<Particle>>,
+    mut markers: Query<
+        (
+            &mut BreadcrumbMarker,
+            &mut Transform,
+            &mut Sprite,
+            &mut Visibility,
+        ),
+        Without<Particle>,
+    >,
+    mut pool: ResMut<BreadcrumbPool>,
+    config: Res<BreadcrumbConfig>,
+    pea_texture: Option<Res<PeaTexture>>,
+) {
+    for (trail, renderer, visual, state) in trails.iter() {
+        if !state.active || !renderer.enabled || renderer.style != TrailStyle::Breadcrumbs {
+            continue;
+        }
 
-    for mut trail in query.iter_mut() {
-        // Apply decay to all segments in the trail
-        for segment in trail.segments.iter_mut() {
-            // Apply exponential decay
-            segment.opacity *= decay_factor;
+        let mut segments = trail.iter_segments();
+        let (Some(head), Some(previous)) = (segments.next(), segments.next()) else {
+            continue;
+        };
 
-            // Clamp to zero when effectively invisible (avoids denormals)
-            if segment.opacity < 0.001 {
-                segment.opacity = 0.0;
-            }
+        if head.opacity <= 0.01
+            || !breadcrumb_due(
+                previous.timestamp_ms,
+                head.timestamp_ms,
+                config.drop_interval_ms,
+            )
+        {
+            continue;
+        }
+
+        let Some(entity) = pool.available_entities.pop() else {
+            // Pool exhausted - skip rather than allocate
+            return;
+        };
+
+        let Ok((mut marker, mut transform, mut sprite, mut visibility)) = markers.get_mut(entity)
+        else {
+            pool.available_entities.push(entity);
+            continue;
+        };
+
+        let opacity = config.marker_opacity * head.opacity;
+        *marker = BreadcrumbMarker {
+            active: true,
+            age_ms: 0.0,
+            lifetime_ms: config.marker_lifetime_ms,
+            initial_opacity: opacity,
+        };
+        transform.translation = head.position.extend(render_layers::BREADCRUMBS);
+        if let Some(ref texture) = pea_texture {
+            sprite.image = texture.handle.clone();
+        }
+        sprite.custom_size = Some(Vec2::splat(config.marker_size));
+        sprite.color = visual.current_color.with_alpha(opacity);
+        *visibility = Visibility::Visible;
+
+        pool.active_count += 1;
+    }
+}
+
+/// Fades active breadcrumb markers and returns expired ones to the pool.
+///
+/// Each marker fades linearly over its own lifetime, independent of the
+/// particle that dropped it.
+///
+/// # System Ordering
+/// - Stage: Update
+/// - After: emit_breadcrumbs
+pub fn update_breadcrumbs(
+    mut markers: Query<(Entity, &mut BreadcrumbMarker, &mut Sprite, &mut Visibility)>,
+    mut pool: ResMut<BreadcrumbPool>,
+    time: Res<Time>,
+) {
+    let dt_ms = time.delta_secs() * 1000.0;
+
+    for (entity, mut marker, mut sprite, mut visibility) in markers.iter_mut() {
+        if !marker.active {
+            continue;
+        }
+
+        marker.age_ms += dt_ms;
+
+        if marker.age_ms >= marker.lifetime_ms {
+            marker.active = false;
+            *visibility = Visibility::Hidden;
+            pool.available_entities.push(entity);
+            pool.active_count = pool.active_count.saturating_sub(1);
+            continue;
         }
+
+        let remaining = 1.0 - marker.age_ms / marker.lifetime_ms.max(1.0);
+        sprite.color.set_alpha(marker.initial_opacity * remaining);
     }
 }
 
@@ -172,36 +627,130 @@ pub fn decay_trail_opacity(
 // POST-UPDATE SYSTEMS
 // =============================================================================
 
-/// Placeholder system for trail rendering.
-///
-/// Actual mesh generation for trails is complex and requires:
-/// - Building triangle strip geometry from trail segments
-/// - Applying per-vertex colors with opacity
-/// - Managing GPU buffers for efficient updates
+/// Ribbon mesh outputs of `render_trails`, bundled to keep system arity low.
+#[derive(SystemParam)]
+pub struct TrailRibbonMeshes<'w, 's> {
+    commands: Commands<'w, 's>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    material: Option<Res<'w, TrailRibbonMaterial>>,
+    ribbons: Query<'w, 's, (&'static Mesh2d, &'static mut Visibility), With<TrailRibbon>>,
+}
+
+impl TrailRibbonMeshes<'_, '_> {
+    /// Uploads `vertices` to the particle's ribbon, creating the ribbon on first use.
+    ///
+    /// Ribbons with nothing to draw are hidden rather than despawned, so a
+    /// pooled particle reuses its mesh the next time it is spawned.
+    fn draw(&mut self, owner: Entity, link: Option<&TrailRibbonLink>, vertices: RibbonVertices) {
+        let drawable = vertices.is_drawable();
+        let Some(link) = link else {
+            if drawable {
+                self.spawn_ribbon(owner, vertices);
+            }
+            return;
+        };
+
+        let Ok((mesh, mut visibility)) = self.ribbons.get_mut(link.ribbon) else {
+            return;
+        };
+        visibility.set_if_neq(if drawable {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+        if drawable {
+            if let Some(mesh) = self.meshes.get_mut(&mesh.0) {
+                write_ribbon_mesh(mesh, vertices);
+            }
+        }
+    }
+
+    /// Spawns a ribbon entity for `owner` and links it to the particle.
+    fn spawn_ribbon(&mut self, owner: Entity, vertices: RibbonVertices) {
+        let Some(material) = &self.material else {
+            return;
+        };
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleStrip,
+            RenderAssetUsages::default(),
+        );
+        write_ribbon_mesh(&mut mesh, vertices);
+
+        let ribbon = self
+            .commands
+            .spawn((
+                TrailRibbon { owner },
+                Mesh2d(self.meshes.add(mesh)),
+                MeshMaterial2d(material.0.clone()),
+                Transform::from_xyz(0.0, 0.0, render_layers::TRAILS),
+                // The mesh is rebuilt every frame, so its startup bounds go stale
+                NoFrustumCulling,
+            ))
+            .id();
+        self.commands
+            .entity(owner)
+            .insert(TrailRibbonLink { ribbon });
+    }
+}
+
+/// Particle trails `render_trails` may draw as ribbons.
+type RibbonTrailQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Trail,
+        &'static TrailRenderer,
+        &'static ParticleVisual,
+        &'static ParticleState,
+        &'static Spawnable,
+        Option<&'static TrailRibbonLink>,
+    ),
+    With<Particle>,
+>;
+
+/// Draws particle trails as ribbon meshes and refreshes `TrailMetrics`.
 ///
-/// This placeholder system:
-/// - Respects TrailRenderer.enabled status
-/// - Can log trail data for debugging
-/// - Sets up the framework for future mesh generation
+/// Each active particle whose `SpawnSource::has_trail()` is true and whose
+/// `TrailRenderer` uses `TrailStyle::Ribbon` gets a `Mesh2d` triangle strip
+/// built by `build_trail_ribbon`, colored from `ParticleVisual.current_color`.
+/// Active orphan trails draw the same way from the renderer and color they
+/// were detached with, so a ribbon outlives its particle. Vertex buffers are
+/// rewritten every frame; ribbons with no visible trail are hidden.
 ///
-/// # Implementation Notes
-/// A full implementation would:
-/// 1. Create a Mesh with vertex positions forming a ribbon
-/// 2. Apply UV coordinates for texture mapping
-/// 3. Set vertex colors including opacity from segments
-/// 4. Update mesh buffers each frame (or use GPU instancing)
+/// Also aggregates per-trail measurements into `TrailMetrics`, counting
+/// fading orphan trails separately.
 ///
 /// # System Ordering
 /// - Stage: PostUpdate
 /// - After: decay_trail_opacity
 pub fn render_trails(
-    query: Query<(Entity, &Trail, &TrailRenderer, &ParticleVisual, &ParticleState), With<Particle>>,
+    query: RibbonTrailQuery,
+    orphans: Query<(Entity, &OrphanTrail, &Trail, Option<&TrailRibbonLink>), Without<Particle>>,
+    mut ribbons: TrailRibbonMeshes,
+    mut metrics: ResMut<TrailMetrics>,
+    time: Res<Time>,
 ) {
-    // Count active trails for potential debugging/metrics
-    let mut _active_trail_count = 0;
-    let mut _total_visible_segments = 0;
+    let current_time_ms = time.elapsed_secs() * 1000.0;
+
+    let mut active_trail_count = 0_u32;
+    let mut total_visible_segments = 0_u32;
+    let mut total_length = 0.0_f32;
+    let mut max_length = 0.0_f32;
+    let mut oldest_segment_age_ms = 0.0_f32;
+
+    for (entity, trail, renderer, visual, state, spawnable, link) in query.iter() {
+        let draws_ribbon = state.active
+            && renderer.enabled
+            && renderer.style == TrailStyle::Ribbon
+            && spawnable.spawn_source.has_trail();
+        let vertices = if draws_ribbon {
+            build_trail_ribbon(trail, renderer, visual.current_color)
+        } else {
+            RibbonVertices::default()
+        };
+        ribbons.draw(entity, link, vertices);
 
-    for (_entity, trail, renderer, _visual, state) in query.iter() {
         // Skip inactive particles
         if !state.active {
             continue;
@@ -212,42 +761,109 @@ pub fn render_trails(
             continue;
         }
 
-        _active_trail_count += 1;
+        active_trail_count += 1;
 
         // Count visible segments (opacity > threshold)
         for segment in trail.iter_segments() {
-            if segment.opacity > 0.01 {
-                _total_visible_segments += 1;
+            if segment.opacity > VISIBLE_SEGMENT_OPACITY {
+                total_visible_segments += 1;
             }
         }
 
-        // Note: Actual mesh generation would happen here
-        // The implementation would involve:
-        //
-        // 1. Build vertex positions:
-        //    - For each pair of adjacent segments, create a quad
-        //    - Calculate perpendicular vectors for ribbon width
-        //    - Apply segment width (with taper factor)
-        //
-        // 2. Build vertex colors:
-        //    - Blend particle visual color with segment opacity
-        //    - Apply gradient along trail length
-        //
-        // 3. Build indices for triangle strip
-        //
-        // 4. Update or create Mesh2d handle
-        //
-        // For now, trails are represented by the Trail component data
-        // and can be visualized through debug gizmos or custom rendering
-    }
-
-    // Debug logging (uncomment for development)
-    // if _active_trail_count > 0 {
-    //     debug!(
-    //         "Trails: {} active, {} visible segments",
-    //         _active_trail_count, _total_visible_segments
-    //     );
-    // }
+        let length = get_trail_length(trail);
+        total_length += length;
+        max_length = max_length.max(length);
+        oldest_segment_age_ms =
+            oldest_segment_age_ms.max(get_oldest_segment_age(trail, current_time_ms));
+    }
+
+    // Orphan trails fade on their own with the renderer and color they were detached with
+    let mut orphan_trail_count = 0_u32;
+    for (entity, orphan, trail, link) in orphans.iter() {
+        let vertices = if orphan.active
+            && orphan.renderer.enabled
+            && orphan.renderer.style == TrailStyle::Ribbon
+        {
+            build_trail_ribbon(trail, &orphan.renderer, orphan.color)
+        } else {
+            RibbonVertices::default()
+        };
+        ribbons.draw(entity, link, vertices);
+
+        if !orphan.active {
+            continue;
+        }
+        orphan_trail_count += 1;
+        total_visible_segments += trail
+            .iter_segments()
+            .filter(|s| s.opacity > VISIBLE_SEGMENT_OPACITY)
+            .count() as u32;
+    }
+
+    metrics.active_trails = active_trail_count;
+    metrics.orphan_trails = orphan_trail_count;
+    metrics.total_visible_segments = total_visible_segments;
+    metrics.average_length = if active_trail_count > 0 {
+        total_length / active_trail_count as f32
+    } else {
+        0.0
+    };
+    metrics.max_length = max_length;
+    metrics.oldest_segment_age_ms = oldest_segment_age_ms;
+}
+
+// =============================================================================
+// STARTUP SYSTEMS
+// =============================================================================
+
+/// Pre-allocates hidden breadcrumb marker sprites.
+///
+/// # System Ordering
+/// - Stage: Startup
+/// - After: load_pea_texture
+pub fn setup_breadcrumb_pool(mut commands: Commands, mut pool: ResMut<BreadcrumbPool>) {
+    pool.available_entities.clear();
+    pool.active_count = 0;
+
+    for _ in 0..BREADCRUMB_POOL_CAPACITY {
+        let entity = commands
+            .spawn((
+                BreadcrumbMarker::default(),
+                Sprite::default(),
+                Transform::from_xyz(0.0, 0.0, render_layers::BREADCRUMBS),
+                Visibility::Hidden,
+            ))
+            .id();
+        pool.available_entities.push(entity);
+    }
+}
+
+/// Creates the shared vertex-colored material for trail ribbons.
+///
+/// # System Ordering
+/// - Stage: Startup
+pub fn setup_trail_ribbon_material(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let handle = materials.add(ColorMaterial::from(Color::WHITE));
+    commands.insert_resource(TrailRibbonMaterial(handle));
+}
+
+/// Pre-allocates inactive orphan trail entities.
+///
+/// # System Ordering
+/// - Stage: Startup
+pub fn setup_orphan_trail_pool(mut commands: Commands, mut pool: ResMut<OrphanTrailPool>) {
+    pool.available_entities.clear();
+    pool.active_count = 0;
+
+    for _ in 0..ORPHAN_TRAIL_POOL_CAPACITY {
+        let entity = commands
+            .spawn((OrphanTrail::default(), Trail::default()))
+            .id();
+        pool.available_entities.push(entity);
+    }
 }
 
 // =============================================================================
@@ -282,22 +898,22 @@ pub fn reset_trail(trail: &mut Trail) {
 /// # Returns
 /// The total length of visible trail segments in world units.
 pub fn get_trail_length(trail: &Trail) -> f32 {
-    let segments: Vec<&TrailSegment> = trail
-        .iter_segments()
-        .filter(|s| s.opacity > 0.01)
-        .collect();
-
-    if segments.len() < 2 {
+    let mut visible = trail.iter_segments().filter(|s| s.opacity > 0.01);
+    let Some(first) = visible.next() else {
         return 0.0;
-    }
-
-    let mut total_length = 0.0;
-    for window in segments.windows(2) {
-        let distance = window[0].position.distance(window[1].position);
-        total_length += distance;
-    }
+    };
 
-    total_length
+    visible
+        .fold(
+            (0.0, first.position),
+            |(total_length, previous), segment| {
+                (
+                    total_length + previous.distance(segment.position),
+                    segment.position,
+                )
+            },
+        )
+        .0
 }
 
 /// Gets the age of the oldest visible segment in milliseconds.
@@ -323,9 +939,12 @@ pub fn get_oldest_segment_age(trail: &Trail, current_time_ms: f32) -> f32 {
 /// Plugin bundling trail rendering systems.
 ///
 /// Registers the following systems:
+/// - Startup: setup_breadcrumb_pool, setup_orphan_trail_pool, setup_trail_ribbon_material
 /// - Update: update_trails (after integrate_particle_motion)
+/// - Update: emit_breadcrumbs, update_breadcrumbs (after update_trails)
 /// - Update: decay_trail_opacity (after update_trails)
-/// - PostUpdate: render_trails
+/// - Update: release_faded_orphan_trails (after decay_trail_opacity)
+/// - PostUpdate: render_trails (ribbon meshes; also refreshes `TrailMetrics`)
 ///
 /// The TrailPlugin works in conjunction with the ParticlePlugin to provide
 /// visual trails that follow particle movement with exponential opacity decay.
@@ -333,22 +952,36 @@ pub struct TrailPlugin;
 
 impl Plugin for TrailPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                update_trails,
-                decay_trail_opacity,
+        app.init_resource::<TrailMetrics>()
+            .init_resource::<BreadcrumbConfig>()
+            .init_resource::<BreadcrumbPool>()
+            .init_resource::<TrailShimmerConfig>()
+            .init_resource::<OrphanTrailPool>()
+            .add_systems(
+                Startup,
+                (
+                    setup_breadcrumb_pool.after(crate::particle::load_pea_texture),
+                    setup_orphan_trail_pool,
+                    setup_trail_ribbon_material,
+                ),
             )
-                .chain()
-                // These systems should run after particle motion is integrated
-                // The particle module's integrate_particle_motion runs in Update
-                .after(crate::particle::integrate_particle_motion)
-                .run_if(in_state(AppState::Fidget)),
-        )
-        .add_systems(
-            PostUpdate,
-            render_trails.run_if(in_state(AppState::Fidget)),
-        );
+            .add_systems(
+                Update,
+                (
+                    update_trails,
+                    emit_breadcrumbs,
+                    update_breadcrumbs,
+                    decay_trail_opacity,
+                    release_faded_orphan_trails,
+                )
+                    .chain()
+                    // These systems should run after particle motion is integrated
+                    // The particle module's integrate_particle_motion runs in Update
+                    .after(crate::particle::integrate_particle_motion)
+                    .run_if(in_fidget_state)
+                    .run_if(experience_running),
+            )
+            .add_systems(PostUpdate, render_trails.run_if(in_fidget_state));
     }
 }
 
@@ -359,6 +992,8 @@ impl Plugin for TrailPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{Act, SpawnSource};
+    use bevy::render::mesh::VertexAttributeValues;
 
     #[test]
     fn test_trail_constants() {
@@ -449,12 +1084,14 @@ mod tests {
             opacity: 0.8,
             width: 4.0,
             timestamp_ms: 100.0,
+            turbulence_modulation: 0.0,
         });
         trail.push_segment(TrailSegment {
             position: Vec2::new(30.0, 40.0),
             opacity: 0.6,
             width: 3.0,
             timestamp_ms: 200.0,
+            turbulence_modulation: 0.0,
         });
 
         // Reset the trail
@@ -483,18 +1120,100 @@ mod tests {
             opacity: 1.0,
             width: 4.0,
             timestamp_ms: 100.0,
+            turbulence_modulation: 0.0,
         });
         trail.push_segment(TrailSegment {
             position: Vec2::new(3.0, 4.0), // Distance 5 from origin
             opacity: 1.0,
             width: 3.0,
             timestamp_ms: 200.0,
+            turbulence_modulation: 0.0,
         });
 
         let length = get_trail_length(&trail);
         assert!((length - 5.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_get_trail_length_fixture() {
+        let mut trail = Trail::default();
+
+        // Right-angle path: (0,0) -> (30,0) -> (30,40) -> (0,40)
+        let positions = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(30.0, 0.0),
+            Vec2::new(30.0, 40.0),
+            Vec2::new(0.0, 40.0),
+        ];
+        for (i, position) in positions.iter().enumerate() {
+            trail.push_segment(TrailSegment {
+                position: *position,
+                opacity: 1.0,
+                width: 4.0,
+                timestamp_ms: 100.0 * (i + 1) as f32,
+                turbulence_modulation: 0.0,
+            });
+        }
+
+        let length = get_trail_length(&trail);
+        assert!(
+            (length - 100.0).abs() < 0.001,
+            "Expected 100, got {}",
+            length
+        );
+    }
+
+    #[test]
+    fn test_render_trails_aggregates_metrics() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<TrailMetrics>()
+            .insert_resource(TrailRibbonMaterial(Handle::default()))
+            .add_systems(PostUpdate, render_trails);
+
+        // A 100-unit right-angle path and a 40-unit straight one
+        let paths: [&[Vec2]; 2] = [
+            &[
+                Vec2::new(0.0, 0.0),
+                Vec2::new(30.0, 0.0),
+                Vec2::new(30.0, 40.0),
+                Vec2::new(0.0, 40.0),
+            ],
+            &[Vec2::new(0.0, 0.0), Vec2::new(40.0, 0.0)],
+        ];
+        for (id, path) in paths.iter().enumerate() {
+            let mut trail = Trail::default();
+            for position in path.iter() {
+                trail.push_segment(TrailSegment {
+                    position: *position,
+                    opacity: 1.0,
+                    ..default()
+                });
+            }
+            app.world_mut().spawn((
+                Particle { id: id as u32 },
+                ParticleState {
+                    active: true,
+                    ..default()
+                },
+                ParticleVisual::default(),
+                TrailRenderer::default(),
+                Spawnable {
+                    spawn_source: SpawnSource::Mouse,
+                },
+                trail,
+            ));
+        }
+        app.update();
+
+        let metrics = app.world().resource::<TrailMetrics>();
+        assert_eq!(metrics.active_trails, 2);
+        assert_eq!(metrics.total_visible_segments, 6);
+        assert!((metrics.max_length - 100.0).abs() < 1e-3);
+        assert!((metrics.average_length - 70.0).abs() < 1e-3);
+    }
+
     #[test]
     fn test_get_trail_length_ignores_invisible() {
         let mut trail = Trail::default();
@@ -505,6 +1224,7 @@ mod tests {
             opacity: 1.0,
             width: 4.0,
             timestamp_ms: 100.0,
+            turbulence_modulation: 0.0,
         });
 
         // Add an invisible segment (should be ignored)
@@ -513,6 +1233,7 @@ mod tests {
             opacity: 0.001, // Below visibility threshold
             width: 3.0,
             timestamp_ms: 200.0,
+            turbulence_modulation: 0.0,
         });
 
         // Only one visible segment, so length should be 0
@@ -533,12 +1254,14 @@ mod tests {
             opacity: 1.0,
             width: 4.0,
             timestamp_ms: 100.0,
+            turbulence_modulation: 0.0,
         });
         trail.push_segment(TrailSegment {
             position: Vec2::ZERO,
             opacity: 1.0,
             width: 3.0,
             timestamp_ms: 200.0,
+            turbulence_modulation: 0.0,
         });
 
         // At time 1000ms, oldest segment (100ms) should be 900ms old
@@ -565,6 +1288,483 @@ mod tests {
         assert!(opacity > 0.005);
     }
 
+    #[test]
+    fn test_trail_speed_factor() {
+        assert_eq!(trail_speed_factor(0.0, 80.0, 60.0), 0.0);
+        assert_eq!(trail_speed_factor(80.0, 80.0, 60.0), 0.0);
+        assert_eq!(trail_speed_factor(140.0, 80.0, 60.0), 1.0);
+        assert_eq!(trail_speed_factor(500.0, 80.0, 60.0), 1.0);
+
+        let mid = trail_speed_factor(110.0, 80.0, 60.0);
+        assert!((mid - 0.5).abs() < 0.001);
+
+        // Zero fade range acts as a hard threshold
+        assert_eq!(trail_speed_factor(79.0, 80.0, 0.0), 0.0);
+        assert_eq!(trail_speed_factor(81.0, 80.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_slow_particle_has_negligible_trail() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Intensity>()
+            .init_resource::<TrailShimmerConfig>()
+            .add_systems(Update, update_trails);
+
+        let spawn = |app: &mut App, speed: f32| {
+            app.world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active: true,
+                        lifetime_remaining_ms: 5000.0,
+                        lifetime_total_ms: 5000.0,
+                    },
+                    ParticleMotion {
+                        velocity: Vec2::new(speed, 0.0),
+                        ..default()
+                    },
+                    TrailRenderer::default(),
+                    Trail::default(),
+                    Transform::default(),
+                ))
+                .id()
+        };
+        let slow = spawn(&mut app, 20.0);
+        let fast = spawn(&mut app, 300.0);
+
+        // Move each particle along x by its speed over ten 0.1s steps
+        for step in 1..=10 {
+            for (entity, speed) in [(slow, 20.0), (fast, 300.0)] {
+                let mut transform = app.world_mut().get_mut::<Transform>(entity).unwrap();
+                transform.translation.x = speed * 0.1 * step as f32;
+            }
+            app.update();
+        }
+
+        let slow_trail = app.world().get::<Trail>(slow).unwrap();
+        let fast_trail = app.world().get::<Trail>(fast).unwrap();
+
+        assert_eq!(get_trail_length(slow_trail), 0.0);
+        assert!(get_trail_length(fast_trail) > 200.0);
+        assert!(
+            fast_trail
+                .iter_segments()
+                .filter(|s| s.opacity > 0.5)
+                .count()
+                >= 10
+        );
+    }
+
+    #[test]
+    fn test_turbulent_segments_carry_modulation() {
+        assert_eq!(trail_turbulence_modulation(Vec2::ZERO), 0.0);
+        let calm = trail_turbulence_modulation(Vec2::new(0.1, 0.05));
+        let rough = trail_turbulence_modulation(Vec2::new(-0.6, 0.5));
+        assert!(rough.abs() > calm.abs());
+        assert!(rough < 0.0);
+        assert_eq!(trail_turbulence_modulation(Vec2::new(3.0, 0.0)), 1.0);
+
+        // Crescendo (rough) vs Transcendence (calm) at the same field sample
+        let record = |act: Act, enabled: bool| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .insert_resource(Intensity::for_act(act))
+                .insert_resource(TrailShimmerConfig {
+                    enabled,
+                    ..Default::default()
+                })
+                .add_systems(Update, update_trails);
+            let particle = app
+                .world_mut()
+                .spawn((
+                    Particle { id: 0 },
+                    ParticleState {
+                        active: true,
+                        lifetime_remaining_ms: 5000.0,
+                        lifetime_total_ms: 5000.0,
+                    },
+                    ParticleMotion {
+                        velocity: Vec2::new(300.0, 0.0),
+                        turbulence_seed: 1.0,
+                        ..default()
+                    },
+                    TrailRenderer::default(),
+                    Trail::default(),
+                    Transform::from_xyz(40.0, 25.0, 0.0),
+                ))
+                .id();
+            app.update();
+            let segment = *app
+                .world()
+                .get::<Trail>(particle)
+                .unwrap()
+                .iter_segments()
+                .next()
+                .unwrap();
+            segment
+        };
+
+        let rough = record(Act::Crescendo, true);
+        let calm = record(Act::Transcendence, true);
+        let clean = record(Act::Crescendo, false);
+        assert!(rough.turbulence_modulation != 0.0);
+        assert!(rough.turbulence_modulation.abs() > calm.turbulence_modulation.abs());
+        assert_eq!(clean.turbulence_modulation, 0.0);
+        assert!(rough.width != clean.width, "shimmer should modulate width");
+    }
+
+    #[test]
+    fn test_longer_fade_duration_keeps_more_opacity() {
+        let full_trail = || {
+            let mut trail = Trail::with_capacity(4);
+            for _ in 0..4 {
+                trail.push_segment(TrailSegment {
+                    opacity: 1.0,
+                    ..default()
+                });
+            }
+            trail
+        };
+        let opacity_after_step = |fade_duration_ms: f32| {
+            let mut trail = full_trail();
+            decay_trail_segments(&mut trail, fade_duration_ms, 16.0);
+            let opacity = trail.iter_segments().next().unwrap().opacity;
+            opacity
+        };
+
+        let short = opacity_after_step(900.0);
+        let long = opacity_after_step(2200.0);
+        assert!(long > short, "{long} should exceed {short}");
+        assert!(short < 1.0 && long < 1.0);
+    }
+
+    #[test]
+    fn test_spawn_sources_get_their_trail_profiles() {
+        use crate::resources::TrailProfiles;
+        use crate::types::SpawnSource;
+
+        let profiles = TrailProfiles::default();
+        let mouse = profiles.for_source(SpawnSource::Mouse);
+        let beat = profiles.for_source(SpawnSource::Beat);
+        assert!(mouse.max_segments > beat.max_segments);
+        assert!(mouse.fade_duration_ms > beat.fade_duration_ms);
+        assert!(mouse.max_segments <= crate::components::MAX_TRAIL_SEGMENTS);
+        assert_eq!(
+            profiles.for_source(SpawnSource::Automatic).max_segments,
+            TrailRenderer::default().max_segments
+        );
+
+        // update_trails resizes a trail to its renderer's length
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Intensity>()
+            .init_resource::<TrailShimmerConfig>()
+            .add_systems(Update, update_trails);
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 5000.0,
+                    lifetime_total_ms: 5000.0,
+                },
+                ParticleMotion::default(),
+                TrailRenderer {
+                    max_segments: mouse.max_segments,
+                    ..default()
+                },
+                Trail::default(),
+                Transform::default(),
+            ))
+            .id();
+        app.update();
+        let trail = app.world().get::<Trail>(particle).unwrap();
+        assert_eq!(trail.capacity, mouse.max_segments);
+        assert_eq!(trail.iter_segments().count(), mouse.max_segments);
+    }
+
+    #[test]
+    fn test_expired_particle_leaves_fading_orphan_trail() {
+        use crate::resources::ParticlePool;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ParticlePool>()
+            .init_resource::<OrphanTrailPool>()
+            .add_systems(Startup, setup_orphan_trail_pool)
+            .add_systems(
+                Update,
+                (
+                    crate::particle::despawn_expired_particles,
+                    decay_trail_opacity,
+                    release_faded_orphan_trails,
+                )
+                    .chain(),
+            );
+
+        let mut trail = Trail::default();
+        for i in 0..4 {
+            trail.push_segment(TrailSegment {
+                position: Vec2::new(i as f32 * 10.0, 0.0),
+                opacity: 1.0,
+                width: TRAIL_BASE_WIDTH,
+                ..default()
+            });
+        }
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 0.0,
+                    lifetime_total_ms: 1000.0,
+                },
+                Visibility::Visible,
+                trail,
+            ))
+            .id();
+
+        let active_orphans = |app: &mut App| -> Vec<Trail> {
+            app.world_mut()
+                .query::<(&OrphanTrail, &Trail)>()
+                .iter(app.world())
+                .filter(|(orphan, _)| orphan.active)
+                .map(|(_, trail)| *trail)
+                .collect()
+        };
+
+        // Expiry hands the trail to an orphan and clears the particle's own copy
+        app.update();
+        let orphans = active_orphans(&mut app);
+        assert_eq!(orphans.len(), 1);
+        assert!(trail_is_visible(&orphans[0]));
+        assert!(!trail_is_visible(
+            app.world().get::<Trail>(particle).unwrap()
+        ));
+        assert_eq!(app.world().resource::<OrphanTrailPool>().active_count, 1);
+
+        // The orphan fades on its own
+        let opacity_of = |trail: &Trail| trail.iter_segments().next().unwrap().opacity;
+        let initial = opacity_of(&orphans[0]);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(200));
+        app.update();
+        let faded = opacity_of(&active_orphans(&mut app)[0]);
+        assert!(faded < initial);
+
+        // Once fully transparent it returns to the pool
+        for _ in 0..20 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+        }
+        assert!(active_orphans(&mut app).is_empty());
+        let pool = app.world().resource::<OrphanTrailPool>();
+        assert_eq!(pool.active_count, 0);
+        assert_eq!(pool.available_entities.len(), ORPHAN_TRAIL_POOL_CAPACITY);
+    }
+
+    #[test]
+    fn test_orphan_trail_draws_ribbon_after_particle_expires() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<TrailMetrics>()
+            .insert_resource(TrailRibbonMaterial(Handle::default()))
+            .add_systems(PostUpdate, render_trails);
+
+        let mut trail = Trail::default();
+        for i in 0..4 {
+            trail.push_segment(TrailSegment {
+                position: Vec2::new(i as f32 * 10.0, 0.0),
+                opacity: 1.0,
+                ..default()
+            });
+        }
+        let color = Color::srgb(0.9, 0.3, 0.1);
+        app.world_mut().spawn((
+            OrphanTrail {
+                active: true,
+                color,
+                ..default()
+            },
+            trail,
+        ));
+        app.update();
+
+        let mesh = app
+            .world_mut()
+            .query_filtered::<&Mesh2d, With<TrailRibbon>>()
+            .single(app.world())
+            .0
+            .clone();
+        let mesh = app.world().resource::<Assets<Mesh>>().get(&mesh).unwrap();
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        assert!(positions.len() >= 4, "orphan ribbon should have vertices");
+        let Some(VertexAttributeValues::Float32x4(colors)) = mesh.attribute(Mesh::ATTRIBUTE_COLOR)
+        else {
+            panic!("ribbon colors missing");
+        };
+        let expected = color.to_linear();
+        assert!((colors[0][0] - expected.red).abs() < 1e-5);
+        assert_eq!(app.world().resource::<TrailMetrics>().orphan_trails, 1);
+    }
+
+    #[test]
+    fn test_breadcrumb_due() {
+        assert!(breadcrumb_due(90.0, 110.0, 100.0));
+        assert!(!breadcrumb_due(110.0, 150.0, 100.0));
+        assert!(breadcrumb_due(150.0, 420.0, 100.0));
+    }
+
+    #[test]
+    fn test_breadcrumbs_emit_at_configured_interval() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(BreadcrumbConfig {
+                drop_interval_ms: 100.0,
+                marker_lifetime_ms: 10_000.0,
+                ..Default::default()
+            })
+            .init_resource::<BreadcrumbPool>()
+            .init_resource::<Intensity>()
+            .init_resource::<TrailShimmerConfig>()
+            .add_systems(Update, (update_trails, emit_breadcrumbs).chain());
+
+        for _ in 0..50 {
+            let entity = app
+                .world_mut()
+                .spawn((
+                    BreadcrumbMarker::default(),
+                    Sprite::default(),
+                    Transform::default(),
+                    Visibility::Hidden,
+                ))
+                .id();
+            app.world_mut()
+                .resource_mut::<BreadcrumbPool>()
+                .available_entities
+                .push(entity);
+        }
+
+        let particle = app
+            .world_mut()
+            .spawn((
+                Particle { id: 0 },
+                ParticleState {
+                    active: true,
+                    lifetime_remaining_ms: 5000.0,
+                    lifetime_total_ms: 5000.0,
+                },
+                ParticleMotion {
+                    velocity: Vec2::new(300.0, 0.0),
+                    ..default()
+                },
+                ParticleVisual::default(),
+                TrailRenderer {
+                    style: TrailStyle::Breadcrumbs,
+                    ..default()
+                },
+                Trail::default(),
+                Transform::default(),
+            ))
+            .id();
+
+        // Move for just over one second in 10ms steps
+        for step in 1..=105 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(10));
+            app.world_mut()
+                .get_mut::<Transform>(particle)
+                .unwrap()
+                .translation
+                .x = 3.0 * step as f32;
+            app.update();
+        }
+
+        // One drop per 100ms boundary crossed
+        let pool = app.world().resource::<BreadcrumbPool>();
+        assert_eq!(pool.active_count, 10);
+
+        // Markers are spaced along the path at the configured interval,
+        // give or take one 3-unit step of timestamp rounding
+        let mut xs: Vec<f32> = app
+            .world_mut()
+            .query::<(&BreadcrumbMarker, &Transform)>()
+            .iter(app.world())
+            .filter(|(marker, _)| marker.active)
+            .map(|(_, transform)| transform.translation.x)
+            .collect();
+        xs.sort_by(f32::total_cmp);
+        for pair in xs.windows(2) {
+            assert!((pair[1] - pair[0] - 30.0).abs() <= 3.01);
+        }
+    }
+
+    #[test]
+    fn test_ribbon_runs_oldest_to_newest_across_wraparound() {
+        let mut trail = Trail::new();
+        // 15 pushes wrap the 12-slot buffer; x grows with age order
+        for i in 0..15 {
+            trail.push_segment(TrailSegment {
+                position: Vec2::new(i as f32 * 10.0, 0.0),
+                opacity: 1.0,
+                width: 4.0,
+                timestamp_ms: i as f32,
+                turbulence_modulation: 0.0,
+            });
+        }
+        let renderer = TrailRenderer::default();
+        let ribbon = build_trail_ribbon(&trail, &renderer, Color::WHITE);
+
+        assert_eq!(ribbon.positions.len(), TRAIL_SEGMENTS * 2);
+        assert_eq!(ribbon.colors.len(), ribbon.positions.len());
+        let xs: Vec<f32> = ribbon.positions.iter().step_by(2).map(|p| p[0]).collect();
+        assert!(xs.windows(2).all(|pair| pair[0] < pair[1]), "{xs:?}");
+        assert_eq!(xs[0], 30.0);
+        assert_eq!(*xs.last().unwrap(), 140.0);
+
+        // The newest end is the widest
+        let half_width = |i: usize| (ribbon.positions[i][1] - ribbon.positions[i + 1][1]).abs();
+        assert!(half_width(ribbon.positions.len() - 2) > half_width(0));
+    }
+
+    #[test]
+    fn test_ribbon_skips_degenerate_points_and_fades_alpha() {
+        let mut trail = Trail::new();
+        let points = [(0.0, 0.2), (10.0, 0.5), (10.2, 0.6), (20.0, 1.0)];
+        for (x, opacity) in points {
+            trail.push_segment(TrailSegment {
+                position: Vec2::new(x, 0.0),
+                opacity,
+                ..Default::default()
+            });
+        }
+        let ribbon = build_trail_ribbon(&trail, &TrailRenderer::default(), Color::WHITE);
+
+        // The point 0.2 units past its predecessor is dropped
+        assert_eq!(ribbon.positions.len(), 6);
+        let alphas: Vec<f32> = ribbon.colors.iter().step_by(2).map(|c| c[3]).collect();
+        assert_eq!(alphas, vec![0.2, 0.5, 1.0]);
+
+        // A lone visible point draws nothing
+        let mut lone = Trail::new();
+        lone.push_segment(TrailSegment {
+            opacity: 1.0,
+            ..Default::default()
+        });
+        assert!(!build_trail_ribbon(&lone, &TrailRenderer::default(), Color::WHITE).is_drawable());
+    }
+
     #[test]
     fn test_trail_width_sequence() {
         // Verify width sequence is monotonically decreasing
@@ -3,6 +3,8 @@
 //! Dependencies: None (foundational module)
 
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use serde::{Deserialize, Serialize};
 
 // =============================================================================
 // ACT TIMING CONSTANTS
@@ -23,6 +25,9 @@ pub const ACT_BOUNDARIES_SECONDS: [f32; 6] = [0.0, 180.0, 420.0, 600.0, 780.0, 9
 /// Default transition duration between acts in milliseconds.
 pub const TRANSITION_DURATION_MS: f32 = 2000.0;
 
+/// Shortest experience an `ExperienceLength::Custom` may request, in seconds.
+pub const MIN_EXPERIENCE_SECONDS: f32 = 60.0;
+
 // =============================================================================
 // ACT ENUM
 // =============================================================================
@@ -91,17 +96,21 @@ impl Act {
     /// Returns `Transcendence` for times beyond the total duration.
     #[must_use]
     pub fn from_elapsed_seconds(elapsed: f32) -> Self {
-        if elapsed < ACT_BOUNDARIES_SECONDS[1] {
-            Act::Emergence
-        } else if elapsed < ACT_BOUNDARIES_SECONDS[2] {
-            Act::Accumulation
-        } else if elapsed < ACT_BOUNDARIES_SECONDS[3] {
-            Act::Crescendo
-        } else if elapsed < ACT_BOUNDARIES_SECONDS[4] {
-            Act::Release
-        } else {
-            Act::Transcendence
-        }
+        Self::from_elapsed_seconds_with(&ACT_BOUNDARIES_SECONDS, elapsed)
+    }
+
+    /// Returns the act for `elapsed` seconds against custom act boundaries.
+    ///
+    /// `boundaries` has the same layout as `ACT_BOUNDARIES_SECONDS`:
+    /// `[start, act2, act3, act4, act5, end]`. Times before the start map to
+    /// `Emergence` and times past the end to `Transcendence`.
+    #[must_use]
+    pub fn from_elapsed_seconds_with(boundaries: &[f32; 6], elapsed: f32) -> Self {
+        Act::all()
+            .into_iter()
+            .rev()
+            .find(|act| elapsed >= boundaries[act.index()])
+            .unwrap_or(Act::Emergence)
     }
 
     /// Returns the next act in sequence, or `None` if this is the final act.
@@ -141,6 +150,11 @@ impl Act {
     }
 
     /// Returns the default interaction mode for this act.
+    ///
+    /// This seeds `ActScene::interaction_mode`; an act opts into a mode that no
+    /// act uses by default (such as `InteractionMode::Vortex`) by overriding its
+    /// entry there. Cycling the mode (see `InteractionMode::CYCLE_ORDER`) also
+    /// reaches Vortex from any act.
     #[must_use]
     pub fn default_interaction_mode(&self) -> InteractionMode {
         match self {
@@ -181,6 +195,41 @@ impl Act {
             Act::Transcendence => "Act V: Transcendence",
         }
     }
+
+    /// Returns a one-line description of the act for display alongside its name.
+    ///
+    /// For the act's representative color see `ColorPalette::theme_color`.
+    #[must_use]
+    pub fn description(&self) -> &'static str {
+        match self {
+            Act::Emergence => "sparse peas drift out of the dark",
+            Act::Accumulation => "particles gather around you",
+            Act::Crescendo => "peak density, gravitational swarms",
+            Act::Release => "peas disperse upward and let go",
+            Act::Transcendence => "weightless light, peaceful dissolution",
+        }
+    }
+}
+
+/// Performs smooth ease-in-out-cubic interpolation.
+///
+/// This easing function provides a natural acceleration and deceleration
+/// curve for smooth visual transitions between acts.
+///
+/// # Arguments
+/// * `t` - Input value in range [0.0, 1.0]
+///
+/// # Returns
+/// Eased value in range [0.0, 1.0]
+#[inline]
+#[must_use]
+pub fn ease_in_out_cubic(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
 }
 
 // =============================================================================
@@ -191,7 +240,9 @@ impl Act {
 ///
 /// Each behavior type corresponds to an act's emotional quality,
 /// creating distinct visual signatures as the experience progresses.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component, Reflect)]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component, Reflect, Serialize, Deserialize,
+)]
 pub enum ParticleBehaviorType {
     /// Slow, aimless movement as particles discover the space.
     /// Characteristic of Act I: Emergence.
@@ -213,6 +264,11 @@ pub enum ParticleBehaviorType {
     /// Weightless, peaceful movement with minimal forces.
     /// Characteristic of Act V: Transcendence.
     Float,
+
+    /// Particles are carried along a divergence-free curl-noise current,
+    /// tracing smooth laminar streams instead of accumulating forces.
+    /// Not used by any act by default; select it through `ActScene`.
+    Flow,
 }
 
 impl ParticleBehaviorType {
@@ -225,6 +281,7 @@ impl ParticleBehaviorType {
             ParticleBehaviorType::Orbit => 1.0,
             ParticleBehaviorType::Disperse => 1.2,
             ParticleBehaviorType::Float => 0.4,
+            ParticleBehaviorType::Flow => 0.6,
         }
     }
 
@@ -237,6 +294,7 @@ impl ParticleBehaviorType {
             ParticleBehaviorType::Orbit => 0.92,
             ParticleBehaviorType::Disperse => 0.96,
             ParticleBehaviorType::Float => 0.99,
+            ParticleBehaviorType::Flow => 0.97,
         }
     }
 
@@ -249,10 +307,111 @@ impl ParticleBehaviorType {
             ParticleBehaviorType::Orbit => 0.2,
             ParticleBehaviorType::Disperse => 0.6,
             ParticleBehaviorType::Float => 0.4,
+            ParticleBehaviorType::Flow => 0.1,
+        }
+    }
+
+    /// Returns the force-kernel weights that produce this behavior.
+    #[must_use]
+    pub fn coefficients(&self) -> BehaviorCoefficients {
+        let zero = BehaviorCoefficients {
+            drag: self.base_drag(),
+            ..BehaviorCoefficients::ZERO
+        };
+        match self {
+            ParticleBehaviorType::Drift => BehaviorCoefficients {
+                wander: 30.0,
+                ..zero
+            },
+            ParticleBehaviorType::Swarm => BehaviorCoefficients {
+                cohesion: 1.0,
+                ..zero
+            },
+            ParticleBehaviorType::Orbit => BehaviorCoefficients {
+                centripetal: 40.0,
+                tangential: 60.0,
+                ..zero
+            },
+            ParticleBehaviorType::Disperse => BehaviorCoefficients {
+                lift: 50.0,
+                outward: 20.0,
+                ..zero
+            },
+            ParticleBehaviorType::Float => BehaviorCoefficients {
+                wander: 10.0,
+                lift: 5.0,
+                ..zero
+            },
+            ParticleBehaviorType::Flow => BehaviorCoefficients { flow: 1.0, ..zero },
+        }
+    }
+}
+
+// =============================================================================
+// BEHAVIOR COEFFICIENTS
+// =============================================================================
+
+/// Weights for the force kernels that make up particle behavior.
+///
+/// Each act's `ParticleBehaviorType` is one point in this space (see
+/// `ParticleBehaviorType::coefficients`); act transitions interpolate every
+/// weight so behaviors morph instead of switching at the midpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct BehaviorCoefficients {
+    /// Amplitude of the random-walk acceleration per axis
+    pub wander: f32,
+    /// Scale of the distance-weighted pull toward the target
+    pub cohesion: f32,
+    /// Constant pull toward the target when orbiting
+    pub centripetal: f32,
+    /// Acceleration perpendicular to the target direction (orbit spin)
+    pub tangential: f32,
+    /// Constant upward acceleration
+    pub lift: f32,
+    /// Acceleration away from the screen center
+    pub outward: f32,
+    /// How strongly velocity is steered onto the curl-noise flow field (0-1)
+    pub flow: f32,
+    /// Drag coefficient given to newly spawned particles
+    pub drag: f32,
+}
+
+impl BehaviorCoefficients {
+    /// All kernels off, with no drag.
+    pub const ZERO: Self = Self {
+        wander: 0.0,
+        cohesion: 0.0,
+        centripetal: 0.0,
+        tangential: 0.0,
+        lift: 0.0,
+        outward: 0.0,
+        flow: 0.0,
+        drag: 0.0,
+    };
+
+    /// Linearly interpolates every weight from `self` toward `other`.
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            wander: mix(self.wander, other.wander),
+            cohesion: mix(self.cohesion, other.cohesion),
+            centripetal: mix(self.centripetal, other.centripetal),
+            tangential: mix(self.tangential, other.tangential),
+            lift: mix(self.lift, other.lift),
+            outward: mix(self.outward, other.outward),
+            flow: mix(self.flow, other.flow),
+            drag: mix(self.drag, other.drag),
         }
     }
 }
 
+impl Default for BehaviorCoefficients {
+    fn default() -> Self {
+        ParticleBehaviorType::default().coefficients()
+    }
+}
+
 // =============================================================================
 // INTERACTION MODE ENUM
 // =============================================================================
@@ -261,7 +420,9 @@ impl ParticleBehaviorType {
 ///
 /// Each mode corresponds to an act, creating a distinct relationship
 /// between viewer and particles as the experience evolves.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component, Reflect)]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component, Reflect, Serialize, Deserialize,
+)]
 pub enum InteractionMode {
     /// Touch creates new particles at the cursor position.
     /// Used in Act I: Emergence.
@@ -283,6 +444,15 @@ pub enum InteractionMode {
     /// Creates gentle wave disturbances in the luminous field.
     /// Used in Act V: Transcendence.
     Ripple,
+
+    /// Cursor becomes a sink that fades out and removes particles.
+    /// Not tied to an act; enabled through `EraserOverride`.
+    Erase,
+
+    /// Particles swirl around the cursor while drifting slowly inward,
+    /// forming a galaxy around the pointer.
+    /// Not used by any act by default; selectable per act through `ActScene`.
+    Vortex,
 }
 
 impl InteractionMode {
@@ -295,6 +465,8 @@ impl InteractionMode {
             InteractionMode::Intensify => 0.5,
             InteractionMode::Disperse => -0.8,
             InteractionMode::Ripple => 0.3,
+            InteractionMode::Erase => 0.0,
+            InteractionMode::Vortex => 0.6,
         }
     }
 
@@ -309,6 +481,27 @@ impl InteractionMode {
     pub fn affects_visuals(&self) -> bool {
         matches!(self, InteractionMode::Intensify | InteractionMode::Ripple)
     }
+
+    /// The pointer modes in cycle order (`Erase` is not part of the cycle).
+    pub const CYCLE_ORDER: [InteractionMode; 6] = [
+        InteractionMode::Paint,
+        InteractionMode::Attract,
+        InteractionMode::Intensify,
+        InteractionMode::Disperse,
+        InteractionMode::Ripple,
+        InteractionMode::Vortex,
+    ];
+
+    /// Returns the mode `steps` places further along `CYCLE_ORDER`, wrapping.
+    ///
+    /// `Erase` is returned unchanged.
+    #[must_use]
+    pub fn cycled(&self, steps: usize) -> InteractionMode {
+        match Self::CYCLE_ORDER.iter().position(|mode| mode == self) {
+            Some(index) => Self::CYCLE_ORDER[(index + steps) % Self::CYCLE_ORDER.len()],
+            None => *self,
+        }
+    }
 }
 
 // =============================================================================
@@ -377,6 +570,19 @@ impl BeatStrength {
             BeatStrength::Strong
         }
     }
+
+    /// Parses a strength name (`silence`, `soft`, `medium`, `strong`),
+    /// ignoring case.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "silence" => Some(BeatStrength::Silence),
+            "soft" => Some(BeatStrength::Soft),
+            "medium" => Some(BeatStrength::Medium),
+            "strong" => Some(BeatStrength::Strong),
+            _ => None,
+        }
+    }
 }
 
 // =============================================================================
@@ -486,7 +692,9 @@ impl SpawnSource {
 ///
 /// Used for mouse influence radius calculations and other
 /// distance-based effect falloffs.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component, Reflect)]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component, Reflect, Serialize, Deserialize,
+)]
 pub enum FalloffType {
     /// Strength decreases linearly with distance: 1 - (d / max_d).
     Linear,
@@ -525,6 +733,291 @@ impl FalloffType {
     }
 }
 
+// =============================================================================
+// FADE PROFILE ENUM
+// =============================================================================
+
+/// Share of a particle's lifetime, at the end, over which it fades out.
+pub const FADE_OUT_FRACTION: f32 = 0.2;
+
+/// Shape of a particle's fade-out over the last `FADE_OUT_FRACTION` of its life.
+///
+/// Chosen per `SpawnSource` through `FadeProfiles`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum FadeProfile {
+    /// Opacity falls at a constant rate.
+    #[default]
+    Linear,
+
+    /// Opacity drops quickly once the fade starts, then lingers faintly.
+    EaseOut,
+
+    /// Opacity eases away gently, falls fastest mid-fade, and settles softly.
+    EaseInOut,
+}
+
+impl FadeProfile {
+    /// Returns the opacity multiplier for a particle with `lifetime_factor`
+    /// (remaining / total lifetime) left.
+    ///
+    /// 1.0 until the last `FADE_OUT_FRACTION` of the lifetime, then eased
+    /// down to 0.0 at expiry.
+    #[must_use]
+    pub fn fade_factor(&self, lifetime_factor: f32) -> f32 {
+        let remaining = (lifetime_factor / FADE_OUT_FRACTION).clamp(0.0, 1.0);
+
+        match self {
+            FadeProfile::Linear => remaining,
+            FadeProfile::EaseOut => remaining * remaining,
+            FadeProfile::EaseInOut => remaining * remaining * (3.0 - 2.0 * remaining),
+        }
+    }
+}
+
+// =============================================================================
+// PALETTE SCHEME ENUM
+// =============================================================================
+
+/// Hue relationship used when generating a palette from a seed.
+///
+/// Every scheme starts from a seeded base hue; accents are spread by the
+/// golden angle (~137.5 degrees) so they stay distinct from the scheme hues.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum PaletteScheme {
+    /// Neighbouring hues (base, +30, -30 degrees). Calm and cohesive.
+    #[default]
+    Analogous,
+
+    /// Base hue and its opposite (+180 degrees) with a split neighbour.
+    /// High contrast between primary and midpoint colors.
+    Complementary,
+
+    /// Three hues evenly spaced around the wheel (+120, +240 degrees).
+    /// Vibrant while still balanced.
+    Triadic,
+}
+
+impl PaletteScheme {
+    /// Returns the three scheme hue offsets in degrees from the base hue.
+    #[must_use]
+    pub fn hue_offsets(&self) -> [f32; 3] {
+        match self {
+            PaletteScheme::Analogous => [0.0, 30.0, -30.0],
+            PaletteScheme::Complementary => [0.0, 180.0, 30.0],
+            PaletteScheme::Triadic => [0.0, 120.0, 240.0],
+        }
+    }
+}
+
+// =============================================================================
+// TRAIL STYLE ENUM
+// =============================================================================
+
+/// Defines how a particle's trail history is drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component, Reflect)]
+pub enum TrailStyle {
+    /// Continuous tapered ribbon built from the trail segments.
+    #[default]
+    Ribbon,
+
+    /// Discrete fading dots dropped along the trail for a pointillist look.
+    Breadcrumbs,
+}
+
+// =============================================================================
+// COLOR LERP SPACE ENUM
+// =============================================================================
+
+/// Color space used when interpolating between two colors.
+///
+/// sRGB blends are cheap but muddy through their midpoints (blue to yellow
+/// passes through gray); OkLab and HSL keep transitions perceptually vivid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum ColorLerpSpace {
+    /// Per-channel blend of gamma-encoded sRGB values.
+    #[default]
+    Srgb,
+
+    /// Perceptually uniform OkLab blend.
+    Oklab,
+
+    /// Hue/saturation/lightness blend along the shortest hue path.
+    Hsl,
+}
+
+/// How bloom is composited over the scene.
+///
+/// Mirrors Bevy's `BloomCompositeMode` so act scenes can name it in RON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum BloomComposite {
+    /// Blends bloom in without adding energy; soft and natural.
+    #[default]
+    EnergyConserving,
+
+    /// Adds bloom on top of the scene; punchy and bright.
+    Additive,
+}
+
+/// Direction of the background gradient's mix from start to end color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum GradientStyle {
+    /// Bottom to top.
+    #[default]
+    Vertical,
+
+    /// Left to right.
+    Horizontal,
+
+    /// Bottom-left to top-right.
+    Diagonal,
+
+    /// Screen center outward.
+    Radial,
+}
+
+impl GradientStyle {
+    /// Returns the branch index read by `background_gradient.wgsl`.
+    #[must_use]
+    pub fn shader_index(self) -> u32 {
+        match self {
+            GradientStyle::Vertical => 0,
+            GradientStyle::Horizontal => 1,
+            GradientStyle::Diagonal => 2,
+            GradientStyle::Radial => 3,
+        }
+    }
+}
+
+/// Preset lengths for one pass of the experience.
+///
+/// Every preset keeps the act proportions of the full 15-minute arc and only
+/// rescales the boundaries; see `ActTimings::set_length`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+pub enum ExperienceLength {
+    /// A 5-minute loop for busy spaces.
+    Short,
+
+    /// The original 15-minute arc.
+    #[default]
+    Full,
+
+    /// A 30-minute arc for slow, ambient installations.
+    Extended,
+
+    /// Any length in seconds, clamped to at least `MIN_EXPERIENCE_SECONDS`.
+    Custom(f32),
+}
+
+impl ExperienceLength {
+    /// Returns the total length of one pass in seconds.
+    ///
+    /// Non-finite or too-short custom lengths fall back to the nearest valid
+    /// value (`Full` for non-finite, `MIN_EXPERIENCE_SECONDS` for short).
+    #[must_use]
+    pub fn total_seconds(&self) -> f32 {
+        match self {
+            ExperienceLength::Short => 300.0,
+            ExperienceLength::Full => TOTAL_DURATION_SECONDS,
+            ExperienceLength::Extended => 1800.0,
+            ExperienceLength::Custom(seconds) if seconds.is_finite() => {
+                seconds.max(MIN_EXPERIENCE_SECONDS)
+            }
+            ExperienceLength::Custom(_) => TOTAL_DURATION_SECONDS,
+        }
+    }
+}
+
+/// How particles are drawn, chosen once when the plugin is built.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum RenderMode {
+    /// One `Sprite` entity per pea; simple, but tops out around 10k active.
+    #[default]
+    Sprites,
+
+    /// Every active pea in a single instanced draw call (see `instancing`),
+    /// with the particle pool sized for 50k active.
+    Instanced,
+}
+
+/// Opacity multiplier of a lone pea under `BlendMode::Additive`.
+pub const ADDITIVE_BASE_GAIN: f32 = 0.35;
+
+/// Opacity multiplier of a pea at full `bloom_contribution` under `BlendMode::Additive`.
+pub const ADDITIVE_PEAK_GAIN: f32 = 0.7;
+
+/// How overlapping peas combine on screen.
+///
+/// Only `RenderMode::Instanced` can switch blend state, so `Additive`
+/// selects it even when `RenderMode::Sprites` was asked for. Under
+/// `Additive`, dense clusters accumulate into the bloom instead of occluding
+/// each other, which suits Crescendo's swarms. Each pea's opacity is scaled
+/// by `opacity_scale` so a cluster takes roughly three overlapping peas to
+/// saturate rather than going white at the first overlap.
+///
+/// Recommended bloom with `Additive`: raise `PostProcessSettings.bloom_threshold`
+/// to about 0.9 and lower `bloom_intensity` to about 0.2, so only real
+/// clusters bloom and the glow stays colored rather than clipping to white.
+#[derive(
+    Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize,
+)]
+pub enum BlendMode {
+    /// Peas occlude whatever they cover, weighted by their opacity.
+    #[default]
+    AlphaBlend,
+
+    /// Peas add their light to whatever they cover.
+    Additive,
+}
+
+impl BlendMode {
+    /// Returns the multiplier applied to a pea's opacity before blending.
+    ///
+    /// Always 1.0 for `AlphaBlend`. For `Additive`, rises from
+    /// `ADDITIVE_BASE_GAIN` to `ADDITIVE_PEAK_GAIN` with `bloom_contribution`,
+    /// so beat- and touch-charged peas still read brighter than the swarm.
+    #[must_use]
+    pub fn opacity_scale(self, bloom_contribution: f32) -> f32 {
+        match self {
+            BlendMode::AlphaBlend => 1.0,
+            BlendMode::Additive => {
+                let charge = bloom_contribution.clamp(0.0, 1.0);
+                ADDITIVE_BASE_GAIN + (ADDITIVE_PEAK_GAIN - ADDITIVE_BASE_GAIN) * charge
+            }
+        }
+    }
+}
+
+// =============================================================================
+// APP STATE
+// =============================================================================
+
+/// Application state controlling whether intro or fidget mode is active.
+///
+/// Registered by `IntroPlugin`; without it (the `intro` feature off, or a
+/// sub-plugin used on its own) the state may be absent entirely, which
+/// `in_fidget_state` treats as Fidget.
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AppState {
+    /// Intro sequence showing app information
+    #[default]
+    Intro,
+    /// Main fidget/visualizer experience
+    Fidget,
+}
+
+/// Condition function for run_if: returns true in `AppState::Fidget`, or when
+/// no `AppState` is registered so sub-plugins run without `IntroPlugin`.
+pub fn in_fidget_state(state: Option<Res<State<AppState>>>) -> bool {
+    state.is_none_or(|state| *state.get() == AppState::Fidget)
+}
+
+/// Condition function for run_if: returns false while `TimeUpdateStrategy`
+/// steps time manually (offline renders, headless runs), where the measured
+/// frame rate says nothing about the simulated frames.
+pub fn paced_by_real_time(strategy: Option<Res<TimeUpdateStrategy>>) -> bool {
+    strategy.is_none_or(|strategy| matches!(*strategy, TimeUpdateStrategy::Automatic))
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -533,6 +1026,30 @@ impl FalloffType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_act_descriptions() {
+        assert_eq!(
+            Act::Emergence.description(),
+            "sparse peas drift out of the dark"
+        );
+        assert_eq!(
+            Act::Accumulation.description(),
+            "particles gather around you"
+        );
+        assert_eq!(
+            Act::Crescendo.description(),
+            "peak density, gravitational swarms"
+        );
+        assert_eq!(
+            Act::Release.description(),
+            "peas disperse upward and let go"
+        );
+        assert_eq!(
+            Act::Transcendence.description(),
+            "weightless light, peaceful dissolution"
+        );
+    }
+
     #[test]
     fn test_act_timing() {
         assert_eq!(Act::Emergence.duration_seconds(), 180.0);
@@ -554,6 +1071,24 @@ mod tests {
         assert_eq!(Act::from_elapsed_seconds(600.0), Act::Release);
         assert_eq!(Act::from_elapsed_seconds(780.0), Act::Transcendence);
         assert_eq!(Act::from_elapsed_seconds(1000.0), Act::Transcendence);
+
+        let boundaries = [0.0, 24.0, 56.0, 80.0, 104.0, 120.0];
+        assert_eq!(
+            Act::from_elapsed_seconds_with(&boundaries, -1.0),
+            Act::Emergence
+        );
+        assert_eq!(
+            Act::from_elapsed_seconds_with(&boundaries, 23.9),
+            Act::Emergence
+        );
+        assert_eq!(
+            Act::from_elapsed_seconds_with(&boundaries, 56.0),
+            Act::Crescendo
+        );
+        assert_eq!(
+            Act::from_elapsed_seconds_with(&boundaries, 130.0),
+            Act::Transcendence
+        );
     }
 
     #[test]
@@ -580,6 +1115,44 @@ mod tests {
         assert_eq!(FalloffType::Quadratic.calculate(150.0, 100.0), 0.0);
     }
 
+    #[test]
+    fn test_fade_profile_shapes() {
+        let check = |profile: FadeProfile, lifetime_factor: f32, expected: f32| {
+            let actual = profile.fade_factor(lifetime_factor);
+            assert!(
+                (actual - expected).abs() < 1e-5,
+                "{profile:?} at {lifetime_factor}: {actual} != {expected}"
+            );
+        };
+
+        // Every shape is opaque before the fade window and clear at expiry
+        for profile in [
+            FadeProfile::Linear,
+            FadeProfile::EaseOut,
+            FadeProfile::EaseInOut,
+        ] {
+            check(profile, 1.0, 1.0);
+            check(profile, 0.5, 1.0);
+            check(profile, FADE_OUT_FRACTION, 1.0);
+            check(profile, 0.0, 0.0);
+        }
+
+        // Linear matches the original lifetime_factor / 0.2 fade
+        check(FadeProfile::Linear, 0.15, 0.75);
+        check(FadeProfile::Linear, 0.1, 0.5);
+        check(FadeProfile::Linear, 0.05, 0.25);
+
+        // Ease-out falls away faster
+        check(FadeProfile::EaseOut, 0.15, 0.5625);
+        check(FadeProfile::EaseOut, 0.1, 0.25);
+        check(FadeProfile::EaseOut, 0.05, 0.0625);
+
+        // Ease-in-out holds on, crosses half at mid-fade, then settles
+        check(FadeProfile::EaseInOut, 0.15, 0.84375);
+        check(FadeProfile::EaseInOut, 0.1, 0.5);
+        check(FadeProfile::EaseInOut, 0.05, 0.15625);
+    }
+
     #[test]
     fn test_beat_strength_from_amplitude() {
         assert_eq!(BeatStrength::from_amplitude(0.0), BeatStrength::Silence);
@@ -598,4 +1171,28 @@ mod tests {
             assert_eq!(max, min, "Frequency bands should be contiguous");
         }
     }
+
+    #[test]
+    fn test_additive_opacity_scale_tracks_bloom_contribution() {
+        assert_eq!(BlendMode::AlphaBlend.opacity_scale(0.8), 1.0);
+        assert_eq!(BlendMode::Additive.opacity_scale(0.0), ADDITIVE_BASE_GAIN);
+        assert_eq!(BlendMode::Additive.opacity_scale(1.0), ADDITIVE_PEAK_GAIN);
+        assert_eq!(BlendMode::Additive.opacity_scale(4.0), ADDITIVE_PEAK_GAIN);
+        // A lone pea never reaches full brightness on its own
+        assert!(BlendMode::Additive.opacity_scale(1.0) < 1.0);
+    }
+
+    #[test]
+    fn test_in_fidget_state_treats_missing_state_as_fidget() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        assert!(world.run_system_once(in_fidget_state).unwrap());
+
+        world.insert_resource(State::new(AppState::Intro));
+        assert!(!world.run_system_once(in_fidget_state).unwrap());
+
+        world.insert_resource(State::new(AppState::Fidget));
+        assert!(world.run_system_once(in_fidget_state).unwrap());
+    }
 }
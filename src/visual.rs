@@ -2,14 +2,22 @@
 //! Purpose: Color management, camera setup, and background rendering for Chromatic Elegy
 //! Dependencies: types, resources, components, bevy::prelude
 
+use bevy::color::{Hsla, Mix, Oklaba};
 use bevy::prelude::*;
 use bevy::render::camera::ScalingMode;
-
-use crate::components::{BackgroundMarker, Particle, ParticleVisual};
-use crate::intro::AppState;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+use bevy::sprite::{Material2d, Material2dPlugin};
+use bevy::window::PrimaryWindow;
+
+#[cfg(feature = "acts")]
+use crate::act_management::ActScene;
+use crate::components::{BackgroundMarker, Particle, ParticleState, ParticleVisual, WhirledCamera};
+use crate::render_layers;
 use crate::resources::{
-    ActState, BackgroundGradients, CurrentBackground, InterpolatedActValues,
+    ActState, ActTimings, BackgroundGradients, ColorInterpolation, ColorPalette, CurrentBackground,
+    DisplayScale, InterpolatedActValues, PaletteConfig,
 };
+use crate::types::{in_fidget_state, Act, AppState, ColorLerpSpace};
 
 // =============================================================================
 // CONSTANTS
@@ -21,20 +29,218 @@ pub const VIEWPORT_WIDTH: f32 = 1920.0;
 /// Target viewport height for the experience.
 pub const VIEWPORT_HEIGHT: f32 = 1080.0;
 
+/// Size of the background quad: the viewport plus a 10% margin on each side,
+/// which bounds how far the camera can pan before an edge shows.
+pub const BACKGROUND_SIZE: Vec2 = Vec2::new(VIEWPORT_WIDTH * 1.2, VIEWPORT_HEIGHT * 1.2);
+
 /// Initial clear color matching Act I background (deep navy void).
 pub const INITIAL_CLEAR_COLOR: Color = Color::srgb(0.051, 0.051, 0.090);
 
-/// Background entity z-depth (far behind particles).
-const BACKGROUND_Z_DEPTH: f32 = -100.0;
+/// Default share of particle warmth driven by overall experience progress.
+const DEFAULT_TEMPERATURE_DRIFT_STRENGTH: f32 = 0.5;
+
+/// Largest blend toward the warm tint, reached at warmth 1.0.
+const MAX_WARM_TINT_BLEND: f32 = 0.3;
+
+/// Fewest active particles before auto-framing follows the swarm.
+const AUTO_FRAME_MIN_PARTICLES: usize = 8;
+
+/// Asset path of the background gradient fragment shader.
+const BACKGROUND_GRADIENT_SHADER_PATH: &str = "shaders/background_gradient.wgsl";
+
+/// Largest blend of the background toward white, reached at full pulse.
+const BACKGROUND_PULSE_MAX_BRIGHTEN: f32 = 0.1;
 
 /// Marker for the intro-phase background (despawned when entering Fidget).
 #[derive(Component)]
 struct IntroBackground;
 
+// =============================================================================
+// RESOURCES
+// =============================================================================
+
+/// Continuous cool-to-warm drift of the particle palette across the experience.
+///
+/// The per-act warmth steps at act boundaries; blending it with overall
+/// progress keeps the palette drifting warmer even within a single act.
+#[derive(Resource, Debug, Clone)]
+pub struct ColorTemperatureDrift {
+    /// Share of warmth taken from overall progress (0.0 = stepwise per-act only,
+    /// 1.0 = purely continuous)
+    pub strength: f32,
+}
+
+impl Default for ColorTemperatureDrift {
+    fn default() -> Self {
+        Self {
+            strength: DEFAULT_TEMPERATURE_DRIFT_STRENGTH,
+        }
+    }
+}
+
+/// Optional slow camera framing that keeps the active swarm near screen center.
+///
+/// The camera pans toward the particle centroid and zooms in slightly when
+/// the swarm is compact. Both are heavily damped so the motion is hard to
+/// notice, and both stay within the background's margin so no edge shows.
+#[derive(Resource, Debug, Clone)]
+pub struct AutoFrame {
+    /// Whether the camera follows the swarm
+    pub enabled: bool,
+    /// Fraction of the remaining pan covered per second
+    pub pan_rate: f32,
+    /// Fraction of the remaining zoom covered per second
+    pub zoom_rate: f32,
+    /// Largest camera offset from the origin per axis (world units)
+    pub max_offset: Vec2,
+    /// Smallest projection scale (most zoomed in); 1.0 disables zooming
+    pub min_scale: f32,
+    /// Share of the view the swarm's extent should fill when zoomed in
+    pub fill: f32,
+    /// Set while a manual zoom or pan owns the camera; auto-framing yields
+    pub manual_control: bool,
+}
+
+impl Default for AutoFrame {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pan_rate: 0.05,
+            zoom_rate: 0.03,
+            max_offset: Vec2::new(VIEWPORT_WIDTH, VIEWPORT_HEIGHT) * 0.08,
+            min_scale: 0.9,
+            fill: 0.8,
+            manual_control: false,
+        }
+    }
+}
+
+impl AutoFrame {
+    /// Returns the camera position and projection scale that frame a swarm.
+    ///
+    /// # Arguments
+    /// * `centroid` - Mean position of the active particles
+    /// * `half_extent` - Half the size of their bounding box
+    /// * `viewport` - Visible world area at scale 1.0
+    #[must_use]
+    pub fn target(&self, centroid: Vec2, half_extent: Vec2, viewport: Vec2) -> (Vec2, f32) {
+        let position = centroid.clamp(-self.max_offset, self.max_offset);
+        let wanted = viewport * self.fill.max(f32::EPSILON);
+        let scale = (half_extent * 2.0 / wanted).max_element();
+        (position, scale.clamp(self.min_scale.min(1.0), 1.0))
+    }
+}
+
+// =============================================================================
+// EVENTS
+// =============================================================================
+
+/// Replaces `ColorPalette` with the preset called `name` (see `ColorPalette::preset`).
+///
+/// "Default" restores the palette the app started with, which may be a
+/// generated or configured one rather than the built-in default.
+///
+/// New spawns pick the palette up immediately; particles already on screen
+/// keep the `base_color` they were spawned with.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct SwitchPalette {
+    /// Preset name, case-insensitive
+    pub name: String,
+}
+
+// =============================================================================
+// MATERIALS
+// =============================================================================
+
+/// Uniform block for `assets/shaders/background_gradient.wgsl`.
+///
+/// Field order and types mirror `BackgroundGradientUniform` in the shader.
+/// Colors are linear RGBA.
+#[derive(ShaderType, Debug, Clone, Copy, PartialEq, Default)]
+pub struct BackgroundGradientUniform {
+    /// Start color (bottom, left, bottom-left, or center)
+    pub gradient_start: Vec4,
+    /// End color (top, right, top-right, or edges)
+    pub gradient_end: Vec4,
+    /// Uniform blend toward white from the audio pulse
+    pub brighten: f32,
+    /// `GradientStyle::shader_index` of the gradient direction
+    pub style: u32,
+    /// Padding for 16-byte alignment
+    pub _padding_b: f32,
+    /// Padding for 16-byte alignment
+    pub _padding_c: f32,
+}
+
+impl BackgroundGradientUniform {
+    /// Packs the current background into the shader uniform layout.
+    #[must_use]
+    pub fn from_background(background: &CurrentBackground) -> Self {
+        Self {
+            gradient_start: linear_vec4(background.gradient_start),
+            gradient_end: linear_vec4(background.gradient_end),
+            brighten: background.pulse_intensity.clamp(0.0, 1.0) * BACKGROUND_PULSE_MAX_BRIGHTEN,
+            style: background.style.shader_index(),
+            ..default()
+        }
+    }
+}
+
+/// Material for the full-screen background quad: a linear or radial gradient
+/// from `gradient_start` to `gradient_end` in the `CurrentBackground::style`
+/// direction (bottom to top by default).
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub struct BackgroundGradientMaterial {
+    /// Gradient colors and pulse, rewritten by `update_background_gradient`
+    #[uniform(0)]
+    pub uniform: BackgroundGradientUniform,
+}
+
+impl Material2d for BackgroundGradientMaterial {
+    fn fragment_shader() -> ShaderRef {
+        BACKGROUND_GRADIENT_SHADER_PATH.into()
+    }
+}
+
 // =============================================================================
 // HELPER FUNCTIONS
 // =============================================================================
 
+/// Returns a color's linear RGBA components as a `Vec4` for shader uniforms.
+#[inline]
+#[must_use]
+fn linear_vec4(color: Color) -> Vec4 {
+    let linear = color.to_linear();
+    Vec4::new(linear.red, linear.green, linear.blue, linear.alpha)
+}
+
+/// Returns the stepwise warmth (0.0 to 1.0) for a point within an act.
+///
+/// Early acts favor cooler tones, later acts warm toward cream.
+#[must_use]
+pub fn act_warmth(act: Act, act_progress: f32) -> f32 {
+    match act {
+        Act::Emergence => 0.0,
+        Act::Accumulation => 0.2 + act_progress * 0.1,
+        Act::Crescendo => 0.3 + act_progress * 0.2,
+        Act::Release => 0.5 + act_progress * 0.2,
+        Act::Transcendence => 0.7 + act_progress * 0.3,
+    }
+}
+
+/// Blends the per-act warmth with overall experience progress.
+///
+/// # Arguments
+/// * `act` - Current act
+/// * `act_progress` - Progress within the act (0.0 to 1.0)
+/// * `total_progress` - Progress through the whole experience (0.0 to 1.0)
+/// * `strength` - Share taken from `total_progress`, clamped to [0.0, 1.0]
+#[must_use]
+pub fn blended_warmth(act: Act, act_progress: f32, total_progress: f32, strength: f32) -> f32 {
+    let strength = strength.clamp(0.0, 1.0);
+    act_warmth(act, act_progress) * (1.0 - strength) + total_progress.clamp(0.0, 1.0) * strength
+}
+
 /// Linearly interpolates between two colors in sRGB space.
 ///
 /// This function provides smooth color transitions for act-based
@@ -70,6 +276,27 @@ pub fn color_lerp(a: Color, b: Color, t: f32) -> Color {
     )
 }
 
+/// Interpolates between two colors in the given color space.
+///
+/// Both colors are converted into `space`, blended, and converted back.
+/// `ColorLerpSpace::Srgb` is identical to [`color_lerp`].
+///
+/// # Arguments
+/// * `a` - Start color
+/// * `b` - End color
+/// * `t` - Interpolation factor, clamped to [0.0, 1.0]
+/// * `space` - Color space to blend in
+#[must_use]
+pub fn color_lerp_in(a: Color, b: Color, t: f32, space: ColorLerpSpace) -> Color {
+    let t = t.clamp(0.0, 1.0);
+
+    match space {
+        ColorLerpSpace::Srgb => color_lerp(a, b, t),
+        ColorLerpSpace::Oklab => Oklaba::from(a).mix(&Oklaba::from(b), t).into(),
+        ColorLerpSpace::Hsl => Hsla::from(a).mix(&Hsla::from(b), t).into(),
+    }
+}
+
 /// Adjusts the saturation of a color by a multiplier.
 ///
 /// Uses the luminance-preserving saturation adjustment method.
@@ -215,12 +442,52 @@ pub fn setup_camera(mut commands: Commands) {
             },
             ..OrthographicProjection::default_2d()
         },
+        WhirledCamera,
         Name::new("MainCamera"),
     ));
 
     info!("Camera setup complete");
 }
 
+/// Replaces the default palette with a seeded generated one when configured.
+///
+/// Writes both `ColorPalette` and `BackgroundGradients`, and with the `acts`
+/// feature mirrors the gradients into `ActScene` so the scene-driven
+/// gradient sync keeps them.
+///
+/// # Stage
+/// Startup
+///
+/// # Ordering
+/// Must run after `load_act_scene` (ordered by `ActManagementPlugin`) so a
+/// loaded scene doesn't override the generated gradients.
+pub fn apply_palette_config(
+    config: Res<PaletteConfig>,
+    mut palette: ResMut<ColorPalette>,
+    mut background_gradients: ResMut<BackgroundGradients>,
+    #[cfg(feature = "acts")] mut scene: ResMut<ActScene>,
+) {
+    if !config.generated {
+        return;
+    }
+
+    *palette = ColorPalette::generate(config.seed, config.scheme);
+    *background_gradients = BackgroundGradients::generate(config.seed, config.scheme);
+    #[cfg(feature = "acts")]
+    {
+        scene.gradients = background_gradients
+            .act_gradients
+            .iter()
+            .map(|pair| [color_to_hex(pair[0]), color_to_hex(pair[1])])
+            .collect();
+    }
+
+    info!(
+        "Generated {:?} palette from seed {}",
+        config.scheme, config.seed
+    );
+}
+
 /// Spawns an immediate solid-color background for the intro phase.
 /// This prevents any flash of content before the UI renders.
 /// Despawned when transitioning to Fidget state.
@@ -231,7 +498,7 @@ fn setup_intro_background(mut commands: Commands) {
             custom_size: Some(Vec2::new(VIEWPORT_WIDTH * 2.0, VIEWPORT_HEIGHT * 2.0)),
             ..default()
         },
-        Transform::from_xyz(0.0, 0.0, BACKGROUND_Z_DEPTH),
+        Transform::from_xyz(0.0, 0.0, render_layers::BACKGROUND),
         IntroBackground,
         Name::new("IntroBackground"),
     ));
@@ -249,7 +516,8 @@ fn cleanup_intro_background(
 
 /// Spawns the background entity with gradient visualization.
 ///
-/// Creates a full-screen sprite that displays the background gradient.
+/// Creates a full-screen quad with a `BackgroundGradientMaterial` that
+/// renders the background gradient.
 /// The gradient transitions between acts following the emotional arc
 /// from deep void (Act I) to luminous cream (Act V).
 ///
@@ -260,9 +528,17 @@ fn cleanup_intro_background(
 /// Must run after `setup_camera`.
 pub fn setup_background(
     mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<BackgroundGradientMaterial>>,
     background_gradients: Res<BackgroundGradients>,
     mut current_background: ResMut<CurrentBackground>,
+    existing_background: Query<(), With<BackgroundMarker>>,
 ) {
+    // Re-entering Fidget (e.g. after a kiosk intro replay) keeps the existing quad
+    if !existing_background.is_empty() {
+        return;
+    }
+
     info!("Setting up background gradient");
 
     // Initialize current background with Act I gradient
@@ -270,17 +546,16 @@ pub fn setup_background(
     current_background.gradient_start = act1_gradient[0];
     current_background.gradient_end = act1_gradient[1];
 
-    // Create the background sprite
-    // The sprite is sized to cover the viewport plus margin
-    let background_size = Vec2::new(VIEWPORT_WIDTH * 1.2, VIEWPORT_HEIGHT * 1.2);
+    // Create the background quad
+    // The quad is sized to cover the viewport plus margin
+    let material = BackgroundGradientMaterial {
+        uniform: BackgroundGradientUniform::from_background(&current_background),
+    };
 
     commands.spawn((
-        Sprite {
-            color: current_background.gradient_start,
-            custom_size: Some(background_size),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, BACKGROUND_Z_DEPTH),
+        Mesh2d(meshes.add(Rectangle::from_size(BACKGROUND_SIZE))),
+        MeshMaterial2d(materials.add(material)),
+        Transform::from_xyz(0.0, 0.0, render_layers::BACKGROUND),
         BackgroundMarker,
         Name::new("Background"),
     ));
@@ -300,10 +575,14 @@ pub fn setup_background(
 ///
 /// This system:
 /// - Queries all particles with `ParticleVisual`
-/// - Shifts `current_color` based on act progression
+/// - Shifts `current_color` warmer with `blended_warmth`, so the drift is
+///   continuous within acts as well as across them
 /// - Uses `InterpolatedActValues` for target colors and saturation
 /// - Applies `saturation_multiplier` for act-specific color intensity
 ///
+/// Every particle is recolored from its `base_color`, so freshly spawned
+/// particles pick up the current temperature on their first frame.
+///
 /// # Stage
 /// Update
 ///
@@ -313,35 +592,44 @@ pub fn apply_act_colors(
     mut particles: Query<&mut ParticleVisual, With<Particle>>,
     interpolated_values: Res<InterpolatedActValues>,
     act_state: Res<ActState>,
+    color_interpolation: Res<ColorInterpolation>,
+    temperature_drift: Res<ColorTemperatureDrift>,
+    act_timings: Res<ActTimings>,
 ) {
     // Skip processing if resources haven't changed to save performance
-    if !interpolated_values.is_changed() && !act_state.is_changed() {
+    if !interpolated_values.is_changed()
+        && !act_state.is_changed()
+        && !temperature_drift.is_changed()
+    {
         return;
     }
 
+    let space = color_interpolation.space;
+
     let saturation = interpolated_values.saturation_multiplier;
-    let act_progress = act_state.act_progress;
-
-    // Calculate target color shift based on act
-    // Early acts favor cooler tones, later acts warm toward cream
-    let warmth_factor = match act_state.current_act {
-        crate::types::Act::Emergence => 0.0,
-        crate::types::Act::Accumulation => 0.2 + act_progress * 0.1,
-        crate::types::Act::Crescendo => 0.3 + act_progress * 0.2,
-        crate::types::Act::Release => 0.5 + act_progress * 0.2,
-        crate::types::Act::Transcendence => 0.7 + act_progress * 0.3,
-    };
+    let warmth_factor = blended_warmth(
+        act_state.current_act,
+        act_state.act_progress,
+        act_timings.progress(act_state.total_elapsed_seconds),
+        temperature_drift.strength,
+    );
 
     // Define warmth colors for blending
     let warm_tint = Color::srgba(1.0, 0.95, 0.9, 0.0); // Subtle cream warmth
 
     for mut visual in particles.iter_mut() {
-        // Start with the base color
-        let base = visual.base_color;
+        // Start with the base color, keeping the audio-driven hue shift
+        let base = if visual.hue_shift_degrees != 0.0 {
+            let mut hsla = Hsla::from(visual.base_color);
+            hsla.hue = (hsla.hue + visual.hue_shift_degrees).rem_euclid(360.0);
+            Color::from(hsla)
+        } else {
+            visual.base_color
+        };
 
         // Apply warmth shift based on act progression
         let with_warmth = if warmth_factor > 0.0 {
-            color_lerp(base, warm_tint, warmth_factor * 0.3)
+            color_lerp_in(base, warm_tint, warmth_factor * MAX_WARM_TINT_BLEND, space)
         } else {
             base
         };
@@ -366,9 +654,11 @@ pub fn apply_act_colors(
 ///
 /// This system:
 /// - Queries the `BackgroundMarker` entity
-/// - Updates the sprite color with `CurrentBackground` values
-/// - Applies gradient from `gradient_start` to `gradient_end`
-/// - Smoothly blends colors during act transitions
+/// - Rewrites its material uniform from `CurrentBackground`, so the shader
+///   draws `gradient_start` to `gradient_end` in the current `GradientStyle`
+/// - Brightens the whole gradient with `pulse_intensity`
+///
+/// Colors already blend across act transitions in `CurrentBackground`.
 ///
 /// # Stage
 /// Update
@@ -376,7 +666,8 @@ pub fn apply_act_colors(
 /// # Ordering
 /// Runs after `interpolate_act_values`.
 pub fn update_background_gradient(
-    mut background_query: Query<&mut Sprite, With<BackgroundMarker>>,
+    background_query: Query<&MeshMaterial2d<BackgroundGradientMaterial>, With<BackgroundMarker>>,
+    mut materials: ResMut<Assets<BackgroundGradientMaterial>>,
     current_background: Res<CurrentBackground>,
 ) {
     // Only update if background changed
@@ -384,30 +675,121 @@ pub fn update_background_gradient(
         return;
     }
 
-    let Ok(mut sprite) = background_query.get_single_mut() else {
+    let Ok(handle) = background_query.get_single() else {
+        return;
+    };
+    let Some(material) = materials.get_mut(&handle.0) else {
         return;
     };
 
-    // For a simple sprite, we use the average of the gradient colors
-    // A more sophisticated approach would use a shader for true gradient rendering
-    let gradient_color = color_lerp(
-        current_background.gradient_start,
-        current_background.gradient_end,
-        0.5,
-    );
+    material.uniform = BackgroundGradientUniform::from_background(&current_background);
+}
 
-    // Apply pulse intensity as a subtle brightness modulation
-    let pulse = current_background.pulse_intensity;
-    if pulse > 0.001 {
-        let brightened = color_lerp(
-            gradient_color,
-            Color::WHITE,
-            pulse * 0.1, // Subtle 10% max brightness boost
-        );
-        sprite.color = brightened;
-    } else {
-        sprite.color = gradient_color;
+/// Returns the centroid and half bounding extent of a set of positions.
+///
+/// Folds the positions in one pass without collecting them. Returns `None`
+/// for fewer than `min_count` positions (and always for an empty set).
+#[must_use]
+pub fn swarm_bounds(
+    positions: impl IntoIterator<Item = Vec2>,
+    min_count: usize,
+) -> Option<(Vec2, Vec2)> {
+    let mut count = 0usize;
+    let mut sum = Vec2::ZERO;
+    let mut min = Vec2::splat(f32::INFINITY);
+    let mut max = Vec2::splat(f32::NEG_INFINITY);
+    for position in positions {
+        count += 1;
+        sum += position;
+        min = min.min(position);
+        max = max.max(position);
+    }
+
+    (count > 0 && count >= min_count).then(|| (sum / count as f32, (max - min) * 0.5))
+}
+
+/// The camera `auto_frame_camera` moves and zooms.
+type FramedCameraQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut Transform, &'static mut OrthographicProjection),
+    (With<WhirledCamera>, Without<Particle>),
+>;
+
+/// Eases the camera toward framing the active swarm.
+///
+/// Does nothing while `AutoFrame` is disabled, while `manual_control` is
+/// set, or while too few particles are active to define a swarm. A single
+/// pass over the active particles gives the centroid and extent.
+///
+/// # Stage
+/// Update
+pub fn auto_frame_camera(
+    config: Res<AutoFrame>,
+    time: Res<Time>,
+    display_scale: Res<DisplayScale>,
+    particles: Query<(&ParticleState, &Transform), With<Particle>>,
+    mut camera_query: FramedCameraQuery,
+) {
+    if !config.enabled || config.manual_control {
+        return;
+    }
+
+    let Ok((mut transform, mut projection)) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    let active = particles
+        .iter()
+        .filter(|(state, _)| state.active)
+        .map(|(_, transform)| transform.translation.truncate());
+    let Some((centroid, half_extent)) = swarm_bounds(active, AUTO_FRAME_MIN_PARTICLES) else {
+        return;
+    };
+
+    let (target_position, target_scale) =
+        config.target(centroid, half_extent, display_scale.world_viewport);
+
+    // Exponential damping keeps the approach frame-rate independent
+    let delta = time.delta_secs();
+    let pan_t = 1.0 - (-config.pan_rate * delta).exp();
+    let zoom_t = 1.0 - (-config.zoom_rate * delta).exp();
+
+    let position = transform
+        .translation
+        .truncate()
+        .lerp(target_position, pan_t);
+    transform.translation.x = position.x;
+    transform.translation.y = position.y;
+    projection.scale += (target_scale - projection.scale) * zoom_t;
+}
+
+/// Tracks the primary window's scale factor and size, and the camera's
+/// zoom, in `DisplayScale`.
+///
+/// Only writes when the values change, so dependent systems can use change
+/// detection.
+///
+/// # Stage
+/// PreUpdate
+///
+/// # Ordering
+/// Runs before `update_mouse_state`.
+pub fn update_display_scale(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<&OrthographicProjection, With<WhirledCamera>>,
+    mut display_scale: ResMut<DisplayScale>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+
+    let mut updated =
+        DisplayScale::from_window(window.scale_factor(), window.size(), VIEWPORT_HEIGHT);
+    if let Ok(projection) = camera_query.get_single() {
+        updated.camera_scale = projection.scale;
     }
+    display_scale.set_if_neq(updated);
 }
 
 /// Updates the camera clear color to match the current background.
@@ -421,7 +803,7 @@ pub fn update_background_gradient(
 /// # Ordering
 /// Runs after `update_background_gradient`.
 pub fn sync_camera_clear_color(
-    mut camera_query: Query<&mut Camera>,
+    mut camera_query: Query<&mut Camera, With<WhirledCamera>>,
     current_background: Res<CurrentBackground>,
 ) {
     if !current_background.is_changed() {
@@ -436,6 +818,62 @@ pub fn sync_camera_clear_color(
     camera.clear_color = ClearColorConfig::Custom(current_background.gradient_start);
 }
 
+/// Sends `SwitchPalette` for the next entry of `ColorPalette::PRESET_NAMES`
+/// when C is pressed, wrapping back to the starting palette.
+///
+/// # Stage
+/// Update
+///
+/// # Ordering
+/// Runs before `switch_palette`.
+pub fn cycle_palette_preset(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut preset_index: Local<usize>,
+    mut switches: EventWriter<SwitchPalette>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyC) {
+        return;
+    }
+
+    *preset_index = (*preset_index + 1) % ColorPalette::PRESET_NAMES.len();
+    switches.send(SwitchPalette {
+        name: ColorPalette::PRESET_NAMES[*preset_index].to_string(),
+    });
+}
+
+/// Replaces `ColorPalette` on `SwitchPalette`; unknown names are ignored
+/// with a warning.
+///
+/// The palette in place before the first switch is kept as the "Default"
+/// preset, so a generated or configured palette survives cycling.
+///
+/// # Stage
+/// Update
+pub fn switch_palette(
+    mut switches: EventReader<SwitchPalette>,
+    mut palette: ResMut<ColorPalette>,
+    mut starting_palette: Local<Option<ColorPalette>>,
+) {
+    for switch in switches.read() {
+        let starting_palette = starting_palette.get_or_insert_with(|| palette.clone());
+        let preset = if switch
+            .name
+            .eq_ignore_ascii_case(ColorPalette::PRESET_NAMES[0])
+        {
+            Some(starting_palette.clone())
+        } else {
+            ColorPalette::preset(&switch.name)
+        };
+        match preset {
+            Some(preset) => {
+                *palette = preset;
+                info!("Switched to {} palette", switch.name);
+            }
+            None => warn!("Unknown palette preset: {}", switch.name),
+        }
+    }
+}
+
 // =============================================================================
 // SYSTEM SETS
 // =============================================================================
@@ -457,23 +895,34 @@ pub struct VisualUpdateSet;
 /// This plugin handles:
 /// - Camera setup with proper viewport configuration
 /// - Background gradient entity creation
-/// - Act-based color modulation for particles
+/// - Act-based color modulation for particles, with continuous warm drift
 /// - Background gradient updates synchronized with act state
 ///
 /// # Systems
 /// - `setup_camera` (Startup): Spawns 2D camera
-/// - `setup_background` (Startup, after camera): Spawns background entity
+/// - `setup_background` (OnEnter Fidget): Spawns the gradient background quad
 /// - `apply_act_colors` (Update): Modulates particle colors per act
 /// - `update_background_gradient` (Update): Updates background gradient
 /// - `sync_camera_clear_color` (Update): Syncs camera clear color
+/// - `auto_frame_camera` (Update): Optionally eases the camera onto the swarm
+/// - `cycle_palette_preset` (Update): C key steps through palette presets
+/// - `switch_palette` (Update): Applies `SwitchPalette` events
 pub struct VisualPlugin;
 
 impl Plugin for VisualPlugin {
     fn build(&self, app: &mut App) {
         // Note: UiFont is loaded by ResourcesPlugin's load_ui_font system
-        app
+        app.add_plugins(Material2dPlugin::<BackgroundGradientMaterial>::default())
+            .init_resource::<ColorTemperatureDrift>()
+            .init_resource::<AutoFrame>()
+            .add_event::<SwitchPalette>()
             // Configure startup systems with ordering - intro background prevents flash
             .add_systems(Startup, (setup_camera, setup_intro_background).chain())
+            .add_systems(Startup, apply_palette_config)
+            .add_systems(
+                PreUpdate,
+                update_display_scale.before(crate::interaction::update_mouse_state),
+            )
             // Setup real background and cleanup intro background when entering Fidget
             .add_systems(
                 OnEnter(AppState::Fidget),
@@ -486,9 +935,11 @@ impl Plugin for VisualPlugin {
                     apply_act_colors,
                     update_background_gradient,
                     sync_camera_clear_color.after(update_background_gradient),
+                    auto_frame_camera,
                 )
-                    .run_if(in_state(AppState::Fidget)),
-            );
+                    .run_if(in_fidget_state),
+            )
+            .add_systems(Update, (cycle_palette_preset, switch_palette).chain());
 
         info!("VisualPlugin initialized");
     }
@@ -501,6 +952,136 @@ impl Plugin for VisualPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{GradientStyle, TOTAL_DURATION_SECONDS};
+
+    #[test]
+    fn test_c_key_cycles_palette_presets() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ColorPalette>()
+            .add_event::<SwitchPalette>()
+            .add_systems(Update, (cycle_palette_preset, switch_palette).chain());
+
+        let press_c = |app: &mut App| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(KeyCode::KeyC);
+            keyboard.clear();
+            keyboard.press(KeyCode::KeyC);
+            app.update();
+            app.world().resource::<ColorPalette>().clone()
+        };
+
+        assert_eq!(press_c(&mut app), ColorPalette::preset("Aurora").unwrap());
+        assert_eq!(press_c(&mut app), ColorPalette::preset("Ember").unwrap());
+        assert_eq!(
+            press_c(&mut app),
+            ColorPalette::preset("Monochrome").unwrap()
+        );
+        assert_eq!(press_c(&mut app), ColorPalette::default());
+
+        // Unknown names leave the palette alone
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.world_mut().send_event(SwitchPalette {
+            name: "Plaid".to_string(),
+        });
+        app.update();
+        assert_eq!(
+            *app.world().resource::<ColorPalette>(),
+            ColorPalette::default()
+        );
+    }
+
+    #[test]
+    fn test_cycling_back_to_default_keeps_generated_palette() {
+        let generated = ColorPalette::generate(7, crate::types::PaletteScheme::default());
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(generated.clone())
+            .add_event::<SwitchPalette>()
+            .add_systems(Update, (cycle_palette_preset, switch_palette).chain());
+
+        for _ in 0..ColorPalette::PRESET_NAMES.len() {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(KeyCode::KeyC);
+            keyboard.clear();
+            keyboard.press(KeyCode::KeyC);
+            app.update();
+        }
+
+        assert_eq!(*app.world().resource::<ColorPalette>(), generated);
+    }
+
+    #[test]
+    fn test_warmth_increases_monotonically_with_elapsed_time() {
+        let drift = ColorTemperatureDrift::default();
+        let warmth_at = |elapsed: f32| {
+            blended_warmth(
+                crate::act_at_time(elapsed),
+                crate::act_progress_at_time(elapsed),
+                crate::total_progress(elapsed),
+                drift.strength,
+            )
+        };
+
+        let mut previous = warmth_at(0.0);
+        let steps = 900;
+        for step in 1..steps {
+            let elapsed = TOTAL_DURATION_SECONDS * step as f32 / steps as f32;
+            let warmth = warmth_at(elapsed);
+            assert!(
+                warmth > previous,
+                "warmth fell at {elapsed}s: {previous} -> {warmth}"
+            );
+            previous = warmth;
+        }
+        assert!(previous <= 1.0);
+
+        // Within a single act the drift still moves even where the act value is flat
+        let emergence_end = Act::Emergence.duration_seconds() * 0.9;
+        assert_eq!(act_warmth(Act::Emergence, 0.9), 0.0);
+        assert!(warmth_at(emergence_end) > warmth_at(0.0));
+    }
+
+    #[test]
+    fn test_background_material_tracks_current_background() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<BackgroundGradientMaterial>>()
+            .init_resource::<BackgroundGradients>()
+            .init_resource::<CurrentBackground>()
+            .add_systems(Startup, setup_background)
+            .add_systems(Update, update_background_gradient);
+        app.update();
+
+        let bottom = Color::srgb(0.2, 0.1, 0.3);
+        let top = Color::srgb(0.9, 0.8, 0.7);
+        {
+            let mut background = app.world_mut().resource_mut::<CurrentBackground>();
+            background.gradient_start = bottom;
+            background.gradient_end = top;
+            background.style = GradientStyle::Radial;
+            background.pulse_intensity = 0.5;
+        }
+        app.update();
+
+        let mut query = app.world_mut().query_filtered::<
+            (&MeshMaterial2d<BackgroundGradientMaterial>, &Transform),
+            With<BackgroundMarker>,
+        >();
+        let (handle, transform) = query.single(app.world());
+        assert_eq!(transform.translation.z, render_layers::BACKGROUND);
+
+        let handle = handle.0.clone();
+        let materials = app.world().resource::<Assets<BackgroundGradientMaterial>>();
+        let uniform = materials.get(&handle).unwrap().uniform;
+        assert_eq!(uniform.gradient_start, linear_vec4(bottom));
+        assert_eq!(uniform.gradient_end, linear_vec4(top));
+        assert!((uniform.brighten - 0.05).abs() < 1e-6);
+        assert_eq!(uniform.style, GradientStyle::Radial.shader_index());
+        assert_ne!(uniform.gradient_start, uniform.gradient_end);
+    }
 
     #[test]
     fn test_color_lerp_endpoints() {
@@ -522,6 +1103,37 @@ mod tests {
         assert!((srgba.blue - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_color_lerp_in_oklab_midpoint() {
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        let green = Color::srgb(0.0, 1.0, 0.0);
+
+        // sRGB mode matches color_lerp exactly
+        let srgb_mid = color_lerp_in(red, green, 0.5, ColorLerpSpace::Srgb);
+        assert_eq!(srgb_mid.to_srgba(), color_lerp(red, green, 0.5).to_srgba());
+
+        // sRGB midpoint is the dark olive (0.5, 0.5, 0.0); OkLab stays bright
+        let oklab_mid = color_lerp_in(red, green, 0.5, ColorLerpSpace::Oklab);
+        let srgb_lightness = Oklaba::from(srgb_mid).lightness;
+        let oklab_lightness = Oklaba::from(oklab_mid).lightness;
+        assert!(oklab_lightness > srgb_lightness + 0.1);
+
+        // OkLab midpoint lightness is the average of the endpoints
+        let expected = (Oklaba::from(red).lightness + Oklaba::from(green).lightness) / 2.0;
+        assert!((oklab_lightness - expected).abs() < 0.001);
+
+        // Endpoints are preserved in every space
+        for space in [
+            ColorLerpSpace::Srgb,
+            ColorLerpSpace::Oklab,
+            ColorLerpSpace::Hsl,
+        ] {
+            let start = color_lerp_in(red, green, 0.0, space).to_srgba();
+            assert!((start.red - 1.0).abs() < 0.001);
+            assert!(start.green.abs() < 0.001);
+        }
+    }
+
     #[test]
     fn test_color_lerp_midpoint() {
         let black = Color::srgb(0.0, 0.0, 0.0);
@@ -639,6 +1251,76 @@ mod tests {
         assert_eq!(hex, "#ff000080");
     }
 
+    #[test]
+    fn test_auto_frame_pans_toward_off_center_swarm() {
+        use std::time::Duration;
+
+        let build = |manual_control: bool| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .init_resource::<DisplayScale>()
+                .insert_resource(AutoFrame {
+                    enabled: true,
+                    manual_control,
+                    ..Default::default()
+                })
+                .add_systems(Update, auto_frame_camera);
+            app.world_mut().spawn((
+                Transform::default(),
+                OrthographicProjection::default_2d(),
+                WhirledCamera,
+            ));
+            // A tight cluster up and to the right of center
+            for i in 0..20 {
+                app.world_mut().spawn((
+                    Particle { id: i },
+                    ParticleState {
+                        active: true,
+                        lifetime_remaining_ms: 1000.0,
+                        lifetime_total_ms: 1000.0,
+                    },
+                    Transform::from_xyz(400.0 + i as f32, 200.0 - i as f32, 0.0),
+                ));
+            }
+            app
+        };
+        let camera = |app: &mut App| {
+            let mut query = app
+                .world_mut()
+                .query_filtered::<(&Transform, &OrthographicProjection), With<WhirledCamera>>();
+            let (transform, projection) = query.single(app.world());
+            (transform.translation.truncate(), projection.scale)
+        };
+        let run = |app: &mut App| {
+            for _ in 0..10 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_secs(1));
+                app.update();
+            }
+        };
+
+        let mut app = build(false);
+        run(&mut app);
+        let (first, first_scale) = camera(&mut app);
+        assert!(
+            first.x > 0.0 && first.y > 0.0,
+            "camera should drift toward the swarm"
+        );
+        assert!(first_scale < 1.0, "a compact swarm should zoom in slightly");
+        run(&mut app);
+        let (second, _) = camera(&mut app);
+        assert!(second.x > first.x && second.y > first.y);
+        // Never beyond the background margin
+        let max_offset = AutoFrame::default().max_offset;
+        assert!(second.x <= max_offset.x && second.y <= max_offset.y);
+
+        // Manual zoom/pan owns the camera
+        let mut manual = build(true);
+        run(&mut manual);
+        assert_eq!(camera(&mut manual), (Vec2::ZERO, 1.0));
+    }
+
     #[test]
     fn test_viewport_constants() {
         assert_eq!(VIEWPORT_WIDTH, 1920.0);